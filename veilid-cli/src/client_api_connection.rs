@@ -25,6 +25,7 @@ struct ClientApiConnectionInner {
     disconnect_requested: bool,
     reply_channels: HashMap<u32, flume::Sender<json::JsonValue>>,
     next_req_id: u32,
+    routing_context_id: Option<u32>,
 }
 
 #[derive(Clone)]
@@ -42,6 +43,7 @@ impl ClientApiConnection {
                 disconnect_requested: false,
                 reply_channels: HashMap::new(),
                 next_req_id: 0,
+                routing_context_id: None,
             })),
         }
     }
@@ -58,6 +60,33 @@ impl ClientApiConnection {
         comproc.update_config(&state["config"]);
     }
 
+    /// Fetches the whole server state, returning false if it could not be retrieved
+    async fn request_state(&self) -> bool {
+        let mut req = json::JsonValue::new_object();
+        req["op"] = "GetState".into();
+        let Some(resp) = self.perform_request(req).await else {
+            error!("failed to get state");
+            return false;
+        };
+        if resp.has_key("error") {
+            error!("failed to get state: {}", resp["error"]);
+            return false;
+        }
+        self.process_veilid_state(&resp["value"]).await;
+        true
+    }
+
+    /// Reloads the server state into the command processor, if connected
+    pub fn refresh_state(&self) {
+        if self.inner.lock().request_sender.is_none() {
+            return;
+        }
+        let capi = self.clone();
+        spawn_detached_local("refresh server state", async move {
+            capi.request_state().await;
+        });
+    }
+
     async fn process_response(&self, response: json::JsonValue) {
         // find the operation id and send the response to the channel for it
         let Some(id) = response["id"].as_u32() else {
@@ -79,6 +108,7 @@ impl ClientApiConnection {
 
     async fn process_veilid_update(&self, update: json::JsonValue) {
         let comproc = self.inner.lock().comproc.clone();
+        comproc.update_received(&update);
         let Some(kind) = update["kind"].as_str() else {
             comproc.log_message(Level::Error, &format!("missing update kind: {}", update));
             return;
@@ -109,6 +139,9 @@ impl ClientApiConnection {
             "ValueChange" => {
                 comproc.update_value_change(&update);
             }
+            "OfflineWrite" => {
+                comproc.update_offline_write(&update);
+            }
             _ => {
                 comproc.log_message(Level::Error, &format!("unknown update kind: {}", update));
             }
@@ -188,17 +221,20 @@ impl ClientApiConnection {
         // Request initial server state
         let capi = self.clone();
         spawn_detached_local("get initial server state", async move {
-            let mut req = json::JsonValue::new_object();
-            req["op"] = "GetState".into();
-            let Some(resp) = capi.perform_request(req).await else {
-                error!("failed to get state");
-                return;
-            };
-            if resp.has_key("error") {
-                error!("failed to get state: {}", resp["error"]);
+            if !capi.request_state().await {
                 return;
             }
-            capi.process_veilid_state(&resp["value"]).await;
+
+            // Request the debug commands the server accepts, for command line completion
+            let mut request = json::JsonValue::new_object();
+            request["kind"] = "CommandSchema".into();
+            match capi.server_debug_request(request).await {
+                Ok(schema) => {
+                    let comproc = capi.inner.lock().comproc.clone();
+                    comproc.update_command_schema(&schema);
+                }
+                Err(e) => debug!("failed to get command schema: {}", e),
+            }
         });
 
         // Send and receive until we're done or a stop is requested
@@ -210,6 +246,7 @@ impl ClientApiConnection {
         inner.request_sender = None;
         inner.disconnector = None;
         inner.disconnect_requested = false;
+        inner.routing_context_id = None;
 
         // Connection finished
         if disconnect_requested {
@@ -416,6 +453,191 @@ impl ClientApiConnection {
         Ok(())
     }
 
+    pub async fn server_debug_request(
+        &self,
+        request: json::JsonValue,
+    ) -> Result<json::JsonValue, String> {
+        trace!("ClientApiConnection::server_debug_request");
+        let mut req = json::JsonValue::new_object();
+        req["op"] = "DebugRequest".into();
+        req["request"] = request;
+        let Some(resp) = self.perform_request(req).await else {
+            return Err("Cancelled".to_owned());
+        };
+        if resp.has_key("error") {
+            return Err(resp["error"].to_string());
+        }
+        Ok(resp["value"].clone())
+    }
+
+    pub async fn server_list_route_health(&self) -> Result<json::JsonValue, String> {
+        trace!("ClientApiConnection::server_list_route_health");
+        let mut req = json::JsonValue::new_object();
+        req["op"] = "ListRouteHealth".into();
+        let Some(resp) = self.perform_request(req).await else {
+            return Err("Cancelled".to_owned());
+        };
+        if resp.has_key("error") {
+            return Err(resp["error"].to_string());
+        }
+        Ok(resp["value"].clone())
+    }
+
+    pub async fn server_get_routing_table_info(&self) -> Result<json::JsonValue, String> {
+        trace!("ClientApiConnection::server_get_routing_table_info");
+        let mut req = json::JsonValue::new_object();
+        req["op"] = "GetRoutingTableInfo".into();
+        let Some(resp) = self.perform_request(req).await else {
+            return Err("Cancelled".to_owned());
+        };
+        if resp.has_key("error") {
+            return Err(resp["error"].to_string());
+        }
+        Ok(resp["value"].clone())
+    }
+
+    pub async fn server_release_private_route(&self, route_id: String) -> Result<(), String> {
+        trace!("ClientApiConnection::server_release_private_route");
+        let mut req = json::JsonValue::new_object();
+        req["op"] = "ReleasePrivateRoute".into();
+        req["route_id"] = route_id.into();
+        let Some(resp) = self.perform_request(req).await else {
+            return Err("Cancelled".to_owned());
+        };
+        if resp.has_key("error") {
+            return Err(resp["error"].to_string());
+        }
+        Ok(())
+    }
+
+    async fn routing_context_id(&self) -> Result<u32, String> {
+        if let Some(rc_id) = self.inner.lock().routing_context_id {
+            return Ok(rc_id);
+        }
+        let mut req = json::JsonValue::new_object();
+        req["op"] = "NewRoutingContext".into();
+        let Some(resp) = self.perform_request(req).await else {
+            return Err("Cancelled".to_owned());
+        };
+        if resp.has_key("error") {
+            return Err(resp["error"].to_string());
+        }
+        let Some(rc_id) = resp["value"].as_u32() else {
+            return Err(format!("invalid routing context id: {}", resp["value"]));
+        };
+        self.inner.lock().routing_context_id = Some(rc_id);
+        Ok(rc_id)
+    }
+
+    async fn perform_routing_context_request(
+        &self,
+        rc_op: &str,
+        mut req: json::JsonValue,
+    ) -> Result<json::JsonValue, String> {
+        let rc_id = self.routing_context_id().await?;
+        req["op"] = "RoutingContext".into();
+        req["rc_id"] = rc_id.into();
+        req["rc_op"] = rc_op.into();
+        let Some(resp) = self.perform_request(req).await else {
+            return Err("Cancelled".to_owned());
+        };
+        if resp.has_key("error") {
+            return Err(resp["error"].to_string());
+        }
+        Ok(resp["value"].clone())
+    }
+
+    pub async fn server_open_dht_record(
+        &self,
+        key: String,
+        writer: Option<String>,
+    ) -> Result<json::JsonValue, String> {
+        trace!("ClientApiConnection::server_open_dht_record");
+        let mut req = json::JsonValue::new_object();
+        req["key"] = key.into();
+        req["writer"] = writer.into();
+        self.perform_routing_context_request("OpenDhtRecord", req)
+            .await
+    }
+
+    pub async fn server_close_dht_record(&self, key: String) -> Result<(), String> {
+        trace!("ClientApiConnection::server_close_dht_record");
+        let mut req = json::JsonValue::new_object();
+        req["key"] = key.into();
+        self.perform_routing_context_request("CloseDhtRecord", req)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn server_inspect_dht_record(
+        &self,
+        key: String,
+        scope: String,
+    ) -> Result<json::JsonValue, String> {
+        trace!("ClientApiConnection::server_inspect_dht_record");
+        let mut req = json::JsonValue::new_object();
+        req["key"] = key.into();
+        req["subkeys"] = json::JsonValue::new_array();
+        req["scope"] = scope.into();
+        self.perform_routing_context_request("InspectDhtRecord", req)
+            .await
+    }
+
+    pub async fn server_get_dht_value(
+        &self,
+        key: String,
+        subkey: u32,
+        force_refresh: bool,
+    ) -> Result<json::JsonValue, String> {
+        trace!("ClientApiConnection::server_get_dht_value");
+        let mut req = json::JsonValue::new_object();
+        req["key"] = key.into();
+        req["subkey"] = subkey.into();
+        req["force_refresh"] = force_refresh.into();
+        self.perform_routing_context_request("GetDhtValue", req)
+            .await
+    }
+
+    pub async fn server_set_dht_value(
+        &self,
+        key: String,
+        subkey: u32,
+        data: Vec<u8>,
+    ) -> Result<json::JsonValue, String> {
+        trace!("ClientApiConnection::server_set_dht_value");
+        let mut req = json::JsonValue::new_object();
+        req["key"] = key.into();
+        req["subkey"] = subkey.into();
+        req["data"] = data_encoding::BASE64URL_NOPAD.encode(&data).into();
+        req["options"] = json::JsonValue::Null;
+        self.perform_routing_context_request("SetDhtValue", req)
+            .await
+    }
+
+    pub async fn server_watch_dht_values(&self, key: String) -> Result<u64, String> {
+        trace!("ClientApiConnection::server_watch_dht_values");
+        let mut req = json::JsonValue::new_object();
+        req["key"] = key.into();
+        req["subkeys"] = json::JsonValue::new_array();
+        req["expiration"] = "0".into();
+        req["count"] = u32::MAX.into();
+        let expiration = self
+            .perform_routing_context_request("WatchDhtValues", req)
+            .await?;
+        Ok(json_str_u64(&expiration))
+    }
+
+    pub async fn server_cancel_dht_watch(&self, key: String) -> Result<bool, String> {
+        trace!("ClientApiConnection::server_cancel_dht_watch");
+        let mut req = json::JsonValue::new_object();
+        req["key"] = key.into();
+        req["subkeys"] = json::JsonValue::new_array();
+        let still_active = self
+            .perform_routing_context_request("CancelDhtWatch", req)
+            .await?;
+        Ok(still_active.as_bool().unwrap_or_default())
+    }
+
     // Start Client API connection
     pub async fn ipc_connect(&self, ipc_path: PathBuf) -> Result<(), String> {
         trace!("ClientApiConnection::ipc_connect");
@@ -0,0 +1,467 @@
+use crate::command_processor::ATTACHMENT_STATES;
+
+/// The kind of value a command argument takes, as published by the server's command schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgKind {
+    Choice(Vec<String>),
+    NodeId,
+    Destination,
+    RouteId,
+    RecordKey,
+    TableName,
+    /// A named connection of the client, not known to the server
+    NodeName,
+    Number,
+    Text,
+}
+
+impl ArgKind {
+    fn from_json(kind: &json::JsonValue) -> Self {
+        match kind["kind"].as_str().unwrap_or_default() {
+            "Choice" => ArgKind::Choice(
+                kind["values"]
+                    .members()
+                    .filter_map(|v| v.as_str())
+                    .map(|v| v.to_owned())
+                    .collect(),
+            ),
+            "NodeId" => ArgKind::NodeId,
+            "Destination" => ArgKind::Destination,
+            "RouteId" => ArgKind::RouteId,
+            "RecordKey" => ArgKind::RecordKey,
+            "TableName" => ArgKind::TableName,
+            "Number" => ArgKind::Number,
+            _ => ArgKind::Text,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandArg {
+    pub name: String,
+    pub kind: ArgKind,
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandSpec {
+    pub words: Vec<String>,
+    pub args: Vec<CommandArg>,
+    pub description: String,
+}
+
+impl CommandSpec {
+    fn new(words: &str, args: Vec<CommandArg>, description: &str) -> Self {
+        Self {
+            words: words.split(' ').map(|w| w.to_owned()).collect(),
+            args,
+            description: description.to_owned(),
+        }
+    }
+
+    fn from_json(command: &json::JsonValue) -> Option<Self> {
+        let words: Vec<String> = command["words"]
+            .members()
+            .filter_map(|w| w.as_str())
+            .map(|w| w.to_owned())
+            .collect();
+        if words.is_empty() {
+            return None;
+        }
+        let args = command["args"]
+            .members()
+            .map(|a| CommandArg {
+                name: a["name"].as_str().unwrap_or_default().to_owned(),
+                kind: ArgKind::from_json(&a["kind"]),
+                optional: a["optional"].as_bool().unwrap_or_default(),
+            })
+            .collect();
+        Some(Self {
+            words,
+            args,
+            description: command["description"]
+                .as_str()
+                .unwrap_or_default()
+                .to_owned(),
+        })
+    }
+
+    pub fn usage(&self) -> String {
+        let mut out = self.words.join(" ");
+        for arg in &self.args {
+            if arg.optional {
+                out += &format!(" [{}]", arg.name);
+            } else {
+                out += &format!(" <{}>", arg.name);
+            }
+        }
+        out
+    }
+}
+
+fn arg(name: &str, kind: ArgKind) -> CommandArg {
+    CommandArg {
+        name: name.to_owned(),
+        kind,
+        optional: false,
+    }
+}
+
+fn opt_arg(name: &str, kind: ArgKind) -> CommandArg {
+    CommandArg {
+        optional: true,
+        ..arg(name, kind)
+    }
+}
+
+fn choice(values: &[&str]) -> ArgKind {
+    ArgKind::Choice(values.iter().map(|v| (*v).to_owned()).collect())
+}
+
+/// The commands handled by the client itself rather than passed to the server as debug commands
+fn client_commands() -> Vec<CommandSpec> {
+    let layer = || choice(&["all", "terminal", "system", "api", "file", "otlp"]);
+    let flag = || choice(&["app_messages"]);
+    vec![
+        CommandSpec::new("help", vec![], "Display the client and server commands"),
+        CommandSpec::new("exit", vec![], "Exit the client"),
+        CommandSpec::new("quit", vec![], "Exit the client"),
+        CommandSpec::new(
+            "disconnect",
+            vec![],
+            "Disconnect the client from the Veilid node",
+        ),
+        CommandSpec::new("shutdown", vec![], "Shut the server down"),
+        CommandSpec::new(
+            "change_log_level",
+            vec![
+                arg("layer", layer()),
+                arg(
+                    "level",
+                    choice(&["off", "error", "warn", "info", "debug", "trace"]),
+                ),
+            ],
+            "Change the log level for a tracing layer",
+        ),
+        CommandSpec::new(
+            "change_log_ignore",
+            vec![arg("layer", layer()), arg("changes", ArgKind::Text)],
+            "Change the log target ignore list for a tracing layer",
+        ),
+        CommandSpec::new("enable", vec![arg("flag", flag())], "Set a flag"),
+        CommandSpec::new("disable", vec![arg("flag", flag())], "Unset a flag"),
+        CommandSpec::new(
+            "wait_attachment",
+            vec![
+                arg("state", choice(&ATTACHMENT_STATES)),
+                opt_arg("timeout", ArgKind::Number),
+            ],
+            "Wait until the node reaches an attachment state",
+        ),
+        CommandSpec::new(
+            "wait_value_change",
+            vec![
+                arg("key", ArgKind::RecordKey),
+                opt_arg("timeout", ArgKind::Number),
+            ],
+            "Wait for a change to a watched dht record",
+        ),
+        CommandSpec::new("node list", vec![], "List the named node connections"),
+        CommandSpec::new(
+            "node switch",
+            vec![arg("name", ArgKind::NodeName)],
+            "Switch to a named node connection",
+        ),
+        CommandSpec::new(
+            "node all",
+            vec![arg("command", ArgKind::Text)],
+            "Run a command on every named node connection",
+        ),
+    ]
+}
+
+/// What may be typed at the end of a command line
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompletionContext {
+    /// Byte offset of the word being completed
+    pub word_start: usize,
+    /// Words that are known without asking the server
+    pub candidates: Vec<String>,
+    /// Argument kinds whose values have to be looked up
+    pub kinds: Vec<ArgKind>,
+}
+
+/// Splits a command line into the words already typed and the start of the word being typed
+fn split_line(line: &str) -> (Vec<&str>, usize) {
+    let word_start = line
+        .rfind(char::is_whitespace)
+        .map(|p| p + 1)
+        .unwrap_or_default();
+    (line[..word_start].split_whitespace().collect(), word_start)
+}
+
+/// The arguments that could be typed next, after some argument words have been typed.
+/// Optional choices that a typed word does not match are taken to have been left out.
+fn next_args<'a>(args: &'a [CommandArg], typed: &[&str]) -> &'a [CommandArg] {
+    let mut pos = 0;
+    for word in typed {
+        while let Some(a) = args.get(pos) {
+            match &a.kind {
+                ArgKind::Choice(values) if a.optional && !values.iter().any(|v| v == word) => {
+                    pos += 1;
+                }
+                _ => break,
+            }
+        }
+        pos += 1;
+    }
+    let rest = &args[pos.min(args.len())..];
+    let end = rest
+        .iter()
+        .position(|a| !a.optional)
+        .map(|p| p + 1)
+        .unwrap_or(rest.len());
+    &rest[..end]
+}
+
+/// Returns the longest string that all of the candidates start with
+pub fn common_prefix(candidates: &[String]) -> String {
+    let Some(first) = candidates.first() else {
+        return String::new();
+    };
+    let mut len = first.len();
+    for c in &candidates[1..] {
+        len = first
+            .char_indices()
+            .zip(c.chars())
+            .take_while(|((_, a), b)| a == b)
+            .map(|((i, a), _)| i + a.len_utf8())
+            .last()
+            .unwrap_or_default()
+            .min(len);
+    }
+    first[..len].to_owned()
+}
+
+/// Completes the last word of a command line from a list of candidates.
+/// Returns the new line and the candidates that matched what was typed.
+pub fn complete_word(
+    line: &str,
+    word_start: usize,
+    candidates: &[String],
+) -> (String, Vec<String>) {
+    let partial = &line[word_start..];
+    let mut matches: Vec<String> = candidates
+        .iter()
+        .filter(|c| c.starts_with(partial))
+        .cloned()
+        .collect();
+    matches.sort();
+    matches.dedup();
+
+    let completed = match matches.len() {
+        0 => line.to_owned(),
+        1 => format!("{}{} ", &line[..word_start], matches[0]),
+        _ => format!("{}{}", &line[..word_start], common_prefix(&matches)),
+    };
+    (completed, matches)
+}
+
+/// The commands known to the command line, used for completion and usage hints
+#[derive(Debug, Clone)]
+pub struct CommandSchema {
+    commands: Vec<CommandSpec>,
+}
+
+impl Default for CommandSchema {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandSchema {
+    pub fn new() -> Self {
+        Self {
+            commands: client_commands(),
+        }
+    }
+
+    /// Replaces the server commands with the ones from a 'CommandSchema' debug response.
+    /// Client commands take precedence over server commands of the same name.
+    pub fn set_server_commands(&mut self, response: &json::JsonValue) {
+        let client = client_commands();
+        let server: Vec<CommandSpec> = response["commands"]
+            .members()
+            .filter_map(CommandSpec::from_json)
+            .filter(|command| !client.iter().any(|c| c.words[0] == command.words[0]))
+            .collect();
+        self.commands = client;
+        self.commands.extend(server);
+    }
+
+    pub fn completion_context(&self, line: &str) -> CompletionContext {
+        let (typed, word_start) = split_line(line);
+        let mut context = CompletionContext {
+            word_start,
+            ..Default::default()
+        };
+        for command in &self.commands {
+            let n = command.words.len();
+            if typed.len() < n {
+                if command.words[..typed.len()] == typed[..] {
+                    context.candidates.push(command.words[typed.len()].clone());
+                }
+                continue;
+            }
+            if command.words[..] != typed[..n] {
+                continue;
+            }
+            for a in next_args(&command.args, &typed[n..]) {
+                match &a.kind {
+                    ArgKind::Choice(values) => context.candidates.extend(values.iter().cloned()),
+                    ArgKind::Number | ArgKind::Text => {}
+                    kind => {
+                        if !context.kinds.contains(kind) {
+                            context.kinds.push(kind.clone());
+                        }
+                    }
+                }
+            }
+        }
+        context
+    }
+
+    /// A one line hint with the usage of the commands matching what has been typed so far
+    pub fn usage_hint(&self, line: &str) -> Option<String> {
+        let (typed, word_start) = split_line(line);
+        let partial = &line[word_start..];
+        if typed.is_empty() && partial.is_empty() {
+            return None;
+        }
+
+        let matching: Vec<&CommandSpec> = self
+            .commands
+            .iter()
+            .filter(|c| {
+                c.words.iter().zip(typed.iter()).all(|(w, t)| w == t)
+                    && (typed.len() >= c.words.len() || c.words[typed.len()].starts_with(partial))
+            })
+            .collect();
+
+        match matching.as_slice() {
+            [] => None,
+            [command] => Some(format!("{} - {}", command.usage(), command.description)),
+            commands => Some(
+                commands
+                    .iter()
+                    .map(|c| c.usage())
+                    .collect::<Vec<_>>()
+                    .join(" | "),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server_schema() -> json::JsonValue {
+        json::parse(
+            r#"{"kind": "CommandSchema", "commands": [
+                {"words": ["help"], "args": [], "description": "Display the debug command help"},
+                {"words": ["route", "publish"], "args": [
+                    {"name": "route", "kind": {"kind": "RouteId"}, "optional": false},
+                    {"name": "full", "kind": {"kind": "Choice", "values": ["full"]}, "optional": true}
+                ], "description": "Publish a route"},
+                {"words": ["route", "print"], "args": [
+                    {"name": "route", "kind": {"kind": "RouteId"}, "optional": false}
+                ], "description": "Display details about a route"},
+                {"words": ["buckets"], "args": [
+                    {"name": "state", "kind": {"kind": "Choice", "values": ["dead", "reliable"]}, "optional": true}
+                ], "description": "Display the routing table bucket statistics"},
+                {"words": ["relay"], "args": [
+                    {"name": "relay", "kind": {"kind": "NodeId"}, "optional": false},
+                    {"name": "routingdomain", "kind": {"kind": "Choice", "values": ["public", "local"]}, "optional": true}
+                ], "description": "Change the relay in use for this node"}
+            ]}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_completion_context() {
+        let mut schema = CommandSchema::new();
+        schema.set_server_commands(&server_schema());
+
+        let context = schema.completion_context("rou");
+        assert_eq!(context.word_start, 0);
+        assert_eq!(complete_word("rou", 0, &context.candidates).0, "route ");
+
+        let context = schema.completion_context("route p");
+        let (line, matches) = complete_word("route p", context.word_start, &context.candidates);
+        assert_eq!(line, "route p");
+        assert_eq!(matches, vec!["print".to_owned(), "publish".to_owned()]);
+
+        let context = schema.completion_context("route publish ");
+        assert!(context.candidates.is_empty());
+        assert_eq!(context.kinds, vec![ArgKind::RouteId]);
+
+        let context = schema.completion_context("route publish abc ");
+        assert_eq!(context.candidates, vec!["full".to_owned()]);
+
+        let context = schema.completion_context("relay ");
+        assert_eq!(context.kinds, vec![ArgKind::NodeId]);
+
+        let context = schema.completion_context("change_log_level api d");
+        let line = "change_log_level api d";
+        assert_eq!(
+            complete_word(line, context.word_start, &context.candidates).0,
+            "change_log_level api debug "
+        );
+
+        // The client's help command replaces the server's
+        assert_eq!(
+            schema
+                .commands
+                .iter()
+                .filter(|c| c.words[0] == "help")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_usage_hint() {
+        let mut schema = CommandSchema::new();
+        schema.set_server_commands(&server_schema());
+
+        assert_eq!(schema.usage_hint(""), None);
+        assert_eq!(schema.usage_hint("nosuchcommand"), None);
+        assert_eq!(
+            schema.usage_hint("route pu"),
+            Some("route publish <route> [full] - Publish a route".to_owned())
+        );
+        assert_eq!(
+            schema.usage_hint("route "),
+            Some("route publish <route> [full] | route print <route>".to_owned())
+        );
+        assert_eq!(
+            schema.usage_hint("buckets dead"),
+            Some("buckets [state] - Display the routing table bucket statistics".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_common_prefix() {
+        assert_eq!(common_prefix(&[]), "");
+        assert_eq!(
+            common_prefix(&["record".to_owned(), "relay".to_owned()]),
+            "re"
+        );
+        assert_eq!(
+            common_prefix(&["route".to_owned(), "route".to_owned()]),
+            "route"
+        );
+    }
+}
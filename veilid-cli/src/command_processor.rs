@@ -1,4 +1,6 @@
 use crate::client_api_connection::*;
+use crate::command_completion::*;
+use crate::fleet::*;
 use crate::settings::Settings;
 use crate::tools::*;
 use crate::ui::*;
@@ -20,6 +22,41 @@ pub fn convert_loglevel(s: &str) -> Result<String, String> {
     }
 }
 
+pub const ATTACHMENT_STATES: [&str; 8] = [
+    "Detached",
+    "Attaching",
+    "AttachedWeak",
+    "AttachedGood",
+    "AttachedStrong",
+    "FullyAttached",
+    "OverAttached",
+    "Detaching",
+];
+
+const DEFAULT_WAIT_TIMEOUT_MS: u32 = 60000;
+
+/// Checks if the node has reached a wanted attachment state.
+/// Attached states are ordered by strength, so a stronger one also satisfies a weaker one.
+fn attachment_state_reached(current: &str, wanted: &str) -> bool {
+    let attached = &ATTACHMENT_STATES[2..7];
+    match (
+        attached.iter().position(|s| *s == current),
+        attached.iter().position(|s| *s == wanted),
+    ) {
+        (Some(c), Some(w)) => c >= w,
+        _ => current == wanted,
+    }
+}
+
+fn parse_wait_timeout(arg: Option<String>) -> Result<u32, String> {
+    match arg {
+        None => Ok(DEFAULT_WAIT_TIMEOUT_MS),
+        Some(arg) => arg
+            .parse::<u32>()
+            .map_err(|e| format!("Invalid timeout '{}': {}", arg, e)),
+    }
+}
+
 #[derive(PartialEq, Clone)]
 pub enum ConnectionState {
     Disconnected,
@@ -40,6 +77,13 @@ impl ConnectionState {
     }
 }
 
+#[derive(Clone)]
+struct OpenedDhtRecord {
+    descriptor: json::JsonValue,
+    writable: bool,
+    watched: bool,
+}
+
 struct CommandProcessorInner {
     ui_sender: Box<dyn UISender>,
     capi: Option<ClientApiConnection>,
@@ -52,6 +96,12 @@ struct CommandProcessorInner {
     connection_waker: Eventual,
     last_call_id: Option<u64>,
     enable_app_messages: bool,
+    opened_dht_records: HashMap<String, OpenedDhtRecord>,
+    attachment_state: String,
+    update_waiters: Vec<flume::Sender<json::JsonValue>>,
+    command_schema: CommandSchema,
+    peer_node_ids: Vec<String>,
+    fleet: Option<WeakFleet>,
 }
 
 #[derive(Clone)]
@@ -74,12 +124,21 @@ impl CommandProcessor {
                 connection_waker: Eventual::new(),
                 last_call_id: None,
                 enable_app_messages: false,
+                opened_dht_records: HashMap::new(),
+                attachment_state: "Detached".to_owned(),
+                update_waiters: Vec::new(),
+                command_schema: CommandSchema::new(),
+                peer_node_ids: Vec::new(),
+                fleet: None,
             })),
         }
     }
     pub fn set_client_api_connection(&self, capi: ClientApiConnection) {
         self.inner.lock().capi = Some(capi);
     }
+    pub fn set_fleet(&self, fleet: WeakFleet) {
+        self.inner.lock().fleet = Some(fleet);
+    }
     fn inner(&self) -> MutexGuard<CommandProcessorInner> {
         self.inner.lock()
     }
@@ -104,6 +163,10 @@ impl CommandProcessor {
         }
     }
 
+    pub fn refresh_state(&self) {
+        self.capi().refresh_state();
+    }
+
     pub fn cancel_command(&self) {
         trace!("CommandProcessor::cancel_command");
         let capi = self.capi();
@@ -117,16 +180,17 @@ impl CommandProcessor {
         spawn_detached_local("cmd help", async move {
             let out = match capi.server_debug("help".to_owned()).await {
                 Err(e) => {
-                    error!("Server command 'debug help' failed: {}", e);
-                    ui.send_callback(callback);
+                    ui.display_command_result(
+                        Err(format!("Server command 'debug help' failed: {}", e)),
+                        callback,
+                    );
                     return;
                 }
                 Ok(v) => v,
             };
 
-            ui.add_node_event(
-                Level::Info,
-                &format!(
+            ui.display_command_result(
+                Ok(format!(
                     r#"Client Commands:
     exit/quit                           exit the client
     disconnect                          disconnect the client from the Veilid node 
@@ -143,13 +207,21 @@ impl CommandProcessor {
     disable [flag]                      unset a flag
                                         valid flags in include:
                                             app_messages
+    wait_attachment <state> [timeout]   wait until the node reaches an attachment state
+                                        attached states are also reached by any stronger
+                                        attachment. timeout is in milliseconds (default 60000)
+    wait_value_change <key> [timeout]   wait for a change to a watched dht record
+                                        timeout is in milliseconds (default 60000)
+    node list                           list the named node connections
+    node switch <name>                  switch to a named node connection
+    node all <command>                  run a command on every named node connection
 Server Debug Commands:
 {}
 "#,
                     indent_all_by(4, out)
-                ),
+                )),
+                callback,
             );
-            ui.send_callback(callback);
         });
         Ok(())
     }
@@ -157,7 +229,7 @@ Server Debug Commands:
     pub fn cmd_exit(&self, callback: UICallback) -> Result<(), String> {
         trace!("CommandProcessor::cmd_exit");
         let ui = self.ui_sender();
-        ui.send_callback(callback);
+        ui.display_command_result(Ok(String::new()), callback);
         ui.quit();
         Ok(())
     }
@@ -167,10 +239,12 @@ Server Debug Commands:
         let capi = self.capi();
         let ui = self.ui_sender();
         spawn_detached_local("cmd shutdown", async move {
-            if let Err(e) = capi.server_shutdown().await {
-                error!("Server command 'shutdown' failed to execute: {}", e);
-            }
-            ui.send_callback(callback);
+            let res = capi
+                .server_shutdown()
+                .await
+                .map(|()| String::new())
+                .map_err(|e| format!("Server command 'shutdown' failed to execute: {}", e));
+            ui.display_command_result(res, callback);
         });
         Ok(())
     }
@@ -181,7 +255,7 @@ Server Debug Commands:
         let ui = self.ui_sender();
         spawn_detached_local("cmd disconnect", async move {
             capi.disconnect().await;
-            ui.send_callback(callback);
+            ui.display_command_result(Ok(String::new()), callback);
         });
         Ok(())
     }
@@ -191,16 +265,8 @@ Server Debug Commands:
         let capi = self.capi();
         let ui = self.ui_sender();
         spawn_detached_local("cmd debug", async move {
-            match capi.server_debug(command_line).await {
-                Ok(output) => {
-                    ui.add_node_event(Level::Info, &output);
-                    ui.send_callback(callback);
-                }
-                Err(e) => {
-                    ui.add_node_event(Level::Error, &e);
-                    ui.send_callback(callback);
-                }
-            }
+            let res = capi.server_debug(command_line).await;
+            ui.display_command_result(res, callback);
         });
         Ok(())
     }
@@ -218,28 +284,20 @@ Server Debug Commands:
             let log_level = match convert_loglevel(&rest.unwrap_or_default()) {
                 Ok(v) => v,
                 Err(e) => {
-                    ui.add_node_event(Level::Error, &format!("Failed to change log level: {}", e));
-                    ui.send_callback(callback);
+                    ui.display_command_result(
+                        Err(format!("Failed to change log level: {}", e)),
+                        callback,
+                    );
                     return;
                 }
             };
 
-            match capi.server_change_log_level(layer, log_level.clone()).await {
-                Ok(()) => {
-                    ui.display_string_dialog(
-                        "Log level changed",
-                        &format!("Log level set to '{}'", log_level),
-                        callback,
-                    );
-                }
-                Err(e) => {
-                    ui.display_string_dialog(
-                        "Server command 'change_log_level' failed",
-                        &e,
-                        callback,
-                    );
-                }
-            }
+            let res = capi
+                .server_change_log_level(layer, log_level.clone())
+                .await
+                .map(|()| format!("Log level set to '{}'", log_level))
+                .map_err(|e| format!("Server command 'change_log_level' failed: {}", e));
+            ui.display_command_result(res, callback);
         });
         Ok(())
     }
@@ -256,25 +314,12 @@ Server Debug Commands:
             let (layer, rest) = Self::word_split(&rest.unwrap_or_default());
             let log_ignore = rest.unwrap_or_default();
 
-            match capi
+            let res = capi
                 .server_change_log_ignore(layer, log_ignore.clone())
                 .await
-            {
-                Ok(()) => {
-                    ui.display_string_dialog(
-                        "Log ignore changed",
-                        &format!("Log ignore changed '{}'", log_ignore),
-                        callback,
-                    );
-                }
-                Err(e) => {
-                    ui.display_string_dialog(
-                        "Server command 'change_log_ignore' failed",
-                        &e,
-                        callback,
-                    );
-                }
-            }
+                .map(|()| format!("Log ignore changed '{}'", log_ignore))
+                .map_err(|e| format!("Server command 'change_log_ignore' failed: {}", e));
+            ui.display_command_result(res, callback);
         });
         Ok(())
     }
@@ -289,12 +334,10 @@ Server Debug Commands:
             match flag.as_str() {
                 "app_messages" => {
                     this.inner.lock().enable_app_messages = true;
-                    ui.add_node_event(Level::Info, &format!("flag enabled: {}", flag));
-                    ui.send_callback(callback);
+                    ui.display_command_result(Ok(format!("flag enabled: {}", flag)), callback);
                 }
                 _ => {
-                    ui.add_node_event(Level::Error, &format!("unknown flag: {}", flag));
-                    ui.send_callback(callback);
+                    ui.display_command_result(Err(format!("unknown flag: {}", flag)), callback);
                 }
             }
         });
@@ -311,18 +354,143 @@ Server Debug Commands:
             match flag.as_str() {
                 "app_messages" => {
                     this.inner.lock().enable_app_messages = false;
-                    ui.add_node_event(Level::Info, &format!("flag disabled: {}", flag));
-                    ui.send_callback(callback);
+                    ui.display_command_result(Ok(format!("flag disabled: {}", flag)), callback);
                 }
                 _ => {
-                    ui.add_node_event(Level::Error, &format!("unknown flag: {}", flag));
-                    ui.send_callback(callback);
+                    ui.display_command_result(Err(format!("unknown flag: {}", flag)), callback);
                 }
             }
         });
         Ok(())
     }
 
+    pub fn cmd_wait_attachment(
+        &self,
+        rest: Option<String>,
+        callback: UICallback,
+    ) -> Result<(), String> {
+        trace!("CommandProcessor::cmd_wait_attachment");
+
+        let ui = self.ui_sender();
+        let this = self.clone();
+        let updates = self.subscribe_updates();
+        spawn_detached_local("cmd wait_attachment", async move {
+            let (state, rest) = Self::word_split(&rest.unwrap_or_default());
+            let Some(state) = ATTACHMENT_STATES
+                .iter()
+                .find(|s| s.eq_ignore_ascii_case(&state))
+            else {
+                ui.display_command_result(
+                    Err(format!("Invalid attachment state: {}", state)),
+                    callback,
+                );
+                return;
+            };
+            let timeout_ms = match parse_wait_timeout(rest) {
+                Ok(v) => v,
+                Err(e) => {
+                    ui.display_command_result(Err(e), callback);
+                    return;
+                }
+            };
+
+            let wait = async {
+                loop {
+                    let current = this.inner().attachment_state.clone();
+                    if attachment_state_reached(&current, state) {
+                        return Ok(format!("Attachment state: {}", current));
+                    }
+                    if updates.recv_async().await.is_err() {
+                        return Err(format!("Stopped waiting for attachment state {}", state));
+                    }
+                }
+            };
+            let res = match timeout(timeout_ms, wait).await {
+                Ok(res) => res,
+                Err(_) => Err(format!("Timed out waiting for attachment state {}", state)),
+            };
+            ui.display_command_result(res, callback);
+        });
+        Ok(())
+    }
+
+    pub fn cmd_wait_value_change(
+        &self,
+        rest: Option<String>,
+        callback: UICallback,
+    ) -> Result<(), String> {
+        trace!("CommandProcessor::cmd_wait_value_change");
+
+        let ui = self.ui_sender();
+        let updates = self.subscribe_updates();
+        spawn_detached_local("cmd wait_value_change", async move {
+            let (key, rest) = Self::word_split(&rest.unwrap_or_default());
+            if key.is_empty() {
+                ui.display_command_result(Err("Missing record key".to_owned()), callback);
+                return;
+            }
+            let timeout_ms = match parse_wait_timeout(rest) {
+                Ok(v) => v,
+                Err(e) => {
+                    ui.display_command_result(Err(e), callback);
+                    return;
+                }
+            };
+
+            let wait = async {
+                while let Ok(update) = updates.recv_async().await {
+                    if update["key"] == key.as_str() {
+                        return Ok(format!(
+                            "Value change: key={} subkeys={} count={} value.seq={}",
+                            key,
+                            update["subkeys"].dump(),
+                            update["count"].dump(),
+                            update["value"]["seq"].dump(),
+                        ));
+                    }
+                }
+                Err("Stopped waiting for value change".to_owned())
+            };
+            let res = match timeout(timeout_ms, wait).await {
+                Ok(res) => res,
+                Err(_) => Err(format!("Timed out waiting for value change on {}", key)),
+            };
+            ui.display_command_result(res, callback);
+        });
+        Ok(())
+    }
+
+    pub fn cmd_node(&self, rest: Option<String>, callback: UICallback) -> Result<(), String> {
+        trace!("CommandProcessor::cmd_node");
+
+        let ui = self.ui_sender();
+        let Some(fleet) = self.inner().fleet.as_ref().and_then(|f| f.upgrade()) else {
+            ui.display_command_result(
+                Err("No named node connections are configured".to_owned()),
+                callback,
+            );
+            return Ok(());
+        };
+        let (subcmd, rest) = Self::word_split(&rest.unwrap_or_default());
+        match subcmd.as_str() {
+            "list" | "" => ui.display_command_result(Ok(fleet.list()), callback),
+            "switch" => {
+                let name = rest.unwrap_or_default();
+                let res = fleet
+                    .switch(&name)
+                    .map(|()| format!("Switched to node '{}'", name));
+                ui.display_command_result(res, callback);
+            }
+            "all" => match rest {
+                Some(command) => fleet.broadcast(&command, callback),
+                None => ui.display_command_result(Err("Missing command".to_owned()), callback),
+            },
+            _ => ui
+                .display_command_result(Err(format!("Unknown node command: {}", subcmd)), callback),
+        }
+        Ok(())
+    }
+
     pub fn run_command(&self, command_line: &str, callback: UICallback) -> Result<(), String> {
         //
         let (cmd, rest) = Self::word_split(command_line);
@@ -336,6 +504,9 @@ Server Debug Commands:
             "change_log_ignore" => self.cmd_change_log_ignore(rest, callback),
             "enable" => self.cmd_enable(rest, callback),
             "disable" => self.cmd_disable(rest, callback),
+            "wait_attachment" => self.cmd_wait_attachment(rest, callback),
+            "wait_value_change" => self.cmd_wait_value_change(rest, callback),
+            "node" => self.cmd_node(rest, callback),
             _ => self.cmd_debug(command_line.to_owned(), callback),
         }
     }
@@ -460,7 +631,27 @@ Server Debug Commands:
         self.inner().ui_sender.add_log_event(log_level, message);
     }
 
+    fn subscribe_updates(&self) -> flume::Receiver<json::JsonValue> {
+        let (sender, receiver) = flume::unbounded();
+        self.inner_mut().update_waiters.push(sender);
+        receiver
+    }
+
+    fn notify_update_waiters(&self, update: &json::JsonValue) {
+        // Waiters that have finished drop their receivers
+        self.inner_mut()
+            .update_waiters
+            .retain(|waiter| waiter.send(update.clone()).is_ok());
+    }
+
+    pub fn update_received(&self, update: &json::JsonValue) {
+        self.inner().ui_sender.add_update(update);
+    }
+
     pub fn update_attachment(&self, attachment: &json::JsonValue) {
+        self.inner_mut().attachment_state =
+            attachment["state"].as_str().unwrap_or_default().to_owned();
+        self.notify_update_waiters(attachment);
         self.inner_mut().ui_sender.set_attachment_state(
             attachment["state"].as_str().unwrap_or_default(),
             attachment["public_internet_ready"]
@@ -473,6 +664,12 @@ Server Debug Commands:
     }
 
     pub fn update_network_status(&self, network: &json::JsonValue) {
+        self.inner_mut().peer_node_ids = network["peers"]
+            .members()
+            .flat_map(|p| p["node_ids"].members())
+            .filter_map(|n| n.as_str())
+            .map(|n| n.to_owned())
+            .collect();
         self.inner_mut().ui_sender.set_network_status(
             network["started"].as_bool().unwrap_or_default(),
             json_str_u64(&network["bps_down"]),
@@ -483,6 +680,9 @@ Server Debug Commands:
                 .collect::<Vec<json::JsonValue>>(),
         );
     }
+    pub fn update_command_schema(&self, schema: &json::JsonValue) {
+        self.inner_mut().command_schema.set_server_commands(schema);
+    }
     pub fn update_config(&self, config: &json::JsonValue) {
         self.inner_mut().ui_sender.set_config(&config["config"])
     }
@@ -500,9 +700,27 @@ Server Debug Commands:
                 route["dead_remote_routes"]
             ));
         }
+        for rotation in route["rotated_routes"].members() {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(&format!(
+                "Rotated route: {} -> {}",
+                rotation["old_route_id"], rotation["new_route_id"]
+            ));
+        }
         if !out.is_empty() {
             self.inner().ui_sender.add_node_event(Level::Info, &out);
         }
+
+        // Keep the route manager current
+        let capi = self.capi();
+        let mut ui = self.ui_sender();
+        spawn_detached_local("route change refresh", async move {
+            if let Ok(routes) = capi.server_list_route_health().await {
+                ui.set_routes(routes.members().cloned().collect());
+            }
+        });
     }
     pub fn update_value_change(&self, value_change: &json::JsonValue) {
         let data = json_str_vec_u8(&value_change["value"]["data"]);
@@ -518,6 +736,41 @@ Server Debug Commands:
             datastr,
             if truncated { "..." } else { "" }
         );
+
+        let mut inner = self.inner_mut();
+        inner.ui_sender.add_node_event(Level::Info, &out);
+
+        // A change count of zero means the watch has ended
+        if value_change["count"].as_u32() == Some(0) {
+            let key = value_change["key"].as_str().unwrap_or_default();
+            if let Some(record) = inner.opened_dht_records.get_mut(key) {
+                record.watched = false;
+            }
+        }
+        inner.ui_sender.add_dht_value_change(value_change);
+        drop(inner);
+
+        self.notify_update_waiters(value_change);
+    }
+
+    pub fn update_offline_write(&self, offline_write: &json::JsonValue) {
+        let out = if offline_write["superseded_by"].is_null() {
+            format!(
+                "Offline write accepted: key={} subkey={} value.seq={}",
+                offline_write["key"].dump(),
+                offline_write["subkey"].dump(),
+                offline_write["value"]["seq"].dump(),
+            )
+        } else {
+            format!(
+                "Offline write superseded: key={} subkey={} value.seq={} superseded_by.seq={} superseded_by.writer={}",
+                offline_write["key"].dump(),
+                offline_write["subkey"].dump(),
+                offline_write["value"]["seq"].dump(),
+                offline_write["superseded_by"]["seq"].dump(),
+                offline_write["superseded_by"]["writer"].dump(),
+            )
+        };
         self.inner().ui_sender.add_node_event(Level::Info, &out);
     }
 
@@ -633,7 +886,14 @@ Server Debug Commands:
     // calls into ui
     ////////////////////////////////////////////
     pub fn set_connection_state(&self, state: ConnectionState) {
-        self.inner_mut().ui_sender.set_connection_state(state);
+        let mut inner = self.inner_mut();
+        if !state.is_connected() {
+            // The server may have restarted, so forget what we had open
+            inner.opened_dht_records.clear();
+            inner.attachment_state = "Detached".to_owned();
+            inner.peer_node_ids.clear();
+        }
+        inner.ui_sender.set_connection_state(state);
     }
     // called by ui
     ////////////////////////////////////////////
@@ -680,4 +940,492 @@ Server Debug Commands:
             }
         });
     }
+
+    async fn dht_record_rows(
+        &self,
+        capi: &ClientApiConnection,
+    ) -> Result<Vec<json::JsonValue>, String> {
+        let opened = self.inner().opened_dht_records.clone();
+
+        let mut rows = Vec::new();
+        for scope in ["Local", "Remote"] {
+            let mut request = json::JsonValue::new_object();
+            request["kind"] = "RecordList".into();
+            request["scope"] = scope.into();
+            let response = capi.server_debug_request(request).await?;
+            for record in response["store"]["records"].members() {
+                let mut row = record.clone();
+                let is_opened = scope == "Local"
+                    && opened.contains_key(row["key"].as_str().unwrap_or_default());
+                row["scope"] = if is_opened { "Opened" } else { scope }.into();
+                rows.push(row);
+            }
+        }
+
+        // Opened records that are not in the local record store yet
+        for (key, record) in opened {
+            if rows
+                .iter()
+                .any(|r| r["scope"] == "Opened" && r["key"] == key.as_str())
+            {
+                continue;
+            }
+            let mut row = json::JsonValue::new_object();
+            row["key"] = key.into();
+            row["scope"] = "Opened".into();
+            row["owner"] = record.descriptor["owner"].clone();
+            row["schema"] = record.descriptor["schema"].clone();
+            rows.push(row);
+        }
+
+        Ok(rows)
+    }
+
+    async fn dht_record_detail(
+        &self,
+        capi: &ClientApiConnection,
+        key: String,
+        scope: String,
+    ) -> Result<json::JsonValue, String> {
+        // Records must be open to be inspected
+        let opened = self.inner().opened_dht_records.get(&key).cloned();
+        let record = match opened {
+            Some(record) => record,
+            None => {
+                let descriptor = capi.server_open_dht_record(key.clone(), None).await?;
+                let record = OpenedDhtRecord {
+                    writable: !descriptor["owner_secret"].is_null(),
+                    descriptor,
+                    watched: false,
+                };
+                self.inner_mut()
+                    .opened_dht_records
+                    .insert(key.clone(), record.clone());
+                record
+            }
+        };
+
+        let report = capi
+            .server_inspect_dht_record(key.clone(), scope.clone())
+            .await?;
+
+        let mut detail = json::JsonValue::new_object();
+        detail["key"] = key.into();
+        detail["owner"] = record.descriptor["owner"].clone();
+        detail["schema"] = record.descriptor["schema"].clone();
+        detail["writable"] = record.writable.into();
+        detail["watched"] = record.watched.into();
+        detail["scope"] = scope.into();
+        detail["report"] = report;
+        Ok(detail)
+    }
+
+    pub fn dht_refresh_records(&self, callback: UICallback) {
+        let capi = self.capi();
+        let mut ui = self.ui_sender();
+        let this = self.clone();
+
+        spawn_detached_local("dht refresh records", async move {
+            match this.dht_record_rows(&capi).await {
+                Ok(rows) => {
+                    ui.set_dht_records(rows);
+                    ui.send_callback(callback);
+                }
+                Err(e) => {
+                    ui.display_string_dialog("Failed to list DHT records", &e, callback);
+                }
+            }
+        });
+    }
+
+    pub fn dht_inspect_record(&self, key: String, scope: String, callback: UICallback) {
+        let capi = self.capi();
+        let mut ui = self.ui_sender();
+        let this = self.clone();
+
+        spawn_detached_local("dht inspect record", async move {
+            match this.dht_record_detail(&capi, key, scope).await {
+                Ok(detail) => {
+                    ui.set_dht_record(detail);
+                    if let Ok(rows) = this.dht_record_rows(&capi).await {
+                        ui.set_dht_records(rows);
+                    }
+                    ui.send_callback(callback);
+                }
+                Err(e) => {
+                    ui.display_string_dialog("Failed to inspect DHT record", &e, callback);
+                }
+            }
+        });
+    }
+
+    pub fn dht_open_record(&self, key: String, writer: Option<String>, callback: UICallback) {
+        let capi = self.capi();
+        let mut ui = self.ui_sender();
+        let this = self.clone();
+
+        spawn_detached_local("dht open record", async move {
+            let res = async {
+                let descriptor = capi
+                    .server_open_dht_record(key.clone(), writer.clone())
+                    .await?;
+                let key = descriptor["key"].as_str().unwrap_or(&key).to_owned();
+                let record = OpenedDhtRecord {
+                    writable: writer.is_some() || !descriptor["owner_secret"].is_null(),
+                    watched: this
+                        .inner()
+                        .opened_dht_records
+                        .get(&key)
+                        .map(|r| r.watched)
+                        .unwrap_or_default(),
+                    descriptor,
+                };
+                this.inner_mut()
+                    .opened_dht_records
+                    .insert(key.clone(), record);
+                this.dht_record_detail(&capi, key, "Local".to_owned()).await
+            }
+            .await;
+
+            match res {
+                Ok(detail) => {
+                    ui.set_dht_record(detail);
+                    if let Ok(rows) = this.dht_record_rows(&capi).await {
+                        ui.set_dht_records(rows);
+                    }
+                    ui.send_callback(callback);
+                }
+                Err(e) => {
+                    ui.display_string_dialog("Failed to open DHT record", &e, callback);
+                }
+            }
+        });
+    }
+
+    pub fn dht_close_record(&self, key: String, callback: UICallback) {
+        let capi = self.capi();
+        let mut ui = self.ui_sender();
+        let this = self.clone();
+
+        spawn_detached_local("dht close record", async move {
+            match capi.server_close_dht_record(key.clone()).await {
+                Ok(()) => {
+                    this.inner_mut().opened_dht_records.remove(&key);
+                    if let Ok(rows) = this.dht_record_rows(&capi).await {
+                        ui.set_dht_records(rows);
+                    }
+                    ui.send_callback(callback);
+                }
+                Err(e) => {
+                    ui.display_string_dialog("Failed to close DHT record", &e, callback);
+                }
+            }
+        });
+    }
+
+    pub fn dht_get_value(
+        &self,
+        key: String,
+        subkey: u32,
+        force_refresh: bool,
+        callback: UICallback,
+    ) {
+        let capi = self.capi();
+        let ui = self.ui_sender();
+
+        spawn_detached_local("dht get value", async move {
+            match capi
+                .server_get_dht_value(key.clone(), subkey, force_refresh)
+                .await
+            {
+                Ok(value) => {
+                    ui.show_dht_value(&key, subkey, &value);
+                    ui.send_callback(callback);
+                }
+                Err(e) => {
+                    ui.display_string_dialog("Failed to get DHT value", &e, callback);
+                }
+            }
+        });
+    }
+
+    pub fn dht_set_value(&self, key: String, subkey: u32, data: Vec<u8>, callback: UICallback) {
+        let capi = self.capi();
+        let mut ui = self.ui_sender();
+        let this = self.clone();
+
+        spawn_detached_local("dht set value", async move {
+            match capi.server_set_dht_value(key.clone(), subkey, data).await {
+                Ok(newer_value) => {
+                    if newer_value.is_null() {
+                        ui.add_node_event(
+                            Level::Info,
+                            &format!("DHT value set: key={} subkey={}", key, subkey),
+                        );
+                    } else {
+                        ui.add_node_event(
+                            Level::Warn,
+                            &format!(
+                                "DHT value not set, newer value exists: key={} subkey={} value.seq={}",
+                                key, subkey, newer_value["seq"]
+                            ),
+                        );
+                    }
+                    if let Ok(detail) = this.dht_record_detail(&capi, key, "Local".to_owned()).await
+                    {
+                        ui.set_dht_record(detail);
+                    }
+                    ui.send_callback(callback);
+                }
+                Err(e) => {
+                    ui.display_string_dialog("Failed to set DHT value", &e, callback);
+                }
+            }
+        });
+    }
+
+    pub fn dht_watch_record(&self, key: String, watch: bool, callback: UICallback) {
+        let capi = self.capi();
+        let mut ui = self.ui_sender();
+        let this = self.clone();
+
+        spawn_detached_local("dht watch record", async move {
+            let res = if watch {
+                capi.server_watch_dht_values(key.clone())
+                    .await
+                    .map(|expiration| expiration != 0)
+            } else {
+                capi.server_cancel_dht_watch(key.clone()).await
+            };
+            match res {
+                Ok(watched) => {
+                    if let Some(record) = this.inner_mut().opened_dht_records.get_mut(&key) {
+                        record.watched = watched;
+                    }
+                    if let Ok(detail) = this.dht_record_detail(&capi, key, "Local".to_owned()).await
+                    {
+                        ui.set_dht_record(detail);
+                    }
+                    ui.send_callback(callback);
+                }
+                Err(e) => {
+                    ui.display_string_dialog("Failed to change DHT watch", &e, callback);
+                }
+            }
+        });
+    }
+
+    pub fn route_refresh(&self, callback: UICallback) {
+        let capi = self.capi();
+        let mut ui = self.ui_sender();
+
+        spawn_detached_local("route refresh", async move {
+            match capi.server_list_route_health().await {
+                Ok(routes) => {
+                    ui.set_routes(routes.members().cloned().collect());
+                    ui.send_callback(callback);
+                }
+                Err(e) => {
+                    ui.display_string_dialog("Failed to list routes", &e, callback);
+                }
+            }
+        });
+    }
+
+    pub fn route_test(&self, route_id: String, callback: UICallback) {
+        let capi = self.capi();
+        let mut ui = self.ui_sender();
+
+        spawn_detached_local("route test", async move {
+            let mut request = json::JsonValue::new_object();
+            request["kind"] = "RouteTest".into();
+            request["route_id"] = route_id.clone().into();
+            match capi.server_debug_request(request).await {
+                Ok(response) => {
+                    let (level, result) = match response["success"].as_bool() {
+                        Some(true) => (Level::Info, "SUCCESS"),
+                        Some(false) => (Level::Warn, "FAILED"),
+                        None => (Level::Warn, "UNTESTED"),
+                    };
+                    ui.add_node_event(level, &format!("Route test {}: {}", route_id, result));
+                    if let Ok(routes) = capi.server_list_route_health().await {
+                        ui.set_routes(routes.members().cloned().collect());
+                    }
+                    ui.send_callback(callback);
+                }
+                Err(e) => {
+                    ui.display_string_dialog("Failed to test route", &e, callback);
+                }
+            }
+        });
+    }
+
+    pub fn route_release(&self, route_id: String, callback: UICallback) {
+        let capi = self.capi();
+        let mut ui = self.ui_sender();
+
+        spawn_detached_local("route release", async move {
+            match capi.server_release_private_route(route_id).await {
+                Ok(()) => {
+                    if let Ok(routes) = capi.server_list_route_health().await {
+                        ui.set_routes(routes.members().cloned().collect());
+                    }
+                    ui.send_callback(callback);
+                }
+                Err(e) => {
+                    ui.display_string_dialog("Failed to release route", &e, callback);
+                }
+            }
+        });
+    }
+
+    pub fn route_export(&self, route_id: String, callback: UICallback) {
+        let capi = self.capi();
+        let mut ui = self.ui_sender();
+
+        spawn_detached_local("route export", async move {
+            let mut request = json::JsonValue::new_object();
+            request["kind"] = "RoutePublish".into();
+            request["route_id"] = route_id.clone().into();
+            match capi.server_debug_request(request).await {
+                Ok(response) => {
+                    ui.show_route_blob(&route_id, response["blob"].as_str().unwrap_or_default());
+                    if let Ok(routes) = capi.server_list_route_health().await {
+                        ui.set_routes(routes.members().cloned().collect());
+                    }
+                    ui.send_callback(callback);
+                }
+                Err(e) => {
+                    ui.display_string_dialog("Failed to export route", &e, callback);
+                }
+            }
+        });
+    }
+
+    pub fn network_refresh(&self, callback: UICallback) {
+        let capi = self.capi();
+        let mut ui = self.ui_sender();
+
+        spawn_detached_local("network refresh", async move {
+            let res = async {
+                let info = capi.server_get_routing_table_info().await?;
+                let routes = capi.server_list_route_health().await?;
+                Ok::<_, String>((info, routes))
+            }
+            .await;
+            match res {
+                Ok((info, routes)) => {
+                    ui.set_routes(routes.members().cloned().collect());
+                    ui.set_routing_table_info(info);
+                    ui.send_callback(callback);
+                }
+                Err(e) => {
+                    ui.display_string_dialog("Failed to get routing table", &e, callback);
+                }
+            }
+        });
+    }
+
+    pub fn command_usage(&self, line: &str) -> Option<String> {
+        self.inner().command_schema.usage_hint(line)
+    }
+
+    async fn completion_values(
+        &self,
+        capi: &ClientApiConnection,
+        kind: &ArgKind,
+    ) -> Result<Vec<String>, String> {
+        let json_strings = |v: &json::JsonValue, field: &str| -> Vec<String> {
+            v.members()
+                .filter_map(|m| m[field].as_str())
+                .map(|m| m.to_owned())
+                .collect()
+        };
+        match kind {
+            ArgKind::NodeId => Ok(self.inner().peer_node_ids.clone()),
+            ArgKind::Destination => {
+                // Nodes, or remote private routes prefixed with '#'
+                let mut values = self.inner().peer_node_ids.clone();
+                let routes = capi.server_list_route_health().await?;
+                values.extend(
+                    routes
+                        .members()
+                        .filter(|r| r["remote"].as_bool().unwrap_or_default())
+                        .filter_map(|r| r["route_id"].as_str())
+                        .map(|r| format!("#{}", r)),
+                );
+                Ok(values)
+            }
+            ArgKind::RouteId => Ok(json_strings(
+                &capi.server_list_route_health().await?,
+                "route_id",
+            )),
+            ArgKind::RecordKey => {
+                let mut values: Vec<String> =
+                    self.inner().opened_dht_records.keys().cloned().collect();
+                let mut request = json::JsonValue::new_object();
+                request["kind"] = "RecordList".into();
+                request["scope"] = "Local".into();
+                let response = capi.server_debug_request(request).await?;
+                values.extend(json_strings(&response["store"]["records"], "key"));
+                Ok(values)
+            }
+            ArgKind::TableName => {
+                let mut request = json::JsonValue::new_object();
+                request["kind"] = "TableList".into();
+                let response = capi.server_debug_request(request).await?;
+                Ok(json_strings(&response["tables"], "name"))
+            }
+            ArgKind::NodeName => Ok(self
+                .inner()
+                .fleet
+                .as_ref()
+                .and_then(|f| f.upgrade())
+                .map(|f| f.names())
+                .unwrap_or_default()),
+            ArgKind::Choice(values) => Ok(values.clone()),
+            ArgKind::Number | ArgKind::Text => Ok(vec![]),
+        }
+    }
+
+    /// Completes the last word of a command line, looking up values such as route ids,
+    /// record keys and table names from the server when the command needs them
+    pub fn complete_command_line(&self, line: String) {
+        let capi = self.capi();
+        let ui = self.ui_sender();
+        let this = self.clone();
+
+        spawn_detached_local("complete command line", async move {
+            let context = this.inner().command_schema.completion_context(&line);
+            let mut candidates = context.candidates;
+            for kind in &context.kinds {
+                match this.completion_values(&capi, kind).await {
+                    Ok(values) => candidates.extend(values),
+                    Err(e) => debug!("failed to look up completions: {}", e),
+                }
+            }
+            let (completed, matches) = complete_word(&line, context.word_start, &candidates);
+            ui.set_command_completion(&line, &completed, matches);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attachment_state_reached() {
+        assert!(attachment_state_reached("FullyAttached", "FullyAttached"));
+        assert!(attachment_state_reached("OverAttached", "FullyAttached"));
+        assert!(attachment_state_reached("AttachedGood", "AttachedWeak"));
+        assert!(!attachment_state_reached("AttachedStrong", "FullyAttached"));
+        assert!(!attachment_state_reached("Attaching", "AttachedWeak"));
+        assert!(!attachment_state_reached("Detaching", "Detached"));
+        assert!(attachment_state_reached("Detached", "Detached"));
+
+        assert_eq!(parse_wait_timeout(None), Ok(DEFAULT_WAIT_TIMEOUT_MS));
+        assert_eq!(parse_wait_timeout(Some("500".to_owned())), Ok(500));
+        assert!(parse_wait_timeout(Some("soon".to_owned())).is_err());
+    }
 }
@@ -1,5 +1,9 @@
 use crate::command_processor::*;
+use crate::dht_records_table_view::*;
+use crate::network_history::*;
+use crate::nodes_table_view::*;
 use crate::peers_table_view::*;
+use crate::routes_table_view::*;
 use crate::settings::Settings;
 use crate::tools::*;
 use crate::ui::*;
@@ -62,6 +66,9 @@ pub type NodeEventsPanel = Panel<NamedView<ScrollView<NamedView<CachedTextView>>
 
 static START_TIME: AtomicU64 = AtomicU64::new(0);
 
+const DHT_VALUE_CHANGES_MAX: usize = 64;
+const NETWORK_CHART_WIDTH: usize = 60;
+
 pub type CursiveUICallback = Box<dyn Fn(&mut Cursive) + Send + Sync>;
 
 struct UIState {
@@ -73,6 +80,11 @@ struct UIState {
     connection_state: Dirty<ConnectionState>,
     peers_state: Dirty<Vec<json::JsonValue>>,
     node_id: Dirty<String>,
+    dht_records: Dirty<Vec<json::JsonValue>>,
+    dht_record: Dirty<json::JsonValue>,
+    routes: Dirty<Vec<json::JsonValue>>,
+    routing_table_info: Dirty<json::JsonValue>,
+    nodes: Dirty<Vec<json::JsonValue>>,
 }
 
 impl UIState {
@@ -86,6 +98,11 @@ impl UIState {
             connection_state: Dirty::new(ConnectionState::Disconnected),
             peers_state: Dirty::new(Vec::new()),
             node_id: Dirty::new("".to_owned()),
+            dht_records: Dirty::new(Vec::new()),
+            dht_record: Dirty::new(json::JsonValue::Null),
+            routes: Dirty::new(Vec::new()),
+            routing_table_info: Dirty::new(json::JsonValue::Null),
+            nodes: Dirty::new(Vec::new()),
         }
     }
 }
@@ -102,6 +119,9 @@ pub struct CursiveUIInner {
     cmd_history_position: usize,
     cmd_history_max_size: usize,
     connection_dialog_state: Option<ConnectionState>,
+    dht_value_changes: VecDeque<(String, String)>,
+    network_history: NetworkHistory,
+    active_node: Option<String>,
 }
 
 pub struct CursiveUI {
@@ -266,6 +286,9 @@ impl CursiveUI {
     fn command_line(s: &mut Cursive) -> ViewRef<EditView> {
         s.find_name("command-line").unwrap()
     }
+    fn command_hint(s: &mut Cursive) -> ViewRef<TextView> {
+        s.find_name("command-hint").unwrap()
+    }
     fn button_attach(s: &mut Cursive) -> ViewRef<Button> {
         s.find_name("button-attach").unwrap()
     }
@@ -293,6 +316,30 @@ impl CursiveUI {
     fn connection_dialog(s: &mut Cursive) -> ViewRef<Dialog> {
         s.find_name("connection-dialog").unwrap()
     }
+    fn dht_records(s: &mut Cursive) -> Option<ViewRef<DhtRecordsTableView>> {
+        s.find_name("dht-records")
+    }
+    fn dht_record_info(s: &mut Cursive) -> Option<ViewRef<TextView>> {
+        s.find_name("dht-record-info")
+    }
+    fn dht_record_subkeys(s: &mut Cursive) -> Option<ViewRef<SelectView<u32>>> {
+        s.find_name("dht-record-subkeys")
+    }
+    fn dht_record_changes(s: &mut Cursive) -> Option<ViewRef<TextView>> {
+        s.find_name("dht-record-changes")
+    }
+    fn routes(s: &mut Cursive) -> Option<ViewRef<RoutesTableView>> {
+        s.find_name("routes")
+    }
+    fn network_charts(s: &mut Cursive) -> Option<ViewRef<TextView>> {
+        s.find_name("network-charts")
+    }
+    fn network_diagram(s: &mut Cursive) -> Option<ViewRef<TextView>> {
+        s.find_name("network-diagram")
+    }
+    fn nodes(s: &mut Cursive) -> Option<ViewRef<NodesTableView>> {
+        s.find_name("nodes")
+    }
     ////////////////////////////////////////////////////////////////////////////////////////////////
 
     fn push_styled_line(s: &mut Cursive, styled_string: StyledString) {
@@ -367,12 +414,61 @@ impl CursiveUI {
     }
 
     fn on_command_line_edit(s: &mut Cursive, text: &str, _pos: usize) {
-        let mut inner = Self::inner_mut(s);
+        {
+            let mut inner = Self::inner_mut(s);
+
+            // save edited command to newest history slot
+            let hlen = inner.cmd_history.len();
+            inner.cmd_history_position = hlen - 1;
+            text.clone_into(&mut inner.cmd_history[hlen - 1]);
+        }
+        Self::refresh_command_hint(s, text);
+    }
+
+    fn refresh_command_hint(s: &mut Cursive, text: &str) {
+        let hint = Self::command_processor(s)
+            .command_usage(text)
+            .unwrap_or_default();
+        Self::command_hint(s).set_content(hint);
+    }
+
+    fn on_command_line_tab(s: &mut Cursive) {
+        let cmdline = Self::command_line(s);
+        if !cmdline.is_enabled() {
+            return;
+        }
+        // Complete the last word on the line
+        let line = cmdline.get_content().to_string();
+        drop(cmdline);
+        Self::command_processor(s).complete_command_line(line);
+    }
+
+    fn on_command_completion(
+        s: &mut Cursive,
+        line: String,
+        completed: String,
+        candidates: Vec<String>,
+    ) {
+        let mut cmdline = Self::command_line(s);
+        // Ignore completions for a line that has been edited since
+        if *cmdline.get_content() != line {
+            return;
+        }
+        cmdline.set_content(completed.as_str());
+        drop(cmdline);
+
+        {
+            let mut inner = Self::inner_mut(s);
+            let hlen = inner.cmd_history.len();
+            inner.cmd_history_position = hlen - 1;
+            completed.clone_into(&mut inner.cmd_history[hlen - 1]);
+        }
 
-        // save edited command to newest history slot
-        let hlen = inner.cmd_history.len();
-        inner.cmd_history_position = hlen - 1;
-        text.clone_into(&mut inner.cmd_history[hlen - 1]);
+        match candidates.len() {
+            0 => Self::command_hint(s).set_content("No completions"),
+            1 => Self::refresh_command_hint(s, &completed),
+            _ => Self::command_hint(s).set_content(candidates.join("  ")),
+        }
     }
 
     fn enable_command_ui(s: &mut Cursive, enabled: bool) {
@@ -481,6 +577,7 @@ impl CursiveUI {
         // Clear the edit field
         let mut cmdline = Self::command_line(s);
         cmdline.set_content("");
+        Self::command_hint(s).set_content("");
     }
 
     fn on_command_line_history(s: &mut Cursive, dir: bool) {
@@ -502,7 +599,11 @@ impl CursiveUI {
 
         // replace text with current line
         let hlen = inner.cmd_history_position;
-        cmdline.set_content(inner.cmd_history[hlen].as_str());
+        let text = inner.cmd_history[hlen].clone();
+        cmdline.set_content(text.as_str());
+        drop(inner);
+        drop(cmdline);
+        Self::refresh_command_hint(s, &text);
     }
 
     fn on_button_attach_pressed(s: &mut Cursive) {
@@ -652,6 +753,634 @@ impl CursiveUI {
         EventResult::Ignored
     }
 
+    ////////////////////////////////////////////////////////////////////////////////////////////////
+    // DHT record browser
+
+    fn noop_callback() -> UICallback {
+        UICallback::Cursive(Box::new(|_s: &mut Cursive| {}))
+    }
+
+    fn dht_record_key(s: &mut Cursive) -> Option<String> {
+        Self::inner(s).ui_state.dht_record.get()["key"]
+            .as_str()
+            .map(|k| k.to_owned())
+    }
+
+    fn dht_selected_subkey(s: &mut Cursive) -> Option<u32> {
+        Self::dht_record_subkeys(s).and_then(|v| v.selection().map(|x| *x))
+    }
+
+    fn show_dht_browser(s: &mut Cursive) {
+        let mut dht_records_table_view = DhtRecordsTableView::new()
+            .column(DhtRecordTableColumn::Key, "Key", |c| c.width(52))
+            .column(DhtRecordTableColumn::Scope, "Scope", |c| c.width(8))
+            .column(DhtRecordTableColumn::Schema, "Schema", |c| c.width(12))
+            .column(DhtRecordTableColumn::Subkeys, "Subkeys", |c| c.width(8))
+            .column(DhtRecordTableColumn::Stored, "Stored", |c| c)
+            .column(DhtRecordTableColumn::Size, "Size", |c| c.width(10));
+        dht_records_table_view.set_on_submit(CursiveUI::on_submit_dht_records_table_view);
+        dht_records_table_view.set_items(Self::inner(s).ui_state.dht_records.get().clone());
+
+        s.add_layer(
+            Dialog::around(
+                dht_records_table_view
+                    .with_name("dht-records")
+                    .min_size((100, 20)),
+            )
+            .title("DHT Records")
+            .button("Refresh", |s| {
+                Self::command_processor(s).dht_refresh_records(Self::noop_callback());
+            })
+            .button("Open...", CursiveUI::show_dht_open_dialog)
+            .button("Close", |s| {
+                s.pop_layer();
+            }),
+        );
+
+        Self::command_processor(s).dht_refresh_records(Self::noop_callback());
+    }
+
+    fn on_submit_dht_records_table_view(
+        s: &mut Cursive,
+        _row: Option<usize>,
+        index: Option<usize>,
+    ) {
+        let Some(index) = index else {
+            return;
+        };
+        let Some(key) = Self::dht_records(s).and_then(|table| {
+            table
+                .borrow_item(index)
+                .and_then(|j| j["key"].as_str().map(|k| k.to_owned()))
+        }) else {
+            return;
+        };
+        Self::command_processor(s).dht_inspect_record(
+            key,
+            "Local".to_owned(),
+            UICallback::Cursive(Box::new(CursiveUI::show_dht_record_dialog)),
+        );
+    }
+
+    fn show_dht_open_dialog(s: &mut Cursive) {
+        let selected_key = Self::dht_records(s)
+            .and_then(|table| {
+                table.item().and_then(|index| {
+                    table
+                        .borrow_item(index)
+                        .and_then(|j| j["key"].as_str().map(|k| k.to_owned()))
+                })
+            })
+            .unwrap_or_default();
+
+        s.add_layer(
+            Dialog::around(
+                LinearLayout::vertical()
+                    .child(TextView::new("Record Key:"))
+                    .child(
+                        EditView::new()
+                            .content(selected_key)
+                            .with_name("dht-open-key")
+                            .fixed_height(1)
+                            .min_width(60),
+                    )
+                    .child(TextView::new(" "))
+                    .child(TextView::new("Writer Keypair (optional):"))
+                    .child(
+                        EditView::new()
+                            .with_name("dht-open-writer")
+                            .fixed_height(1)
+                            .min_width(60),
+                    ),
+            )
+            .title("Open DHT Record")
+            .button("Open", |s| {
+                let key = s
+                    .call_on_name("dht-open-key", |v: &mut EditView| v.get_content())
+                    .map(|c| c.trim().to_owned())
+                    .unwrap_or_default();
+                let writer = s
+                    .call_on_name("dht-open-writer", |v: &mut EditView| v.get_content())
+                    .map(|c| c.trim().to_owned())
+                    .filter(|w| !w.is_empty());
+                if key.is_empty() {
+                    return;
+                }
+                s.pop_layer();
+                Self::command_processor(s).dht_open_record(
+                    key,
+                    writer,
+                    UICallback::Cursive(Box::new(CursiveUI::show_dht_record_dialog)),
+                );
+            })
+            .button("Cancel", |s| {
+                s.pop_layer();
+            }),
+        );
+    }
+
+    fn show_dht_record_dialog(s: &mut Cursive) {
+        if Self::dht_record_info(s).is_some() {
+            // Already showing, the update will refresh it
+            return;
+        }
+
+        let mut subkeys = SelectView::<u32>::new();
+        subkeys.set_on_submit(|s, subkey: &u32| {
+            if let Some(key) = Self::dht_record_key(s) {
+                Self::command_processor(s).dht_get_value(
+                    key,
+                    *subkey,
+                    false,
+                    Self::noop_callback(),
+                );
+            }
+        });
+
+        s.add_layer(
+            Dialog::around(
+                LinearLayout::vertical()
+                    .child(TextView::new("").with_name("dht-record-info"))
+                    .child(
+                        Panel::new(
+                            subkeys
+                                .with_name("dht-record-subkeys")
+                                .scrollable()
+                                .min_height(8),
+                        )
+                        .title("Subkeys")
+                        .title_position(HAlign::Left),
+                    )
+                    .child(
+                        Panel::new(
+                            TextView::new("")
+                                .with_name("dht-record-changes")
+                                .scrollable()
+                                .scroll_strategy(cursive::view::ScrollStrategy::StickToBottom)
+                                .min_height(4),
+                        )
+                        .title("Value Changes")
+                        .title_position(HAlign::Left),
+                    )
+                    .min_width(80),
+            )
+            .title("DHT Record")
+            .button("Get", |s| Self::on_dht_get_value(s, false))
+            .button("Fetch", |s| Self::on_dht_get_value(s, true))
+            .button("Set...", CursiveUI::show_dht_set_dialog)
+            .button("Sync", |s| {
+                if let Some(key) = Self::dht_record_key(s) {
+                    Self::command_processor(s).dht_inspect_record(
+                        key,
+                        "SyncGet".to_owned(),
+                        Self::noop_callback(),
+                    );
+                }
+            })
+            .button("Watch", |s| {
+                let watched = Self::inner(s).ui_state.dht_record.get()["watched"]
+                    .as_bool()
+                    .unwrap_or_default();
+                if let Some(key) = Self::dht_record_key(s) {
+                    Self::command_processor(s).dht_watch_record(
+                        key,
+                        !watched,
+                        Self::noop_callback(),
+                    );
+                }
+            })
+            .button("Close Record", |s| {
+                if let Some(key) = Self::dht_record_key(s) {
+                    s.pop_layer();
+                    Self::command_processor(s).dht_close_record(key, Self::noop_callback());
+                }
+            })
+            .button("Back", |s| {
+                s.pop_layer();
+            }),
+        );
+
+        Self::refresh_dht_record(s);
+    }
+
+    fn on_dht_get_value(s: &mut Cursive, force_refresh: bool) {
+        let (Some(key), Some(subkey)) = (Self::dht_record_key(s), Self::dht_selected_subkey(s))
+        else {
+            return;
+        };
+        Self::command_processor(s).dht_get_value(key, subkey, force_refresh, Self::noop_callback());
+    }
+
+    fn show_dht_set_dialog(s: &mut Cursive) {
+        let Some(key) = Self::dht_record_key(s) else {
+            return;
+        };
+        let subkey = Self::dht_selected_subkey(s).unwrap_or_default();
+
+        let encoding = SelectView::new()
+            .popup()
+            .item(ValueEncoding::Text.as_str(), ValueEncoding::Text)
+            .item(ValueEncoding::Hex.as_str(), ValueEncoding::Hex)
+            .item(ValueEncoding::Json.as_str(), ValueEncoding::Json);
+
+        s.add_layer(
+            Dialog::around(
+                LinearLayout::vertical()
+                    .child(TextView::new(format!("Record: {}", key)))
+                    .child(TextView::new(" "))
+                    .child(
+                        LinearLayout::horizontal()
+                            .child(TextView::new("Subkey: "))
+                            .child(
+                                EditView::new()
+                                    .content(subkey.to_string())
+                                    .with_name("dht-set-subkey")
+                                    .fixed_width(10),
+                            )
+                            .child(TextView::new("  Encoding: "))
+                            .child(encoding.with_name("dht-set-encoding")),
+                    )
+                    .child(TextView::new(" "))
+                    .child(TextView::new("Value:"))
+                    .child(TextArea::new().with_name("dht-set-value").min_size((60, 5))),
+            )
+            .title("Set DHT Value")
+            .button("Set", move |s| {
+                let subkey = s
+                    .call_on_name("dht-set-subkey", |v: &mut EditView| v.get_content())
+                    .and_then(|c| c.trim().parse::<u32>().ok());
+                let encoding = s
+                    .call_on_name("dht-set-encoding", |v: &mut SelectView<ValueEncoding>| {
+                        v.selection().map(|e| *e)
+                    })
+                    .flatten()
+                    .unwrap_or(ValueEncoding::Text);
+                let text = s
+                    .call_on_name("dht-set-value", |v: &mut TextArea| {
+                        v.get_content().to_owned()
+                    })
+                    .unwrap_or_default();
+
+                let Some(subkey) = subkey else {
+                    Self::display_string_dialog_cb(
+                        s,
+                        "Invalid subkey".to_owned(),
+                        "The subkey must be a number".to_owned(),
+                        None,
+                    );
+                    return;
+                };
+                let data = match encoding.encode(&text) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        Self::display_string_dialog_cb(
+                            s,
+                            format!("Invalid {} value", encoding.as_str()),
+                            e,
+                            None,
+                        );
+                        return;
+                    }
+                };
+                s.pop_layer();
+                Self::command_processor(s).dht_set_value(
+                    key.clone(),
+                    subkey,
+                    data,
+                    Self::noop_callback(),
+                );
+            })
+            .button("Cancel", |s| {
+                s.pop_layer();
+            }),
+        );
+    }
+
+    fn show_dht_value_dialog(s: &mut Cursive, key: String, subkey: u32, value: json::JsonValue) {
+        if value.is_null() {
+            Self::display_string_dialog_cb(
+                s,
+                format!("{} #{}", key, subkey),
+                "No value".to_owned(),
+                None,
+            );
+            return;
+        }
+
+        let data = Arc::new(json_str_vec_u8(&value["data"]));
+        let info = format!(
+            "Seq: {}\nWriter: {}\nSize: {} bytes\n ",
+            value["seq"],
+            value["writer"],
+            data.len()
+        );
+        let encoding = if data.iter().all(|c| (32..=126).contains(c)) {
+            ValueEncoding::Text
+        } else {
+            ValueEncoding::Hex
+        };
+        let text = encoding.decode(&data).unwrap_or_default();
+
+        let mut dialog = Dialog::around(
+            LinearLayout::vertical().child(TextView::new(info)).child(
+                TextView::new(text)
+                    .with_name("dht-value-text")
+                    .scrollable()
+                    .min_size((60, 5)),
+            ),
+        )
+        .title(format!("{} #{}", key, subkey));
+        for encoding in [ValueEncoding::Text, ValueEncoding::Hex, ValueEncoding::Json] {
+            let data = data.clone();
+            dialog.add_button(encoding.as_str(), move |s| {
+                let text = encoding
+                    .decode(&data)
+                    .unwrap_or_else(|e| format!("Value is not {}: {}", encoding.as_str(), e));
+                s.call_on_name("dht-value-text", |v: &mut TextView| v.set_content(text));
+            });
+        }
+        dialog.add_button("Copy", |s| {
+            let text = s
+                .call_on_name("dht-value-text", |v: &mut TextView| {
+                    v.get_content().source().to_owned()
+                })
+                .unwrap_or_default();
+            Self::copy_to_clipboard(s, text);
+        });
+        dialog.add_button("Close", |s| {
+            s.pop_layer();
+        });
+        s.add_layer(dialog);
+    }
+
+    fn on_dht_value_change(s: &mut Cursive, key: String) {
+        Self::refresh_dht_value_changes(s);
+
+        // Pick up the new sequence numbers if this record is being shown
+        if Self::dht_record_info(s).is_some() && Self::dht_record_key(s).as_ref() == Some(&key) {
+            let scope = Self::inner(s).ui_state.dht_record.get()["scope"]
+                .as_str()
+                .unwrap_or("Local")
+                .to_owned();
+            Self::command_processor(s).dht_inspect_record(key, scope, Self::noop_callback());
+        }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////////////////////
+    // Route manager
+
+    fn selected_route(s: &mut Cursive) -> Option<json::JsonValue> {
+        Self::routes(s).and_then(|table| {
+            table
+                .item()
+                .and_then(|index| table.borrow_item(index).cloned())
+        })
+    }
+
+    fn show_route_manager(s: &mut Cursive) {
+        let mut routes_table_view = RoutesTableView::new()
+            .column(RouteTableColumn::RouteId, "Route Id", |c| c.width(45))
+            .column(RouteTableColumn::Kind, "Kind", |c| c.width(10))
+            .column(RouteTableColumn::Published, "Pub", |c| c.width(4))
+            .column(RouteTableColumn::Stability, "Stability", |c| c.width(10))
+            .column(RouteTableColumn::Sequencing, "Sequencing", |c| c.width(10))
+            .column(RouteTableColumn::Hops, "Hops", |c| c.width(5))
+            .column(RouteTableColumn::LatencyAvg, "Latency", |c| c.width(10))
+            .column(RouteTableColumn::Reliability, "Reliability", |c| c);
+        routes_table_view.set_on_submit(CursiveUI::on_submit_routes_table_view);
+        routes_table_view.set_items(Self::inner(s).ui_state.routes.get().clone());
+
+        s.add_layer(
+            Dialog::around(routes_table_view.with_name("routes").min_size((120, 16)))
+                .title("Routes")
+                .button("Refresh", |s| {
+                    Self::command_processor(s).route_refresh(Self::noop_callback());
+                })
+                .button("Test", |s| {
+                    let Some(route) = Self::selected_route(s) else {
+                        return;
+                    };
+                    Self::command_processor(s)
+                        .route_test(route["route_id"].to_string(), Self::noop_callback());
+                })
+                .button("Export", CursiveUI::on_route_export)
+                .button("Release", CursiveUI::on_route_release)
+                .button("Close", |s| {
+                    s.pop_layer();
+                }),
+        );
+
+        Self::command_processor(s).route_refresh(Self::noop_callback());
+    }
+
+    fn on_submit_routes_table_view(s: &mut Cursive, _row: Option<usize>, index: Option<usize>) {
+        let Some(index) = index else {
+            return;
+        };
+        let route_id = Self::routes(s)
+            .and_then(|table| table.borrow_item(index).map(|j| j["route_id"].to_string()));
+        if let Some(route_id) = route_id {
+            Self::copy_to_clipboard(s, route_id);
+        }
+    }
+
+    fn on_route_export(s: &mut Cursive) {
+        let Some(route) = Self::selected_route(s) else {
+            return;
+        };
+        if route["remote"].as_bool().unwrap_or_default() {
+            Self::display_string_dialog_cb(
+                s,
+                "Export Route".to_owned(),
+                "Only allocated routes can be exported".to_owned(),
+                None,
+            );
+            return;
+        }
+        Self::command_processor(s)
+            .route_export(route["route_id"].to_string(), Self::noop_callback());
+    }
+
+    fn on_route_release(s: &mut Cursive) {
+        let Some(route) = Self::selected_route(s) else {
+            return;
+        };
+        let route_id = route["route_id"].to_string();
+        s.add_layer(
+            Dialog::text(format!("Release route {}?", route_id))
+                .title("Release Route")
+                .button("Release", move |s| {
+                    s.pop_layer();
+                    Self::command_processor(s)
+                        .route_release(route_id.clone(), Self::noop_callback());
+                })
+                .dismiss_button("Cancel"),
+        );
+    }
+
+    fn show_route_blob_dialog(s: &mut Cursive, route_id: String, blob: String) {
+        Self::copy_to_clipboard_osc52(s, &blob);
+        s.add_layer(
+            Dialog::around(TextView::new(blob).scrollable().min_size((60, 5)))
+                .title(format!("Route Blob: {}", route_id))
+                .button("Close", |s| {
+                    s.pop_layer();
+                }),
+        );
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////////////////////
+    // Network view
+
+    fn show_network_view(s: &mut Cursive) {
+        let charts = Panel::new(TextView::new("").with_name("network-charts")).title("History");
+        let diagram = Panel::new(
+            TextView::new("")
+                .with_name("network-diagram")
+                .scrollable()
+                .min_height(8),
+        )
+        .title("Relays and Routes");
+
+        s.add_layer(
+            Dialog::around(LinearLayout::vertical().child(charts).child(diagram))
+                .title("Network")
+                .button("Refresh", |s| {
+                    Self::command_processor(s).network_refresh(Self::noop_callback());
+                })
+                .button("Close", |s| {
+                    s.pop_layer();
+                }),
+        );
+
+        Self::refresh_network_charts(s);
+        Self::refresh_network_diagram(s);
+        Self::command_processor(s).network_refresh(Self::noop_callback());
+    }
+
+    fn refresh_network_charts(s: &mut Cursive) {
+        let Some(mut charts) = Self::network_charts(s) else {
+            return;
+        };
+        let inner = Self::inner(s);
+        charts.set_content(inner.network_history.render_charts(NETWORK_CHART_WIDTH));
+    }
+
+    fn refresh_network_diagram(s: &mut Cursive) {
+        let Some(mut diagram) = Self::network_diagram(s) else {
+            return;
+        };
+        let inner = Self::inner(s);
+        diagram.set_content(format_network_diagram(
+            inner.ui_state.routing_table_info.get(),
+            inner.ui_state.routes.get(),
+        ));
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////////////////////
+    // Nodes dashboard
+
+    fn selected_node_name(s: &mut Cursive) -> Option<String> {
+        Self::nodes(s).and_then(|table| {
+            table
+                .item()
+                .and_then(|index| table.borrow_item(index).map(|j| j["name"].to_string()))
+        })
+    }
+
+    fn show_nodes_dashboard(s: &mut Cursive) {
+        let mut nodes_table_view = NodesTableView::new()
+            .column(NodeTableColumn::Name, "Name", |c| c.width(24))
+            .column(NodeTableColumn::Connection, "Connection", |c| c.width(14))
+            .column(NodeTableColumn::Attachment, "Attachment", |c| c.width(16))
+            .column(NodeTableColumn::PublicInternet, "Internet", |c| c.width(9))
+            .column(NodeTableColumn::Peers, "Peers", |c| c.width(6))
+            .column(NodeTableColumn::TransferDown, "Down", |c| c.width(12))
+            .column(NodeTableColumn::TransferUp, "Up", |c| c);
+        nodes_table_view.set_on_submit(CursiveUI::on_submit_nodes_table_view);
+        nodes_table_view.set_items(Self::inner(s).ui_state.nodes.get().clone());
+
+        s.add_layer(
+            Dialog::around(nodes_table_view.with_name("nodes").min_size((100, 10)))
+                .title("Nodes")
+                .button("Switch", |s| {
+                    if let Some(name) = Self::selected_node_name(s) {
+                        Self::switch_node(s, name);
+                    }
+                })
+                .button("Broadcast", CursiveUI::show_broadcast_dialog)
+                .button("Close", |s| {
+                    s.pop_layer();
+                }),
+        );
+    }
+
+    fn on_submit_nodes_table_view(s: &mut Cursive, _row: Option<usize>, index: Option<usize>) {
+        let Some(index) = index else {
+            return;
+        };
+        let name = Self::nodes(s)
+            .and_then(|table| table.borrow_item(index).map(|j| j["name"].to_string()));
+        if let Some(name) = name {
+            Self::switch_node(s, name);
+        }
+    }
+
+    fn switch_node(s: &mut Cursive, name: String) {
+        if let Err(e) = Self::run_command(s, &format!("node switch {}", name)) {
+            Self::display_string_dialog_cb(s, "Switch Node".to_owned(), e, None);
+        }
+    }
+
+    fn show_broadcast_dialog(s: &mut Cursive) {
+        s.add_layer(
+            Dialog::around(
+                EditView::new()
+                    .on_submit(|s, command| {
+                        s.pop_layer();
+                        Self::broadcast_command(s, command);
+                    })
+                    .with_name("broadcast-command")
+                    .fixed_width(60),
+            )
+            .title("Run on all nodes")
+            .button("Run", |s| {
+                let command = s
+                    .call_on_name("broadcast-command", |v: &mut EditView| v.get_content())
+                    .unwrap_or_default();
+                s.pop_layer();
+                Self::broadcast_command(s, &command);
+            })
+            .dismiss_button("Cancel"),
+        );
+    }
+
+    fn broadcast_command(s: &mut Cursive, command: &str) {
+        let command = command.trim();
+        if command.is_empty() {
+            return;
+        }
+        if let Err(e) = Self::run_command(s, &format!("node all {}", command)) {
+            Self::display_string_dialog_cb(s, "Run on all nodes".to_owned(), e, None);
+        }
+    }
+
+    fn refresh_nodes(s: &mut Cursive) {
+        let Some(mut nodes) = CursiveUI::nodes(s) else {
+            return;
+        };
+        let inner = Self::inner(s);
+        let sel_name = nodes
+            .item()
+            .and_then(|x| nodes.borrow_items().get(x).map(|n| n["name"].clone()));
+        nodes.set_items_stable(inner.ui_state.nodes.get().clone());
+        if let Some(name) = sel_name {
+            if let Some(n) = nodes.borrow_items().iter().position(|n| n["name"] == name) {
+                nodes.set_selected_item(n);
+            }
+        }
+    }
+
     fn draw_connection_dialog(s: &mut Cursive, state: ConnectionState) -> bool {
         let is_ipc = Self::command_processor(s).get_ipc_path().is_some();
         let mut inner = Self::inner_mut(s);
@@ -863,7 +1592,12 @@ impl CursiveUI {
     fn refresh_main_titlebar(s: &mut Cursive) {
         let mut main_window = CursiveUI::node_events_panel(s);
         let inner = Self::inner_mut(s);
-        main_window.set_title(format!("Node: {}", inner.ui_state.node_id.get()));
+        match &inner.active_node {
+            Some(name) => {
+                main_window.set_title(format!("Node [{}]: {}", name, inner.ui_state.node_id.get()))
+            }
+            None => main_window.set_title(format!("Node: {}", inner.ui_state.node_id.get())),
+        }
     }
 
     fn refresh_statusbar(s: &mut Cursive) {
@@ -973,6 +1707,137 @@ impl CursiveUI {
         }
     }
 
+    fn refresh_dht_records(s: &mut Cursive) {
+        let Some(mut dht_records) = CursiveUI::dht_records(s) else {
+            return;
+        };
+        let inner = Self::inner_mut(s);
+        let sel_item = dht_records.item();
+        let sel_item_key = dht_records.item().map(|x| {
+            let item = &dht_records.borrow_items()[x];
+            (item["key"].clone(), item["scope"].clone())
+        });
+
+        dht_records.set_items_stable(inner.ui_state.dht_records.get().clone());
+
+        let mut selected = false;
+        if let Some((key, scope)) = sel_item_key {
+            // First select by key
+            for n in 0..dht_records.borrow_items().len() {
+                if dht_records.borrow_items()[n]["key"] == key
+                    && dht_records.borrow_items()[n]["scope"] == scope
+                {
+                    dht_records.set_selected_item(n);
+                    selected = true;
+                }
+            }
+        }
+        if !selected {
+            if let Some(sel_item) = sel_item {
+                dht_records.set_selected_item(sel_item);
+            }
+        }
+    }
+
+    fn refresh_dht_record(s: &mut Cursive) {
+        let (Some(mut info), Some(mut subkeys)) =
+            (Self::dht_record_info(s), Self::dht_record_subkeys(s))
+        else {
+            return;
+        };
+        let record = Self::inner(s).ui_state.dht_record.get().clone();
+
+        info.set_content(format!(
+            "Key: {}\nOwner: {}\nSchema: {}\nWritable: {}\nWatched: {}\nReport: {}",
+            record["key"],
+            record["owner"],
+            format_schema(&record["schema"]),
+            if record["writable"].as_bool().unwrap_or_default() {
+                "yes"
+            } else {
+                "no"
+            },
+            if record["watched"].as_bool().unwrap_or_default() {
+                "yes"
+            } else {
+                "no"
+            },
+            record["scope"],
+        ));
+
+        let format_seq = |seq: Option<u32>| seq.map(|x| x.to_string()).unwrap_or("---".to_owned());
+        let sel_item = subkeys.selected_id();
+        subkeys.clear();
+        for (subkey, local_seq, network_seq) in report_subkey_seqs(&record["report"]) {
+            subkeys.add_item(
+                format!(
+                    "{:>6}  local seq: {:>10}  network seq: {:>10}",
+                    subkey,
+                    format_seq(local_seq),
+                    format_seq(network_seq)
+                ),
+                subkey,
+            );
+        }
+        if let Some(sel_item) = sel_item {
+            if sel_item < subkeys.len() {
+                let _ = subkeys.set_selection(sel_item);
+            }
+        }
+        drop(info);
+        drop(subkeys);
+
+        Self::refresh_dht_value_changes(s);
+    }
+
+    fn refresh_dht_value_changes(s: &mut Cursive) {
+        let Some(mut changes) = Self::dht_record_changes(s) else {
+            return;
+        };
+        let Some(key) = Self::dht_record_key(s) else {
+            return;
+        };
+        let inner = Self::inner(s);
+        let lines = inner
+            .dht_value_changes
+            .iter()
+            .filter(|(k, _)| *k == key)
+            .map(|(_, line)| line.as_str())
+            .collect::<Vec<_>>();
+        changes.set_content(lines.join("\n"));
+    }
+
+    fn refresh_routes(s: &mut Cursive) {
+        let Some(mut routes) = CursiveUI::routes(s) else {
+            return;
+        };
+        let inner = Self::inner_mut(s);
+        let sel_item = routes.item();
+        let sel_item_route_id = routes
+            .item()
+            .map(|x| routes.borrow_items()[x]["route_id"].clone());
+
+        routes.set_items_stable(inner.ui_state.routes.get().clone());
+
+        let mut selected = false;
+        if let Some(route_id) = sel_item_route_id {
+            // First select by route id
+            for n in 0..routes.borrow_items().len() {
+                if routes.borrow_items()[n]["route_id"] == route_id {
+                    routes.set_selected_item(n);
+                    selected = true;
+                }
+            }
+        }
+        if !selected {
+            if let Some(sel_item) = sel_item {
+                if sel_item < routes.borrow_items().len() {
+                    routes.set_selected_item(sel_item);
+                }
+            }
+        }
+    }
+
     fn update_cb(s: &mut Cursive) {
         let mut inner = Self::inner_mut(s);
 
@@ -981,6 +1846,11 @@ impl CursiveUI {
         let mut refresh_connection_dialog = false;
         let mut refresh_peers = false;
         let mut refresh_main_titlebar = false;
+        let mut refresh_dht_records = false;
+        let mut refresh_dht_record = false;
+        let mut refresh_routes = false;
+        let mut refresh_network_diagram = false;
+        let mut refresh_nodes = false;
         if inner.ui_state.attachment_state.take_dirty() {
             refresh_statusbar = true;
             refresh_button_attach = true;
@@ -1004,6 +1874,22 @@ impl CursiveUI {
         if inner.ui_state.node_id.take_dirty() {
             refresh_main_titlebar = true;
         }
+        if inner.ui_state.dht_records.take_dirty() {
+            refresh_dht_records = true;
+        }
+        if inner.ui_state.dht_record.take_dirty() {
+            refresh_dht_record = true;
+        }
+        if inner.ui_state.routes.take_dirty() {
+            refresh_routes = true;
+            refresh_network_diagram = true;
+        }
+        if inner.ui_state.routing_table_info.take_dirty() {
+            refresh_network_diagram = true;
+        }
+        if inner.ui_state.nodes.take_dirty() {
+            refresh_nodes = true;
+        }
 
         drop(inner);
 
@@ -1022,6 +1908,21 @@ impl CursiveUI {
         if refresh_main_titlebar {
             Self::refresh_main_titlebar(s);
         }
+        if refresh_dht_records {
+            Self::refresh_dht_records(s);
+        }
+        if refresh_dht_record {
+            Self::refresh_dht_record(s);
+        }
+        if refresh_routes {
+            Self::refresh_routes(s);
+        }
+        if refresh_network_diagram {
+            Self::refresh_network_diagram(s);
+        }
+        if refresh_nodes {
+            Self::refresh_nodes(s);
+        }
     }
 
     ////////////////////////////////////////////////////////////////////////////
@@ -1114,6 +2015,9 @@ impl CursiveUI {
                 cmd_history_position: 0,
                 cmd_history_max_size: settings.interface.command_line.history_size,
                 connection_dialog_state: None,
+                dht_value_changes: VecDeque::new(),
+                network_history: NetworkHistory::new(),
+                active_node: None,
             })),
         };
 
@@ -1164,13 +2068,21 @@ impl CursiveUI {
         mainlayout.add_child(peers_table_view);
         // ^^^ fixed layout
 
+        mainlayout.add_child(
+            TextView::new("")
+                .style(ColorStyle::secondary())
+                .with_name("command-hint")
+                .full_width()
+                .fixed_height(1),
+        );
+
         let mut command = StyledString::new();
         command.append_styled("Command> ", ColorStyle::title_primary());
         //
-        mainlayout.add_child(
-            LinearLayout::horizontal()
-                .child(TextView::new(command))
-                .child(
+        let mut command_bar = LinearLayout::horizontal()
+            .child(TextView::new(command))
+            .child(
+                OnEventView::new(
                     EditView::new()
                         .on_submit(CursiveUI::on_command_line_entered)
                         .on_edit(CursiveUI::on_command_line_edit)
@@ -1179,17 +2091,25 @@ impl CursiveUI {
                             PaletteColor::Background,
                             PaletteColor::Secondary,
                         ))
-                        .with_name("command-line")
-                        .full_screen()
-                        .fixed_height(1),
+                        .with_name("command-line"),
                 )
-                .child(
-                    Button::new("Attach", |s| {
-                        CursiveUI::on_button_attach_pressed(s);
-                    })
-                    .with_name("button-attach"),
-                ),
-        );
+                .on_pre_event(Key::Tab, CursiveUI::on_command_line_tab)
+                .full_screen()
+                .fixed_height(1),
+            )
+            .child(
+                Button::new("Attach", |s| {
+                    CursiveUI::on_button_attach_pressed(s);
+                })
+                .with_name("button-attach"),
+            )
+            .child(Button::new("DHT", CursiveUI::show_dht_browser))
+            .child(Button::new("Routes", CursiveUI::show_route_manager))
+            .child(Button::new("Network", CursiveUI::show_network_view));
+        if !settings.connections.is_empty() {
+            command_bar.add_child(Button::new("Nodes", CursiveUI::show_nodes_dashboard));
+        }
+        mainlayout.add_child(command_bar);
         let mut version = StyledString::new();
         version.append_styled(
             concat!(" | veilid-cli v", env!("CARGO_PKG_VERSION")),
@@ -1326,9 +2246,20 @@ impl UISender for CursiveUISender {
                     .to_string()
                     .cmp(&b["node_ids"][0].to_string())
             });
+            let attachment_state = inner.ui_state.attachment_state.get().clone();
+            inner.network_history.add_sample(
+                get_timestamp() / 1000,
+                &attachment_state,
+                bps_down,
+                bps_up,
+                &peers,
+            );
             inner.ui_state.peers_state.set(peers);
         }
         let _ = self.cb_sink.send(Box::new(CursiveUI::update_cb));
+        let _ = self
+            .cb_sink
+            .send(Box::new(CursiveUI::refresh_network_charts));
     }
     fn set_config(&mut self, config: &json::JsonValue) {
         let mut inner = self.inner.lock();
@@ -1346,9 +2277,117 @@ impl UISender for CursiveUISender {
 
         inner.ui_state.node_id.set(node_id_str);
     }
+    fn set_dht_records(&mut self, records: Vec<json::JsonValue>) {
+        {
+            let mut inner = self.inner.lock();
+            inner.ui_state.dht_records.set(records);
+        }
+        let _ = self.cb_sink.send(Box::new(CursiveUI::update_cb));
+    }
+    fn set_dht_record(&mut self, record: json::JsonValue) {
+        {
+            let mut inner = self.inner.lock();
+            inner.ui_state.dht_record.set(record);
+        }
+        let _ = self.cb_sink.send(Box::new(CursiveUI::update_cb));
+    }
+    fn show_dht_value(&self, key: &str, subkey: u32, value: &json::JsonValue) {
+        let key = key.to_string();
+        let value = value.clone();
+        let _ = self.cb_sink.send(Box::new(move |s| {
+            CursiveUI::show_dht_value_dialog(s, key, subkey, value);
+        }));
+    }
+    fn add_dht_value_change(&mut self, value_change: &json::JsonValue) {
+        let key = value_change["key"].as_str().unwrap_or_default().to_owned();
+        let mut line = format!(
+            "{}: subkeys={} count={}",
+            CursiveUI::cli_ts(CursiveUI::get_start_time()),
+            format_subkey_ranges(&value_change["subkeys"]),
+            value_change["count"],
+        );
+        if !value_change["value"].is_null() {
+            line.push_str(&format!(
+                " value.seq={} value.size={}",
+                value_change["value"]["seq"],
+                json_str_vec_u8(&value_change["value"]["data"]).len()
+            ));
+        }
+        {
+            let mut inner = self.inner.lock();
+            if inner.dht_value_changes.len() == DHT_VALUE_CHANGES_MAX {
+                inner.dht_value_changes.pop_front();
+            }
+            inner.dht_value_changes.push_back((key.clone(), line));
+        }
+        let _ = self.cb_sink.send(Box::new(move |s| {
+            CursiveUI::on_dht_value_change(s, key);
+        }));
+    }
+    fn set_routes(&mut self, routes: Vec<json::JsonValue>) {
+        {
+            let mut inner = self.inner.lock();
+            inner.ui_state.routes.set(routes);
+        }
+        let _ = self.cb_sink.send(Box::new(CursiveUI::update_cb));
+    }
+    fn show_route_blob(&self, route_id: &str, blob: &str) {
+        let route_id = route_id.to_string();
+        let blob = blob.to_string();
+        let _ = self.cb_sink.send(Box::new(move |s| {
+            CursiveUI::show_route_blob_dialog(s, route_id, blob);
+        }));
+    }
+    fn set_routing_table_info(&mut self, info: json::JsonValue) {
+        {
+            let mut inner = self.inner.lock();
+            inner.ui_state.routing_table_info.set(info);
+        }
+        let _ = self.cb_sink.send(Box::new(CursiveUI::update_cb));
+    }
+    fn set_nodes(&mut self, nodes: Vec<json::JsonValue>) {
+        {
+            let mut inner = self.inner.lock();
+            inner.ui_state.nodes.set(nodes);
+        }
+        let _ = self.cb_sink.send(Box::new(CursiveUI::update_cb));
+    }
+    fn set_active_node(&mut self, name: &str, cmdproc: CommandProcessor) {
+        {
+            let mut inner = self.inner.lock();
+            inner.cmdproc = Some(cmdproc);
+            inner.active_node = Some(name.to_owned());
+
+            // Everything shown so far came from the previous node
+            inner.ui_state.peers_state.set(Vec::new());
+            inner.ui_state.dht_records.set(Vec::new());
+            inner.ui_state.dht_record.set(json::JsonValue::Null);
+            inner.ui_state.routes.set(Vec::new());
+            inner.ui_state.routing_table_info.set(json::JsonValue::Null);
+            inner.dht_value_changes.clear();
+            inner.network_history.clear();
+        }
+        let _ = self.cb_sink.send(Box::new(|s| {
+            CursiveUI::refresh_main_titlebar(s);
+            CursiveUI::update_cb(s);
+        }));
+    }
+    fn set_command_completion(&self, line: &str, completed: &str, candidates: Vec<String>) {
+        let line = line.to_string();
+        let completed = completed.to_string();
+        let _ = self.cb_sink.send(Box::new(move |s| {
+            CursiveUI::on_command_completion(s, line, completed, candidates);
+        }));
+    }
+    fn add_update(&self, _update: &json::JsonValue) {
+        //
+    }
     fn set_connection_state(&mut self, state: ConnectionState) {
         {
             let mut inner = self.inner.lock();
+            if !state.is_connected() {
+                inner.network_history.clear();
+            }
             inner.ui_state.connection_state.set(state);
         }
         let _ = self.cb_sink.send(Box::new(CursiveUI::update_cb));
@@ -0,0 +1,203 @@
+use super::*;
+use cursive_table_view::*;
+use std::cmp::Ordering;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DhtRecordTableColumn {
+    Key,
+    Scope,
+    Schema,
+    Subkeys,
+    Stored,
+    Size,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ValueEncoding {
+    Text,
+    Hex,
+    Json,
+}
+
+impl ValueEncoding {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ValueEncoding::Text => "Text",
+            ValueEncoding::Hex => "Hex",
+            ValueEncoding::Json => "JSON",
+        }
+    }
+
+    pub fn decode(&self, data: &[u8]) -> Result<String, String> {
+        match self {
+            ValueEncoding::Text => Ok(String::from_utf8_lossy(data).to_string()),
+            ValueEncoding::Hex => Ok(hex::encode(data)),
+            ValueEncoding::Json => {
+                let text = std::str::from_utf8(data).map_err(|e| e.to_string())?;
+                let value = json::parse(text).map_err(|e| e.to_string())?;
+                Ok(json::stringify_pretty(value, 2))
+            }
+        }
+    }
+
+    pub fn encode(&self, text: &str) -> Result<Vec<u8>, String> {
+        match self {
+            ValueEncoding::Text => Ok(text.as_bytes().to_vec()),
+            ValueEncoding::Hex => hex::decode(text.trim()).map_err(|e| e.to_string()),
+            ValueEncoding::Json => {
+                let value = json::parse(text).map_err(|e| e.to_string())?;
+                Ok(value.dump().into_bytes())
+            }
+        }
+    }
+}
+
+pub fn format_schema(schema: &json::JsonValue) -> String {
+    match schema["kind"].as_str() {
+        Some("DFLT") => format!("DFLT({})", schema["o_cnt"]),
+        Some("SMPL") => {
+            let members = schema["members"]
+                .members()
+                .map(|m| m["m_cnt"].to_string())
+                .collect::<Vec<_>>()
+                .join("+");
+            format!("SMPL({};{})", schema["o_cnt"], members)
+        }
+        _ => "---".to_owned(),
+    }
+}
+
+pub fn format_subkey_ranges(ranges: &json::JsonValue) -> String {
+    if ranges.is_empty() {
+        return "---".to_owned();
+    }
+    ranges
+        .members()
+        .map(|r| {
+            if r[0] == r[1] {
+                r[0].to_string()
+            } else {
+                format!("{}-{}", r[0], r[1])
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Pairs each subkey in an inspection report with its local and network sequence numbers
+pub fn report_subkey_seqs(report: &json::JsonValue) -> Vec<(u32, Option<u32>, Option<u32>)> {
+    let seq = |v: &json::JsonValue| v.as_u32().filter(|s| *s != u32::MAX);
+
+    let mut out = Vec::new();
+    for range in report["subkeys"].members() {
+        let (Some(start), Some(end)) = (range[0].as_u32(), range[1].as_u32()) else {
+            continue;
+        };
+        for subkey in start..=end {
+            let n = out.len();
+            out.push((
+                subkey,
+                seq(&report["local_seqs"][n]),
+                seq(&report["network_seqs"][n]),
+            ));
+        }
+    }
+    out
+}
+
+fn format_size(size: &json::JsonValue) -> String {
+    if size.is_null() {
+        return "---".to_owned();
+    }
+    let size = size.as_u64().unwrap_or_default();
+    if size >= 1024u64 * 1024u64 {
+        format!("{:.2}MB", size as f64 / (1024.0 * 1024.0))
+    } else if size >= 1024u64 {
+        format!("{:.2}KB", size as f64 / 1024.0)
+    } else {
+        format!("{}B", size)
+    }
+}
+
+impl TableViewItem<DhtRecordTableColumn> for json::JsonValue {
+    fn to_column(&self, column: DhtRecordTableColumn) -> String {
+        match column {
+            DhtRecordTableColumn::Key => self["key"].to_string(),
+            DhtRecordTableColumn::Scope => self["scope"].to_string(),
+            DhtRecordTableColumn::Schema => format_schema(&self["schema"]),
+            DhtRecordTableColumn::Subkeys => {
+                if self["subkey_count"].is_null() {
+                    "---".to_owned()
+                } else {
+                    self["subkey_count"].to_string()
+                }
+            }
+            DhtRecordTableColumn::Stored => format_subkey_ranges(&self["stored_subkeys"]),
+            DhtRecordTableColumn::Size => format_size(&self["record_data_size"]),
+        }
+    }
+
+    fn cmp(&self, other: &Self, column: DhtRecordTableColumn) -> Ordering
+    where
+        Self: Sized,
+    {
+        match column {
+            DhtRecordTableColumn::Key
+            | DhtRecordTableColumn::Scope
+            | DhtRecordTableColumn::Schema
+            | DhtRecordTableColumn::Stored => self
+                .to_column(column)
+                .to_ascii_lowercase()
+                .cmp(&other.to_column(column).to_ascii_lowercase()),
+            DhtRecordTableColumn::Subkeys => self["subkey_count"]
+                .as_u32()
+                .cmp(&other["subkey_count"].as_u32()),
+            DhtRecordTableColumn::Size => self["record_data_size"]
+                .as_u64()
+                .cmp(&other["record_data_size"].as_u64()),
+        }
+    }
+}
+
+pub type DhtRecordsTableView = TableView<json::JsonValue, DhtRecordTableColumn>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_encoding() {
+        for (encoding, text) in [
+            (ValueEncoding::Text, "hello"),
+            (ValueEncoding::Hex, "00ff7f"),
+            (ValueEncoding::Json, r#"{"a": [1, 2]}"#),
+        ] {
+            let data = encoding.encode(text).unwrap();
+            let decoded = encoding.decode(&data).unwrap();
+            assert_eq!(encoding.encode(&decoded).unwrap(), data);
+        }
+        assert_eq!(ValueEncoding::Hex.decode(b"\x00\xff").unwrap(), "00ff");
+        assert!(ValueEncoding::Hex.encode("0g").is_err());
+        assert!(ValueEncoding::Json.encode("{").is_err());
+        assert!(ValueEncoding::Json.decode(b"not json").is_err());
+    }
+
+    #[test]
+    fn test_report_subkey_seqs() {
+        let report = json::parse(
+            r#"{
+                "subkeys": [[0, 1], [4, 4]],
+                "offline_subkeys": [],
+                "local_seqs": [3, 4294967295, 0],
+                "network_seqs": []
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            report_subkey_seqs(&report),
+            vec![(0, Some(3), None), (1, None, None), (4, Some(0), None)]
+        );
+        assert_eq!(format_subkey_ranges(&report["subkeys"]), "0-1,4");
+        assert_eq!(format_subkey_ranges(&report["offline_subkeys"]), "---");
+    }
+}
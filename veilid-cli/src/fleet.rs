@@ -0,0 +1,525 @@
+use crate::command_processor::*;
+use crate::tools::*;
+use crate::ui::*;
+use flexi_logger::writers::LogWriter;
+use std::sync::Weak;
+
+#[derive(Clone)]
+struct FleetNodeStatus {
+    connection_state: ConnectionState,
+    attachment_state: String,
+    public_internet_ready: bool,
+    peer_count: usize,
+    bps_down: u64,
+    bps_up: u64,
+}
+
+impl Default for FleetNodeStatus {
+    fn default() -> Self {
+        Self {
+            connection_state: ConnectionState::Disconnected,
+            attachment_state: "Detached".to_owned(),
+            public_internet_ready: false,
+            peer_count: 0,
+            bps_down: 0,
+            bps_up: 0,
+        }
+    }
+}
+
+struct FleetNode {
+    name: String,
+    cmdproc: Option<CommandProcessor>,
+    status: FleetNodeStatus,
+}
+
+struct FleetInner {
+    ui_sender: Box<dyn UISender>,
+    nodes: Vec<FleetNode>,
+    active: usize,
+}
+
+impl FleetInner {
+    fn node_mut(&mut self, name: &str) -> Option<&mut FleetNode> {
+        self.nodes.iter_mut().find(|n| n.name == name)
+    }
+
+    fn is_active(&self, name: &str) -> bool {
+        self.nodes
+            .get(self.active)
+            .is_some_and(|node| node.name == name)
+    }
+
+    fn node_rows(&self) -> Vec<json::JsonValue> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(n, node)| {
+                let mut row = json::JsonValue::new_object();
+                row["name"] = node.name.clone().into();
+                row["active"] = (n == self.active).into();
+                row["connection"] = match node.status.connection_state {
+                    ConnectionState::Disconnected => "Disconnected",
+                    ConnectionState::ConnectedTCP(_, _) | ConnectionState::ConnectedIPC(_, _) => {
+                        "Connected"
+                    }
+                    ConnectionState::RetryingTCP(_, _) | ConnectionState::RetryingIPC(_, _) => {
+                        "Retrying"
+                    }
+                }
+                .into();
+                row["attachment_state"] = node.status.attachment_state.clone().into();
+                row["public_internet_ready"] = node.status.public_internet_ready.into();
+                row["peers"] = node.status.peer_count.into();
+                row["bps_down"] = node.status.bps_down.to_string().into();
+                row["bps_up"] = node.status.bps_up.to_string().into();
+                row
+            })
+            .collect()
+    }
+}
+
+/// Several named veilid-server connections managed from one client.
+/// Each node has its own command processor, and only the active node drives the UI.
+#[derive(Clone)]
+pub struct Fleet {
+    inner: Arc<Mutex<FleetInner>>,
+}
+
+/// A handle to a fleet that does not keep it alive, held by its own command processors
+#[derive(Clone)]
+pub struct WeakFleet {
+    inner: Weak<Mutex<FleetInner>>,
+}
+
+impl WeakFleet {
+    pub fn upgrade(&self) -> Option<Fleet> {
+        self.inner.upgrade().map(|inner| Fleet { inner })
+    }
+}
+
+impl Fleet {
+    pub fn new(ui_sender: Box<dyn UISender>) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(FleetInner {
+                ui_sender,
+                nodes: Vec::new(),
+                active: 0,
+            })),
+        }
+    }
+
+    pub fn downgrade(&self) -> WeakFleet {
+        WeakFleet {
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+
+    /// Adds a named node, creating its command processor with a UI sender for that node
+    pub fn add_node<F>(&self, name: &str, make_cmdproc: F) -> Result<CommandProcessor, String>
+    where
+        F: FnOnce(Box<dyn UISender>) -> CommandProcessor,
+    {
+        let ui_sender = {
+            let mut inner = self.inner.lock();
+            if inner.node_mut(name).is_some() {
+                return Err(format!("Duplicate connection name '{}'", name));
+            }
+            inner.nodes.push(FleetNode {
+                name: name.to_owned(),
+                cmdproc: None,
+                status: FleetNodeStatus::default(),
+            });
+            FleetUISender {
+                name: name.to_owned(),
+                fleet: self.downgrade(),
+                ui_sender: inner.ui_sender.clone_uisender(),
+            }
+        };
+        let cmdproc = make_cmdproc(Box::new(ui_sender));
+        cmdproc.set_fleet(self.downgrade());
+        if let Some(node) = self.inner.lock().node_mut(name) {
+            node.cmdproc = Some(cmdproc.clone());
+        }
+        Ok(cmdproc)
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.inner
+            .lock()
+            .nodes
+            .iter()
+            .map(|n| n.name.clone())
+            .collect()
+    }
+
+    pub fn active_command_processor(&self) -> CommandProcessor {
+        let inner = self.inner.lock();
+        inner.nodes[inner.active].cmdproc.clone().unwrap()
+    }
+
+    /// Makes a node the one the UI shows and runs commands on
+    pub fn switch(&self, name: &str) -> Result<(), String> {
+        let (cmdproc, connection_state, mut ui_sender) = {
+            let mut inner = self.inner.lock();
+            let Some(n) = inner.nodes.iter().position(|n| n.name == name) else {
+                return Err(format!("Unknown node '{}'", name));
+            };
+            inner.active = n;
+            let node = &inner.nodes[n];
+            (
+                node.cmdproc.clone().unwrap(),
+                node.status.connection_state.clone(),
+                inner.ui_sender.clone_uisender(),
+            )
+        };
+        ui_sender.set_active_node(name, cmdproc.clone());
+        ui_sender.set_connection_state(connection_state);
+        self.publish_status();
+        // Reload the state of the newly active node into the UI
+        cmdproc.refresh_state();
+        Ok(())
+    }
+
+    /// Runs a command on every node, reporting one result that combines those of all the nodes
+    pub fn broadcast(&self, command: &str, callback: UICallback) {
+        let (nodes, ui_sender) = {
+            let inner = self.inner.lock();
+            (
+                inner
+                    .nodes
+                    .iter()
+                    .map(|n| (n.name.clone(), n.cmdproc.clone().unwrap()))
+                    .collect::<Vec<_>>(),
+                inner.ui_sender.clone_uisender(),
+            )
+        };
+        let group = FleetCallback::new(
+            nodes.iter().map(|(name, _)| name.clone()).collect(),
+            callback,
+            ui_sender,
+        );
+        for (name, cmdproc) in nodes {
+            if let Err(e) = cmdproc.run_command(command, UICallback::Fleet(group.clone())) {
+                group.add_result(&name, Err(e));
+            }
+        }
+    }
+
+    pub fn list(&self) -> String {
+        let inner = self.inner.lock();
+        inner
+            .node_rows()
+            .iter()
+            .map(|row| {
+                format!(
+                    "{} {} {} {} peers={}",
+                    if row["active"].as_bool().unwrap_or_default() {
+                        "*"
+                    } else {
+                        " "
+                    },
+                    row["name"],
+                    row["connection"],
+                    row["attachment_state"],
+                    row["peers"]
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn publish_status(&self) {
+        let mut inner = self.inner.lock();
+        let rows = inner.node_rows();
+        inner.ui_sender.set_nodes(rows);
+    }
+
+    /// Updates the status of a node, returning true if it is the active node
+    fn update_status<F: FnOnce(&mut FleetNodeStatus)>(&self, name: &str, f: F) -> bool {
+        let is_active = {
+            let mut inner = self.inner.lock();
+            if let Some(node) = inner.node_mut(name) {
+                f(&mut node.status);
+            }
+            inner.is_active(name)
+        };
+        self.publish_status();
+        is_active
+    }
+
+    fn is_active(&self, name: &str) -> bool {
+        self.inner.lock().is_active(name)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+struct FleetCallbackInner {
+    results: Vec<(String, Option<Result<String, String>>)>,
+    callback: Option<UICallback>,
+    ui_sender: Box<dyn UISender>,
+}
+
+/// Collects the result of a broadcast command from each node, and reports them together
+/// once every node has finished
+#[derive(Clone)]
+pub struct FleetCallback {
+    inner: Arc<Mutex<FleetCallbackInner>>,
+}
+
+impl FleetCallback {
+    fn new(names: Vec<String>, callback: UICallback, ui_sender: Box<dyn UISender>) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(FleetCallbackInner {
+                results: names.into_iter().map(|n| (n, None)).collect(),
+                callback: Some(callback),
+                ui_sender,
+            })),
+        }
+    }
+
+    fn add_result(&self, name: &str, result: Result<String, String>) {
+        let done = {
+            let mut inner = self.inner.lock();
+            if let Some((_, slot)) = inner
+                .results
+                .iter_mut()
+                .find(|(n, slot)| n == name && slot.is_none())
+            {
+                *slot = Some(result);
+            }
+            if inner.results.iter().all(|(_, r)| r.is_some()) {
+                inner.callback.take().map(|callback| {
+                    let results = inner
+                        .results
+                        .iter()
+                        .map(|(n, r)| (n.clone(), r.clone().unwrap()))
+                        .collect::<Vec<_>>();
+                    (
+                        combine_results(results),
+                        callback,
+                        inner.ui_sender.clone_uisender(),
+                    )
+                })
+            } else {
+                None
+            }
+        };
+        if let Some((result, callback, ui_sender)) = done {
+            ui_sender.display_command_result(result, callback);
+        }
+    }
+}
+
+/// Joins the results of a command run on several nodes, failing if any of them failed
+fn combine_results(results: Vec<(String, Result<String, String>)>) -> Result<String, String> {
+    let failed = results.iter().any(|(_, r)| r.is_err());
+    let out = results
+        .into_iter()
+        .map(|(name, result)| match result {
+            Ok(output) if output.trim().is_empty() => format!("[{}] ok", name),
+            Ok(output) => format!("[{}] {}", name, output.trim_end()),
+            Err(e) => format!("[{}] error: {}", name, e),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    if failed {
+        Err(out)
+    } else {
+        Ok(out)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+/// The UI sender of one node in a fleet. It keeps the node's status for the fleet dashboard,
+/// and passes updates on to the UI only while the node is the active one.
+pub struct FleetUISender {
+    name: String,
+    fleet: WeakFleet,
+    ui_sender: Box<dyn UISender>,
+}
+
+impl FleetUISender {
+    fn is_active(&self) -> bool {
+        self.fleet
+            .upgrade()
+            .is_some_and(|fleet| fleet.is_active(&self.name))
+    }
+
+    fn update_status<F: FnOnce(&mut FleetNodeStatus)>(&self, f: F) -> bool {
+        self.fleet
+            .upgrade()
+            .is_some_and(|fleet| fleet.update_status(&self.name, f))
+    }
+}
+
+impl UISender for FleetUISender {
+    fn clone_uisender(&self) -> Box<dyn UISender> {
+        Box::new(FleetUISender {
+            name: self.name.clone(),
+            fleet: self.fleet.clone(),
+            ui_sender: self.ui_sender.clone_uisender(),
+        })
+    }
+    fn as_logwriter(&self) -> Option<Box<dyn LogWriter>> {
+        self.ui_sender.as_logwriter()
+    }
+
+    fn display_string_dialog(&self, title: &str, text: &str, close_cb: UICallback) {
+        if let UICallback::Fleet(group) = close_cb {
+            group.add_result(&self.name, Ok(format!("{}: {}", title, text)));
+            return;
+        }
+        self.ui_sender
+            .display_string_dialog(&format!("[{}] {}", self.name, title), text, close_cb);
+    }
+    fn quit(&self) {
+        self.ui_sender.quit();
+    }
+    fn send_callback(&self, callback: UICallback) {
+        if let UICallback::Fleet(group) = callback {
+            group.add_result(&self.name, Ok(String::new()));
+            return;
+        }
+        self.ui_sender.send_callback(callback);
+    }
+    fn display_command_result(&self, result: Result<String, String>, callback: UICallback) {
+        if let UICallback::Fleet(group) = callback {
+            group.add_result(&self.name, result);
+            return;
+        }
+        self.ui_sender.display_command_result(result, callback);
+    }
+    fn set_attachment_state(
+        &mut self,
+        state: &str,
+        public_internet_ready: bool,
+        local_network_ready: bool,
+    ) {
+        if self.update_status(|status| {
+            status.attachment_state = state.to_owned();
+            status.public_internet_ready = public_internet_ready;
+        }) {
+            self.ui_sender
+                .set_attachment_state(state, public_internet_ready, local_network_ready);
+        }
+    }
+    fn set_network_status(
+        &mut self,
+        started: bool,
+        bps_down: u64,
+        bps_up: u64,
+        peers: Vec<json::JsonValue>,
+    ) {
+        if self.update_status(|status| {
+            status.peer_count = peers.len();
+            status.bps_down = bps_down;
+            status.bps_up = bps_up;
+        }) {
+            self.ui_sender
+                .set_network_status(started, bps_down, bps_up, peers);
+        }
+    }
+    fn set_config(&mut self, config: &json::JsonValue) {
+        if self.is_active() {
+            self.ui_sender.set_config(config);
+        }
+    }
+    fn set_dht_records(&mut self, records: Vec<json::JsonValue>) {
+        if self.is_active() {
+            self.ui_sender.set_dht_records(records);
+        }
+    }
+    fn set_dht_record(&mut self, record: json::JsonValue) {
+        if self.is_active() {
+            self.ui_sender.set_dht_record(record);
+        }
+    }
+    fn show_dht_value(&self, key: &str, subkey: u32, value: &json::JsonValue) {
+        if self.is_active() {
+            self.ui_sender.show_dht_value(key, subkey, value);
+        }
+    }
+    fn add_dht_value_change(&mut self, value_change: &json::JsonValue) {
+        if self.is_active() {
+            self.ui_sender.add_dht_value_change(value_change);
+        }
+    }
+    fn set_routes(&mut self, routes: Vec<json::JsonValue>) {
+        if self.is_active() {
+            self.ui_sender.set_routes(routes);
+        }
+    }
+    fn show_route_blob(&self, route_id: &str, blob: &str) {
+        if self.is_active() {
+            self.ui_sender.show_route_blob(route_id, blob);
+        }
+    }
+    fn set_command_completion(&self, line: &str, completed: &str, candidates: Vec<String>) {
+        if self.is_active() {
+            self.ui_sender
+                .set_command_completion(line, completed, candidates);
+        }
+    }
+    fn set_routing_table_info(&mut self, info: json::JsonValue) {
+        if self.is_active() {
+            self.ui_sender.set_routing_table_info(info);
+        }
+    }
+    fn set_nodes(&mut self, nodes: Vec<json::JsonValue>) {
+        self.ui_sender.set_nodes(nodes);
+    }
+    fn set_active_node(&mut self, name: &str, cmdproc: CommandProcessor) {
+        self.ui_sender.set_active_node(name, cmdproc);
+    }
+    fn add_update(&self, update: &json::JsonValue) {
+        if self.is_active() {
+            self.ui_sender.add_update(update);
+        }
+    }
+    fn set_connection_state(&mut self, state: ConnectionState) {
+        if self.update_status(|status| status.connection_state = state.clone()) {
+            self.ui_sender.set_connection_state(state);
+        }
+    }
+
+    fn add_node_event(&self, log_color: Level, event: &str) {
+        if self.is_active() {
+            self.ui_sender.add_node_event(log_color, event);
+        } else {
+            self.ui_sender
+                .add_node_event(log_color, &format!("[{}] {}", self.name, event));
+        }
+    }
+    fn add_log_event(&self, log_color: Level, event: &str) {
+        // Only the active node's log is shown, the others would drown it out
+        if self.is_active() {
+            self.ui_sender.add_log_event(log_color, event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combine_results() {
+        assert_eq!(
+            combine_results(vec![
+                ("a".to_owned(), Ok("done\n".to_owned())),
+                ("b".to_owned(), Ok(String::new())),
+            ]),
+            Ok("[a] done\n[b] ok".to_owned())
+        );
+        assert_eq!(
+            combine_results(vec![
+                ("a".to_owned(), Ok("done".to_owned())),
+                ("b".to_owned(), Err("Cancelled".to_owned())),
+            ]),
+            Err("[a] done\n[b] error: Cancelled".to_owned())
+        );
+    }
+}
@@ -251,6 +251,40 @@ impl<R: AsyncRead + Unpin + Send + 'static, W: AsyncWrite + Unpin + Send + 'stat
     fn set_config(&mut self, _config: &json::JsonValue) {
         //
     }
+    fn set_dht_records(&mut self, _records: Vec<json::JsonValue>) {
+        //
+    }
+    fn set_dht_record(&mut self, _record: json::JsonValue) {
+        //
+    }
+    fn show_dht_value(&self, _key: &str, _subkey: u32, _value: &json::JsonValue) {
+        //
+    }
+    fn add_dht_value_change(&mut self, _value_change: &json::JsonValue) {
+        //
+    }
+    fn set_routes(&mut self, _routes: Vec<json::JsonValue>) {
+        //
+    }
+    fn show_route_blob(&self, _route_id: &str, _blob: &str) {
+        //
+    }
+    fn set_routing_table_info(&mut self, _info: json::JsonValue) {
+        //
+    }
+    fn set_command_completion(&self, _line: &str, _completed: &str, _candidates: Vec<String>) {
+        //
+    }
+    fn set_nodes(&mut self, _nodes: Vec<json::JsonValue>) {
+        //
+    }
+    fn set_active_node(&mut self, _name: &str, cmdproc: CommandProcessor) {
+        let mut inner = self.inner.lock();
+        inner.cmdproc = Some(cmdproc);
+    }
+    fn add_update(&self, _update: &json::JsonValue) {
+        //
+    }
     fn set_connection_state(&mut self, state: ConnectionState) {
         if let Err(e) = self.connection_state_sender.send(state) {
             eprintln!("Error: {:?}", e);
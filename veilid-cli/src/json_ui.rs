@@ -0,0 +1,354 @@
+use crate::command_processor::*;
+use crate::settings::*;
+use crate::tools::*;
+use crate::ui::*;
+
+use futures::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader, BufWriter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use stop_token::future::FutureExt as StopTokenFutureExt;
+use stop_token::*;
+use veilid_tools::AsyncMutex;
+
+use flexi_logger::writers::LogWriter;
+
+static FINISHED_LINE: &str = "\x7F ===FINISHED=== \x7F";
+
+pub type JsonUICallback = Box<dyn FnMut() + Send>;
+
+pub struct JsonUIInner<R: AsyncRead + Unpin + Send, W: AsyncWrite + Unpin + Send> {
+    cmdproc: Option<CommandProcessor>,
+    in_io: Arc<AsyncMutex<BufReader<R>>>,
+    out_io: Arc<AsyncMutex<BufWriter<W>>>,
+    out_receiver: flume::Receiver<String>,
+    out_sender: flume::Sender<String>,
+    done: Option<StopSource>,
+    connection_state_receiver: flume::Receiver<ConnectionState>,
+    command: Option<String>,
+    failed: Arc<AtomicBool>,
+}
+
+/// Scripting UI that reads commands like IOReadWriteUI, but writes every command result
+/// and every update from the server as one JSON object per line.
+/// Processing stops at the first command that fails.
+pub struct JsonUI<R: AsyncRead + Unpin + Send, W: AsyncWrite + Unpin + Send> {
+    inner: Arc<Mutex<JsonUIInner<R, W>>>,
+}
+impl<R: AsyncRead + Unpin + Send, W: AsyncWrite + Unpin + Send> Clone for JsonUI<R, W> {
+    fn clone(&self) -> Self {
+        JsonUI {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin + Send, W: AsyncWrite + Unpin + Send> JsonUI<R, W> {
+    pub fn new(_settings: &Settings, in_io: R, out_io: W) -> (Self, JsonUISender<R, W>) {
+        // Create the UI object
+        let (sender, receiver) = flume::unbounded::<String>();
+        let (cssender, csreceiver) = flume::unbounded::<ConnectionState>();
+        let this = Self {
+            inner: Arc::new(Mutex::new(JsonUIInner {
+                cmdproc: None,
+                in_io: Arc::new(AsyncMutex::new(BufReader::new(in_io))),
+                out_io: Arc::new(AsyncMutex::new(BufWriter::new(out_io))),
+                out_receiver: receiver,
+                out_sender: sender.clone(),
+                connection_state_receiver: csreceiver,
+                done: Some(StopSource::new()),
+                command: None,
+                failed: Arc::new(AtomicBool::new(false)),
+            })),
+        };
+
+        let ui_sender = JsonUISender {
+            inner: this.inner.clone(),
+            out_sender: sender,
+            connection_state_sender: cssender,
+        };
+
+        (this, ui_sender)
+    }
+
+    /// Set once any command has failed, so the process can exit with an error
+    pub fn failed(&self) -> Arc<AtomicBool> {
+        self.inner.lock().failed.clone()
+    }
+
+    pub async fn output_loop(&self) {
+        let out_receiver = self.inner.lock().out_receiver.clone();
+        let out_io = self.inner.lock().out_io.clone();
+
+        let mut out = out_io.lock().await;
+        let done = self.inner.lock().done.as_ref().unwrap().token();
+
+        while let Ok(Ok(line)) = out_receiver.recv_async().timeout_at(done.clone()).await {
+            if line == FINISHED_LINE {
+                break;
+            }
+            let line = format!("{}\n", line);
+            if let Err(e) = out.write_all(line.as_bytes()).await {
+                eprintln!("Error: {:?}", e);
+                break;
+            }
+            if let Err(e) = out.flush().await {
+                eprintln!("Error: {:?}", e);
+                break;
+            }
+        }
+    }
+
+    pub async fn command_loop(&self) {
+        let (in_io, out_sender, connection_state_receiver, failed, done) = {
+            let inner = self.inner.lock();
+            (
+                inner.in_io.clone(),
+                inner.out_sender.clone(),
+                inner.connection_state_receiver.clone(),
+                inner.failed.clone(),
+                inner.done.as_ref().unwrap().token(),
+            )
+        };
+        let mut in_io = in_io.lock().await;
+
+        let (exec_sender, exec_receiver) = flume::bounded(1);
+
+        // Wait for connection to be established
+        loop {
+            match connection_state_receiver.recv_async().await {
+                Ok(ConnectionState::ConnectedTCP(_, _))
+                | Ok(ConnectionState::ConnectedIPC(_, _)) => {
+                    break;
+                }
+                Ok(ConnectionState::RetryingTCP(_, _)) | Ok(ConnectionState::RetryingIPC(_, _)) => {
+                }
+                Ok(ConnectionState::Disconnected) => {}
+                Err(e) => {
+                    eprintln!("Error: {:?}", e);
+                    self.inner.lock().done.take();
+                    break;
+                }
+            }
+        }
+
+        // Process the input
+        loop {
+            let mut line = String::new();
+            match in_io.read_line(&mut line).timeout_at(done.clone()).await {
+                Ok(Ok(bytes)) => {
+                    if bytes == 0 {
+                        // Clean exit after everything else is sent
+                        if let Err(e) = out_sender.send(FINISHED_LINE.to_string()) {
+                            eprintln!("Error: {:?}", e);
+                            self.inner.lock().done.take();
+                        }
+                        break;
+                    }
+                    let line = line.trim();
+                    if !line.is_empty() {
+                        let cmdproc = {
+                            let mut inner = self.inner.lock();
+                            inner.command = Some(line.to_owned());
+                            inner.cmdproc.clone()
+                        };
+                        if let Some(cmdproc) = &cmdproc {
+                            // Run command
+                            if let Err(e) = cmdproc.run_command(
+                                line,
+                                UICallback::Json(Box::new({
+                                    let exec_sender = exec_sender.clone();
+                                    move || {
+                                        // Let the next command execute
+                                        if let Err(e) = exec_sender.send(()) {
+                                            eprintln!("Error: {:?}", e);
+                                        }
+                                    }
+                                })),
+                            ) {
+                                eprintln!("Error: {:?}", e);
+                                self.inner.lock().done.take();
+                                break;
+                            }
+                            // Wait until command is done executing before running the next line
+                            if let Err(e) = exec_receiver.recv_async().await {
+                                eprintln!("Error: {:?}", e);
+                                self.inner.lock().done.take();
+                                break;
+                            }
+                            // Stop the script at the first failed command
+                            if failed.load(Ordering::Acquire) {
+                                if let Err(e) = out_sender.send(FINISHED_LINE.to_string()) {
+                                    eprintln!("Error: {:?}", e);
+                                    self.inner.lock().done.take();
+                                }
+                                break;
+                            }
+                        }
+                    }
+                }
+                Ok(Err(e)) => {
+                    eprintln!("IO Error: {:?}", e);
+                    self.inner.lock().done.take();
+                    break;
+                }
+                Err(_) => {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin + Send + 'static, W: AsyncWrite + Unpin + Send + 'static> UI
+    for JsonUI<R, W>
+{
+    fn set_command_processor(&mut self, cmdproc: CommandProcessor) {
+        let mut inner = self.inner.lock();
+        inner.cmdproc = Some(cmdproc);
+    }
+    fn run_async(&mut self) -> Pin<Box<dyn core::future::Future<Output = ()>>> {
+        let this = self.clone();
+        Box::pin(async move {
+            let out_fut = this.output_loop();
+            let cmd_fut = this.command_loop();
+            futures::join!(out_fut, cmd_fut);
+        })
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone)]
+pub struct JsonUISender<R: AsyncRead + Unpin + Send, W: AsyncWrite + Unpin + Send> {
+    inner: Arc<Mutex<JsonUIInner<R, W>>>,
+    out_sender: flume::Sender<String>,
+    connection_state_sender: flume::Sender<ConnectionState>,
+}
+
+impl<R: AsyncRead + Unpin + Send, W: AsyncWrite + Unpin + Send> JsonUISender<R, W> {
+    fn send_json(&self, value: json::JsonValue) {
+        if let Err(e) = self.out_sender.send(value.dump()) {
+            eprintln!("Error: {:?}", e);
+            self.inner.lock().done.take();
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin + Send + 'static, W: AsyncWrite + Unpin + Send + 'static> UISender
+    for JsonUISender<R, W>
+{
+    fn clone_uisender(&self) -> Box<dyn UISender> {
+        Box::new(JsonUISender {
+            inner: self.inner.clone(),
+            out_sender: self.out_sender.clone(),
+            connection_state_sender: self.connection_state_sender.clone(),
+        })
+    }
+    fn as_logwriter(&self) -> Option<Box<dyn LogWriter>> {
+        None
+    }
+
+    fn display_string_dialog(&self, title: &str, text: &str, close_cb: UICallback) {
+        let mut message = json::JsonValue::new_object();
+        message["type"] = "Message".into();
+        message["title"] = title.into();
+        message["text"] = text.into();
+        self.send_json(message);
+        if let UICallback::Json(mut close_cb) = close_cb {
+            close_cb()
+        }
+    }
+
+    fn quit(&self) {
+        self.inner.lock().done.take();
+    }
+
+    fn send_callback(&self, callback: UICallback) {
+        if let UICallback::Json(mut callback) = callback {
+            callback();
+        }
+    }
+    fn display_command_result(&self, result: Result<String, String>, callback: UICallback) {
+        let mut out = json::JsonValue::new_object();
+        out["type"] = "CommandResult".into();
+        out["command"] = self.inner.lock().command.clone().unwrap_or_default().into();
+        match result {
+            Ok(output) => {
+                out["ok"] = true.into();
+                out["output"] = output.into();
+            }
+            Err(e) => {
+                out["ok"] = false.into();
+                out["error"] = e.into();
+                self.inner.lock().failed.store(true, Ordering::Release);
+            }
+        }
+        self.send_json(out);
+        self.send_callback(callback);
+    }
+    fn set_attachment_state(
+        &mut self,
+        _state: &str,
+        _public_internet_ready: bool,
+        _local_network_ready: bool,
+    ) {
+        //
+    }
+    fn set_network_status(
+        &mut self,
+        _started: bool,
+        _bps_down: u64,
+        _bps_up: u64,
+        mut _peers: Vec<json::JsonValue>,
+    ) {
+        //
+    }
+    fn set_config(&mut self, _config: &json::JsonValue) {
+        //
+    }
+    fn set_dht_records(&mut self, _records: Vec<json::JsonValue>) {
+        //
+    }
+    fn set_dht_record(&mut self, _record: json::JsonValue) {
+        //
+    }
+    fn show_dht_value(&self, _key: &str, _subkey: u32, _value: &json::JsonValue) {
+        //
+    }
+    fn add_dht_value_change(&mut self, _value_change: &json::JsonValue) {
+        //
+    }
+    fn set_routes(&mut self, _routes: Vec<json::JsonValue>) {
+        //
+    }
+    fn show_route_blob(&self, _route_id: &str, _blob: &str) {
+        //
+    }
+    fn set_routing_table_info(&mut self, _info: json::JsonValue) {
+        //
+    }
+    fn set_command_completion(&self, _line: &str, _completed: &str, _candidates: Vec<String>) {
+        //
+    }
+    fn set_nodes(&mut self, _nodes: Vec<json::JsonValue>) {
+        //
+    }
+    fn set_active_node(&mut self, _name: &str, cmdproc: CommandProcessor) {
+        let mut inner = self.inner.lock();
+        inner.cmdproc = Some(cmdproc);
+    }
+    fn add_update(&self, update: &json::JsonValue) {
+        // Updates are passed through as the server sent them
+        self.send_json(update.clone());
+    }
+    fn set_connection_state(&mut self, state: ConnectionState) {
+        if let Err(e) = self.connection_state_sender.send(state) {
+            eprintln!("Error: {:?}", e);
+            self.inner.lock().done.take();
+        }
+    }
+
+    fn add_node_event(&self, _log_color: Level, _event: &str) {
+        // Node events are text renderings of updates, which are written out as they are received
+    }
+    fn add_log_event(&self, _log_color: Level, _event: &str) {}
+}
@@ -7,16 +7,23 @@ use crate::{settings::NamedSocketAddrs, tools::*, ui::*};
 
 use clap::{Parser, ValueEnum};
 use flexi_logger::*;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 mod cached_text_view;
 mod client_api_connection;
+mod command_completion;
 mod command_processor;
 mod cursive_ui;
+mod dht_records_table_view;
+mod fleet;
 mod interactive_ui;
 mod io_read_write_ui;
+mod json_ui;
 mod log_viewer_ui;
+mod network_history;
+mod nodes_table_view;
 mod peers_table_view;
+mod routes_table_view;
 mod settings;
 mod tools;
 mod ui;
@@ -41,6 +48,9 @@ struct CmdlineArgs {
     /// Address to connect to
     #[arg(long, short = 'a')]
     address: Option<String>,
+    /// Named connection from the configuration file to start on, when it has several
+    #[arg(long, conflicts_with_all = ["ipc_path", "address"])]
+    node: Option<String>,
     /// Wait for debugger to attach
     #[arg(long)]
     wait_for_debug: bool,
@@ -67,6 +77,19 @@ struct CmdlineArgs {
         value_name = "COMMAND_FILE"
     )]
     command_file: Option<PathBuf>,
+    /// write command results and updates as json lines, exiting with an error if a command fails
+    /// (commands are read from stdin unless --evaluate or --command-file is given)
+    #[arg(long, conflicts_with_all = ["interactive", "log"])]
+    json: bool,
+}
+
+/// The IPC socket of a named connection, whose path may be a directory of subnode sockets
+fn named_connection_ipc_path(ipc_path: &Path, subnode_index: usize) -> PathBuf {
+    if !is_ipc_socket_path(ipc_path) && ipc_path.is_dir() {
+        ipc_path.join(subnode_index.to_string())
+    } else {
+        ipc_path.to_owned()
+    }
 }
 
 fn main() -> Result<(), String> {
@@ -102,14 +125,25 @@ fn main() -> Result<(), String> {
             settings.logging.terminal.enabled = true;
         }
 
+        // A server given on the command line replaces the named connections
+        if args.ipc_path.is_some() || args.address.is_some() {
+            settings.connections.clear();
+        }
+
         // If we are running in interactive mode disable some things
         let mut enable_cursive = true;
-        if args.interactive || args.log || args.command_file.is_some() || args.evaluate.is_some() {
+        if args.interactive
+            || args.log
+            || args.command_file.is_some()
+            || args.evaluate.is_some()
+            || args.json
+        {
             settings.logging.terminal.enabled = false;
             enable_cursive = false;
         }
 
         // Create UI object
+        let mut json_failed = None;
         let (mut ui, uisender) = if enable_cursive {
             let (ui, uisender) = cursive_ui::CursiveUI::new(&settings);
             (
@@ -122,11 +156,16 @@ fn main() -> Result<(), String> {
                 Box::new(ui) as Box<dyn UI>,
                 Box::new(uisender) as Box<dyn UISender>,
             )
-        } else if let Some(command_file) = args.command_file {
+        } else if args.command_file.is_some() || args.evaluate.is_some() || args.json {
+            // Commands come from the evaluate argument, a file, or stdin
+            let command_file = args.command_file.unwrap_or_else(|| PathBuf::from("-"));
             cfg_if! {
                 if #[cfg(feature="rt-async-std")] {
                     let (in_obj, out_obj) =
-                        if command_file.to_string_lossy() == "-" {
+                        if let Some(evaluate) = args.evaluate {
+                            let in_str = format!("{}\n", evaluate);
+                            (Box::pin(futures::io::Cursor::new(in_str)) as Pin<Box<dyn futures::AsyncRead + Send>>, async_std::io::stdout())
+                        } else if command_file.to_string_lossy() == "-" {
                             (Box::pin(async_std::io::stdin()) as Pin<Box<dyn futures::AsyncRead + Send>>, async_std::io::stdout())
                         } else {
                             let f = match async_std::fs::File::open(command_file).await {
@@ -140,7 +179,10 @@ fn main() -> Result<(), String> {
                 } else if #[cfg(feature="rt-tokio")] {
                     use tokio_util::compat::{TokioAsyncWriteCompatExt, TokioAsyncReadCompatExt};
                     let (in_obj, out_obj) =
-                        if command_file.to_string_lossy() == "-" {
+                        if let Some(evaluate) = args.evaluate {
+                            let in_str = format!("{}\n", evaluate);
+                            (Box::pin(futures::io::Cursor::new(in_str)) as Pin<Box<dyn futures::AsyncRead + Send>>, tokio::io::stdout().compat_write())
+                        } else if command_file.to_string_lossy() == "-" {
                             (Box::pin(tokio::io::stdin().compat()) as Pin<Box<dyn futures::AsyncRead + Send>>, tokio::io::stdout().compat_write())
                         } else {
                             let f = match tokio::fs::File::open(command_file).await {
@@ -156,30 +198,21 @@ fn main() -> Result<(), String> {
                 }
             }
 
-            let (ui, uisender) = io_read_write_ui::IOReadWriteUI::new(&settings, in_obj, out_obj);
-            (
-                Box::new(ui) as Box<dyn UI>,
-                Box::new(uisender) as Box<dyn UISender>,
-            )
-        } else if let Some(evaluate) = args.evaluate {
-            cfg_if! {
-                if #[cfg(feature="rt-async-std")] {
-                    let in_str = format!("{}\n", evaluate);
-                    let (in_obj, out_obj) = (futures::io::Cursor::new(in_str), async_std::io::stdout());
-                } else if #[cfg(feature="rt-tokio")] {
-                    use tokio_util::compat::{TokioAsyncWriteCompatExt};
-                    let in_str = format!("{}\n", evaluate);
-                    let (in_obj, out_obj) = (futures::io::Cursor::new(in_str), tokio::io::stdout().compat_write());
-                } else {
-                    compile_error!("needs executor implementation");
-                }
+            if args.json {
+                let (ui, uisender) = json_ui::JsonUI::new(&settings, in_obj, out_obj);
+                json_failed = Some(ui.failed());
+                (
+                    Box::new(ui) as Box<dyn UI>,
+                    Box::new(uisender) as Box<dyn UISender>,
+                )
+            } else {
+                let (ui, uisender) =
+                    io_read_write_ui::IOReadWriteUI::new(&settings, in_obj, out_obj);
+                (
+                    Box::new(ui) as Box<dyn UI>,
+                    Box::new(uisender) as Box<dyn UISender>,
+                )
             }
-
-            let (ui, uisender) = io_read_write_ui::IOReadWriteUI::new(&settings, in_obj, out_obj);
-            (
-                Box::new(ui) as Box<dyn UI>,
-                Box::new(uisender) as Box<dyn UISender>,
-            )
         } else if args.log {
             let (ui, uisender) = log_viewer_ui::LogViewerUI::new(&settings);
             (
@@ -303,39 +336,84 @@ fn main() -> Result<(), String> {
             }
         }
 
-        // Create command processor
-        debug!("Creating Command Processor ");
-        let comproc = command_processor::CommandProcessor::new(uisender, &settings);
+        // Create a command processor and client api connection for each server
+        let mut nodes = Vec::new();
+        let _fleet = if settings.connections.is_empty() {
+            debug!("Creating Command Processor ");
+            let comproc = command_processor::CommandProcessor::new(uisender, &settings);
 
-        ui.set_command_processor(comproc.clone());
+            ui.set_command_processor(comproc.clone());
 
-        // Create client api client side
-        info!("Starting API connection");
-        let capi = client_api_connection::ClientApiConnection::new(comproc.clone());
+            // Create client api client side
+            info!("Starting API connection");
+            let capi = client_api_connection::ClientApiConnection::new(comproc.clone());
 
-        // Save client api in command processor
-        comproc.set_client_api_connection(capi.clone());
+            // Save client api in command processor
+            comproc.set_client_api_connection(capi.clone());
 
-        // Keep a connection to the server
-        if let Some(client_api_ipc_path) = client_api_ipc_path {
-            comproc.set_ipc_path(Some(client_api_ipc_path));
-        } else if let Some(client_api_network_address) = client_api_network_addresses {
-            let network_addr = client_api_network_address.first().cloned();
-            comproc.set_network_address(network_addr);
+            // Keep a connection to the server
+            if let Some(client_api_ipc_path) = client_api_ipc_path {
+                comproc.set_ipc_path(Some(client_api_ipc_path));
+            } else if let Some(client_api_network_address) = client_api_network_addresses {
+                let network_addr = client_api_network_address.first().cloned();
+                comproc.set_network_address(network_addr);
+            } else {
+                return Err("veilid-server could not be reached".to_owned());
+            }
+            nodes.push((comproc, capi));
+            None
         } else {
-            return Err("veilid-server could not be reached".to_owned());
-        }
+            // Several named servers, only the active one is shown in the UI
+            let fleet = fleet::Fleet::new(uisender);
+            for connection in &settings.connections {
+                debug!("Creating Command Processor for {}", connection.name);
+                let comproc = fleet.add_node(&connection.name, |uisender| {
+                    command_processor::CommandProcessor::new(uisender, &settings)
+                })?;
 
-        let comproc2 = comproc.clone();
-        let connection_future = comproc.connection_manager();
+                info!("Starting API connection to {}", connection.name);
+                let capi = client_api_connection::ClientApiConnection::new(comproc.clone());
+                comproc.set_client_api_connection(capi.clone());
+
+                if let Some(ipc_path) = &connection.ipc_path {
+                    comproc.set_ipc_path(Some(named_connection_ipc_path(
+                        ipc_path,
+                        connection.subnode_index,
+                    )));
+                } else if let Some(address) = &connection.address {
+                    comproc.set_network_address(address.addrs.first().cloned());
+                } else {
+                    return Err(format!(
+                        "Connection '{}' needs an ipc_path or an address",
+                        connection.name
+                    ));
+                }
+                nodes.push((comproc, capi));
+            }
+
+            let active = args
+                .node
+                .unwrap_or_else(|| settings.connections[0].name.clone());
+            fleet.switch(&active)?;
+            ui.set_command_processor(fleet.active_command_processor());
+            Some(fleet)
+        };
+
+        let connection_future = futures::future::join_all(
+            nodes
+                .iter()
+                .map(|(comproc, _)| comproc.connection_manager()),
+        );
 
         // Start UI
-        let ui_future = async move {
+        let ui_future = async {
             ui.run_async().await;
 
-            // When UI quits, close connection and command processor cleanly
-            comproc2.quit();
-            capi.disconnect().await;
+            // When UI quits, close connections and command processors cleanly
+            for (comproc, capi) in &nodes {
+                comproc.quit();
+                capi.disconnect().await;
+            }
         };
 
         cfg_if! {
@@ -350,6 +428,11 @@ fn main() -> Result<(), String> {
                 compile_error!("needs executor implementation");
             }
         }
+
+        // Scripts see a failed command in the exit code
+        if json_failed.is_some_and(|failed| failed.load(std::sync::atomic::Ordering::Acquire)) {
+            std::process::exit(1);
+        }
         Ok(())
     })
 }
@@ -0,0 +1,365 @@
+use super::*;
+use crate::command_processor::ATTACHMENT_STATES;
+use crate::routes_table_view::format_reliability;
+use std::collections::{HashMap, VecDeque};
+
+/// The number of network updates kept for the charts
+pub const NETWORK_HISTORY_MAX: usize = 600;
+
+const SPARK_CHARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NetworkSample {
+    pub timestamp_ms: u64,
+    pub attachment_state: String,
+    pub bps_down: u64,
+    pub bps_up: u64,
+    pub peer_count: usize,
+    /// Questions sent and answers received per second since the previous sample
+    pub questions_per_sec: f64,
+    pub answers_per_sec: f64,
+    /// Answers that were given up on since the previous sample
+    pub lost_answers: u64,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct RpcCounters {
+    questions_sent: u64,
+    answers_rcvd: u64,
+    lost_answers: u64,
+}
+
+impl RpcCounters {
+    fn from_peer(peer: &json::JsonValue) -> Self {
+        let rpc_stats = &peer["peer_stats"]["rpc_stats"];
+        Self {
+            questions_sent: rpc_stats["questions_sent"].as_u64().unwrap_or_default(),
+            answers_rcvd: rpc_stats["answers_rcvd"].as_u64().unwrap_or_default(),
+            lost_answers: rpc_stats["lost_answers"].as_u64().unwrap_or_default(),
+        }
+    }
+}
+
+/// Peer counts seen while the node was in one attachment state
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttachmentPeerCounts {
+    pub attachment_state: String,
+    pub samples: usize,
+    pub min: usize,
+    pub max: usize,
+    pub average: f64,
+}
+
+/// A rolling history of the network updates from the server, for drawing charts
+#[derive(Default)]
+pub struct NetworkHistory {
+    samples: VecDeque<NetworkSample>,
+    rpc_counters: HashMap<String, RpcCounters>,
+}
+
+impl NetworkHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+        self.rpc_counters.clear();
+    }
+
+    /// Records a network update. RPC rates come from the change in each peer's counters,
+    /// so peers seen for the first time do not count until their next update.
+    pub fn add_sample(
+        &mut self,
+        timestamp_ms: u64,
+        attachment_state: &str,
+        bps_down: u64,
+        bps_up: u64,
+        peers: &[json::JsonValue],
+    ) {
+        let mut rpc_counters = HashMap::new();
+        let mut delta = RpcCounters::default();
+        for peer in peers {
+            let node_id = peer["node_ids"][0].to_string();
+            let cur = RpcCounters::from_peer(peer);
+            if let Some(prev) = self.rpc_counters.get(&node_id) {
+                delta.questions_sent += cur.questions_sent.saturating_sub(prev.questions_sent);
+                delta.answers_rcvd += cur.answers_rcvd.saturating_sub(prev.answers_rcvd);
+                delta.lost_answers += cur.lost_answers.saturating_sub(prev.lost_answers);
+            }
+            rpc_counters.insert(node_id, cur);
+        }
+        self.rpc_counters = rpc_counters;
+
+        let elapsed_ms = self
+            .samples
+            .back()
+            .map(|s| timestamp_ms.saturating_sub(s.timestamp_ms))
+            .unwrap_or_default();
+        let per_sec = |count: u64| {
+            if elapsed_ms == 0 {
+                0.0
+            } else {
+                count as f64 * 1000.0 / elapsed_ms as f64
+            }
+        };
+
+        if self.samples.len() == NETWORK_HISTORY_MAX {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(NetworkSample {
+            timestamp_ms,
+            attachment_state: attachment_state.to_owned(),
+            bps_down,
+            bps_up,
+            peer_count: peers.len(),
+            questions_per_sec: per_sec(delta.questions_sent),
+            answers_per_sec: per_sec(delta.answers_rcvd),
+            lost_answers: delta.lost_answers,
+        });
+    }
+
+    /// Peer counts for each attachment state the node has been in, weakest state first
+    pub fn peers_by_attachment(&self) -> Vec<AttachmentPeerCounts> {
+        let mut out: Vec<AttachmentPeerCounts> = Vec::new();
+        for sample in &self.samples {
+            match out
+                .iter_mut()
+                .find(|c| c.attachment_state == sample.attachment_state)
+            {
+                Some(c) => {
+                    c.average = (c.average * c.samples as f64 + sample.peer_count as f64)
+                        / (c.samples + 1) as f64;
+                    c.samples += 1;
+                    c.min = c.min.min(sample.peer_count);
+                    c.max = c.max.max(sample.peer_count);
+                }
+                None => out.push(AttachmentPeerCounts {
+                    attachment_state: sample.attachment_state.clone(),
+                    samples: 1,
+                    min: sample.peer_count,
+                    max: sample.peer_count,
+                    average: sample.peer_count as f64,
+                }),
+            }
+        }
+        out.sort_by_key(|c| {
+            ATTACHMENT_STATES
+                .iter()
+                .position(|s| *s == c.attachment_state)
+                .unwrap_or(ATTACHMENT_STATES.len())
+        });
+        out
+    }
+
+    /// Renders a chart line for each series, covering the most recent `width` samples
+    pub fn render_charts(&self, width: usize) -> String {
+        if self.samples.is_empty() {
+            return "No network updates received yet".to_owned();
+        }
+        let series = |f: &dyn Fn(&NetworkSample) -> f64| -> Vec<f64> {
+            self.samples.iter().map(f).collect()
+        };
+        let kbps = |v: f64| format!("{:.2}KB/s", v / 1000.0);
+        let count = |v: f64| format!("{:.0}", v);
+        let rate = |v: f64| format!("{:.2}/s", v);
+
+        let mut out = String::new();
+        for (name, values, fmt) in [
+            (
+                "Down",
+                series(&|s| s.bps_down as f64),
+                &kbps as &dyn Fn(f64) -> String,
+            ),
+            ("Up", series(&|s| s.bps_up as f64), &kbps),
+            ("Peers", series(&|s| s.peer_count as f64), &count),
+            ("Questions", series(&|s| s.questions_per_sec), &rate),
+            ("Answers", series(&|s| s.answers_per_sec), &rate),
+            ("Lost answers", series(&|s| s.lost_answers as f64), &count),
+        ] {
+            let cur = values.last().copied().unwrap_or_default();
+            let max = values.iter().copied().fold(0.0, f64::max);
+            out += &format!(
+                "{:<13}{:<width$} now {:>12} max {:>12}\n",
+                name,
+                sparkline(&values, width),
+                fmt(cur),
+                fmt(max),
+                width = width
+            );
+        }
+
+        out += "\nPeers per attachment state:\n";
+        for c in self.peers_by_attachment() {
+            out += &format!(
+                "  {:<15} min {:>4} max {:>4} avg {:>7.1} ({} updates)\n",
+                c.attachment_state, c.min, c.max, c.average, c.samples
+            );
+        }
+        out
+    }
+}
+
+/// Draws the last `width` values as a row of bars scaled to the largest of them
+pub fn sparkline(values: &[f64], width: usize) -> String {
+    let values = &values[values.len().saturating_sub(width)..];
+    let max = values.iter().copied().fold(0.0, f64::max);
+    values
+        .iter()
+        .map(|v| {
+            if max <= 0.0 {
+                SPARK_CHARS[0]
+            } else {
+                let level = (v / max * (SPARK_CHARS.len() - 1) as f64).round() as usize;
+                SPARK_CHARS[level.min(SPARK_CHARS.len() - 1)]
+            }
+        })
+        .collect()
+}
+
+/// Draws this node's relays, the nodes relaying through it, and its private routes,
+/// from a routing table info response and the route health list
+pub fn format_network_diagram(info: &json::JsonValue, routes: &[json::JsonValue]) -> String {
+    if info.is_null() {
+        return "Routing table not loaded yet".to_owned();
+    }
+    let node_ids: Vec<String> = info["node_ids"].members().map(|n| n.to_string()).collect();
+    let entry_state = |id: &json::JsonValue| -> String {
+        info["entries"]
+            .members()
+            .find(|e| e["node_ids"].members().any(|n| n == id))
+            .map(|e| e["state_reason"].to_string())
+            .unwrap_or_else(|| "unknown".to_owned())
+    };
+
+    let mut out = format!("[this node {}]\n", node_ids.join(" "));
+    for (domain, relay) in [
+        ("PublicInternet", &info["public_internet_relay"]),
+        ("LocalNetwork", &info["local_network_relay"]),
+    ] {
+        if relay.is_empty() {
+            out += &format!("  |-- {}: direct\n", domain);
+        } else {
+            for id in relay.members() {
+                out += &format!("  |-- {} relay --> [{}] {}\n", domain, id, entry_state(id));
+            }
+        }
+    }
+
+    for entry in info["entries"].members() {
+        let relays_through_us = ["public_internet", "local_network"].iter().any(|domain| {
+            entry[*domain]["relay_ids"]
+                .members()
+                .any(|id| node_ids.contains(&id.to_string()))
+        });
+        if relays_through_us {
+            out += &format!(
+                "  |<-- relaying for [{}] {}\n",
+                entry["node_ids"][0], entry["state_reason"]
+            );
+        }
+    }
+
+    for route in routes {
+        let hops = format!("{} hops", route["hop_count"]);
+        if route["remote"].as_bool().unwrap_or_default() {
+            out += &format!(
+                "  |<== remote route {} ({}, {})\n",
+                route["route_id"],
+                hops,
+                format_reliability(route)
+            );
+        } else {
+            let published = if route["published"].as_bool().unwrap_or_default() {
+                ", published"
+            } else {
+                ""
+            };
+            out += &format!(
+                "  |==> allocated route {} ({}{}, {})\n",
+                route["route_id"],
+                hops,
+                published,
+                format_reliability(route)
+            );
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn peer(node_id: &str, questions: u32, answers: u32, lost: u32) -> json::JsonValue {
+        json::parse(&format!(
+            r#"{{"node_ids": ["{}"], "peer_stats": {{"rpc_stats": {{
+                "questions_sent": {}, "answers_rcvd": {}, "lost_answers": {}}}}}}}"#,
+            node_id, questions, answers, lost
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_network_history() {
+        let mut history = NetworkHistory::new();
+        history.add_sample(1000, "Attaching", 100, 50, &[peer("A", 10, 8, 1)]);
+        history.add_sample(
+            3000,
+            "AttachedWeak",
+            200,
+            50,
+            &[peer("A", 30, 20, 3), peer("B", 100, 100, 0)],
+        );
+        history.add_sample(4000, "AttachedWeak", 0, 0, &[peer("B", 110, 104, 1)]);
+
+        let samples = &history.samples;
+        assert_eq!(samples[0].questions_per_sec, 0.0);
+        // Only peer A was known before the second sample
+        assert_eq!(samples[1].questions_per_sec, 10.0);
+        assert_eq!(samples[1].answers_per_sec, 6.0);
+        assert_eq!(samples[1].lost_answers, 2);
+        assert_eq!(samples[2].questions_per_sec, 10.0);
+        assert_eq!(samples[2].lost_answers, 1);
+
+        let counts = history.peers_by_attachment();
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[0].attachment_state, "Attaching");
+        assert_eq!(counts[1].attachment_state, "AttachedWeak");
+        assert_eq!((counts[1].min, counts[1].max), (1, 2));
+        assert_eq!(counts[1].average, 1.5);
+    }
+
+    #[test]
+    fn test_sparkline() {
+        assert_eq!(sparkline(&[0.0, 0.0], 10), "▁▁");
+        assert_eq!(sparkline(&[0.0, 7.0, 14.0], 10), "▁▅█");
+        assert_eq!(sparkline(&[14.0, 0.0, 7.0], 2), "▁█");
+    }
+
+    #[test]
+    fn test_network_diagram() {
+        let info = json::parse(
+            r#"{"node_ids": ["VLD0:me"], "public_internet_relay": ["VLD0:relay"],
+                "local_network_relay": [], "entries": [
+                {"node_ids": ["VLD0:relay"], "state_reason": "RELIBL"},
+                {"node_ids": ["VLD0:child"], "state_reason": "UNRELY",
+                 "public_internet": {"relay_ids": ["VLD0:me"]}}
+            ]}"#,
+        )
+        .unwrap();
+        let routes = vec![json::parse(
+            r#"{"route_id": "r1", "remote": false, "published": true, "hop_count": 2,
+                "successes": 1, "failures": 0, "recent_failures": 0}"#,
+        )
+        .unwrap()];
+        assert_eq!(
+            format_network_diagram(&info, &routes),
+            "[this node VLD0:me]\n\
+             \x20 |-- PublicInternet relay --> [VLD0:relay] RELIBL\n\
+             \x20 |-- LocalNetwork: direct\n\
+             \x20 |<-- relaying for [VLD0:child] UNRELY\n\
+             \x20 |==> allocated route r1 (2 hops, published, 100% (0 recent fail))\n"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use super::*;
+use crate::peers_table_view::format_bps;
+use cursive_table_view::*;
+use std::cmp::Ordering;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NodeTableColumn {
+    Name,
+    Connection,
+    Attachment,
+    PublicInternet,
+    Peers,
+    TransferDown,
+    TransferUp,
+}
+
+impl TableViewItem<NodeTableColumn> for json::JsonValue {
+    fn to_column(&self, column: NodeTableColumn) -> String {
+        match column {
+            NodeTableColumn::Name => {
+                if self["active"].as_bool().unwrap_or_default() {
+                    format!("* {}", self["name"])
+                } else {
+                    format!("  {}", self["name"])
+                }
+            }
+            NodeTableColumn::Connection => self["connection"].to_string(),
+            NodeTableColumn::Attachment => self["attachment_state"].to_string(),
+            NodeTableColumn::PublicInternet => {
+                if self["public_internet_ready"].as_bool().unwrap_or_default() {
+                    "ready".to_owned()
+                } else {
+                    "---".to_owned()
+                }
+            }
+            NodeTableColumn::Peers => self["peers"].to_string(),
+            NodeTableColumn::TransferDown => format_bps(&self["bps_down"]),
+            NodeTableColumn::TransferUp => format_bps(&self["bps_up"]),
+        }
+    }
+
+    fn cmp(&self, other: &Self, column: NodeTableColumn) -> Ordering
+    where
+        Self: Sized,
+    {
+        match column {
+            NodeTableColumn::Name => self["name"].to_string().cmp(&other["name"].to_string()),
+            NodeTableColumn::Connection
+            | NodeTableColumn::Attachment
+            | NodeTableColumn::PublicInternet => self
+                .to_column(column)
+                .to_ascii_lowercase()
+                .cmp(&other.to_column(column).to_ascii_lowercase()),
+            NodeTableColumn::Peers => self["peers"].as_u32().cmp(&other["peers"].as_u32()),
+            NodeTableColumn::TransferDown => {
+                json_str_u64(&self["bps_down"]).cmp(&json_str_u64(&other["bps_down"]))
+            }
+            NodeTableColumn::TransferUp => {
+                json_str_u64(&self["bps_up"]).cmp(&json_str_u64(&other["bps_up"]))
+            }
+        }
+    }
+}
+
+pub type NodesTableView = TableView<json::JsonValue, NodeTableColumn>;
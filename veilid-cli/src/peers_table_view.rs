@@ -23,7 +23,7 @@ pub enum PeerTableColumn {
 //     }
 // }
 
-fn format_ts(ts: &json::JsonValue) -> String {
+pub fn format_ts(ts: &json::JsonValue) -> String {
     if ts.is_null() {
         return "---".to_owned();
     }
@@ -36,7 +36,7 @@ fn format_ts(ts: &json::JsonValue) -> String {
     }
 }
 
-fn format_bps(bps: &json::JsonValue) -> String {
+pub fn format_bps(bps: &json::JsonValue) -> String {
     if bps.is_null() {
         return "---".to_owned();
     }
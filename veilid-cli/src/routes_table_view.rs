@@ -0,0 +1,121 @@
+use super::*;
+use crate::peers_table_view::format_ts;
+use cursive_table_view::*;
+use std::cmp::Ordering;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RouteTableColumn {
+    RouteId,
+    Kind,
+    Published,
+    Stability,
+    Sequencing,
+    Hops,
+    LatencyAvg,
+    Reliability,
+}
+
+/// Fraction of the messages and questions sent over a route that got through, if any were sent
+pub fn route_reliability(route: &json::JsonValue) -> Option<f64> {
+    let successes = route["successes"].as_u64().unwrap_or_default();
+    let failures = route["failures"].as_u64().unwrap_or_default();
+    if successes + failures == 0 {
+        return None;
+    }
+    Some(successes as f64 / (successes + failures) as f64)
+}
+
+pub fn format_reliability(route: &json::JsonValue) -> String {
+    match route_reliability(route) {
+        Some(r) => format!(
+            "{:.0}% ({} recent fail)",
+            r * 100.0,
+            route["recent_failures"]
+        ),
+        None => "---".to_owned(),
+    }
+}
+
+impl TableViewItem<RouteTableColumn> for json::JsonValue {
+    fn to_column(&self, column: RouteTableColumn) -> String {
+        match column {
+            RouteTableColumn::RouteId => self["route_id"].to_string(),
+            RouteTableColumn::Kind => {
+                if self["remote"].as_bool().unwrap_or_default() {
+                    "Remote".to_owned()
+                } else {
+                    "Allocated".to_owned()
+                }
+            }
+            RouteTableColumn::Published => {
+                if self["published"].as_bool().unwrap_or_default() {
+                    "yes".to_owned()
+                } else {
+                    "no".to_owned()
+                }
+            }
+            RouteTableColumn::Stability => {
+                if self["stability"].is_null() {
+                    "---".to_owned()
+                } else {
+                    self["stability"].to_string()
+                }
+            }
+            RouteTableColumn::Sequencing => match self["can_do_sequenced"].as_bool() {
+                Some(true) => "Ordered".to_owned(),
+                Some(false) => "Unordered".to_owned(),
+                None => "---".to_owned(),
+            },
+            RouteTableColumn::Hops => self["hop_count"].to_string(),
+            RouteTableColumn::LatencyAvg => format_ts(&self["latency"]["average"]),
+            RouteTableColumn::Reliability => format_reliability(self),
+        }
+    }
+
+    fn cmp(&self, other: &Self, column: RouteTableColumn) -> Ordering
+    where
+        Self: Sized,
+    {
+        match column {
+            RouteTableColumn::RouteId
+            | RouteTableColumn::Kind
+            | RouteTableColumn::Published
+            | RouteTableColumn::Stability
+            | RouteTableColumn::Sequencing => self
+                .to_column(column)
+                .to_ascii_lowercase()
+                .cmp(&other.to_column(column).to_ascii_lowercase()),
+            RouteTableColumn::Hops => self["hop_count"].as_u32().cmp(&other["hop_count"].as_u32()),
+            RouteTableColumn::LatencyAvg => json_str_u64(&self["latency"]["average"])
+                .cmp(&json_str_u64(&other["latency"]["average"])),
+            RouteTableColumn::Reliability => route_reliability(self)
+                .partial_cmp(&route_reliability(other))
+                .unwrap_or(Ordering::Equal),
+        }
+    }
+}
+
+pub type RoutesTableView = TableView<json::JsonValue, RouteTableColumn>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_reliability() {
+        let route = json::parse(
+            r#"{"route_id": "abc", "successes": 3, "failures": 1, "recent_failures": 1}"#,
+        )
+        .unwrap();
+        assert_eq!(route_reliability(&route), Some(0.75));
+        assert_eq!(format_reliability(&route), "75% (1 recent fail)");
+
+        let untested = json::parse(r#"{"successes": 0, "failures": 0}"#).unwrap();
+        assert_eq!(route_reliability(&untested), None);
+        assert_eq!(format_reliability(&untested), "---");
+        assert_eq!(
+            TableViewItem::<RouteTableColumn>::to_column(&untested, RouteTableColumn::Sequencing),
+            "---"
+        );
+    }
+}
@@ -13,6 +13,7 @@ enable_network: false
 address: "localhost:5959"
 autoconnect: true
 autoreconnect: true
+connections: []
 logging: 
     level: "info"
     terminal: 
@@ -148,6 +149,17 @@ impl<'de> serde::Deserialize<'de> for NamedSocketAddrs {
     }
 }
 
+/// A named server connection, for managing several nodes from one client.
+/// Either an ipc path or a network address must be given.
+#[derive(Debug, Deserialize)]
+pub struct NamedConnection {
+    pub name: String,
+    pub ipc_path: Option<PathBuf>,
+    #[serde(default)]
+    pub subnode_index: usize,
+    pub address: Option<NamedSocketAddrs>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Terminal {
     pub enabled: bool,
@@ -224,6 +236,8 @@ pub struct Settings {
     pub address: Option<NamedSocketAddrs>,
     pub autoconnect: bool,
     pub autoreconnect: bool,
+    #[serde(default)]
+    pub connections: Vec<NamedConnection>,
     pub logging: Logging,
     pub interface: Interface,
 }
@@ -308,3 +322,32 @@ fn test_default_config() {
 
     println!("default settings: {:?}", settings);
 }
+
+#[test]
+fn test_named_connections() {
+    let cfg = config::Config::builder()
+        .add_source(load_default_config().unwrap())
+        .add_source(config::File::from_str(
+            r#"
+connections:
+    - name: "alpha"
+      address: "127.0.0.1:5959"
+    - name: "beta"
+      ipc_path: "/var/db/veilid-server/ipc"
+      subnode_index: 1
+"#,
+            config::FileFormat::Yaml,
+        ))
+        .build()
+        .unwrap();
+    let settings = cfg.try_deserialize::<Settings>().unwrap();
+
+    assert_eq!(settings.connections.len(), 2);
+    assert_eq!(settings.connections[0].name, "alpha");
+    assert_eq!(
+        settings.connections[0].address.as_ref().unwrap().addrs,
+        vec!["127.0.0.1:5959".parse::<SocketAddr>().unwrap()]
+    );
+    assert_eq!(settings.connections[1].subnode_index, 1);
+    assert!(settings.connections[1].address.is_none());
+}
@@ -1,7 +1,9 @@
 use crate::command_processor::*;
 use crate::cursive_ui::CursiveUICallback;
+use crate::fleet::FleetCallback;
 use crate::interactive_ui::InteractiveUICallback;
 use crate::io_read_write_ui::IOReadWriteUICallback;
+use crate::json_ui::JsonUICallback;
 use crate::log_viewer_ui::LogViewerUICallback;
 use crate::tools::*;
 use flexi_logger::writers::LogWriter;
@@ -12,6 +14,8 @@ pub enum UICallback {
     Interactive(InteractiveUICallback),
     IOReadWrite(IOReadWriteUICallback),
     LogViewer(LogViewerUICallback),
+    Json(JsonUICallback),
+    Fleet(FleetCallback),
 }
 
 pub trait UISender: Send {
@@ -21,6 +25,19 @@ pub trait UISender: Send {
     fn display_string_dialog(&self, title: &str, text: &str, close_cb: UICallback);
     fn quit(&self);
     fn send_callback(&self, callback: UICallback);
+    fn display_command_result(&self, result: Result<String, String>, callback: UICallback) {
+        match result {
+            Ok(output) => {
+                if !output.is_empty() {
+                    self.add_node_event(Level::Info, &output);
+                }
+            }
+            Err(e) => {
+                self.add_node_event(Level::Error, &e);
+            }
+        }
+        self.send_callback(callback);
+    }
     fn set_attachment_state(
         &mut self,
         state: &str,
@@ -35,6 +52,17 @@ pub trait UISender: Send {
         peers: Vec<json::JsonValue>,
     );
     fn set_config(&mut self, config: &json::JsonValue);
+    fn set_dht_records(&mut self, records: Vec<json::JsonValue>);
+    fn set_dht_record(&mut self, record: json::JsonValue);
+    fn show_dht_value(&self, key: &str, subkey: u32, value: &json::JsonValue);
+    fn add_dht_value_change(&mut self, value_change: &json::JsonValue);
+    fn set_routes(&mut self, routes: Vec<json::JsonValue>);
+    fn show_route_blob(&self, route_id: &str, blob: &str);
+    fn set_routing_table_info(&mut self, info: json::JsonValue);
+    fn set_command_completion(&self, line: &str, completed: &str, candidates: Vec<String>);
+    fn set_nodes(&mut self, nodes: Vec<json::JsonValue>);
+    fn set_active_node(&mut self, name: &str, cmdproc: CommandProcessor);
+    fn add_update(&self, update: &json::JsonValue);
     fn set_connection_state(&mut self, state: ConnectionState);
     fn add_node_event(&self, log_color: Level, event: &str);
     fn add_log_event(&self, log_color: Level, event: &str);
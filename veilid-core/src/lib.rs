@@ -21,6 +21,10 @@
 //! * `default-async-std` - Uses `async-std` as the async runtime.
 //! * `default-wasm` - When building for the `wasm32` architecture, use this to enable `wasm-bindgen-futures` as the async runtime.
 //!
+//! For testing, the `virtual-network` feature replaces the native low level network with an in-process UDP network
+//! shared by every node in the process, with latency, loss, NAT and partitions controlled through [VirtualRouter].
+//! The `simulation` feature lets nodes run on a virtual clock with seeded random numbers, see `veilid_tools::simulation`.
+//!
 
 #![deny(clippy::all)]
 #![allow(clippy::comparison_chain, clippy::upper_case_acronyms)]
@@ -63,6 +67,8 @@ pub use self::logging::{
     ApiTracingLayer, VeilidLayerFilter, DEFAULT_LOG_FACILITIES_ENABLED_LIST,
     DEFAULT_LOG_FACILITIES_IGNORE_LIST, DURATION_LOG_FACILITIES, FLAME_LOG_FACILITIES_IGNORE_LIST,
 };
+#[cfg(all(not(target_arch = "wasm32"), feature = "virtual-network"))]
+pub use self::network_manager::{VirtualLinkConfig, VirtualNatType, VirtualRouter};
 pub use self::veilid_api::*;
 pub use self::veilid_config::*;
 pub use veilid_tools as tools;
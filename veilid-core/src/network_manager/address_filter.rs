@@ -278,6 +278,30 @@ impl AddressFilter {
         inner.punishments_by_node_id.clear();
     }
 
+    pub fn debug_punishments(&self) -> Vec<DebugPunishment> {
+        let inner = self.inner.lock();
+        let ip4 = inner
+            .punishments_by_ip4
+            .iter()
+            .map(|(k, v)| (k.to_string(), v));
+        let ip6 = inner
+            .punishments_by_ip6_prefix
+            .iter()
+            .map(|(k, v)| (k.to_string(), v));
+        let node_ids = inner
+            .punishments_by_node_id
+            .iter()
+            .map(|(k, v)| (k.to_string(), v));
+        ip4.chain(ip6)
+            .chain(node_ids)
+            .map(|(target, punishment)| DebugPunishment {
+                target,
+                reason: format!("{:?}", punishment.reason),
+                timestamp: punishment.timestamp,
+            })
+            .collect()
+    }
+
     pub fn punish_ip_addr(&self, addr: IpAddr, reason: PunishmentReason) {
         warn!("Punished: {} for {:?}", addr, reason);
         let timestamp = Timestamp::now();
@@ -4,10 +4,12 @@ use super::*;
 pub struct ConnectionHandle {
     connection_id: NetworkConnectionId,
     flow: Flow,
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     channel: flume::Sender<(Option<Id>, Vec<u8>)>,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "virtual-network", allow(dead_code))]
 pub enum ConnectionHandleSendResult {
     Sent,
     NotSent(Vec<u8>),
@@ -9,6 +9,7 @@ use stop_token::future::FutureExt;
 
 #[derive(Debug)]
 enum ConnectionManagerEvent {
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     Accepted(ProtocolNetworkConnection),
     Dead(NetworkConnection),
 }
@@ -49,6 +50,7 @@ struct ConnectionManagerInner {
 
 struct ConnectionManagerArc {
     network_manager: NetworkManager,
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     connection_initial_timeout_ms: u32,
     connection_inactivity_timeout_ms: u32,
     connection_table: ConnectionTable,
@@ -346,6 +348,7 @@ impl ConnectionManager {
     /// in order to make room for the new connection in the system's connection table
     /// This routine needs to be atomic, or connections may exist in the table that are not established
     //#[instrument(level = "trace", skip(self), ret, err)]
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     pub async fn get_or_create_connection(
         &self,
         dial_info: DialInfo,
@@ -510,6 +513,7 @@ impl ConnectionManager {
     // Called by low-level network when any connection-oriented protocol connection appears
     // either from incoming connections.
     #[cfg_attr(target_arch = "wasm32", expect(dead_code))]
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     pub(super) async fn on_accepted_protocol_network_connection(
         &self,
         protocol_connection: ProtocolNetworkConnection,
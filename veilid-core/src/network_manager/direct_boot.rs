@@ -1,5 +1,87 @@
 use super::*;
 
+/// A list of peers vouched for by a bootstrap server
+/// Each PeerInfo is signed by its own node, and the peer list exactly as it was serialized
+/// for sending, along with the timestamp, is signed by the bootstrap server's node ids
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SignedBootstrapList {
+    timestamp: Timestamp,
+    peers_json: String,
+    signatures: Vec<TypedSignature>,
+}
+
+impl SignedBootstrapList {
+    fn make_signatures(
+        crypto: Crypto,
+        typed_key_pairs: Vec<TypedKeyPair>,
+        peers: &[PeerInfo],
+    ) -> VeilidAPIResult<Self> {
+        let timestamp = Timestamp::now();
+        let peers_json = serialize_json(peers);
+        let signature_bytes = Self::make_signature_bytes(&peers_json, timestamp);
+        let signatures =
+            crypto.generate_signatures(&signature_bytes, &typed_key_pairs, |kp, s| {
+                TypedSignature::new(kp.kind, s)
+            })?;
+        Ok(Self {
+            timestamp,
+            peers_json,
+            signatures,
+        })
+    }
+
+    /// Check the list was signed by the bootstrap node and is current, and return its peers
+    fn validate(
+        &self,
+        bootstrap_node_id: TypedKey,
+        cur_ts: Timestamp,
+        max_timestamp_behind: Option<TimestampDuration>,
+        max_timestamp_ahead: Option<TimestampDuration>,
+        crypto: Crypto,
+    ) -> VeilidAPIResult<Vec<PeerInfo>> {
+        let signature_bytes = Self::make_signature_bytes(&self.peers_json, self.timestamp);
+        let opt_validated_node_ids =
+            crypto.verify_signatures(&[bootstrap_node_id], &signature_bytes, &self.signatures)?;
+        let Some(validated_node_ids) = opt_validated_node_ids else {
+            apibail_generic!("verification error in signed bootstrap list");
+        };
+        if validated_node_ids.is_empty() {
+            apibail_generic!("bootstrap list is not signed by the bootstrap node id");
+        }
+
+        // Ensure the list isn't stale, or from the future
+        let lts = self.timestamp;
+        if let Some(tsbehind) = max_timestamp_behind {
+            if tsbehind.as_u64() != 0 && (cur_ts > lts && cur_ts.saturating_sub(lts) > tsbehind) {
+                apibail_generic!(format!(
+                    "signed bootstrap list is too old: {}ms",
+                    timestamp_to_secs(cur_ts.saturating_sub(lts).as_u64()) * 1000f64
+                ));
+            }
+        }
+        if let Some(tsahead) = max_timestamp_ahead {
+            if tsahead.as_u64() != 0 && (cur_ts < lts && lts.saturating_sub(cur_ts) > tsahead) {
+                apibail_generic!(format!(
+                    "signed bootstrap list is from the future: {}ms",
+                    timestamp_to_secs(lts.saturating_sub(cur_ts).as_u64()) * 1000f64
+                ));
+            }
+        }
+
+        // Only the bytes that were signed are deserialized
+        deserialize_json(&self.peers_json)
+    }
+
+    fn make_signature_bytes(peers_json: &str, timestamp: Timestamp) -> Vec<u8> {
+        let mut signature_bytes = peers_json.as_bytes().to_vec();
+
+        // Add timestamp to signature
+        signature_bytes.extend_from_slice(&timestamp.as_u64().to_le_bytes());
+
+        signature_bytes
+    }
+}
+
 impl NetworkManager {
     // Direct bootstrap request handler (separate fallback mechanism from cheaper TXT bootstrap mechanism)
     #[instrument(level = "trace", target = "net", skip(self), ret, err)]
@@ -32,6 +114,60 @@ impl NetworkManager {
         }
     }
 
+    // Signed bootstrap request handler, only answered when we are running as a bootstrap server
+    #[instrument(level = "trace", target = "net", skip(self), ret, err)]
+    pub(crate) async fn handle_signed_boot_request(
+        &self,
+        flow: Flow,
+    ) -> EyreResult<NetworkResult<()>> {
+        let (enabled, max_peers_per_type) = self.with_config(|c| {
+            (
+                c.network.bootstrap_server.enabled,
+                c.network.bootstrap_server.max_peers_per_type as usize,
+            )
+        });
+        if !enabled {
+            return Ok(NetworkResult::invalid_message(
+                "not running as a bootstrap server",
+            ));
+        }
+
+        let routing_table = self.routing_table();
+
+        // Include ourselves so the requester can bootstrap with us too
+        let mut bootstrap_peerinfo: Vec<PeerInfo> = routing_table
+            .get_published_peer_info(RoutingDomain::PublicInternet)
+            .map(|pi| vec![pi.as_ref().clone()])
+            .unwrap_or_default();
+
+        // Add the most reliable nodes we know about for each protocol and address type
+        bootstrap_peerinfo.extend(
+            routing_table
+                .find_bootstrap_nodes_filtered(max_peers_per_type)
+                .iter()
+                .filter_map(|nr| nr.make_peer_info(RoutingDomain::PublicInternet)),
+        );
+
+        // Sign the list with our node ids
+        let signed_bootstrap_list = SignedBootstrapList::make_signatures(
+            self.crypto(),
+            routing_table.node_id_typed_key_pairs(),
+            &bootstrap_peerinfo,
+        )?;
+        let json_bytes = serialize_json(signed_bootstrap_list).as_bytes().to_vec();
+
+        match self
+            .net()
+            .send_data_to_existing_flow(flow, json_bytes)
+            .await?
+        {
+            SendDataToExistingFlowResult::Sent(_) => Ok(NetworkResult::value(())),
+            SendDataToExistingFlowResult::NotSent(_) => Ok(NetworkResult::no_connection_other(
+                "signed bootstrap reply could not be sent",
+            )),
+        }
+    }
+
     // Direct bootstrap request
     #[instrument(level = "trace", target = "net", err, skip(self))]
     pub async fn boot_request(&self, dial_info: DialInfo) -> EyreResult<Vec<Arc<PeerInfo>>> {
@@ -53,4 +189,231 @@ impl NetworkManager {
 
         Ok(bootstrap_peerinfo.into_iter().map(Arc::new).collect())
     }
+
+    // Signed bootstrap request to a bootstrap server with a pinned node id
+    #[instrument(level = "trace", target = "net", err, skip(self))]
+    pub async fn signed_boot_request(
+        &self,
+        bootstrap_node_id: TypedKey,
+        dial_info: DialInfo,
+    ) -> EyreResult<Vec<Arc<PeerInfo>>> {
+        let (timeout_ms, tsbehind, tsahead) = self.with_config(|c| {
+            (
+                c.network.rpc.timeout_ms,
+                c.network
+                    .rpc
+                    .max_timestamp_behind_ms
+                    .map(ms_to_us)
+                    .map(TimestampDuration::new),
+                c.network
+                    .rpc
+                    .max_timestamp_ahead_ms
+                    .map(ms_to_us)
+                    .map(TimestampDuration::new),
+            )
+        });
+        // Send signed boot magic to requested peer address
+        let data = BOOT_SIGNED_MAGIC.to_vec();
+
+        let out_data: Vec<u8> = network_result_value_or_log!(self
+            .net()
+            .send_recv_data_unbound_to_dial_info(dial_info, data, timeout_ms)
+            .await? => [ format!(": dial_info={}, data.len={}", dial_info, data.len()) ]
+        {
+            return Ok(Vec::new());
+        });
+
+        let signed_bootstrap_list: SignedBootstrapList = deserialize_json(
+            std::str::from_utf8(&out_data).wrap_err("bad utf8 in signed boot list")?,
+        )
+        .wrap_err("failed to deserialize signed boot list")?;
+
+        // Ensure the list was signed by the bootstrap server we expected and is current
+        let peers = signed_bootstrap_list
+            .validate(
+                bootstrap_node_id,
+                Timestamp::now(),
+                tsbehind,
+                tsahead,
+                self.crypto(),
+            )
+            .wrap_err("failed to validate signed boot list")?;
+
+        // Only keep public internet peers whose own signatures are valid
+        let mut peers: Vec<Arc<PeerInfo>> = peers
+            .into_iter()
+            .filter(|pi| pi.routing_domain() == RoutingDomain::PublicInternet)
+            .map(Arc::new)
+            .collect();
+        PeerInfo::validate_vec(&mut peers, self.crypto());
+
+        Ok(peers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock_routing_table;
+
+    fn make_peer_info(crypto: Crypto, routing_domain: RoutingDomain) -> PeerInfo {
+        let ck = best_crypto_kind();
+        let keypair = crypto.get(ck).unwrap().generate_keypair();
+        let node_info = NodeInfo::new(
+            NetworkClass::InboundCapable,
+            ProtocolTypeSet::all(),
+            AddressTypeSet::all(),
+            VALID_ENVELOPE_VERSIONS.to_vec(),
+            VALID_CRYPTO_KINDS.to_vec(),
+            PUBLIC_INTERNET_CAPABILITIES.to_vec(),
+            vec![DialInfoDetail {
+                class: DialInfoClass::Direct,
+                dial_info: DialInfo::udp(SocketAddress::default()),
+            }],
+        );
+        let sni = SignedDirectNodeInfo::make_signatures(
+            crypto,
+            vec![TypedKeyPair::new(ck, keypair)],
+            node_info,
+        )
+        .unwrap();
+        PeerInfo::new(
+            routing_domain,
+            TypedKey::new(ck, keypair.key).into(),
+            SignedNodeInfo::Direct(sni),
+        )
+    }
+
+    #[test]
+    fn test_signed_bootstrap_list_validate() {
+        let crypto = mock_routing_table().crypto();
+        let ck = best_crypto_kind();
+        let bootstrap_keypair = crypto.get(ck).unwrap().generate_keypair();
+        let bootstrap_node_id = TypedKey::new(ck, bootstrap_keypair.key);
+        let tsbehind = Some(TimestampDuration::new(10_000_000u64));
+        let tsahead = Some(TimestampDuration::new(10_000_000u64));
+
+        let peers = vec![
+            make_peer_info(crypto.clone(), RoutingDomain::PublicInternet),
+            make_peer_info(crypto.clone(), RoutingDomain::PublicInternet),
+        ];
+        let list = SignedBootstrapList::make_signatures(
+            crypto.clone(),
+            vec![TypedKeyPair::new(ck, bootstrap_keypair)],
+            &peers,
+        )
+        .unwrap();
+
+        // Valid list, which gives back the peers that were signed
+        let validated_peers = list
+            .validate(
+                bootstrap_node_id,
+                list.timestamp,
+                tsbehind,
+                tsahead,
+                crypto.clone(),
+            )
+            .unwrap();
+        assert_eq!(validated_peers.len(), peers.len());
+        for (validated, peer) in validated_peers.iter().zip(peers.iter()) {
+            assert_eq!(validated.node_ids(), peer.node_ids());
+            assert_eq!(validated.routing_domain(), peer.routing_domain());
+        }
+
+        // The list survives the trip through the wire format
+        let received: SignedBootstrapList =
+            deserialize_json(&serialize_json(list.clone())).unwrap();
+        received
+            .validate(
+                bootstrap_node_id,
+                list.timestamp,
+                tsbehind,
+                tsahead,
+                crypto.clone(),
+            )
+            .unwrap();
+
+        // Wrong bootstrap key
+        let other_keypair = crypto.get(ck).unwrap().generate_keypair();
+        assert!(list
+            .validate(
+                TypedKey::new(ck, other_keypair.key),
+                list.timestamp,
+                tsbehind,
+                tsahead,
+                crypto.clone()
+            )
+            .is_err());
+
+        // Stale timestamp, unless staleness checking is disabled
+        let late_ts = list.timestamp + TimestampDuration::new(10_000_001u64);
+        assert!(list
+            .validate(
+                bootstrap_node_id,
+                late_ts,
+                tsbehind,
+                tsahead,
+                crypto.clone()
+            )
+            .is_err());
+        list.validate(bootstrap_node_id, late_ts, None, tsahead, crypto.clone())
+            .unwrap();
+
+        // Timestamp from the future, unless that checking is disabled
+        let early_ts = list.timestamp - TimestampDuration::new(10_000_001u64);
+        assert!(list
+            .validate(
+                bootstrap_node_id,
+                early_ts,
+                tsbehind,
+                tsahead,
+                crypto.clone()
+            )
+            .is_err());
+        list.validate(bootstrap_node_id, early_ts, tsbehind, None, crypto.clone())
+            .unwrap();
+
+        // Any change to the signed bytes invalidates the list, even one that parses the same
+        let mut tampered = list.clone();
+        tampered.peers_json.push(' ');
+        assert!(tampered
+            .validate(
+                bootstrap_node_id,
+                list.timestamp,
+                tsbehind,
+                tsahead,
+                crypto.clone()
+            )
+            .is_err());
+
+        // Substituting other peers
+        let mut tampered = list.clone();
+        let other_peers = peers
+            .iter()
+            .map(|pi| {
+                PeerInfo::new(
+                    RoutingDomain::LocalNetwork,
+                    pi.node_ids().clone(),
+                    pi.signed_node_info().clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        tampered.peers_json = serialize_json(other_peers);
+        assert!(tampered
+            .validate(
+                bootstrap_node_id,
+                list.timestamp,
+                tsbehind,
+                tsahead,
+                crypto.clone()
+            )
+            .is_err());
+
+        // Replaying the signatures with a different timestamp
+        let mut tampered = list.clone();
+        tampered.timestamp = list.timestamp + TimestampDuration::new(1u64);
+        assert!(tampered
+            .validate(bootstrap_node_id, list.timestamp, tsbehind, tsahead, crypto)
+            .is_err());
+    }
 }
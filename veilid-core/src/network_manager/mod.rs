@@ -1,7 +1,9 @@
 use crate::*;
 
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "virtual-network")))]
 mod native;
+#[cfg(all(not(target_arch = "wasm32"), feature = "virtual-network"))]
+mod virtual_network;
 #[cfg(target_arch = "wasm32")]
 mod wasm;
 
@@ -35,20 +37,54 @@ use connection_handle::*;
 use crypto::*;
 use futures_util::stream::FuturesUnordered;
 use hashlink::LruCache;
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "virtual-network")))]
 use native::*;
-#[cfg(not(target_arch = "wasm32"))]
-pub use native::{MAX_CAPABILITIES, PUBLIC_INTERNET_CAPABILITIES};
 use routing_table::*;
 use rpc_processor::*;
 use storage_manager::*;
+#[cfg(all(not(target_arch = "wasm32"), feature = "virtual-network"))]
+use virtual_network::*;
+#[cfg(all(not(target_arch = "wasm32"), feature = "virtual-network"))]
+pub use virtual_network::{VirtualLinkConfig, VirtualNatType, VirtualRouter};
 #[cfg(target_arch = "wasm32")]
 use wasm::*;
 #[cfg(target_arch = "wasm32")]
-pub use wasm::{/* LOCAL_NETWORK_CAPABILITIES, */ MAX_CAPABILITIES, PUBLIC_INTERNET_CAPABILITIES,};
+pub use wasm::{/* LOCAL_NETWORK_CAPABILITIES, */ PUBLIC_INTERNET_CAPABILITIES};
 
 ////////////////////////////////////////////////////////////////////////////////////////
 
+pub const MAX_CAPABILITIES: usize = 64;
+
+cfg_if! {
+    // Native nodes advertise the same capabilities on real and virtual networks
+    if #[cfg(not(target_arch = "wasm32"))] {
+        cfg_if! {
+            if #[cfg(all(feature = "unstable-blockstore", feature="unstable-tunnels"))] {
+                const PUBLIC_INTERNET_CAPABILITIES_LEN: usize = 10;
+            } else if #[cfg(any(feature = "unstable-blockstore", feature="unstable-tunnels"))] {
+                const PUBLIC_INTERNET_CAPABILITIES_LEN: usize = 9;
+            } else  {
+                const PUBLIC_INTERNET_CAPABILITIES_LEN: usize = 8;
+            }
+        }
+        pub const PUBLIC_INTERNET_CAPABILITIES: [Capability; PUBLIC_INTERNET_CAPABILITIES_LEN] = [
+            CAP_ROUTE,
+            #[cfg(feature = "unstable-tunnels")]
+            CAP_TUNNEL,
+            CAP_SIGNAL,
+            CAP_RELAY,
+            CAP_VALIDATE_DIAL_INFO,
+            CAP_DHT,
+            CAP_DHT_WATCH,
+            CAP_DHT_BATCH,
+            CAP_APPMESSAGE,
+            #[cfg(feature = "unstable-blockstore")]
+            CAP_BLOCKSTORE,
+        ];
+    }
+}
+
+#[cfg_attr(feature = "virtual-network", allow(dead_code))]
 pub const MAX_MESSAGE_SIZE: usize = MAX_ENVELOPE_SIZE;
 pub const IPADDR_TABLE_SIZE: usize = 1024;
 pub const IPADDR_MAX_INACTIVE_DURATION_US: TimestampDuration =
@@ -64,6 +100,7 @@ pub const PUBLIC_ADDRESS_INCONSISTENCY_PUNISHMENT_TIMEOUT_US: TimestampDuration
     TimestampDuration::new(3_600_000_000_u64); // 60 minutes
 pub const ADDRESS_FILTER_TASK_INTERVAL_SECS: u32 = 60;
 pub const BOOT_MAGIC: &[u8; 4] = b"BOOT";
+pub const BOOT_SIGNED_MAGIC: &[u8; 4] = b"BSIG";
 
 // Things we get when we start up and go away when we shut down
 // Routing table is not in here because we want it to survive a network shutdown/startup restart
@@ -129,6 +166,7 @@ enum SendDataToExistingFlowResult {
 pub enum StartupDisposition {
     Success,
     #[cfg_attr(target_arch = "wasm32", expect(dead_code))]
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     BindRetry,
 }
 
@@ -156,6 +194,7 @@ struct NetworkManagerUnlockedInner {
     address_filter: RwLock<Option<AddressFilter>>,
     components: RwLock<Option<NetworkComponents>>,
     update_callback: RwLock<Option<UpdateCallback>>,
+    app_channel_table: AppChannelTable,
     // Background processes
     rolling_transfers_task: TickTask<EyreReport>,
     public_internet_address_check_task: TickTask<EyreReport>,
@@ -201,6 +240,7 @@ impl NetworkManager {
             routing_table: RwLock::new(None),
             components: RwLock::new(None),
             update_callback: RwLock::new(None),
+            app_channel_table: AppChannelTable::default(),
             rolling_transfers_task: TickTask::new(
                 "rolling_transfers_task",
                 ROLLING_TRANSFERS_INTERVAL_SECS,
@@ -291,6 +331,9 @@ impl NetworkManager {
     pub fn crypto(&self) -> Crypto {
         self.unlocked_inner.crypto.clone()
     }
+    pub fn network_key(&self) -> Option<SharedSecret> {
+        self.unlocked_inner.network_key
+    }
     pub fn address_filter(&self) -> AddressFilter {
         self.unlocked_inner
             .address_filter
@@ -332,6 +375,9 @@ impl NetworkManager {
             .receipt_manager
             .clone()
     }
+    pub fn app_channel_table(&self) -> AppChannelTable {
+        self.unlocked_inner.app_channel_table.clone()
+    }
     pub fn rpc_processor(&self) -> RPCProcessor {
         self.unlocked_inner
             .components
@@ -1010,6 +1056,12 @@ impl NetworkManager {
             return Ok(true);
         }
 
+        // Is this a signed bootstrap request for a bootstrap server?
+        if data[0..4] == *BOOT_SIGNED_MAGIC {
+            network_result_value_or_log!(self.handle_signed_boot_request(flow).await? => [ format!(": flow={:?}", flow) ] {});
+            return Ok(true);
+        }
+
         // Is this an out-of-band receipt instead of an envelope?
         if data[0..3] == *RECEIPT_MAGIC {
             network_result_value_or_log!(self.handle_out_of_band_receipt(data).await => [ format!(": data.len={}", data.len()) ] {});
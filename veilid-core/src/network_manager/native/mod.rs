@@ -1,5 +1,6 @@
 mod discovery_context;
 mod igd_manager;
+pub(super) mod network_lan_discovery;
 mod network_state;
 mod network_tcp;
 mod network_udp;
@@ -11,6 +12,7 @@ use super::*;
 use crate::routing_table::*;
 use connection_manager::*;
 use discovery_context::*;
+use network_lan_discovery::*;
 use network_state::*;
 use network_tcp::*;
 use protocol::tcp::RawTcpProtocolHandler;
@@ -32,45 +34,21 @@ use std::path::{Path, PathBuf};
 
 pub const PEEK_DETECT_LEN: usize = 64;
 
-cfg_if! {
-    if #[cfg(all(feature = "unstable-blockstore", feature="unstable-tunnels"))] {
-        const PUBLIC_INTERNET_CAPABILITIES_LEN: usize = 9;
-    } else if #[cfg(any(feature = "unstable-blockstore", feature="unstable-tunnels"))] {
-        const PUBLIC_INTERNET_CAPABILITIES_LEN: usize = 8;
-    } else  {
-        const PUBLIC_INTERNET_CAPABILITIES_LEN: usize = 7;
-    }
-}
-pub const PUBLIC_INTERNET_CAPABILITIES: [Capability; PUBLIC_INTERNET_CAPABILITIES_LEN] = [
-    CAP_ROUTE,
-    #[cfg(feature = "unstable-tunnels")]
-    CAP_TUNNEL,
-    CAP_SIGNAL,
-    CAP_RELAY,
-    CAP_VALIDATE_DIAL_INFO,
-    CAP_DHT,
-    CAP_DHT_WATCH,
-    CAP_APPMESSAGE,
-    #[cfg(feature = "unstable-blockstore")]
-    CAP_BLOCKSTORE,
-];
-
 #[cfg(feature = "unstable-blockstore")]
-const LOCAL_NETWORK_CAPABILITIES_LEN: usize = 5;
+const LOCAL_NETWORK_CAPABILITIES_LEN: usize = 6;
 #[cfg(not(feature = "unstable-blockstore"))]
-const LOCAL_NETWORK_CAPABILITIES_LEN: usize = 4;
+const LOCAL_NETWORK_CAPABILITIES_LEN: usize = 5;
 
 pub const LOCAL_NETWORK_CAPABILITIES: [Capability; LOCAL_NETWORK_CAPABILITIES_LEN] = [
     CAP_RELAY,
     CAP_DHT,
     CAP_DHT_WATCH,
+    CAP_DHT_BATCH,
     CAP_APPMESSAGE,
     #[cfg(feature = "unstable-blockstore")]
     CAP_BLOCKSTORE,
 ];
 
-pub const MAX_CAPABILITIES: usize = 64;
-
 /////////////////////////////////////////////////////////////////
 
 struct NetworkInner {
@@ -106,6 +84,8 @@ struct NetworkInner {
     static_public_dial_info: ProtocolTypeSet,
     /// Network state
     network_state: Option<NetworkState>,
+    /// LAN discovery multicast state, if enabled
+    lan_discovery_state: Option<LanDiscoveryState>,
 }
 
 struct NetworkUnlockedInner {
@@ -122,6 +102,7 @@ struct NetworkUnlockedInner {
     update_network_class_task: TickTask<EyreReport>,
     network_interfaces_task: TickTask<EyreReport>,
     upnp_task: TickTask<EyreReport>,
+    lan_discovery_task: TickTask<EyreReport>,
     network_task_lock: AsyncMutex<()>,
 
     // Managers
@@ -153,6 +134,7 @@ impl Network {
             preferred_local_addresses: BTreeMap::new(),
             static_public_dial_info: ProtocolTypeSet::new(),
             network_state: None,
+            lan_discovery_state: None,
         }
     }
 
@@ -162,6 +144,7 @@ impl Network {
         connection_manager: ConnectionManager,
     ) -> NetworkUnlockedInner {
         let config = network_manager.config();
+        let lan_discovery_interval_ms = config.get().network.lan_discovery.interval_ms;
         NetworkUnlockedInner {
             startup_lock: StartupLock::new(),
             network_manager,
@@ -171,6 +154,7 @@ impl Network {
             update_network_class_task: TickTask::new("update_network_class_task", 1),
             network_interfaces_task: TickTask::new("network_interfaces_task", 1),
             upnp_task: TickTask::new("upnp_task", 1),
+            lan_discovery_task: TickTask::new_ms("lan_discovery_task", lan_discovery_interval_ms),
             network_task_lock: AsyncMutex::new(()),
             igd_manager: igd_manager::IGDManager::new(config.clone()),
         }
@@ -738,6 +722,17 @@ impl Network {
         self.register_all_dial_info(&mut editor_public_internet, &mut editor_local_network)
             .await?;
 
+        // Start listening for other nodes on the local network
+        let lan_discovery = {
+            let c = self.config.get();
+            c.network.lan_discovery.enabled
+        };
+        if lan_discovery {
+            if let Err(e) = self.start_lan_discovery().await {
+                warn!("lan discovery failed to start: {}", e);
+            }
+        }
+
         // Set network class statically if we have static public dialinfo
         let detect_address_changes = {
             let c = self.config.get();
@@ -846,6 +841,10 @@ impl Network {
         if let Err(e) = self.unlocked_inner.update_network_class_task.stop().await {
             error!("update_network_class_task not cancelled: {}", e);
         }
+        log_net!(debug "stopping lan discovery task");
+        if let Err(e) = self.unlocked_inner.lan_discovery_task.stop().await {
+            error!("lan_discovery_task not cancelled: {}", e);
+        }
 
         let mut unord = FuturesUnordered::new();
         {
@@ -0,0 +1,423 @@
+use super::*;
+use socket2::{Domain, SockAddr};
+use sockets::*;
+use stop_token::future::FutureExt;
+
+/// Magic number at the start of every LAN discovery beacon
+pub const LAN_BEACON_MAGIC: &[u8; 4] = b"LANB";
+/// Length of the beacon header: magic followed by a nonce
+const LAN_BEACON_HEADER_LEN: usize = 4 + NONCE_LENGTH;
+/// Beacons must fit in a single unfragmented-ish datagram
+const MAX_LAN_BEACON_SIZE: usize = 8192;
+/// Number of beacon sources tracked for rate limiting
+const LAN_BEACON_RATE_TABLE_SIZE: usize = 256;
+
+/// State for the LAN discovery multicast sockets
+pub(crate) struct LanDiscoveryState {
+    /// Sockets we send beacons out on, one per local interface for ipv4
+    send_sockets: Vec<Arc<UdpSocket>>,
+    /// Timestamps of recently accepted beacons per source address
+    beacon_timestamps: LruCache<IpAddr, Vec<Timestamp>>,
+}
+
+impl LanDiscoveryState {
+    pub fn new() -> Self {
+        Self {
+            send_sockets: Vec::new(),
+            beacon_timestamps: LruCache::new(LAN_BEACON_RATE_TABLE_SIZE),
+        }
+    }
+
+    /// Returns true if a beacon from this address is within the rate limit, and records it
+    pub fn check_beacon_rate(
+        &mut self,
+        addr: IpAddr,
+        cur_ts: Timestamp,
+        max_beacons_per_min: usize,
+    ) -> bool {
+        let tstamps = self.beacon_timestamps.entry(addr).or_insert_with(Vec::new);
+        tstamps.retain(|v| {
+            // keep timestamps that are less than a minute away
+            cur_ts.saturating_sub(*v) < TimestampDuration::new(60_000_000u64)
+        });
+        if tstamps.len() >= max_beacons_per_min {
+            return false;
+        }
+        tstamps.push(cur_ts);
+        true
+    }
+}
+
+/// Encode a LocalNetwork peer info into a beacon, encrypting it with the network key if there is one
+pub(crate) fn encode_lan_beacon(
+    crypto: &Crypto,
+    network_key: Option<SharedSecret>,
+    peer_info: &PeerInfo,
+) -> Vec<u8> {
+    let vcrypto = crypto.best();
+    let nonce = vcrypto.random_nonce();
+
+    let mut body = serialize_json(peer_info).into_bytes();
+    if let Some(network_key) = network_key {
+        vcrypto.crypt_in_place_no_auth(&mut body, &nonce.bytes, &network_key);
+    }
+
+    let mut beacon = Vec::with_capacity(LAN_BEACON_HEADER_LEN + body.len());
+    beacon.extend_from_slice(LAN_BEACON_MAGIC);
+    beacon.extend_from_slice(&nonce.bytes);
+    beacon.extend_from_slice(&body);
+    beacon
+}
+
+/// Decode and validate a beacon from another node
+pub(crate) fn decode_lan_beacon(
+    crypto: &Crypto,
+    network_key: Option<SharedSecret>,
+    data: &[u8],
+) -> EyreResult<Arc<PeerInfo>> {
+    if data.len() < LAN_BEACON_HEADER_LEN || data[0..4] != *LAN_BEACON_MAGIC {
+        bail!("not a lan beacon");
+    }
+    let nonce = Nonce::try_from(&data[4..LAN_BEACON_HEADER_LEN]).wrap_err("invalid nonce")?;
+
+    let mut body = data[LAN_BEACON_HEADER_LEN..].to_vec();
+    if let Some(network_key) = network_key {
+        crypto
+            .best()
+            .crypt_in_place_no_auth(&mut body, &nonce.bytes, &network_key);
+    }
+
+    let peer_info: PeerInfo =
+        deserialize_json(std::str::from_utf8(&body).wrap_err("bad utf8 in lan beacon")?)
+            .wrap_err("failed to deserialize lan beacon")?;
+    if peer_info.routing_domain() != RoutingDomain::LocalNetwork {
+        bail!("lan beacon is not for the LocalNetwork routing domain");
+    }
+    peer_info
+        .validate(crypto.clone())
+        .wrap_err("lan beacon failed validation")?;
+
+    Ok(Arc::new(peer_info))
+}
+
+/// Returns true if the beacon came from a punished address or names a punished node
+pub(crate) fn is_lan_beacon_punished(
+    address_filter: &AddressFilter,
+    remote_addr: IpAddr,
+    peer_info: &PeerInfo,
+) -> bool {
+    address_filter.is_ip_addr_punished(remote_addr)
+        || peer_info
+            .node_ids()
+            .iter()
+            .any(|nid| address_filter.is_node_id_punished(*nid))
+}
+
+impl Network {
+    fn lan_discovery_groups(&self) -> EyreResult<(SocketAddr, SocketAddr)> {
+        let c = self.config.get();
+        let port = c.network.lan_discovery.port;
+        let group_v4 = c
+            .network
+            .lan_discovery
+            .multicast_group_v4
+            .parse::<Ipv4Addr>()
+            .wrap_err("invalid lan discovery ipv4 group")?;
+        let group_v6 = c
+            .network
+            .lan_discovery
+            .multicast_group_v6
+            .parse::<Ipv6Addr>()
+            .wrap_err("invalid lan discovery ipv6 group")?;
+        Ok((
+            SocketAddr::new(IpAddr::V4(group_v4), port),
+            SocketAddr::new(IpAddr::V6(group_v6), port),
+        ))
+    }
+
+    fn make_async_udp_socket(socket: socket2::Socket) -> EyreResult<Arc<UdpSocket>> {
+        let std_udp_socket: std::net::UdpSocket = socket.into();
+        cfg_if! {
+            if #[cfg(feature="rt-async-std")] {
+                let udp_socket = UdpSocket::from(std_udp_socket);
+            } else if #[cfg(feature="rt-tokio")] {
+                std_udp_socket.set_nonblocking(true).expect("failed to set nonblocking");
+                let udp_socket = UdpSocket::from_std(std_udp_socket).wrap_err("failed to make lan discovery tokio udpsocket")?;
+            } else {
+                compile_error!("needs executor implementation");
+            }
+        }
+        Ok(Arc::new(udp_socket))
+    }
+
+    fn new_lan_discovery_listen_socket(
+        &self,
+        group: SocketAddr,
+        interface_addrs: &[IpAddr],
+    ) -> EyreResult<Option<Arc<UdpSocket>>> {
+        let domain = Domain::for_address(group);
+        let socket = new_shared_udp_socket(domain)?;
+        let bind_addr = match group {
+            SocketAddr::V4(_) => SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), group.port()),
+            SocketAddr::V6(_) => SocketAddr::new(IpAddr::V6(Ipv6Addr::UNSPECIFIED), group.port()),
+        };
+        if let Err(e) = socket.bind(&SockAddr::from(bind_addr)) {
+            log_net!(debug "unable to bind lan discovery socket on {}: {}", bind_addr, e);
+            return Ok(None);
+        }
+
+        let mut joined = false;
+        match group.ip() {
+            IpAddr::V4(group_v4) => {
+                for addr in interface_addrs {
+                    let IpAddr::V4(addr) = addr else {
+                        continue;
+                    };
+                    match socket.join_multicast_v4(&group_v4, addr) {
+                        Ok(()) => joined = true,
+                        Err(e) => {
+                            log_net!(debug "unable to join {} on {}: {}", group_v4, addr, e);
+                        }
+                    }
+                }
+            }
+            IpAddr::V6(group_v6) => match socket.join_multicast_v6(&group_v6, 0) {
+                Ok(()) => joined = true,
+                Err(e) => {
+                    log_net!(debug "unable to join {}: {}", group_v6, e);
+                }
+            },
+        }
+        if !joined {
+            return Ok(None);
+        }
+
+        log_net!("created lan discovery listen socket for {:?}", group);
+
+        Ok(Some(Self::make_async_udp_socket(socket)?))
+    }
+
+    fn new_lan_discovery_send_socket(
+        &self,
+        interface_addr: IpAddr,
+    ) -> EyreResult<Option<Arc<UdpSocket>>> {
+        let Some(socket) = new_bound_default_udp_socket(SocketAddr::new(interface_addr, 0))? else {
+            return Ok(None);
+        };
+        match interface_addr {
+            IpAddr::V4(addr) => {
+                socket.set_multicast_if_v4(&addr)?;
+                socket.set_multicast_ttl_v4(1)?;
+            }
+            IpAddr::V6(_) => {
+                socket.set_multicast_if_v6(0)?;
+                socket.set_multicast_hops_v6(1)?;
+            }
+        }
+
+        Ok(Some(Self::make_async_udp_socket(socket)?))
+    }
+
+    /// Bind the multicast sockets and start listening for beacons from other nodes on the local network
+    #[instrument(level = "trace", skip_all)]
+    pub(super) async fn start_lan_discovery(&self) -> EyreResult<()> {
+        let (group_v4, group_v6) = self.lan_discovery_groups()?;
+        let network_state = self.last_network_state();
+
+        // Only announce and listen on local network interfaces
+        let interface_addrs: Vec<IpAddr> = network_state
+            .stable_interface_addresses
+            .iter()
+            .copied()
+            .filter(|addr| Address::from_ip_addr(*addr).is_local())
+            .collect();
+
+        let mut listen_sockets = Vec::new();
+        let mut send_sockets = Vec::new();
+        if network_state.enable_ipv4 {
+            if let Some(s) = self.new_lan_discovery_listen_socket(group_v4, &interface_addrs)? {
+                listen_sockets.push(s);
+            }
+            for addr in interface_addrs.iter().filter(|a| a.is_ipv4()) {
+                if let Some(s) = self.new_lan_discovery_send_socket(*addr)? {
+                    send_sockets.push(s);
+                }
+            }
+        }
+        if network_state.enable_ipv6_local {
+            if let Some(s) = self.new_lan_discovery_listen_socket(group_v6, &interface_addrs)? {
+                listen_sockets.push(s);
+            }
+            if let Some(s) =
+                self.new_lan_discovery_send_socket(IpAddr::V6(Ipv6Addr::UNSPECIFIED))?
+            {
+                send_sockets.push(s);
+            }
+        }
+
+        if listen_sockets.is_empty() {
+            info!("lan discovery unavailable: could not join any multicast group");
+            return Ok(());
+        }
+
+        let stop_token = {
+            let mut inner = self.inner.lock();
+            let Some(stop_source) = inner.stop_source.as_ref() else {
+                bail!("network is not started");
+            };
+            let stop_token = stop_source.token();
+            let mut lan_discovery_state = LanDiscoveryState::new();
+            lan_discovery_state.send_sockets = send_sockets;
+            inner.lan_discovery_state = Some(lan_discovery_state);
+            stop_token
+        };
+
+        for socket in listen_sockets {
+            let this = self.clone();
+            let stop_token = stop_token.clone();
+            let jh = spawn(
+                "LAN discovery listener",
+                async move {
+                    log_net!("LAN discovery listener task spawned");
+                    let mut data = vec![0u8; MAX_LAN_BEACON_SIZE];
+                    while let Ok(res) = socket
+                        .recv_from(&mut data)
+                        .timeout_at(stop_token.clone())
+                        .in_current_span()
+                        .await
+                    {
+                        match res {
+                            Ok((size, remote_addr)) => {
+                                this.on_recv_lan_beacon(&data[..size], remote_addr);
+                            }
+                            Err(e) => {
+                                log_net!(debug "LAN discovery socket error: {}", e);
+                                break;
+                            }
+                        }
+                    }
+                    log_net!("LAN discovery listener task stopped");
+                }
+                .instrument(trace_span!(parent: None, "LAN Discovery Listener")),
+            );
+            self.add_to_join_handles(jh);
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if a beacon from this address is within the rate limit, and records it
+    fn check_lan_beacon_rate(&self, addr: IpAddr) -> bool {
+        let max_beacons_per_min =
+            self.config.get().network.lan_discovery.max_beacons_per_min as usize;
+        let cur_ts = Timestamp::now();
+
+        let mut inner = self.inner.lock();
+        let Some(lan_discovery_state) = inner.lan_discovery_state.as_mut() else {
+            return false;
+        };
+        lan_discovery_state.check_beacon_rate(addr, cur_ts, max_beacons_per_min)
+    }
+
+    #[instrument(level = "trace", target = "net", skip(self, data), fields(data.len = data.len()))]
+    fn on_recv_lan_beacon(&self, data: &[u8], remote_addr: SocketAddr) {
+        let network_manager = self.network_manager();
+        let routing_table = self.routing_table();
+
+        network_manager.stats_packet_rcvd(remote_addr.ip(), ByteCount::new(data.len() as u64));
+
+        // Beacons must come from our local network and not be punished
+        if network_manager
+            .address_filter()
+            .is_ip_addr_punished(remote_addr.ip())
+        {
+            return;
+        }
+        if routing_table.routing_domain_for_address(Address::from_socket_addr(remote_addr))
+            != Some(RoutingDomain::LocalNetwork)
+        {
+            log_net!(debug "ignoring lan beacon from non-local address {}", remote_addr);
+            return;
+        }
+        if !self.check_lan_beacon_rate(remote_addr.ip()) {
+            log_net!(debug "lan beacon rate exceeded for {}", remote_addr);
+            return;
+        }
+
+        let peer_info = match decode_lan_beacon(
+            &network_manager.crypto(),
+            network_manager.network_key(),
+            data,
+        ) {
+            Ok(v) => v,
+            Err(e) => {
+                log_net!(debug "dropping lan beacon from {}: {}", remote_addr, e);
+                return;
+            }
+        };
+
+        // Skip our own beacons
+        if routing_table.matches_own_node_id(peer_info.node_ids()) {
+            return;
+        }
+        if is_lan_beacon_punished(
+            &network_manager.address_filter(),
+            remote_addr.ip(),
+            &peer_info,
+        ) {
+            return;
+        }
+
+        log_net!(debug "lan discovery found {} at {}", peer_info.node_ids(), remote_addr);
+        if let Err(e) = routing_table.register_node_with_peer_info(peer_info, false) {
+            log_net!(debug "failed to register lan beacon peer info: {}", e);
+        }
+    }
+
+    /// Send our LocalNetwork peer info out to all of the multicast groups
+    #[instrument(level = "trace", target = "net", skip_all, err)]
+    pub(super) async fn send_lan_beacons(&self) -> EyreResult<()> {
+        let Some(peer_info) = self
+            .routing_table()
+            .get_published_peer_info(RoutingDomain::LocalNetwork)
+        else {
+            return Ok(());
+        };
+        let send_sockets = {
+            let inner = self.inner.lock();
+            let Some(lan_discovery_state) = inner.lan_discovery_state.as_ref() else {
+                return Ok(());
+            };
+            lan_discovery_state.send_sockets.clone()
+        };
+        let (group_v4, group_v6) = self.lan_discovery_groups()?;
+
+        let network_manager = self.network_manager();
+        let beacon = encode_lan_beacon(
+            &network_manager.crypto(),
+            network_manager.network_key(),
+            &peer_info,
+        );
+        if beacon.len() > MAX_LAN_BEACON_SIZE {
+            bail!("lan beacon too large: {} bytes", beacon.len());
+        }
+
+        for socket in send_sockets {
+            let group = match socket.local_addr()? {
+                SocketAddr::V4(_) => group_v4,
+                SocketAddr::V6(_) => group_v6,
+            };
+            match socket.send_to(&beacon, group).await {
+                Ok(_) => {
+                    network_manager
+                        .stats_packet_sent(group.ip(), ByteCount::new(beacon.len() as u64));
+                }
+                Err(e) => {
+                    log_net!(debug "failed to send lan beacon to {}: {}", group, e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
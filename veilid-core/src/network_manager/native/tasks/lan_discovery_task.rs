@@ -0,0 +1,14 @@
+use super::*;
+
+impl Network {
+    #[instrument(parent = None, level = "trace", target = "net", skip_all, err)]
+    pub(super) async fn lan_discovery_task_routine(
+        self,
+        _stop_token: StopToken,
+        _l: Timestamp,
+        _t: Timestamp,
+    ) -> EyreResult<()> {
+        // Announce ourselves to the other nodes on the local network
+        self.send_lan_beacons().await
+    }
+}
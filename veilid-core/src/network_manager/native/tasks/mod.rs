@@ -1,3 +1,4 @@
+mod lan_discovery_task;
 mod network_interfaces_task;
 mod update_network_class_task;
 mod upnp_task;
@@ -42,6 +43,19 @@ impl Network {
                 )
             });
         }
+        // Set lan discovery tick task
+        {
+            let this = self.clone();
+            self.unlocked_inner
+                .lan_discovery_task
+                .set_routine(move |s, l, t| {
+                    Box::pin(this.clone().lan_discovery_task_routine(
+                        s,
+                        Timestamp::new(l),
+                        Timestamp::new(t),
+                    ))
+                });
+        }
     }
 
     #[instrument(level = "trace", target = "net", name = "Network::tick", skip_all, err)]
@@ -56,10 +70,14 @@ impl Network {
             return Ok(());
         }
 
-        let (detect_address_changes, upnp) = {
+        let (detect_address_changes, upnp, lan_discovery) = {
             let config = self.network_manager().config();
             let c = config.get();
-            (c.network.detect_address_changes, c.network.upnp)
+            (
+                c.network.detect_address_changes,
+                c.network.upnp,
+                c.network.lan_discovery.enabled,
+            )
         };
 
         // If we need to figure out our network class, tick the task for it
@@ -105,6 +123,11 @@ impl Network {
             self.unlocked_inner.upnp_task.tick().await?;
         }
 
+        // If we are announcing ourselves on the local network, do it
+        if lan_discovery {
+            self.unlocked_inner.lan_discovery_task.tick().await?;
+        }
+
         Ok(())
     }
 }
@@ -12,6 +12,7 @@ cfg_if::cfg_if! {
         // Accept
 
         pub(in crate::network_manager) trait ProtocolAcceptHandler: ProtocolAcceptHandlerClone + Send + Sync {
+            #[cfg_attr(feature = "virtual-network", allow(dead_code))]
             fn on_accept(
                 &self,
                 stream: AsyncPeekStream,
@@ -38,6 +39,7 @@ cfg_if::cfg_if! {
             }
         }
 
+        #[cfg_attr(feature = "virtual-network", allow(dead_code))]
         pub(in crate::network_manager) type NewProtocolAcceptHandler =
             dyn Fn(VeilidConfig, bool) -> Box<dyn ProtocolAcceptHandler> + Send;
     }
@@ -58,6 +58,7 @@ type ReceiptCallbackType = Box<dyn ReceiptCallback>;
 type ReceiptSingleShotType = SingleShotEventual<ReceiptEvent>;
 
 enum ReceiptRecordCallbackType {
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     Normal(ReceiptCallbackType),
     SingleShot(Option<ReceiptSingleShotType>),
 }
@@ -95,7 +96,8 @@ impl fmt::Debug for ReceiptRecord {
 }
 
 impl ReceiptRecord {
-    #[expect(dead_code)]
+    #[cfg_attr(not(feature = "virtual-network"), expect(dead_code))]
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     pub fn new(
         receipt: Receipt,
         expiration_ts: Timestamp,
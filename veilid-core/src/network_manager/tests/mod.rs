@@ -1,4 +1,8 @@
 pub mod test_connection_table;
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "virtual-network")))]
+pub mod test_lan_discovery;
 pub mod test_signed_node_info;
+#[cfg(feature = "virtual-network")]
+pub mod test_virtual_network;
 
 use super::*;
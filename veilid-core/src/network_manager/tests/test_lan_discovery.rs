@@ -0,0 +1,164 @@
+use super::*;
+use crate::network_manager::native::network_lan_discovery::*;
+use crate::tests::common::test_veilid_config::*;
+
+fn make_local_peer_info(crypto: Crypto, ck: CryptoKind) -> (PeerInfo, TypedKeyGroup) {
+    let vcrypto = crypto.get(ck).unwrap();
+    let node_info = NodeInfo::new(
+        NetworkClass::InboundCapable,
+        ProtocolTypeSet::all(),
+        AddressTypeSet::all(),
+        VALID_ENVELOPE_VERSIONS.to_vec(),
+        VALID_CRYPTO_KINDS.to_vec(),
+        PUBLIC_INTERNET_CAPABILITIES.to_vec(),
+        vec![DialInfoDetail {
+            class: DialInfoClass::Direct,
+            dial_info: DialInfo::udp(SocketAddress::new(
+                Address::IPV4(Ipv4Addr::new(192, 168, 0, 2)),
+                5150,
+            )),
+        }],
+    );
+    let keypair = vcrypto.generate_keypair();
+    let sni = SignedDirectNodeInfo::make_signatures(
+        crypto,
+        vec![TypedKeyPair::new(ck, keypair)],
+        node_info,
+    )
+    .unwrap();
+    let tks: TypedKeyGroup = TypedKey::new(ck, keypair.key).into();
+    (
+        PeerInfo::new(
+            RoutingDomain::LocalNetwork,
+            tks.clone(),
+            SignedNodeInfo::Direct(sni),
+        ),
+        tks,
+    )
+}
+
+pub async fn test_lan_beacon() {
+    info!("--- test_lan_beacon ---");
+
+    let (update_callback, config_callback) = setup_veilid_core();
+    let api = api_startup(update_callback, config_callback)
+        .await
+        .expect("startup failed");
+
+    let crypto = api.crypto().unwrap();
+    for ck in VALID_CRYPTO_KINDS {
+        let (peer_info, _) = make_local_peer_info(crypto.clone(), ck);
+
+        // Round trip, with and without a network key
+        let beacon = encode_lan_beacon(&crypto, None, &peer_info);
+        let decoded = decode_lan_beacon(&crypto, None, &beacon).unwrap();
+        assert_eq!(*decoded, peer_info);
+
+        let network_key = crypto.get(ck).unwrap().random_shared_secret();
+        let beacon = encode_lan_beacon(&crypto, Some(network_key), &peer_info);
+        let decoded = decode_lan_beacon(&crypto, Some(network_key), &beacon).unwrap();
+        assert_eq!(*decoded, peer_info);
+
+        // Wrong network key
+        assert!(decode_lan_beacon(&crypto, None, &beacon).is_err());
+        let other_key = crypto.get(ck).unwrap().random_shared_secret();
+        assert!(decode_lan_beacon(&crypto, Some(other_key), &beacon).is_err());
+
+        // Signature does not match the node ids
+        let (other_peer_info, other_tks) = make_local_peer_info(crypto.clone(), ck);
+        let forged = PeerInfo::new(
+            RoutingDomain::LocalNetwork,
+            other_tks,
+            peer_info.signed_node_info().clone(),
+        );
+        let beacon = encode_lan_beacon(&crypto, None, &forged);
+        assert!(decode_lan_beacon(&crypto, None, &beacon).is_err());
+
+        // Wrong routing domain
+        let public = PeerInfo::new(
+            RoutingDomain::PublicInternet,
+            other_peer_info.node_ids().clone(),
+            other_peer_info.signed_node_info().clone(),
+        );
+        let beacon = encode_lan_beacon(&crypto, None, &public);
+        assert!(decode_lan_beacon(&crypto, None, &beacon).is_err());
+
+        // Truncated input
+        let beacon = encode_lan_beacon(&crypto, None, &peer_info);
+        assert!(decode_lan_beacon(&crypto, None, &[]).is_err());
+        assert!(decode_lan_beacon(&crypto, None, &beacon[..4]).is_err());
+        assert!(decode_lan_beacon(&crypto, None, &beacon[..4 + NONCE_LENGTH]).is_err());
+        assert!(decode_lan_beacon(&crypto, None, &beacon[..beacon.len() - 1]).is_err());
+
+        // Wrong magic
+        let mut bad_magic = beacon.clone();
+        bad_magic[0] = b'X';
+        assert!(decode_lan_beacon(&crypto, None, &bad_magic).is_err());
+    }
+
+    api.shutdown().await;
+}
+
+pub async fn test_lan_beacon_rate() {
+    info!("--- test_lan_beacon_rate ---");
+
+    let mut state = LanDiscoveryState::new();
+    let addr1 = IpAddr::V4(Ipv4Addr::new(192, 168, 0, 2));
+    let addr2 = IpAddr::V4(Ipv4Addr::new(192, 168, 0, 3));
+    let start = Timestamp::new(1_000_000_000);
+
+    assert!(state.check_beacon_rate(addr1, start, 2));
+    assert!(state.check_beacon_rate(addr1, start, 2));
+    assert!(!state.check_beacon_rate(addr1, start, 2));
+
+    // Other addresses are counted separately
+    assert!(state.check_beacon_rate(addr2, start, 2));
+
+    // Rejected beacons are not counted, and the window slides after a minute
+    let ts = start + TimestampDuration::new(59_999_999);
+    assert!(!state.check_beacon_rate(addr1, ts, 2));
+    let ts = start + TimestampDuration::new(60_000_000);
+    assert!(state.check_beacon_rate(addr1, ts, 2));
+    assert!(state.check_beacon_rate(addr1, ts, 2));
+    assert!(!state.check_beacon_rate(addr1, ts, 2));
+
+    // A limit of zero drops everything
+    assert!(!state.check_beacon_rate(addr2, ts, 0));
+}
+
+pub async fn test_lan_beacon_punished() {
+    info!("--- test_lan_beacon_punished ---");
+
+    let (update_callback, config_callback) = setup_veilid_core();
+    let api = api_startup(update_callback, config_callback)
+        .await
+        .expect("startup failed");
+
+    let crypto = api.crypto().unwrap();
+    let address_filter = api.network_manager().unwrap().address_filter();
+    let addr1 = IpAddr::V4(Ipv4Addr::new(192, 168, 0, 2));
+    let addr2 = IpAddr::V4(Ipv4Addr::new(192, 168, 0, 3));
+    let (peer_info, tks) = make_local_peer_info(crypto.clone(), best_crypto_kind());
+
+    assert!(!is_lan_beacon_punished(&address_filter, addr1, &peer_info));
+
+    // Punished source address
+    address_filter.punish_ip_addr(addr1, PunishmentReason::FailedToDecodeEnvelope);
+    assert!(is_lan_beacon_punished(&address_filter, addr1, &peer_info));
+    assert!(!is_lan_beacon_punished(&address_filter, addr2, &peer_info));
+
+    // Punished node id from an unpunished address
+    address_filter.punish_node_id(
+        tks.get(best_crypto_kind()).unwrap(),
+        PunishmentReason::FailedToDecodeOperation,
+    );
+    assert!(is_lan_beacon_punished(&address_filter, addr2, &peer_info));
+
+    api.shutdown().await;
+}
+
+pub async fn test_all() {
+    test_lan_beacon().await;
+    test_lan_beacon_rate().await;
+    test_lan_beacon_punished().await;
+}
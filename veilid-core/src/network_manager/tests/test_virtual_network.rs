@@ -0,0 +1,503 @@
+use super::*;
+use crate::tests::common::test_veilid_config::*;
+
+const VIRTUAL_NODE_COUNT: usize = 4;
+const VIRTUAL_NODE_PORT: u16 = 5150;
+/// How long anything on the virtual network may take, it has no real latency so this is generous
+const VIRTUAL_NETWORK_TIMEOUT_MS: u32 = 30_000;
+
+pub fn virtual_node_address(index: usize) -> SocketAddr {
+    SocketAddr::new(
+        IpAddr::V4(Ipv4Addr::new(12, 0, 0, index as u8 + 1)),
+        VIRTUAL_NODE_PORT,
+    )
+}
+
+/// Clear the virtual router and give it a fast, lossless default link
+pub fn reset_virtual_router() {
+    let router = VirtualRouter::global();
+    router.reset();
+    router.set_seed(1);
+    router.set_default_link(VirtualLinkConfig {
+        latency_min_ms: 1,
+        latency_max_ms: 20,
+        loss: 0.0,
+    });
+}
+
+/// Start one node on the virtual router at `virtual_node_address(index)`
+///
+/// Its node id is generated unless 'node_keypair' is given
+pub async fn startup_virtual_node(
+    index: usize,
+    bootstrap: Vec<String>,
+    disabled_capabilities: Vec<Capability>,
+    node_keypair: Option<TypedKeyPair>,
+) -> (VeilidAPI, flume::Receiver<VeilidUpdate>) {
+    let (sender, receiver) = flume::unbounded();
+    let update_callback: UpdateCallback = Arc::new(move |update| {
+        let _ = sender.send(update);
+    });
+    let config_callback: ConfigCallback = Arc::new(move |key| match key.as_str() {
+        "namespace" => Ok(Box::new(format!("virtual_network_{}", index))),
+        "network.protocol.udp.listen_address" => {
+            Ok(Box::new(virtual_node_address(index).to_string()))
+        }
+        "network.routing_table.bootstrap" => Ok(Box::new(bootstrap.clone())),
+        "capabilities.disable" => Ok(Box::new(disabled_capabilities.clone())),
+        "network.routing_table.node_id" => Ok(Box::new(
+            node_keypair
+                .map(|kp| TypedKeyGroup::from(TypedKey::new(kp.kind, kp.value.key)))
+                .unwrap_or_default(),
+        )),
+        "network.routing_table.node_id_secret" => Ok(Box::new(
+            node_keypair
+                .map(|kp| TypedSecretGroup::from(TypedSecret::new(kp.kind, kp.value.secret)))
+                .unwrap_or_default(),
+        )),
+        _ => config_callback(key),
+    });
+    let api = api_startup(update_callback, config_callback)
+        .await
+        .expect("startup failed");
+    api.attach().await.expect("attach failed");
+    (api, receiver)
+}
+
+pub fn node_id(api: &VeilidAPI) -> TypedKey {
+    api.routing_table().unwrap().node_id(CRYPTO_KIND_VLD0)
+}
+
+/// The bootstrap list that points other virtual nodes at this one
+pub fn virtual_node_bootstrap(index: usize, api: &VeilidAPI) -> Vec<String> {
+    vec![format!(
+        "{}@udp://{}",
+        node_id(api),
+        virtual_node_address(index)
+    )]
+}
+
+/// Start nodes on the virtual router at the addresses from `virtual_node_address`
+///
+/// The first node is the bootstrap server for the rest. NAT types and links set on the router
+/// beforehand apply from the start. Returns once every node that can be reached has found the others.
+pub async fn startup_virtual_network(
+    count: usize,
+) -> Vec<(VeilidAPI, flume::Receiver<VeilidUpdate>)> {
+    startup_virtual_network_with_disabled_capabilities(count, |_| vec![]).await
+}
+
+/// Like `startup_virtual_network`, but each node disables the capabilities returned for its index
+pub async fn startup_virtual_network_with_disabled_capabilities(
+    count: usize,
+    disabled_capabilities: impl Fn(usize) -> Vec<Capability>,
+) -> Vec<(VeilidAPI, flume::Receiver<VeilidUpdate>)> {
+    let mut nodes = vec![startup_virtual_node(0, vec![], disabled_capabilities(0), None).await];
+    let bootstrap = virtual_node_bootstrap(0, &nodes[0].0);
+    for index in 1..count {
+        nodes.push(
+            startup_virtual_node(index, bootstrap.clone(), disabled_capabilities(index), None)
+                .await,
+        );
+    }
+    wait_for_peers(&nodes[0].0, count - 1).await;
+    nodes
+}
+
+pub async fn shutdown_virtual_network(nodes: Vec<(VeilidAPI, flume::Receiver<VeilidUpdate>)>) {
+    for (api, _) in nodes {
+        api.shutdown().await;
+    }
+    VirtualRouter::global().reset();
+}
+
+pub async fn wait_for_peers(api: &VeilidAPI, count: usize) {
+    timeout(VIRTUAL_NETWORK_TIMEOUT_MS, async {
+        while api.get_state().await.unwrap().network.peers.len() < count {
+            sleep(250).await;
+        }
+    })
+    .await
+    .expect("timed out waiting for peers");
+}
+
+/// Wait for a node to learn what another node published on the public internet
+async fn wait_for_public_internet_node_info(
+    api: &VeilidAPI,
+    node_id: TypedKey,
+) -> RoutingTableNodeInfo {
+    timeout(VIRTUAL_NETWORK_TIMEOUT_MS, async {
+        loop {
+            let rti = api
+                .get_routing_table_info(RoutingTableEntryState::Unreliable)
+                .unwrap();
+            if let Some(node_info) = rti
+                .entries
+                .into_iter()
+                .find(|e| e.node_ids.contains(&node_id))
+                .and_then(|e| e.public_internet)
+            {
+                return node_info;
+            }
+            sleep(250).await;
+        }
+    })
+    .await
+    .expect("timed out waiting for node info")
+}
+
+/// Wait for an app message to arrive, or return None if none arrives in time
+async fn recv_app_message(
+    updates: &flume::Receiver<VeilidUpdate>,
+    timeout_ms: u32,
+) -> Option<VeilidAppMessage> {
+    timeout(timeout_ms, async {
+        loop {
+            if let VeilidUpdate::AppMessage(message) = updates.recv_async().await.unwrap() {
+                return *message;
+            }
+        }
+    })
+    .await
+    .ok()
+}
+
+fn unsafe_routing_context(api: &VeilidAPI) -> RoutingContext {
+    api.routing_context()
+        .unwrap()
+        .with_safety(SafetySelection::Unsafe(Sequencing::NoPreference))
+        .unwrap()
+}
+
+pub async fn test_virtual_network_app_message() {
+    info!("--- test_virtual_network_app_message ---");
+    reset_virtual_router();
+
+    let nodes = startup_virtual_network(VIRTUAL_NODE_COUNT).await;
+    for (api, _) in &nodes {
+        wait_for_peers(api, VIRTUAL_NODE_COUNT - 1).await;
+    }
+
+    // Send directly from the last node to the first node
+    let (first_api, first_updates) = &nodes[0];
+    let (last_api, _) = &nodes[VIRTUAL_NODE_COUNT - 1];
+    unsafe_routing_context(last_api)
+        .app_message(Target::NodeId(node_id(first_api)), b"hello".to_vec())
+        .await
+        .unwrap();
+    let message = recv_app_message(first_updates, VIRTUAL_NETWORK_TIMEOUT_MS)
+        .await
+        .expect("timed out waiting for app message");
+    assert_eq!(message.message(), b"hello");
+
+    // A call on a refused channel is answered with the refusal rather than timing out
+    first_api.set_reject_unknown_app_channels(true).unwrap();
+    let res = unsafe_routing_context(last_api)
+        .app_call_on_channel(
+            Target::NodeId(node_id(first_api)),
+            AppChannel::from_str("FILE").unwrap(),
+            b"refused".to_vec(),
+        )
+        .await;
+    assert!(
+        matches!(res, Err(VeilidAPIError::InvalidTarget { .. })),
+        "unexpected app call result: {:?}",
+        res
+    );
+    first_api.set_reject_unknown_app_channels(false).unwrap();
+
+    // The bootstrap node knows everyone, with their published dial info
+    let rti = first_api
+        .get_routing_table_info(RoutingTableEntryState::Unreliable)
+        .unwrap();
+    assert!(rti.entries.len() >= VIRTUAL_NODE_COUNT - 1);
+    assert!(rti.entries.iter().all(|e| e
+        .public_internet
+        .as_ref()
+        .is_some_and(|ni| !ni.dial_info.is_empty())));
+    let last_node_id = node_id(last_api);
+    let peers = first_api.get_closest_peers(last_node_id, 1).unwrap();
+    assert!(peers[0].node_ids.contains(&last_node_id));
+
+    shutdown_virtual_network(nodes).await;
+}
+
+pub async fn test_virtual_network_nat() {
+    info!("--- test_virtual_network_nat ---");
+    reset_virtual_router();
+
+    // The bootstrap node has no NAT, the others each have a different one
+    let nat_types = [
+        (VirtualNatType::None, Some(DialInfoClass::Direct)),
+        (VirtualNatType::FullCone, Some(DialInfoClass::FullConeNAT)),
+        (
+            VirtualNatType::PortRestricted,
+            Some(DialInfoClass::PortRestrictedNAT),
+        ),
+        (VirtualNatType::Symmetric, None),
+    ];
+    let router = VirtualRouter::global();
+    for (index, (nat_type, _)) in nat_types.iter().enumerate() {
+        router.set_nat_type(virtual_node_address(index).ip(), *nat_type);
+    }
+    let nodes = startup_virtual_network(nat_types.len()).await;
+
+    // Nodes behind a cone NAT publish their address with its class, a symmetric NAT can not be dialed
+    let (first_api, first_updates) = &nodes[0];
+    for (index, (_, dial_info_class)) in nat_types.iter().enumerate().skip(1) {
+        let node_info =
+            wait_for_public_internet_node_info(first_api, node_id(&nodes[index].0)).await;
+        match dial_info_class {
+            Some(class) => {
+                assert_eq!(node_info.network_class, NetworkClass::InboundCapable);
+                assert_eq!(node_info.dial_info.len(), 1);
+                assert_eq!(node_info.dial_info[0].class, *class);
+            }
+            None => {
+                assert_eq!(node_info.network_class, NetworkClass::OutboundOnly);
+                assert!(node_info.dial_info.is_empty());
+            }
+        }
+    }
+
+    // The symmetric NAT node can still send out through its mappings
+    let (symmetric_api, _) = &nodes[3];
+    unsafe_routing_context(symmetric_api)
+        .app_message(Target::NodeId(node_id(first_api)), b"outbound".to_vec())
+        .await
+        .unwrap();
+    let message = recv_app_message(first_updates, VIRTUAL_NETWORK_TIMEOUT_MS)
+        .await
+        .expect("timed out waiting for app message");
+    assert_eq!(message.message(), b"outbound");
+
+    // The port restricted node lets the bootstrap node in, since it has sent to it
+    let (restricted_api, restricted_updates) = &nodes[2];
+    unsafe_routing_context(first_api)
+        .app_message(Target::NodeId(node_id(restricted_api)), b"inbound".to_vec())
+        .await
+        .unwrap();
+    let message = recv_app_message(restricted_updates, VIRTUAL_NETWORK_TIMEOUT_MS)
+        .await
+        .expect("timed out waiting for app message");
+    assert_eq!(message.message(), b"inbound");
+
+    shutdown_virtual_network(nodes).await;
+}
+
+pub async fn test_virtual_network_partition() {
+    info!("--- test_virtual_network_partition ---");
+    reset_virtual_router();
+
+    let nodes = startup_virtual_network(VIRTUAL_NODE_COUNT).await;
+    let (first_api, first_updates) = &nodes[0];
+    let (last_api, _) = &nodes[VIRTUAL_NODE_COUNT - 1];
+    wait_for_peers(last_api, VIRTUAL_NODE_COUNT - 1).await;
+    let rc = unsafe_routing_context(last_api);
+    let target = Target::NodeId(node_id(first_api));
+
+    // Nothing crosses a partition
+    let router = VirtualRouter::global();
+    router.set_partition(virtual_node_address(VIRTUAL_NODE_COUNT - 1).ip(), 1);
+    first_updates.drain();
+    let _ = rc.app_message(target, b"partitioned".to_vec()).await;
+    assert!(recv_app_message(first_updates, 2_000).await.is_none());
+
+    // Messages get through again once the partition heals, after the failed node is retried
+    router.heal_partitions();
+    timeout(VIRTUAL_NETWORK_TIMEOUT_MS, async {
+        loop {
+            let _ = rc.app_message(target, b"healed".to_vec()).await;
+            if let Some(message) = recv_app_message(first_updates, 1_000).await {
+                assert_eq!(message.message(), b"healed");
+                break;
+            }
+        }
+    })
+    .await
+    .expect("timed out waiting for partition to heal");
+
+    shutdown_virtual_network(nodes).await;
+}
+
+pub async fn test_virtual_network_dht() {
+    info!("--- test_virtual_network_dht ---");
+    reset_virtual_router();
+
+    let nodes = startup_virtual_network(VIRTUAL_NODE_COUNT).await;
+    for (api, _) in &nodes {
+        wait_for_peers(api, VIRTUAL_NODE_COUNT - 1).await;
+    }
+    let writer_rc = unsafe_routing_context(&nodes[1].0);
+    let reader_rc = unsafe_routing_context(&nodes[2].0);
+
+    // A value written on one node can be read from another
+    let rec = writer_rc
+        .create_dht_record(DHTSchema::dflt(2).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let key = *rec.key();
+    assert_eq!(
+        writer_rc
+            .set_dht_value(key, 1, b"FIRST".to_vec(), None)
+            .await
+            .unwrap(),
+        None
+    );
+
+    reader_rc.open_dht_record(key, None).await.unwrap();
+    let value = reader_rc
+        .get_dht_value(key, 1, true)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(value.data(), b"FIRST");
+    assert_eq!(value.seq(), 0);
+    assert!(reader_rc
+        .get_dht_value(key, 0, true)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Updates are seen by a forced refresh
+    writer_rc
+        .set_dht_value(key, 1, b"SECOND".to_vec(), None)
+        .await
+        .unwrap();
+    let value = reader_rc
+        .get_dht_value(key, 1, true)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(value.data(), b"SECOND");
+    assert_eq!(value.seq(), 1);
+
+    // A compare-and-set that can not reach the network is not kept, so retrying it later succeeds
+    let cas_options = Some(SetDHTValueOptions {
+        writer: None,
+        expected_seq: Some(1),
+    });
+    let router = VirtualRouter::global();
+    router.set_partition(virtual_node_address(1).ip(), 1);
+    assert!(matches!(
+        writer_rc
+            .set_dht_value(key, 1, b"THIRD".to_vec(), cas_options.clone())
+            .await,
+        Err(VeilidAPIError::TryAgain { .. })
+    ));
+    assert_eq!(
+        writer_rc
+            .get_dht_value(key, 1, false)
+            .await
+            .unwrap()
+            .unwrap()
+            .data(),
+        b"SECOND"
+    );
+    router.heal_partitions();
+    timeout(VIRTUAL_NETWORK_TIMEOUT_MS, async {
+        loop {
+            match writer_rc
+                .set_dht_value(key, 1, b"THIRD".to_vec(), cas_options.clone())
+                .await
+            {
+                Ok(out) => break assert_eq!(out, None),
+                Err(VeilidAPIError::TryAgain { .. }) => sleep(1000).await,
+                Err(e) => panic!("compare-and-set retry failed: {}", e),
+            }
+        }
+    })
+    .await
+    .expect("timed out retrying compare-and-set");
+    let value = reader_rc
+        .get_dht_value(key, 1, true)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(value.data(), b"THIRD");
+    assert_eq!(value.seq(), 2);
+
+    reader_rc.close_dht_record(key).await.unwrap();
+    writer_rc.close_dht_record(key).await.unwrap();
+    writer_rc.delete_dht_record(key).await.unwrap();
+
+    shutdown_virtual_network(nodes).await;
+}
+
+pub async fn test_virtual_network_dht_batch() {
+    info!("--- test_virtual_network_dht_batch ---");
+    reset_virtual_router();
+
+    // Odd nodes can not decode batched questions, so every fanout visits nodes of both kinds
+    let nodes = startup_virtual_network_with_disabled_capabilities(VIRTUAL_NODE_COUNT, |index| {
+        if index % 2 == 1 {
+            vec![CAP_DHT_BATCH]
+        } else {
+            vec![]
+        }
+    })
+    .await;
+    for (api, _) in &nodes {
+        wait_for_peers(api, VIRTUAL_NODE_COUNT - 1).await;
+    }
+    let writer_rc = unsafe_routing_context(&nodes[0].0);
+    let reader_rc = unsafe_routing_context(&nodes[1].0);
+
+    // Values too large to share one request make the batched path send several,
+    // and the unbatched path sends one request per subkey
+    let rec = writer_rc
+        .create_dht_record(DHTSchema::dflt(4).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let key = *rec.key();
+    let values: Vec<(ValueSubkey, Vec<u8>)> = (0..3)
+        .map(|subkey| (subkey, vec![b'A' + subkey as u8; 20_000]))
+        .collect();
+    assert_eq!(
+        writer_rc
+            .set_dht_values(key, values.clone(), None)
+            .await
+            .unwrap(),
+        vec![(0, None), (1, None), (2, None)]
+    );
+
+    // The reader asks both kinds of node, and every subkey comes back from the network
+    reader_rc.open_dht_record(key, None).await.unwrap();
+    let got = reader_rc
+        .get_dht_values(key, ValueSubkeyRangeSet::full(), true)
+        .await
+        .unwrap();
+    assert_eq!(got.len(), 4);
+    for ((subkey, value), (expected_subkey, expected_data)) in got.iter().zip(values.iter()) {
+        assert_eq!(subkey, expected_subkey);
+        assert_eq!(value.as_ref().unwrap().data(), expected_data.as_slice());
+    }
+    assert_eq!(got[3], (3, None));
+
+    // A node without batch support stored every subkey, which only the fallback path could have sent it
+    let unbatched_rc = unsafe_routing_context(&nodes[3].0);
+    unbatched_rc.open_dht_record(key, None).await.unwrap();
+    for (subkey, data) in &values {
+        let value = unbatched_rc
+            .get_dht_value(key, *subkey, false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(value.data(), data.as_slice());
+    }
+
+    unbatched_rc.close_dht_record(key).await.unwrap();
+    reader_rc.close_dht_record(key).await.unwrap();
+    writer_rc.close_dht_record(key).await.unwrap();
+    writer_rc.delete_dht_record(key).await.unwrap();
+
+    shutdown_virtual_network(nodes).await;
+}
+
+pub async fn test_all() {
+    test_virtual_network_app_message().await;
+    test_virtual_network_nat().await;
+    test_virtual_network_partition().await;
+    test_virtual_network_dht().await;
+    test_virtual_network_dht_batch().await;
+}
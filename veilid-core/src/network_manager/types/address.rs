@@ -21,6 +21,7 @@ impl Address {
         }
     }
     #[cfg_attr(target_arch = "wasm32", expect(dead_code))]
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     pub fn from_ip_addr(addr: IpAddr) -> Address {
         match addr {
             IpAddr::V4(v4) => Address::IPV4(v4),
@@ -260,6 +260,7 @@ impl DialInfo {
         }
     }
     #[cfg_attr(target_arch = "wasm32", expect(dead_code))]
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     pub fn port(&self) -> u16 {
         match self {
             Self::UDP(di) => di.socket_address.port(),
@@ -269,6 +270,7 @@ impl DialInfo {
         }
     }
     #[cfg_attr(target_arch = "wasm32", expect(dead_code))]
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     pub fn set_port(&mut self, port: u16) {
         match self {
             Self::UDP(di) => di.socket_address.set_port(port),
@@ -285,6 +287,7 @@ impl DialInfo {
             Self::WSS(di) => di.socket_address.socket_addr(),
         }
     }
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     pub fn peer_address(&self) -> PeerAddress {
         match self {
             Self::UDP(di) => PeerAddress::new(di.socket_address, ProtocolType::UDP),
@@ -12,6 +12,7 @@ pub enum PunishmentReason {
     WrongSenderPeerInfo,
     // FailedToVerifySenderPeerInfo,
     FailedToRegisterSenderPeerInfo,
+    ExceededRateLimit,
     // Route-level punishments
     // FailedToDecodeRoutedMessage,
 }
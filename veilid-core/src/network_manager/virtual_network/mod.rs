@@ -0,0 +1,550 @@
+mod virtual_router;
+
+use super::*;
+
+use crate::routing_table::*;
+use connection_manager::*;
+use futures_util::StreamExt;
+use std::io;
+use stop_token::future::FutureExt as _;
+pub use virtual_router::*;
+
+/////////////////////////////////////////////////////////////////
+
+/// The port nodes listen on when their udp listen address does not name one
+const DEFAULT_VIRTUAL_PORT: u16 = 5150;
+
+/////////////////////////////////////////////////////////////////
+
+/// The virtual network only carries packets, so connection-oriented protocols never connect
+#[derive(Debug)]
+pub(in crate::network_manager) enum ProtocolNetworkConnection {}
+
+impl ProtocolNetworkConnection {
+    #[allow(dead_code)]
+    pub async fn connect(
+        _local_address: Option<SocketAddr>,
+        _dial_info: &DialInfo,
+        _timeout_ms: u32,
+        _address_filter: AddressFilter,
+    ) -> io::Result<NetworkResult<ProtocolNetworkConnection>> {
+        Ok(NetworkResult::no_connection_other(
+            "only UDP is supported on the virtual network",
+        ))
+    }
+
+    pub fn flow(&self) -> Flow {
+        match *self {}
+    }
+    pub async fn close(&self) -> io::Result<NetworkResult<()>> {
+        match *self {}
+    }
+    pub async fn send(&self, _message: Vec<u8>) -> io::Result<NetworkResult<()>> {
+        match *self {}
+    }
+    pub async fn recv(&self) -> io::Result<NetworkResult<Vec<u8>>> {
+        match *self {}
+    }
+}
+
+/////////////////////////////////////////////////////////////////
+
+struct NetworkInner {
+    network_needs_restart: bool,
+    /// the address bound on the virtual router
+    local_address: Option<SocketAddr>,
+    /// join handles for all the low level network background tasks
+    join_handles: Vec<MustJoinHandle<()>>,
+    /// stop source for shutting down the low level network background tasks
+    stop_source: Option<StopSource>,
+}
+
+struct NetworkUnlockedInner {
+    // Startup lock
+    startup_lock: StartupLock,
+
+    // Accessors
+    routing_table: RoutingTable,
+    network_manager: NetworkManager,
+    router: VirtualRouter,
+}
+
+#[derive(Clone)]
+pub(in crate::network_manager) struct Network {
+    config: VeilidConfig,
+    inner: Arc<Mutex<NetworkInner>>,
+    unlocked_inner: Arc<NetworkUnlockedInner>,
+}
+
+impl Network {
+    fn new_inner() -> NetworkInner {
+        NetworkInner {
+            network_needs_restart: false,
+            local_address: None,
+            join_handles: Vec::new(),
+            stop_source: None,
+        }
+    }
+
+    fn new_unlocked_inner(
+        network_manager: NetworkManager,
+        routing_table: RoutingTable,
+    ) -> NetworkUnlockedInner {
+        NetworkUnlockedInner {
+            startup_lock: StartupLock::new(),
+            network_manager,
+            routing_table,
+            router: VirtualRouter::global(),
+        }
+    }
+
+    pub fn new(
+        network_manager: NetworkManager,
+        routing_table: RoutingTable,
+        _connection_manager: ConnectionManager,
+    ) -> Self {
+        Self {
+            config: network_manager.config(),
+            inner: Arc::new(Mutex::new(Self::new_inner())),
+            unlocked_inner: Arc::new(Self::new_unlocked_inner(network_manager, routing_table)),
+        }
+    }
+
+    fn network_manager(&self) -> NetworkManager {
+        self.unlocked_inner.network_manager.clone()
+    }
+    fn routing_table(&self) -> RoutingTable {
+        self.unlocked_inner.routing_table.clone()
+    }
+
+    fn local_address(&self) -> EyreResult<SocketAddr> {
+        self.inner
+            .lock()
+            .local_address
+            .ok_or_else(|| eyre!("virtual network is not bound"))
+    }
+
+    /////////////////////////////////////////////////////////////////
+
+    // Record DialInfo failures
+    async fn record_dial_info_failure<T, F: Future<Output = EyreResult<NetworkResult<T>>>>(
+        &self,
+        dial_info: DialInfo,
+        fut: F,
+    ) -> EyreResult<NetworkResult<T>> {
+        let network_result = fut.await?;
+        if matches!(network_result, NetworkResult::NoConnection(_)) {
+            self.network_manager()
+                .address_filter()
+                .set_dial_info_failed(dial_info);
+        }
+        Ok(network_result)
+    }
+
+    // Send data to a dial info from our bound address, without expecting a reply
+    #[instrument(level="trace", target="net", err, skip(self, data), fields(data.len = data.len()))]
+    pub async fn send_data_unbound_to_dial_info(
+        &self,
+        dial_info: DialInfo,
+        data: Vec<u8>,
+    ) -> EyreResult<NetworkResult<()>> {
+        let _guard = self.unlocked_inner.startup_lock.enter()?;
+
+        self.record_dial_info_failure(dial_info.clone(), async move {
+            let data_len = data.len();
+
+            if self
+                .network_manager()
+                .address_filter()
+                .is_ip_addr_punished(dial_info.address().ip_addr())
+            {
+                return Ok(NetworkResult::no_connection_other("punished"));
+            }
+            if dial_info.protocol_type() != ProtocolType::UDP {
+                return Ok(NetworkResult::no_connection_other(
+                    "only UDP is supported on the virtual network",
+                ));
+            }
+
+            let local_address = self.local_address()?;
+            self.unlocked_inner
+                .router
+                .send(local_address, dial_info.to_socket_addr(), data)?;
+
+            // Network accounting
+            self.network_manager()
+                .stats_packet_sent(dial_info.ip_addr(), ByteCount::new(data_len as u64));
+
+            Ok(NetworkResult::Value(()))
+        })
+        .await
+    }
+
+    // Send data to a dial info from a new port, and wait for a specified amount of time to receive a single response
+    #[instrument(level="trace", target="net", err, skip(self, data), fields(data.len = data.len()))]
+    pub async fn send_recv_data_unbound_to_dial_info(
+        &self,
+        dial_info: DialInfo,
+        data: Vec<u8>,
+        timeout_ms: u32,
+    ) -> EyreResult<NetworkResult<Vec<u8>>> {
+        let _guard = self.unlocked_inner.startup_lock.enter()?;
+
+        self.record_dial_info_failure(dial_info.clone(), async move {
+            let data_len = data.len();
+
+            if self
+                .network_manager()
+                .address_filter()
+                .is_ip_addr_punished(dial_info.address().ip_addr())
+            {
+                return Ok(NetworkResult::no_connection_other("punished"));
+            }
+            if dial_info.protocol_type() != ProtocolType::UDP {
+                return Ok(NetworkResult::no_connection_other(
+                    "only UDP is supported on the virtual network",
+                ));
+            }
+
+            let router = self.unlocked_inner.router.clone();
+            let local_ip = self.local_address()?.ip();
+            let (unbound_address, receiver) = router.bind_ephemeral(local_ip)?;
+            let peer_socket_addr = dial_info.to_socket_addr();
+
+            let res = async {
+                router.send(unbound_address, peer_socket_addr, data)?;
+                self.network_manager()
+                    .stats_packet_sent(dial_info.ip_addr(), ByteCount::new(data_len as u64));
+
+                // receive single response
+                let packet = network_result_try!(timeout(timeout_ms, receiver.recv_async())
+                    .await
+                    .into_network_result())
+                .wrap_err("recv failure")?;
+
+                self.network_manager()
+                    .stats_packet_rcvd(packet.from.ip(), ByteCount::new(packet.data.len() as u64));
+
+                // if the from address is not the same as the one we sent to, then drop this
+                if packet.from != peer_socket_addr {
+                    bail!("wrong address");
+                }
+                Ok(NetworkResult::Value(packet.data))
+            }
+            .await;
+
+            router.unbind(unbound_address);
+            res
+        })
+        .await
+    }
+
+    #[instrument(level="trace", target="net", err, skip(self, data), fields(data.len = data.len()))]
+    pub async fn send_data_to_existing_flow(
+        &self,
+        flow: Flow,
+        data: Vec<u8>,
+    ) -> EyreResult<SendDataToExistingFlowResult> {
+        let _guard = self.unlocked_inner.startup_lock.enter()?;
+
+        if flow.protocol_type() != ProtocolType::UDP {
+            return Ok(SendDataToExistingFlowResult::NotSent(data));
+        }
+
+        // Send from the address the flow arrived at, since packets are not connection oriented
+        let data_len = data.len();
+        let local_address = match flow.local() {
+            Some(local) => local.socket_addr(),
+            None => self.local_address()?,
+        };
+        let peer_socket_addr = flow.remote().socket_addr();
+        self.unlocked_inner
+            .router
+            .send(local_address, peer_socket_addr, data)?;
+
+        // Network accounting
+        self.network_manager()
+            .stats_packet_sent(peer_socket_addr.ip(), ByteCount::new(data_len as u64));
+
+        // Data was consumed
+        Ok(SendDataToExistingFlowResult::Sent(UniqueFlow {
+            flow,
+            connection_id: None,
+        }))
+    }
+
+    // Send data directly to a dial info, possibly without knowing which node it is going to
+    // Returns a flow for the address used to send the data
+    #[instrument(level="trace", target="net", err, skip(self, data), fields(data.len = data.len()))]
+    pub async fn send_data_to_dial_info(
+        &self,
+        dial_info: DialInfo,
+        data: Vec<u8>,
+    ) -> EyreResult<NetworkResult<UniqueFlow>> {
+        let _guard = self.unlocked_inner.startup_lock.enter()?;
+
+        self.record_dial_info_failure(dial_info.clone(), async move {
+            let data_len = data.len();
+            if dial_info.protocol_type() != ProtocolType::UDP {
+                return Ok(NetworkResult::no_connection_other(
+                    "only UDP is supported on the virtual network",
+                ));
+            }
+
+            let local_address = self.local_address()?;
+            let peer_socket_addr = dial_info.to_socket_addr();
+            self.unlocked_inner
+                .router
+                .send(local_address, peer_socket_addr, data)?;
+
+            // Network accounting
+            self.network_manager()
+                .stats_packet_sent(dial_info.ip_addr(), ByteCount::new(data_len as u64));
+
+            Ok(NetworkResult::value(UniqueFlow {
+                flow: Flow::new(
+                    PeerAddress::new(
+                        SocketAddress::from_socket_addr(peer_socket_addr),
+                        ProtocolType::UDP,
+                    ),
+                    SocketAddress::from_socket_addr(local_address),
+                ),
+                connection_id: None,
+            }))
+        })
+        .await
+    }
+
+    /////////////////////////////////////////////////////////////////
+
+    // Pass packets from the virtual router up for processing
+    async fn receive_packets(
+        self,
+        receiver: flume::Receiver<VirtualPacket>,
+        stop_token: StopToken,
+    ) {
+        let network_manager = self.network_manager();
+        while let Ok(Ok(mut packet)) = receiver.recv_async().timeout_at(stop_token.clone()).await {
+            let flow = Flow::new(
+                PeerAddress::new(
+                    SocketAddress::from_socket_addr(packet.from),
+                    ProtocolType::UDP,
+                ),
+                SocketAddress::from_socket_addr(packet.to),
+            );
+            if let Err(e) = network_manager
+                .on_recv_envelope(packet.data.as_mut_slice(), flow)
+                .await
+            {
+                log_net!(debug "failed to process received virtual envelope: {}", e);
+            }
+        }
+    }
+
+    pub async fn startup_internal(&self) -> EyreResult<StartupDisposition> {
+        log_net!(debug "starting virtual network");
+        let router = self.unlocked_inner.router.clone();
+
+        // The udp listen address picks this node's address on the virtual network
+        let (listen_address, public_internet_capabilities) = {
+            let c = self.config.get();
+            let public_internet_capabilities = PUBLIC_INTERNET_CAPABILITIES
+                .iter()
+                .copied()
+                .filter(|cap| !c.capabilities.disable.contains(cap))
+                .collect::<Vec<Capability>>();
+            (
+                c.network.protocol.udp.listen_address.clone(),
+                public_internet_capabilities,
+            )
+        };
+        let mut local_address = listen_address_to_socket_addrs(&listen_address)
+            .map_err(|e| eyre!("{}", e))?
+            .first()
+            .copied()
+            .unwrap_or_else(|| SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0));
+        if local_address.ip().is_unspecified() {
+            local_address.set_ip(router.allocate_address());
+        }
+        if local_address.port() == 0 {
+            local_address.set_port(DEFAULT_VIRTUAL_PORT);
+        }
+
+        let receiver = router.bind(local_address)?;
+        log_net!(debug "virtual network bound to {}", local_address);
+
+        let stop_source = StopSource::new();
+        let jh = spawn(
+            "virtual network receiver",
+            self.clone().receive_packets(receiver, stop_source.token()),
+        );
+        {
+            let mut inner = self.inner.lock();
+            inner.local_address = Some(local_address);
+            inner.stop_source = Some(stop_source);
+            inner.join_handles.push(jh);
+        }
+
+        // Start editing routing table
+        let mut editor_public_internet = self
+            .unlocked_inner
+            .routing_table
+            .edit_public_internet_routing_domain();
+
+        // Dial info and network class follow from the NAT type of this node's address
+        let protocols = ProtocolTypeSet::from(ProtocolType::UDP);
+        let address_type = if local_address.is_ipv4() {
+            AddressType::IPV4
+        } else {
+            AddressType::IPV6
+        };
+        editor_public_internet.setup_network(
+            protocols,
+            protocols,
+            address_type.into(),
+            public_internet_capabilities,
+        );
+        match router.nat_type(local_address.ip()).dial_info_class() {
+            Some(class) => {
+                editor_public_internet
+                    .add_dial_info(DialInfo::udp_from_socketaddr(local_address), class);
+                editor_public_internet.set_network_class(Some(NetworkClass::InboundCapable));
+            }
+            None => {
+                editor_public_internet.set_network_class(Some(NetworkClass::OutboundOnly));
+            }
+        }
+
+        // commit routing domain edits
+        if editor_public_internet.commit(true).await {
+            editor_public_internet.publish();
+        }
+
+        Ok(StartupDisposition::Success)
+    }
+
+    #[instrument(level = "debug", err, skip_all)]
+    pub async fn startup(&self) -> EyreResult<StartupDisposition> {
+        let guard = self.unlocked_inner.startup_lock.startup()?;
+
+        match self.startup_internal().await {
+            Ok(StartupDisposition::Success) => {
+                info!("network started");
+                guard.success();
+                Ok(StartupDisposition::Success)
+            }
+            Ok(StartupDisposition::BindRetry) => {
+                debug!("network bind retry");
+                self.shutdown_internal().await;
+                Ok(StartupDisposition::BindRetry)
+            }
+            Err(e) => {
+                debug!("network failed to start");
+                self.shutdown_internal().await;
+                Err(e)
+            }
+        }
+    }
+
+    pub fn needs_restart(&self) -> bool {
+        self.inner.lock().network_needs_restart
+    }
+
+    pub fn is_started(&self) -> bool {
+        self.unlocked_inner.startup_lock.is_started()
+    }
+
+    #[instrument(level = "debug", skip_all)]
+    pub fn restart_network(&self) {
+        self.inner.lock().network_needs_restart = true;
+    }
+
+    #[instrument(level = "debug", skip_all)]
+    async fn shutdown_internal(&self) {
+        let mut unord = FuturesUnordered::new();
+        let opt_local_address = {
+            let mut inner = self.inner.lock();
+            // take the join handles out
+            for h in inner.join_handles.drain(..) {
+                unord.push(h);
+            }
+            // Drop the stop
+            drop(inner.stop_source.take());
+            inner.local_address.take()
+        };
+        // Wait for everything to stop
+        while unord.next().await.is_some() {}
+
+        if let Some(local_address) = opt_local_address {
+            self.unlocked_inner.router.unbind(local_address);
+        }
+
+        // Reset state
+        self.routing_table()
+            .edit_public_internet_routing_domain()
+            .shutdown()
+            .await;
+
+        *self.inner.lock() = Self::new_inner();
+    }
+
+    #[instrument(level = "debug", skip_all)]
+    pub async fn shutdown(&self) {
+        log_net!(debug "starting low level network shutdown");
+        let Ok(guard) = self.unlocked_inner.startup_lock.shutdown().await else {
+            log_net!(debug "low level network is already shut down");
+            return;
+        };
+
+        self.shutdown_internal().await;
+
+        guard.success();
+        log_net!(debug "finished low level network shutdown");
+    }
+
+    #[allow(dead_code)]
+    pub fn get_preferred_local_address(&self, _dial_info: &DialInfo) -> Option<SocketAddr> {
+        self.inner.lock().local_address
+    }
+
+    pub fn get_preferred_local_address_by_key(
+        &self,
+        _pt: ProtocolType,
+        _at: AddressType,
+    ) -> Option<SocketAddr> {
+        self.inner.lock().local_address
+    }
+
+    //////////////////////////////////////////
+
+    pub fn set_needs_public_dial_info_check(
+        &self,
+        _punishment: Option<Box<dyn FnOnce() + Send + 'static>>,
+    ) {
+        let Ok(_guard) = self.unlocked_inner.startup_lock.enter() else {
+            log_net!(debug "ignoring due to not started up");
+            return;
+        };
+    }
+
+    pub fn needs_public_dial_info_check(&self) -> bool {
+        let Ok(_guard) = self.unlocked_inner.startup_lock.enter() else {
+            log_net!(debug "ignoring due to not started up");
+            return false;
+        };
+
+        // Dial info on the virtual network is known from the NAT type, never detected
+        false
+    }
+
+    //////////////////////////////////////////
+    #[instrument(level = "trace", target = "net", name = "Network::tick", skip_all, err)]
+    pub(crate) async fn tick(&self) -> EyreResult<()> {
+        let Ok(_guard) = self.unlocked_inner.startup_lock.enter() else {
+            log_net!(debug "ignoring due to not started up");
+            return Ok(());
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,483 @@
+use super::*;
+use lazy_static::*;
+
+lazy_static! {
+    static ref GLOBAL_VIRTUAL_ROUTER: VirtualRouter = VirtualRouter::new();
+}
+
+/// The first address handed out to nodes that do not configure one, 11.0.0.1
+const FIRST_ALLOCATED_ADDRESS: u32 = 0x0b00_0001;
+/// The first port used for unbound sockets and symmetric NAT mappings
+const FIRST_EPHEMERAL_PORT: u16 = 49152;
+
+/// How a virtual node's address translation filters inbound packets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VirtualNatType {
+    /// Directly reachable with no address translation
+    #[default]
+    None,
+    /// Reachable by anyone on the node's listening port
+    FullCone,
+    /// Reachable only from addresses the node has sent to from the same port
+    AddressRestricted,
+    /// Reachable only from addresses and ports the node has sent to from the same port
+    PortRestricted,
+    /// Every destination sees a different port, reachable only from that destination
+    Symmetric,
+}
+
+impl VirtualNatType {
+    /// The dial info class a node with this NAT type registers, or None if it has no usable dial info
+    pub(super) fn dial_info_class(&self) -> Option<DialInfoClass> {
+        match self {
+            VirtualNatType::None => Some(DialInfoClass::Direct),
+            VirtualNatType::FullCone => Some(DialInfoClass::FullConeNAT),
+            VirtualNatType::AddressRestricted => Some(DialInfoClass::AddressRestrictedNAT),
+            VirtualNatType::PortRestricted => Some(DialInfoClass::PortRestrictedNAT),
+            VirtualNatType::Symmetric => None,
+        }
+    }
+}
+
+/// Delivery characteristics of the virtual link between two addresses
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VirtualLinkConfig {
+    /// The least time a packet takes to arrive
+    pub latency_min_ms: u32,
+    /// The most time a packet takes to arrive
+    pub latency_max_ms: u32,
+    /// The chance that a packet is dropped, from 0.0 to 1.0
+    pub loss: f32,
+}
+
+impl Default for VirtualLinkConfig {
+    fn default() -> Self {
+        Self {
+            latency_min_ms: 0,
+            latency_max_ms: 0,
+            loss: 0.0,
+        }
+    }
+}
+
+/// A packet delivered to a bound virtual socket
+#[derive(Debug, Clone)]
+pub(super) struct VirtualPacket {
+    /// The address the packet appears to come from, after address translation
+    pub from: SocketAddr,
+    /// The bound address of the socket receiving the packet
+    pub to: SocketAddr,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug)]
+struct VirtualEndpoint {
+    sender: flume::Sender<VirtualPacket>,
+    /// Destinations this socket has sent to, which restricted NATs let reply
+    sent_to: HashSet<SocketAddr>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct VirtualMapping {
+    /// The bound socket that made the mapping
+    internal: SocketAddr,
+    /// The only address that may send through the mapping
+    remote: SocketAddr,
+}
+
+#[derive(Debug)]
+struct VirtualRouterInner {
+    rng_state: u64,
+    default_link: VirtualLinkConfig,
+    links: HashMap<(IpAddr, IpAddr), VirtualLinkConfig>,
+    nat_types: HashMap<IpAddr, VirtualNatType>,
+    partitions: HashMap<IpAddr, u32>,
+    endpoints: HashMap<SocketAddr, VirtualEndpoint>,
+    /// Symmetric NAT mappings by their external address
+    mappings: HashMap<SocketAddr, VirtualMapping>,
+    /// Symmetric NAT mapping external addresses by the socket and destination that made them
+    mapping_addresses: HashMap<(SocketAddr, SocketAddr), SocketAddr>,
+    next_address: u32,
+    next_port: u16,
+}
+
+impl VirtualRouterInner {
+    fn new() -> Self {
+        Self {
+            rng_state: 0,
+            default_link: VirtualLinkConfig::default(),
+            links: HashMap::new(),
+            nat_types: HashMap::new(),
+            partitions: HashMap::new(),
+            endpoints: HashMap::new(),
+            mappings: HashMap::new(),
+            mapping_addresses: HashMap::new(),
+            next_address: FIRST_ALLOCATED_ADDRESS,
+            next_port: FIRST_EPHEMERAL_PORT,
+        }
+    }
+
+    // SplitMix64, so runs with the same seed drop and delay the same packets
+    fn next_random(&mut self) -> u64 {
+        self.rng_state = self.rng_state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.rng_state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn link(&self, a: IpAddr, b: IpAddr) -> VirtualLinkConfig {
+        self.links
+            .get(&link_key(a, b))
+            .copied()
+            .unwrap_or(self.default_link)
+    }
+
+    fn nat_type(&self, ip: IpAddr) -> VirtualNatType {
+        self.nat_types.get(&ip).copied().unwrap_or_default()
+    }
+
+    fn partition(&self, ip: IpAddr) -> u32 {
+        self.partitions.get(&ip).copied().unwrap_or_default()
+    }
+
+    fn allocate_port(&mut self, ip: IpAddr) -> EyreResult<SocketAddr> {
+        for _ in FIRST_EPHEMERAL_PORT..=u16::MAX {
+            let port = self.next_port;
+            self.next_port = if port == u16::MAX {
+                FIRST_EPHEMERAL_PORT
+            } else {
+                port + 1
+            };
+            let addr = SocketAddr::new(ip, port);
+            if !self.endpoints.contains_key(&addr) && !self.mappings.contains_key(&addr) {
+                return Ok(addr);
+            }
+        }
+        bail!("no free virtual ports on {}", ip);
+    }
+
+    // The address a packet from 'from' to 'to' appears to come from
+    fn translate_source(&mut self, from: SocketAddr, to: SocketAddr) -> EyreResult<SocketAddr> {
+        if self.nat_type(from.ip()) != VirtualNatType::Symmetric {
+            return Ok(from);
+        }
+        if let Some(external) = self.mapping_addresses.get(&(from, to)) {
+            return Ok(*external);
+        }
+        let external = self.allocate_port(from.ip())?;
+        self.mappings.insert(
+            external,
+            VirtualMapping {
+                internal: from,
+                remote: to,
+            },
+        );
+        self.mapping_addresses.insert((from, to), external);
+        Ok(external)
+    }
+
+    // The bound socket a packet from 'from' to 'to' arrives at, if the destination lets it in
+    fn translate_destination(&self, from: SocketAddr, to: SocketAddr) -> Option<SocketAddr> {
+        if let Some(mapping) = self.mappings.get(&to) {
+            return (mapping.remote == from).then_some(mapping.internal);
+        }
+        let endpoint = self.endpoints.get(&to)?;
+        let allowed = match self.nat_type(to.ip()) {
+            VirtualNatType::None | VirtualNatType::FullCone => true,
+            VirtualNatType::AddressRestricted => {
+                endpoint.sent_to.iter().any(|sa| sa.ip() == from.ip())
+            }
+            VirtualNatType::PortRestricted => endpoint.sent_to.contains(&from),
+            VirtualNatType::Symmetric => false,
+        };
+        allowed.then_some(to)
+    }
+}
+
+// Links are the same in both directions
+fn link_key(a: IpAddr, b: IpAddr) -> (IpAddr, IpAddr) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Routes packets between the virtual networks of the nodes in this process
+///
+/// Every node started with the 'virtual-network' feature binds its sockets here instead of on the host.
+/// Latency, loss, NAT types and partitions can be changed at any time and apply to packets sent afterward.
+#[derive(Debug, Clone)]
+pub struct VirtualRouter {
+    inner: Arc<Mutex<VirtualRouterInner>>,
+}
+
+impl VirtualRouter {
+    fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(VirtualRouterInner::new())),
+        }
+    }
+
+    /// The router shared by every node in this process
+    pub fn global() -> VirtualRouter {
+        GLOBAL_VIRTUAL_ROUTER.clone()
+    }
+
+    /// Return the network configuration to its defaults, leaving bound sockets in place
+    pub fn reset(&self) {
+        let mut inner = self.inner.lock();
+        let endpoints = mem::take(&mut inner.endpoints);
+        let mappings = mem::take(&mut inner.mappings);
+        let mapping_addresses = mem::take(&mut inner.mapping_addresses);
+        *inner = VirtualRouterInner {
+            endpoints,
+            mappings,
+            mapping_addresses,
+            ..VirtualRouterInner::new()
+        };
+    }
+
+    /// Seed the choice of which packets are dropped and how long they take to arrive
+    pub fn set_seed(&self, seed: u64) {
+        self.inner.lock().rng_state = seed;
+    }
+
+    /// Set the link used between addresses that have no link of their own
+    pub fn set_default_link(&self, link: VirtualLinkConfig) {
+        self.inner.lock().default_link = link;
+    }
+
+    /// Set or clear the link between two addresses, in both directions
+    pub fn set_link(&self, a: IpAddr, b: IpAddr, link: Option<VirtualLinkConfig>) {
+        let mut inner = self.inner.lock();
+        match link {
+            Some(link) => {
+                inner.links.insert(link_key(a, b), link);
+            }
+            None => {
+                inner.links.remove(&link_key(a, b));
+            }
+        }
+    }
+
+    /// Set the NAT type of a node's address
+    /// Takes effect on the dial info the node registers the next time its network starts
+    pub fn set_nat_type(&self, ip: IpAddr, nat_type: VirtualNatType) {
+        self.inner.lock().nat_types.insert(ip, nat_type);
+    }
+
+    /// Get the NAT type of a node's address
+    pub fn nat_type(&self, ip: IpAddr) -> VirtualNatType {
+        self.inner.lock().nat_type(ip)
+    }
+
+    /// Put an address in a partition, only addresses in the same partition can reach each other
+    /// Addresses start in partition zero
+    pub fn set_partition(&self, ip: IpAddr, partition: u32) {
+        self.inner.lock().partitions.insert(ip, partition);
+    }
+
+    /// Put every address back in partition zero
+    pub fn heal_partitions(&self) {
+        self.inner.lock().partitions.clear();
+    }
+
+    /// Allocate an address for a node that did not configure one
+    pub(super) fn allocate_address(&self) -> IpAddr {
+        let mut inner = self.inner.lock();
+        let address = inner.next_address;
+        inner.next_address += 1;
+        IpAddr::V4(Ipv4Addr::from(address))
+    }
+
+    /// Bind a socket at a specific address
+    pub(super) fn bind(&self, addr: SocketAddr) -> EyreResult<flume::Receiver<VirtualPacket>> {
+        let mut inner = self.inner.lock();
+        if inner.endpoints.contains_key(&addr) || inner.mappings.contains_key(&addr) {
+            bail!("virtual address already in use: {}", addr);
+        }
+        let (sender, receiver) = flume::unbounded();
+        inner.endpoints.insert(
+            addr,
+            VirtualEndpoint {
+                sender,
+                sent_to: HashSet::new(),
+            },
+        );
+        Ok(receiver)
+    }
+
+    /// Bind a socket at a free port of an address
+    pub(super) fn bind_ephemeral(
+        &self,
+        ip: IpAddr,
+    ) -> EyreResult<(SocketAddr, flume::Receiver<VirtualPacket>)> {
+        let addr = self.inner.lock().allocate_port(ip)?;
+        Ok((addr, self.bind(addr)?))
+    }
+
+    /// Close a bound socket and any NAT mappings it made
+    pub(super) fn unbind(&self, addr: SocketAddr) {
+        let mut inner = self.inner.lock();
+        inner.endpoints.remove(&addr);
+        inner.mappings.retain(|_, m| m.internal != addr);
+        inner
+            .mapping_addresses
+            .retain(|(internal, _), _| *internal != addr);
+    }
+
+    /// Send a packet from a bound socket
+    /// Like UDP, a packet that can not be delivered is dropped without an error
+    pub(super) fn send(&self, from: SocketAddr, to: SocketAddr, data: Vec<u8>) -> EyreResult<()> {
+        let (sender, latency_ms, packet) = {
+            let mut inner = self.inner.lock();
+            let Some(endpoint) = inner.endpoints.get_mut(&from) else {
+                bail!("virtual socket is not bound: {}", from);
+            };
+            endpoint.sent_to.insert(to);
+            let external = inner.translate_source(from, to)?;
+
+            let Some(internal) = inner.translate_destination(external, to) else {
+                log_net!("virtual packet filtered: {} -> {}", external, to);
+                return Ok(());
+            };
+            if inner.partition(from.ip()) != inner.partition(internal.ip()) {
+                log_net!("virtual packet partitioned: {} -> {}", external, to);
+                return Ok(());
+            }
+
+            let link = inner.link(from.ip(), internal.ip());
+            if link.loss > 0.0 && (inner.next_random() as f64 / u64::MAX as f64) < link.loss as f64
+            {
+                log_net!("virtual packet lost: {} -> {}", external, to);
+                return Ok(());
+            }
+            let latency_ms = if link.latency_max_ms > link.latency_min_ms {
+                link.latency_min_ms
+                    + (inner.next_random() % (link.latency_max_ms - link.latency_min_ms + 1) as u64)
+                        as u32
+            } else {
+                link.latency_min_ms
+            };
+
+            let Some(endpoint) = inner.endpoints.get(&internal) else {
+                return Ok(());
+            };
+            (
+                endpoint.sender.clone(),
+                latency_ms,
+                VirtualPacket {
+                    from: external,
+                    to: internal,
+                    data,
+                },
+            )
+        };
+
+        if latency_ms == 0 {
+            let _ = sender.send(packet);
+        } else {
+            spawn_detached("virtual packet delivery", async move {
+                sleep(latency_ms).await;
+                let _ = sender.send_async(packet).await;
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addr(ip: [u8; 4], port: u16) -> SocketAddr {
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::from(ip)), port)
+    }
+
+    fn recv(rx: &flume::Receiver<VirtualPacket>) -> Option<(SocketAddr, Vec<u8>)> {
+        rx.try_recv().ok().map(|p| (p.from, p.data))
+    }
+
+    #[test]
+    fn virtual_router_delivers_and_filters() {
+        let router = VirtualRouter::new();
+        let a = addr([12, 0, 0, 1], 5150);
+        let b = addr([12, 0, 0, 2], 5150);
+        let c = addr([12, 0, 0, 3], 5150);
+        let rx_a = router.bind(a).unwrap();
+        let rx_b = router.bind(b).unwrap();
+        let _rx_c = router.bind(c).unwrap();
+        assert!(router.bind(a).is_err());
+
+        // Direct delivery
+        router.send(a, b, b"hello".to_vec()).unwrap();
+        assert_eq!(recv(&rx_b), Some((a, b"hello".to_vec())));
+
+        // Port restricted NAT only lets in replies
+        router.set_nat_type(b.ip(), VirtualNatType::PortRestricted);
+        router.send(c, b, b"blocked".to_vec()).unwrap();
+        assert_eq!(recv(&rx_b), None);
+        router.send(b, a, b"out".to_vec()).unwrap();
+        assert_eq!(recv(&rx_a), Some((b, b"out".to_vec())));
+        router.send(a, b, b"reply".to_vec()).unwrap();
+        assert_eq!(recv(&rx_b), Some((a, b"reply".to_vec())));
+
+        // Partitions drop everything between them
+        router.set_partition(a.ip(), 1);
+        router.send(a, b, b"partitioned".to_vec()).unwrap();
+        assert_eq!(recv(&rx_b), None);
+        router.heal_partitions();
+        router.send(a, b, b"healed".to_vec()).unwrap();
+        assert_eq!(recv(&rx_b), Some((a, b"healed".to_vec())));
+
+        // Total loss drops everything
+        router.set_link(
+            a.ip(),
+            b.ip(),
+            Some(VirtualLinkConfig {
+                loss: 1.0,
+                ..Default::default()
+            }),
+        );
+        router.send(a, b, b"lost".to_vec()).unwrap();
+        assert_eq!(recv(&rx_b), None);
+    }
+
+    #[test]
+    fn virtual_router_symmetric_nat() {
+        let router = VirtualRouter::new();
+        let a = addr([12, 0, 0, 1], 5150);
+        let b = addr([12, 0, 0, 2], 5150);
+        let c = addr([12, 0, 0, 3], 5150);
+        let rx_a = router.bind(a).unwrap();
+        let rx_b = router.bind(b).unwrap();
+        let rx_c = router.bind(c).unwrap();
+        router.set_nat_type(a.ip(), VirtualNatType::Symmetric);
+
+        // Each destination sees a different mapped port
+        router.send(a, b, b"to b".to_vec()).unwrap();
+        router.send(a, c, b"to c".to_vec()).unwrap();
+        let (from_b, _) = recv(&rx_b).unwrap();
+        let (from_c, _) = recv(&rx_c).unwrap();
+        assert_eq!(from_b.ip(), a.ip());
+        assert_ne!(from_b, a);
+        assert_ne!(from_b, from_c);
+
+        // Only the destination of a mapping can reply through it
+        router.send(c, from_b, b"wrong".to_vec()).unwrap();
+        assert_eq!(recv(&rx_a), None);
+        router.send(b, from_b, b"right".to_vec()).unwrap();
+        assert_eq!(recv(&rx_a), Some((b, b"right".to_vec())));
+
+        // The listening port is not reachable
+        router.send(b, a, b"direct".to_vec()).unwrap();
+        assert_eq!(recv(&rx_a), None);
+
+        // Unbinding removes the mappings
+        router.unbind(a);
+        router.send(b, from_b, b"closed".to_vec()).unwrap();
+        assert_eq!(recv(&rx_a), None);
+    }
+}
@@ -12,11 +12,11 @@ use std::io;
 
 cfg_if! {
     if #[cfg(all(feature = "unstable-blockstore", feature="unstable-tunnels"))] {
-        const PUBLIC_INTERNET_CAPABILITIES_LEN: usize = 7;
+        const PUBLIC_INTERNET_CAPABILITIES_LEN: usize = 8;
     } else if #[cfg(any(feature = "unstable-blockstore", feature="unstable-tunnels"))] {
-        const PUBLIC_INTERNET_CAPABILITIES_LEN: usize = 6;
+        const PUBLIC_INTERNET_CAPABILITIES_LEN: usize = 7;
     } else  {
-        const PUBLIC_INTERNET_CAPABILITIES_LEN: usize = 5;
+        const PUBLIC_INTERNET_CAPABILITIES_LEN: usize = 6;
     }
 }
 pub const PUBLIC_INTERNET_CAPABILITIES: [Capability; PUBLIC_INTERNET_CAPABILITIES_LEN] = [
@@ -28,6 +28,7 @@ pub const PUBLIC_INTERNET_CAPABILITIES: [Capability; PUBLIC_INTERNET_CAPABILITIE
     //CAP_VALIDATE_DIAL_INFO,
     CAP_DHT,
     CAP_DHT_WATCH,
+    CAP_DHT_BATCH,
     CAP_APPMESSAGE,
     #[cfg(feature = "unstable-blockstore")]
     CAP_BLOCKSTORE,
@@ -47,8 +48,6 @@ pub const PUBLIC_INTERNET_CAPABILITIES: [Capability; PUBLIC_INTERNET_CAPABILITIE
 //     CAP_BLOCKSTORE,
 // ];
 
-pub const MAX_CAPABILITIES: usize = 64;
-
 /////////////////////////////////////////////////////////////////
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -142,6 +142,9 @@ pub(crate) struct BucketEntryInner {
     /// The accounting for the transfer statistics
     #[serde(skip)]
     transfer_stats_accounting: TransferStatsAccounting,
+    /// The accounting for inbound rate limits
+    #[serde(skip)]
+    rate_limit_accounting: RateLimitAccounting,
     /// If the entry is being punished and should be considered dead
     #[serde(skip)]
     punishment: Option<PunishmentReason>,
@@ -877,6 +880,7 @@ impl BucketEntryInner {
         self.peer_stats.rpc_stats.failed_to_send = 0;
         if expects_answer {
             self.peer_stats.rpc_stats.questions_in_flight += 1;
+            self.peer_stats.rpc_stats.questions_sent += 1;
             self.peer_stats.rpc_stats.last_question_ts = Some(ts);
         }
     }
@@ -885,6 +889,21 @@ impl BucketEntryInner {
         self.peer_stats.rpc_stats.messages_rcvd += 1;
         self.touch_last_seen(ts);
     }
+    pub(super) fn check_rate_limit(
+        &mut self,
+        operation: &'static str,
+        capability: Capability,
+        limit: &VeilidConfigRPCRateLimit,
+        cur_ts: Timestamp,
+    ) -> Option<u32> {
+        self.rate_limit_accounting.check(
+            operation,
+            capability,
+            limit,
+            cur_ts,
+            &mut self.peer_stats.rate_limits,
+        )
+    }
     pub(super) fn answer_sent(&mut self, bytes: ByteCount) {
         self.transfer_stats_accounting.add_up(bytes);
         self.peer_stats.rpc_stats.messages_sent += 1;
@@ -894,6 +913,7 @@ impl BucketEntryInner {
         self.transfer_stats_accounting.add_down(bytes);
         self.peer_stats.rpc_stats.messages_rcvd += 1;
         self.peer_stats.rpc_stats.questions_in_flight -= 1;
+        self.peer_stats.rpc_stats.answers_rcvd += 1;
         self.record_latency(recv_ts.saturating_sub(send_ts));
         self.touch_last_seen(recv_ts);
         self.peer_stats.rpc_stats.recent_lost_answers = 0;
@@ -902,6 +922,7 @@ impl BucketEntryInner {
         self.peer_stats.rpc_stats.first_consecutive_seen_ts = None;
         self.peer_stats.rpc_stats.questions_in_flight -= 1;
         self.peer_stats.rpc_stats.recent_lost_answers += 1;
+        self.peer_stats.rpc_stats.lost_answers += 1;
     }
     pub(super) fn failed_to_send(&mut self, ts: Timestamp, expects_answer: bool) {
         if expects_answer {
@@ -945,9 +966,11 @@ impl BucketEntry {
                 rpc_stats: RPCStats::default(),
                 latency: None,
                 transfer: TransferStatsDownUp::default(),
+                rate_limits: Vec::new(),
             },
             latency_stats_accounting: LatencyStatsAccounting::new(),
             transfer_stats_accounting: TransferStatsAccounting::new(),
+            rate_limit_accounting: RateLimitAccounting::new(),
             punishment: None,
             #[cfg(feature = "tracking")]
             next_track_id: 0,
@@ -1,8 +1,11 @@
 use super::*;
-use routing_table::tasks::bootstrap::BOOTSTRAP_TXT_VERSION_0;
+use routing_table::tasks::bootstrap::{BOOTSTRAP_TXT_VERSION_0, BOOTSTRAP_TXT_VERSION_1};
 
 impl RoutingTable {
-    pub(crate) async fn debug_info_txtrecord(&self) -> String {
+    pub(crate) async fn debug_info_txtrecord(
+        &self,
+        opt_signing_key_pair: Option<TypedKeyPair>,
+    ) -> String {
         let mut out = String::new();
 
         let gdis = self.dial_info_details(RoutingDomain::PublicInternet);
@@ -41,15 +44,40 @@ impl RoutingTable {
                 .map(|x| x.to_string())
                 .collect::<Vec<String>>()
                 .join(",");
-            out += "TXT Record:\n";
-            out += &format!(
-                "{}|{}|{}|{}|",
-                BOOTSTRAP_TXT_VERSION_0,
+            let txt_version = if opt_signing_key_pair.is_some() {
+                BOOTSTRAP_TXT_VERSION_1
+            } else {
+                BOOTSTRAP_TXT_VERSION_0
+            };
+            let mut record = format!(
+                "{}|{}|{}|{}|{}",
+                txt_version,
                 valid_envelope_versions,
                 node_ids,
-                some_hostname.unwrap()
+                some_hostname.unwrap(),
+                short_urls.join(",")
             );
-            out += &short_urls.join(",");
+
+            // Sign the record if we were given a bootstrap signing key
+            if let Some(signing_key_pair) = opt_signing_key_pair {
+                let Some(vcrypto) = self.crypto().get(signing_key_pair.kind) else {
+                    return "Unsupported crypto kind for bootstrap signing key".to_owned();
+                };
+                let signature = match vcrypto.sign(
+                    &signing_key_pair.value.key,
+                    &signing_key_pair.value.secret,
+                    record.as_bytes(),
+                ) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        return format!("Failed to sign TXT record: {}", e);
+                    }
+                };
+                record += &format!("|{}", TypedSignature::new(signing_key_pair.kind, signature));
+            }
+
+            out += "TXT Record:\n";
+            out += &record;
             out += "\n";
         }
         out
@@ -135,6 +163,7 @@ impl RoutingTable {
                 PunishmentReason::WrongSenderPeerInfo => "PSPBAD",
                 // PunishmentReason::FailedToVerifySenderPeerInfo => "PSPVER",
                 PunishmentReason::FailedToRegisterSenderPeerInfo => "PSPREG",
+                PunishmentReason::ExceededRateLimit => "PRATE",
                 //
             },
             BucketEntryStateReason::Dead(d) => match d {
@@ -153,130 +182,180 @@ impl RoutingTable {
         }
     }
 
-    pub(crate) fn debug_info_entries(
+    pub(crate) fn debug_entries(
         &self,
         min_state: BucketEntryState,
         capabilities: Vec<FourCC>,
-    ) -> String {
+    ) -> (u32, Vec<DebugBucketEntries>) {
+        let relay_ids = [
+            self.relay_ids(RoutingDomain::PublicInternet),
+            self.relay_ids(RoutingDomain::LocalNetwork),
+        ];
+
         let inner = self.inner.read();
         let inner = &*inner;
         let cur_ts = Timestamp::now();
 
-        let mut out = String::new();
-
-        out += &format!("Entries: {}\n", inner.bucket_entry_count());
-        out += "   Live:\n";
-        for ec in inner.cached_entry_counts() {
-            let routing_domain = ec.0 .0;
-            let crypto_kind = ec.0 .1;
-            let count = ec.1;
-            out += &format!("{:?}: {}: {}\n", routing_domain, crypto_kind, count);
-        }
+        let mut out = Vec::new();
         for ck in &VALID_CRYPTO_KINDS {
-            let mut filtered_total = 0;
-            let mut b = 0;
-            let blen = inner.buckets[ck].len();
-            while b < blen {
-                let filtered_entries: Vec<(&PublicKey, &Arc<BucketEntry>)> = inner.buckets[ck][b]
+            for (b, bucket) in inner.buckets[ck].iter().enumerate() {
+                let entries: Vec<RoutingTableEntry> = bucket
                     .entries()
-                    .filter(|e| {
-                        let cap_match = e.1.with(inner, |_rti, e| {
-                            e.has_all_capabilities(RoutingDomain::PublicInternet, &capabilities)
-                        });
-                        let state = e.1.with(inner, |_rti, e| e.state(cur_ts));
-                        state >= min_state && cap_match
+                    .filter_map(|e| {
+                        e.1.with(inner, |_rti, e| {
+                            if e.state(cur_ts) < min_state
+                                || !e.has_all_capabilities(
+                                    RoutingDomain::PublicInternet,
+                                    &capabilities,
+                                )
+                            {
+                                return None;
+                            }
+                            Some(Self::make_routing_table_entry(e, cur_ts, &relay_ids))
+                        })
                     })
                     .collect();
-                filtered_total += filtered_entries.len();
-                if !filtered_entries.is_empty() {
-                    out += &format!("{} Bucket #{}:\n", ck, b);
-                    for e in filtered_entries {
-                        let state_reason = e.1.with(inner, |_rti, e| e.state_reason(cur_ts));
-                        out += &format!(
-                            "    {} [{}] {} [{}]\n",
-                            e.0.encode(),
-                            Self::format_state_reason(state_reason),
-                            e.1.with(inner, |_rti, e| {
-                                e.peer_stats()
-                                    .latency
-                                    .as_ref()
-                                    .map(|l| {
-                                        format!(
-                                            "{:.2}ms",
-                                            timestamp_to_secs(l.average.as_u64()) * 1000.0
-                                        )
-                                    })
-                                    .unwrap_or_else(|| "???.??ms".to_string())
-                            }),
-                            e.1.with(inner, |_rti, e| {
-                                if let Some(ni) = e.node_info(RoutingDomain::PublicInternet) {
-                                    ni.capabilities()
-                                        .iter()
-                                        .map(|x| x.to_string())
-                                        .collect::<Vec<String>>()
-                                        .join(",")
-                                } else {
-                                    "???".to_owned()
-                                }
-                            })
-                        );
-                    }
+                if !entries.is_empty() {
+                    out.push(DebugBucketEntries {
+                        kind: *ck,
+                        index: b as u32,
+                        entries,
+                    });
                 }
-                b += 1;
             }
-            out += &format!("{} Filtered Total: {}\n", ck, filtered_total);
         }
 
-        out
+        (inner.bucket_entry_count() as u32, out)
     }
 
-    pub(crate) fn debug_info_entry(&self, node_ref: NodeRef) -> String {
+    pub(crate) fn debug_entry(&self, node_ref: NodeRef) -> RoutingTableEntry {
+        let relay_ids = [
+            self.relay_ids(RoutingDomain::PublicInternet),
+            self.relay_ids(RoutingDomain::LocalNetwork),
+        ];
         let cur_ts = Timestamp::now();
-
-        let mut out = String::new();
-        out += &node_ref.operate(|_rti, e| {
-            let state_reason = e.state_reason(cur_ts);
-            format!(
-                "state: {}\n{:#?}\n",
-                Self::format_state_reason(state_reason),
-                e
-            )
-        });
-        out
+        node_ref.operate(|_rti, e| Self::make_routing_table_entry(e, cur_ts, &relay_ids))
     }
 
-    pub(crate) fn debug_info_buckets(&self, min_state: BucketEntryState) -> String {
+    pub(crate) fn debug_buckets(&self, min_state: BucketEntryState) -> Vec<DebugBucketCounts> {
         let inner = self.inner.read();
         let inner = &*inner;
         let cur_ts = Timestamp::now();
 
-        let mut out = String::new();
-        const COLS: usize = 16;
-        out += "Buckets:\n";
+        let mut out = Vec::new();
         for ck in &VALID_CRYPTO_KINDS {
-            out += &format!("  {}:\n", ck);
-            let rows = inner.buckets[ck].len() / COLS;
-            let mut r = 0;
-            let mut b = 0;
-            while r < rows {
-                let mut c = 0;
-                out += format!("    {:>3}: ", b).as_str();
-                while c < COLS {
-                    let mut cnt = 0;
-                    for e in inner.buckets[ck][b].entries() {
-                        if e.1.with(inner, |_rti, e| e.state(cur_ts) >= min_state) {
-                            cnt += 1;
-                        }
-                    }
-                    out += format!("{:>3} ", cnt).as_str();
-                    b += 1;
-                    c += 1;
-                }
-                out += "\n";
-                r += 1;
-            }
+            let counts = inner.buckets[ck]
+                .iter()
+                .map(|bucket| {
+                    bucket
+                        .entries()
+                        .filter(|e| e.1.with(inner, |_rti, e| e.state(cur_ts) >= min_state))
+                        .count() as u32
+                })
+                .collect();
+            out.push(DebugBucketCounts { kind: *ck, counts });
         }
 
         out
     }
+
+    fn make_routing_table_node_info(
+        e: &BucketEntryInner,
+        routing_domain: RoutingDomain,
+    ) -> Option<RoutingTableNodeInfo> {
+        let sni = e.signed_node_info(routing_domain)?;
+        let ni = sni.node_info();
+        Some(RoutingTableNodeInfo {
+            network_class: ni.network_class(),
+            capabilities: ni.capabilities().to_vec(),
+            dial_info: ni
+                .dial_info_detail_list()
+                .iter()
+                .map(|did| RoutingTableDialInfo {
+                    class: did.class,
+                    dial_info: did.dial_info.to_string(),
+                })
+                .collect(),
+            relay_ids: sni.relay_ids().iter().copied().collect(),
+        })
+    }
+
+    fn make_routing_table_entry(
+        e: &BucketEntryInner,
+        cur_ts: Timestamp,
+        relay_ids: &[TypedKeyGroup],
+    ) -> RoutingTableEntry {
+        let state_reason = e.state_reason(cur_ts);
+        let node_ids = e.node_ids();
+        RoutingTableEntry {
+            node_ids: node_ids.iter().copied().collect(),
+            state: BucketEntryState::from(state_reason).into(),
+            state_reason: Self::format_state_reason(state_reason).to_owned(),
+            is_relay: relay_ids.iter().any(|r| r.contains_any(&node_ids)),
+            public_internet: Self::make_routing_table_node_info(e, RoutingDomain::PublicInternet),
+            local_network: Self::make_routing_table_node_info(e, RoutingDomain::LocalNetwork),
+            peer_stats: e.peer_stats().clone(),
+        }
+    }
+
+    fn relay_ids(&self, routing_domain: RoutingDomain) -> TypedKeyGroup {
+        self.relay_node(routing_domain)
+            .map(|nr| nr.node_ids())
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn routing_table_info(&self, min_state: BucketEntryState) -> RoutingTableInfo {
+        let public_internet_relay = self.relay_ids(RoutingDomain::PublicInternet);
+        let local_network_relay = self.relay_ids(RoutingDomain::LocalNetwork);
+        let relay_ids = [public_internet_relay.clone(), local_network_relay.clone()];
+        let buckets = self.debug_buckets(min_state);
+
+        let inner = self.inner.read();
+        let inner = &*inner;
+        let cur_ts = Timestamp::now();
+
+        let mut entries = Vec::new();
+        inner.with_entries(cur_ts, min_state, |rti, entry| {
+            entries.push(entry.with(rti, |_rti, e| {
+                Self::make_routing_table_entry(e, cur_ts, &relay_ids)
+            }));
+            Option::<()>::None
+        });
+
+        RoutingTableInfo {
+            node_ids: self.unlocked_inner.node_ids().iter().copied().collect(),
+            public_internet_relay: public_internet_relay.iter().copied().collect(),
+            local_network_relay: local_network_relay.iter().copied().collect(),
+            buckets,
+            entries,
+        }
+    }
+
+    pub(crate) fn closest_peers(
+        &self,
+        key: TypedKey,
+        count: usize,
+    ) -> VeilidAPIResult<Vec<RoutingTableEntry>> {
+        let relay_ids = [
+            self.relay_ids(RoutingDomain::PublicInternet),
+            self.relay_ids(RoutingDomain::LocalNetwork),
+        ];
+        let cur_ts = Timestamp::now();
+
+        let filter = Box::new(
+            move |rti: &RoutingTableInner, opt_entry: Option<Arc<BucketEntry>>| {
+                // Exclude our own node and nodes that can't use the key's crypto kind
+                let Some(entry) = opt_entry else {
+                    return false;
+                };
+                entry.with(rti, |_rti, e| e.crypto_kinds().contains(&key.kind))
+            },
+        ) as RoutingTableEntryFilter;
+
+        self.find_preferred_closest_nodes(count, key, VecDeque::from([filter]), |rti, entry| {
+            entry.unwrap().with(rti, |_rti, e| {
+                Self::make_routing_table_entry(e, cur_ts, &relay_ids)
+            })
+        })
+    }
 }
@@ -581,6 +581,7 @@ impl RoutingTable {
 
     /// Return the domain's currently registered network class
     #[cfg_attr(target_arch = "wasm32", expect(dead_code))]
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     pub fn get_network_class(&self, routing_domain: RoutingDomain) -> Option<NetworkClass> {
         self.inner.read().get_network_class(routing_domain)
     }
@@ -790,6 +791,7 @@ impl RoutingTable {
 
     /// Makes a filter that finds nodes with a matching inbound dialinfo
     #[cfg_attr(target_arch = "wasm32", expect(dead_code))]
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     pub fn make_inbound_dial_info_entry_filter<'a>(
         routing_domain: RoutingDomain,
         dial_info_filter: DialInfoFilter,
@@ -273,6 +273,17 @@ pub trait NodeRefCommonTrait: NodeRefAccessorsTrait + NodeRefOperateTrait {
             e.question_rcvd(ts, bytes);
         })
     }
+    /// Take a token from the peer's inbound rate limit for an operation
+    /// Returns the number of recent violations if the operation is over its limit
+    fn check_rate_limit(
+        &self,
+        operation: &'static str,
+        capability: Capability,
+        limit: &VeilidConfigRPCRateLimit,
+        cur_ts: Timestamp,
+    ) -> Option<u32> {
+        self.operate_mut(|_rti, e| e.check_rate_limit(operation, capability, limit, cur_ts))
+    }
     fn stats_answer_sent(&self, bytes: ByteCount) {
         self.operate_mut(|rti, e| {
             rti.transfer_stats_accounting().add_up(bytes);
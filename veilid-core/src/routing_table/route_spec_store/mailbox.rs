@@ -0,0 +1,20 @@
+use super::*;
+
+/// A DHT record subkey that is kept pointing at a live allocated private route
+#[derive(Debug, Clone)]
+pub(crate) struct Mailbox {
+    /// The DHT record the private route blob is published to
+    pub key: TypedKey,
+    /// The subkey of the DHT record the private route blob is published to
+    pub subkey: ValueSubkey,
+    /// The route currently bound to the mailbox, or None if it died and needs replacing
+    pub route_id: Option<RouteId>,
+    /// Stability preference for routes allocated for this mailbox
+    pub stability: Stability,
+    /// Sequencing preference for routes allocated for this mailbox
+    pub sequencing: Sequencing,
+    /// Rotation policy applied to routes allocated for this mailbox
+    pub rotation_policy: Option<RouteRotationPolicy>,
+    /// The bound route changed and its blob has not been written to the DHT yet
+    pub needs_publish: bool,
+}
@@ -1,6 +1,7 @@
 use super::*;
 use crate::veilid_api::*;
 
+mod mailbox;
 mod permutation;
 mod remote_private_route_info;
 mod route_set_spec_detail;
@@ -9,6 +10,8 @@ mod route_spec_store_content;
 mod route_stats;
 
 use permutation::*;
+
+pub(crate) use mailbox::*;
 use remote_private_route_info::*;
 use route_set_spec_detail::*;
 use route_spec_store_cache::*;
@@ -25,6 +28,8 @@ const REMOTE_PRIVATE_ROUTE_CACHE_EXPIRY: TimestampDuration = TimestampDuration::
 const ROUTE_MIN_IDLE_TIME_MS: u32 = 30_000;
 /// The size of the compiled route cache
 const COMPILED_ROUTE_CACHE_SIZE: usize = 256;
+/// Routes replaced by rotation stay allocated for 2 minutes so senders holding the old blob can switch over
+const ROTATED_ROUTE_GRACE_PERIOD: TimestampDuration = TimestampDuration::new(120_000_000u64);
 
 #[derive(Debug)]
 struct RouteSpecStoreInner {
@@ -32,6 +37,10 @@ struct RouteSpecStoreInner {
     content: RouteSpecStoreContent,
     /// RouteSpecStore cache
     cache: RouteSpecStoreCache,
+    /// DHT subkeys kept pointing at our allocated routes
+    mailboxes: HashMap<(TypedKey, ValueSubkey), Mailbox>,
+    /// Allocated routes replaced by rotation and the time they may be released
+    retired_routes: HashMap<RouteId, Timestamp>,
 }
 
 struct RouteSpecStoreUnlockedInner {
@@ -73,6 +82,8 @@ impl RouteSpecStore {
             inner: Arc::new(Mutex::new(RouteSpecStoreInner {
                 content: RouteSpecStoreContent::new(),
                 cache: Default::default(),
+                mailboxes: HashMap::new(),
+                retired_routes: HashMap::new(),
             })),
         }
     }
@@ -94,6 +105,8 @@ impl RouteSpecStore {
         let mut inner = RouteSpecStoreInner {
             content,
             cache: Default::default(),
+            mailboxes: HashMap::new(),
+            retired_routes: HashMap::new(),
         };
 
         // Rebuild the routespecstore cache
@@ -132,7 +145,7 @@ impl RouteSpecStore {
 
     #[instrument(level = "trace", target = "route", skip(self))]
     pub fn send_route_update(&self) {
-        let (dead_routes, dead_remote_routes) = {
+        let (dead_routes, dead_remote_routes, rotated_routes) = {
             let mut inner = self.inner.lock();
             let Some(dr) = inner.cache.take_dead_routes() else {
                 // Nothing to do
@@ -144,6 +157,7 @@ impl RouteSpecStore {
         let update = VeilidUpdate::RouteChange(Box::new(VeilidRouteChange {
             dead_routes,
             dead_remote_routes,
+            rotated_routes,
         }));
 
         let update_callback = self.unlocked_inner.routing_table.update_callback();
@@ -843,6 +857,13 @@ impl RouteSpecStore {
             return false;
         };
 
+        // Any mailbox bound to this route needs a new one
+        for mailbox in inner.mailboxes.values_mut() {
+            if mailbox.route_id == Some(id) {
+                mailbox.route_id = None;
+            }
+        }
+
         // Remove from hop cache
         let rti = &*self.unlocked_inner.routing_table.inner.read();
         if !inner.cache.remove_from_cache(rti, id, &rssd) {
@@ -984,17 +1005,323 @@ impl RouteSpecStore {
         out
     }
 
-    /// Get the debug description of a route
-    pub fn debug_route(&self, id: &RouteId) -> Option<String> {
-        let inner = &mut *self.inner.lock();
+    /// Get the health of a route we know about, allocated or remote
+    pub fn get_route_health(&self, id: &RouteId) -> Option<RouteHealth> {
+        let inner = &*self.inner.lock();
         let cur_ts = Timestamp::now();
         if let Some(rpri) = inner.cache.peek_remote_private_route(cur_ts, id) {
-            return Some(format!("{:#?}", rpri));
+            return Some(Self::make_remote_route_health(cur_ts, id, rpri));
+        }
+        let rssd = inner.content.get_detail(id)?;
+        let rti = &*self.unlocked_inner.routing_table.inner.read();
+        Some(Self::make_allocated_route_health(rti, cur_ts, id, rssd))
+    }
+
+    /// List the health of all routes we know about, allocated and remote
+    pub fn list_route_health(&self) -> Vec<RouteHealth> {
+        let inner = &*self.inner.lock();
+        let cur_ts = Timestamp::now();
+        let rti = &*self.unlocked_inner.routing_table.inner.read();
+        let mut out = Vec::with_capacity(inner.content.get_detail_count());
+        for (id, rssd) in inner.content.iter_details() {
+            out.push(Self::make_allocated_route_health(rti, cur_ts, id, rssd));
         }
-        if let Some(rssd) = inner.content.get_detail(id) {
-            return Some(format!("{:#?}", rssd));
+        for id in inner.cache.get_remote_private_route_ids(cur_ts) {
+            if let Some(rpri) = inner.cache.peek_remote_private_route(cur_ts, &id) {
+                out.push(Self::make_remote_route_health(cur_ts, &id, rpri));
+            }
+        }
+        out
+    }
+
+    fn make_allocated_route_health(
+        rti: &RoutingTableInner,
+        cur_ts: Timestamp,
+        id: &RouteId,
+        rssd: &RouteSetSpecDetail,
+    ) -> RouteHealth {
+        let ip6_prefix_size = rti
+            .unlocked_inner
+            .config
+            .get()
+            .network
+            .max_connections_per_ip6_prefix_size as usize;
+
+        // Count the distinct network blocks our hops are on
+        let mut hop_node_infos = Vec::<NodeInfo>::new();
+        for hop in rssd.hops_node_refs() {
+            let Some(node_info) = hop.locked(rti).node_info(RoutingDomain::PublicInternet) else {
+                continue;
+            };
+            if !hop_node_infos
+                .iter()
+                .any(|x| x.node_is_on_same_ipblock(&node_info, ip6_prefix_size))
+            {
+                hop_node_infos.push(node_info);
+            }
+        }
+
+        let stats = rssd.get_stats();
+        RouteHealth {
+            route_id: *id,
+            remote: false,
+            published: rssd.is_published(),
+            age: cur_ts.saturating_sub(stats.created_ts),
+            latency: stats.latency_stats().clone(),
+            transfer: stats.transfer_stats().clone(),
+            successes: stats.successes,
+            failures: stats.failures,
+            recent_failures: stats.recent_failures(),
+            last_tested_ts: stats.last_tested_ts,
+            hop_count: rssd.hop_count() as u32,
+            hop_ipblock_count: Some(hop_node_infos.len() as u32),
+            stability: Some(rssd.get_stability()),
+            can_do_sequenced: Some(rssd.is_sequencing_match(Sequencing::EnsureOrdered)),
+        }
+    }
+
+    fn make_remote_route_health(
+        cur_ts: Timestamp,
+        id: &RouteId,
+        rpri: &RemotePrivateRouteInfo,
+    ) -> RouteHealth {
+        let stats = rpri.get_stats();
+        RouteHealth {
+            route_id: *id,
+            remote: true,
+            published: false,
+            age: cur_ts.saturating_sub(stats.created_ts),
+            latency: stats.latency_stats().clone(),
+            transfer: stats.transfer_stats().clone(),
+            successes: stats.successes,
+            failures: stats.failures,
+            recent_failures: stats.recent_failures(),
+            last_tested_ts: stats.last_tested_ts,
+            hop_count: rpri
+                .best_private_route()
+                .map(|pr| pr.hop_count as u32)
+                .unwrap_or_default(),
+            hop_ipblock_count: None,
+            stability: None,
+            can_do_sequenced: None,
+        }
+    }
+
+    /// Set the policy for replacing an allocated route before it dies
+    pub fn set_route_rotation_policy(
+        &self,
+        id: &RouteId,
+        rotation_policy: Option<RouteRotationPolicy>,
+    ) -> VeilidAPIResult<()> {
+        let inner = &mut *self.inner.lock();
+        let Some(rssd) = inner.content.get_detail_mut(id) else {
+            apibail_invalid_target!("route does not exist");
+        };
+        rssd.set_rotation_policy(rotation_policy);
+        Ok(())
+    }
+
+    /// Allocate and test a new route, then mark it as published with a rotation policy
+    /// Returns Err(VeilidAPIError::TryAgain) if no route could be allocated or tested at this time
+    /// Returns Ok((route id, private route blob)) on success
+    #[instrument(level = "trace", target = "route", skip(self), err)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn allocate_published_route(
+        &self,
+        crypto_kinds: &[CryptoKind],
+        stability: Stability,
+        sequencing: Sequencing,
+        hop_count: usize,
+        directions: DirectionSet,
+        avoid_nodes: &[TypedKey],
+        rotation_policy: Option<RouteRotationPolicy>,
+    ) -> VeilidAPIResult<(RouteId, Vec<u8>)> {
+        let new_id = self.allocate_route(
+            crypto_kinds,
+            stability,
+            sequencing,
+            hop_count,
+            directions,
+            avoid_nodes,
+            false,
+        )?;
+        match self.test_route(new_id).await {
+            Ok(Some(true)) => {
+                // route tested okay
+            }
+            Ok(Some(false)) | Ok(None) => {
+                self.release_route(new_id);
+                apibail_try_again!("allocated route failed to test");
+            }
+            Err(e) => {
+                self.release_route(new_id);
+                return Err(e);
+            }
+        }
+
+        let blob = match self.private_route_blob(&new_id) {
+            Ok(v) => v,
+            Err(e) => {
+                self.release_route(new_id);
+                return Err(e);
+            }
+        };
+
+        {
+            let inner = &mut *self.inner.lock();
+            let Some(rssd) = inner.content.get_detail_mut(&new_id) else {
+                apibail_internal!("allocated route disappeared");
+            };
+            rssd.set_published(true);
+            rssd.set_rotation_policy(rotation_policy);
+        }
+
+        Ok((new_id, blob))
+    }
+
+    /// Assemble the publishable private route blob for an allocated route
+    pub fn private_route_blob(&self, id: &RouteId) -> VeilidAPIResult<Vec<u8>> {
+        let private_routes = self.assemble_private_routes(id, Some(true))?;
+        Self::private_routes_to_blob(&private_routes)
+    }
+
+    /// Allocate and test a replacement for an allocated route, with the same parameters
+    /// The new route avoids the hops of the old route, is marked as published, and
+    /// inherits the rotation policy of the old route. The old route is not released.
+    /// Returns Err(VeilidAPIError::TryAgain) if no replacement could be allocated at this time
+    /// Returns Ok((new route id, private route blob)) on success
+    #[instrument(level = "trace", target = "route", skip(self), err)]
+    pub async fn rotate_route(&self, id: RouteId) -> VeilidAPIResult<(RouteId, Vec<u8>)> {
+        let (crypto_kinds, stability, sequencing, hop_count, directions, avoid_nodes, policy) = {
+            let inner = self.inner.lock();
+            let Some(rssd) = inner.content.get_detail(&id) else {
+                apibail_invalid_target!("route does not exist");
+            };
+            let sequencing = if rssd.can_do_sequenced() {
+                Sequencing::PreferOrdered
+            } else {
+                Sequencing::NoPreference
+            };
+            let avoid_nodes: Vec<TypedKey> = rssd
+                .hops_node_refs()
+                .iter()
+                .flat_map(|nr| nr.node_ids().iter().copied().collect::<Vec<_>>())
+                .collect();
+            (
+                rssd.get_route_set_keys().kinds(),
+                rssd.get_stability(),
+                sequencing,
+                rssd.hop_count(),
+                rssd.get_directions(),
+                avoid_nodes,
+                rssd.get_rotation_policy().cloned(),
+            )
+        };
+
+        self.allocate_published_route(
+            &crypto_kinds,
+            stability,
+            sequencing,
+            hop_count,
+            directions,
+            &avoid_nodes,
+            policy,
+        )
+        .await
+    }
+
+    /// Retire an allocated route that was replaced by its rotation policy
+    /// and report the rotation to clients with the next route update
+    /// Mailboxes bound to the old route are rebound to the new one
+    /// The old route is not rotated again and stays allocated until its grace period is over
+    pub fn retire_rotated_route(&self, cur_ts: Timestamp, rotation: VeilidRouteRotation) {
+        let inner = &mut *self.inner.lock();
+        for mailbox in inner.mailboxes.values_mut() {
+            if mailbox.route_id == Some(rotation.old_route_id) {
+                mailbox.route_id = Some(rotation.new_route_id);
+                mailbox.needs_publish = true;
+            }
+        }
+        if let Some(rssd) = inner.content.get_detail_mut(&rotation.old_route_id) {
+            rssd.set_rotation_policy(None);
+        }
+        inner
+            .retired_routes
+            .insert(rotation.old_route_id, cur_ts + ROTATED_ROUTE_GRACE_PERIOD);
+        inner.cache.add_rotated_route(rotation);
+    }
+
+    /// Release retired routes whose grace period is over
+    /// Returns the routes that were released
+    pub fn release_retired_routes(&self, cur_ts: Timestamp) -> Vec<RouteId> {
+        let expired: Vec<RouteId> = {
+            let inner = &mut *self.inner.lock();
+            let expired = inner
+                .retired_routes
+                .iter()
+                .filter_map(|(k, v)| (*v <= cur_ts).then_some(*k))
+                .collect::<Vec<_>>();
+            for k in &expired {
+                inner.retired_routes.remove(k);
+            }
+            expired
+        };
+
+        // Routes that already died during their grace period are skipped
+        expired
+            .into_iter()
+            .filter(|id| self.release_route(*id))
+            .collect()
+    }
+
+    //////////////////////////////////////////////////////////////////////
+
+    /// Bind a DHT record subkey to an allocated route so it is kept pointing at a live route
+    pub fn add_mailbox(&self, mailbox: Mailbox) -> VeilidAPIResult<()> {
+        let inner = &mut *self.inner.lock();
+        let mailbox_key = (mailbox.key, mailbox.subkey);
+        if inner.mailboxes.contains_key(&mailbox_key) {
+            apibail_invalid_argument!("publish_mailbox", "key", mailbox.key);
+        }
+        inner.mailboxes.insert(mailbox_key, mailbox);
+        Ok(())
+    }
+
+    /// Unbind a DHT record subkey from its route, returning the mailbox if it existed
+    pub fn remove_mailbox(&self, key: &TypedKey, subkey: ValueSubkey) -> Option<Mailbox> {
+        let inner = &mut *self.inner.lock();
+        inner.mailboxes.remove(&(*key, subkey))
+    }
+
+    /// Get the mailboxes that need a new route or need their route blob written to the DHT
+    pub fn get_mailboxes_needing_publish(&self) -> Vec<Mailbox> {
+        let inner = &*self.inner.lock();
+        inner
+            .mailboxes
+            .values()
+            .filter(|mailbox| mailbox.route_id.is_none() || mailbox.needs_publish)
+            .cloned()
+            .collect()
+    }
+
+    /// Bind a mailbox to a newly allocated route whose blob has not been written yet
+    pub fn set_mailbox_route(&self, key: &TypedKey, subkey: ValueSubkey, route_id: RouteId) {
+        let inner = &mut *self.inner.lock();
+        if let Some(mailbox) = inner.mailboxes.get_mut(&(*key, subkey)) {
+            mailbox.route_id = Some(route_id);
+            mailbox.needs_publish = true;
+        }
+    }
+
+    /// Note that a mailbox's route blob was written to the DHT
+    /// Does nothing if the mailbox has been bound to a different route since
+    pub fn set_mailbox_published(&self, key: &TypedKey, subkey: ValueSubkey, route_id: RouteId) {
+        let inner = &mut *self.inner.lock();
+        if let Some(mailbox) = inner.mailboxes.get_mut(&(*key, subkey)) {
+            if mailbox.route_id == Some(route_id) {
+                mailbox.needs_publish = false;
+            }
         }
-        None
     }
 
     //////////////////////////////////////////////////////////////////////
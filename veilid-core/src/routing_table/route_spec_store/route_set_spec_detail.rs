@@ -31,6 +31,10 @@ pub(crate) struct RouteSetSpecDetail {
     stats: RouteStats,
     /// Automatically allocated route vs manually allocated route
     automatic: bool,
+    /// Policy for replacing this route before it dies
+    /// Not serialized because all routes should be re-published when restarting
+    #[serde(skip)]
+    rotation_policy: Option<RouteRotationPolicy>,
 }
 
 impl RouteSetSpecDetail {
@@ -52,6 +56,7 @@ impl RouteSetSpecDetail {
             can_do_sequenced,
             stats: RouteStats::new(cur_ts),
             automatic,
+            rotation_policy: None,
         }
     }
     pub fn get_route_by_key(&self, key: &PublicKey) -> Option<&RouteSpecDetail> {
@@ -122,6 +127,15 @@ impl RouteSetSpecDetail {
     pub fn is_automatic(&self) -> bool {
         self.automatic
     }
+    pub fn get_rotation_policy(&self) -> Option<&RouteRotationPolicy> {
+        self.rotation_policy.as_ref()
+    }
+    pub fn set_rotation_policy(&mut self, rotation_policy: Option<RouteRotationPolicy>) {
+        self.rotation_policy = rotation_policy;
+    }
+    pub fn can_do_sequenced(&self) -> bool {
+        self.can_do_sequenced
+    }
 
     /// Generate a key for the cache that can be used to uniquely identify this route's contents
     pub fn make_cache_key(&self, rti: &RoutingTableInner) -> Vec<u8> {
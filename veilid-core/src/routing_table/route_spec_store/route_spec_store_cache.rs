@@ -38,6 +38,8 @@ pub(super) struct RouteSpecStoreCache {
     dead_routes: Vec<RouteId>,
     /// List of dead remote routes
     dead_remote_routes: Vec<RouteId>,
+    /// List of allocated routes replaced by their rotation policy
+    rotated_routes: Vec<VeilidRouteRotation>,
 }
 
 impl RouteSpecStoreCache {
@@ -339,15 +341,28 @@ impl RouteSpecStoreCache {
         }
     }
 
-    /// Take the dead local and remote routes so we can update clients
-    pub fn take_dead_routes(&mut self) -> Option<(Vec<RouteId>, Vec<RouteId>)> {
-        if self.dead_routes.is_empty() && self.dead_remote_routes.is_empty() {
+    /// Note an allocated route that was replaced by its rotation policy
+    /// The replaced route is reported as rotated rather than dead
+    pub fn add_rotated_route(&mut self, rotation: VeilidRouteRotation) {
+        self.dead_routes.retain(|x| *x != rotation.old_route_id);
+        self.rotated_routes.push(rotation);
+    }
+
+    /// Take the dead local and remote routes and rotated routes so we can update clients
+    pub fn take_dead_routes(
+        &mut self,
+    ) -> Option<(Vec<RouteId>, Vec<RouteId>, Vec<VeilidRouteRotation>)> {
+        if self.dead_routes.is_empty()
+            && self.dead_remote_routes.is_empty()
+            && self.rotated_routes.is_empty()
+        {
             // Nothing to do
             return None;
         }
         let dead_routes = core::mem::take(&mut self.dead_routes);
         let dead_remote_routes = core::mem::take(&mut self.dead_remote_routes);
-        Some((dead_routes, dead_remote_routes))
+        let rotated_routes = core::mem::take(&mut self.rotated_routes);
+        Some((dead_routes, dead_remote_routes, rotated_routes))
     }
 
     /// Clean up imported remote routes
@@ -378,6 +393,7 @@ impl Default for RouteSpecStoreCache {
             compiled_route_cache: LruCache::new(COMPILED_ROUTE_CACHE_SIZE),
             dead_routes: Default::default(),
             dead_remote_routes: Default::default(),
+            rotated_routes: Default::default(),
         }
     }
 }
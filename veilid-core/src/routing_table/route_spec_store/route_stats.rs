@@ -8,6 +8,12 @@ pub(crate) struct RouteStats {
     /// Questions lost
     #[serde(skip)]
     pub questions_lost: u32,
+    /// Total messages or answers received since startup
+    #[serde(skip)]
+    pub successes: u64,
+    /// Total failed sends and lost questions since startup
+    #[serde(skip)]
+    pub failures: u64,
     /// Timestamp of when the route was created
     pub created_ts: Timestamp,
     /// Timestamp of when the route was last checked for validity
@@ -42,17 +48,20 @@ impl RouteStats {
     /// Mark a route as having failed to send
     pub fn record_send_failed(&mut self) {
         self.failed_to_send += 1;
+        self.failures += 1;
     }
 
     /// Mark a route as having lost a question
     pub fn record_question_lost(&mut self) {
         self.questions_lost += 1;
+        self.failures += 1;
     }
 
     /// Mark a route as having received something
     pub fn record_received(&mut self, cur_ts: Timestamp, bytes: ByteCount) {
         self.last_received_ts = Some(cur_ts);
         self.last_tested_ts = Some(cur_ts);
+        self.successes += 1;
         self.transfer_stats_accounting.add_down(bytes);
     }
 
@@ -94,11 +103,32 @@ impl RouteStats {
     }
 
     /// Get the transfer stats
-    #[expect(dead_code)]
     pub fn transfer_stats(&self) -> &TransferStatsDownUp {
         &self.transfer_stats_down_up
     }
 
+    /// Get the number of failures since the route last tested clean
+    pub fn recent_failures(&self) -> u32 {
+        self.failed_to_send + self.questions_lost
+    }
+
+    /// Check if a route should be replaced according to its rotation policy
+    pub fn needs_rotation(&self, cur_ts: Timestamp, policy: &RouteRotationPolicy) -> bool {
+        if let Some(max_age_ms) = policy.max_age_ms {
+            if cur_ts.saturating_sub(self.created_ts)
+                >= TimestampDuration::new(max_age_ms as u64 * 1000u64)
+            {
+                return true;
+            }
+        }
+        if let Some(max_recent_failures) = policy.max_recent_failures {
+            if self.recent_failures() >= max_recent_failures {
+                return true;
+            }
+        }
+        false
+    }
+
     /// Reset stats when network restarts
     pub fn reset(&mut self) {
         self.last_tested_ts = None;
@@ -132,3 +162,47 @@ impl RouteStats {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_needs_rotation() {
+        let start = Timestamp::new(1_000_000_000);
+        let mut stats = RouteStats::new(start);
+
+        // A policy with no limits never fires
+        let no_limits = RouteRotationPolicy {
+            max_age_ms: None,
+            max_recent_failures: None,
+            publish_key: None,
+            publish_subkey: 0,
+        };
+        assert!(!stats.needs_rotation(start + TimestampDuration::new(3_600_000_000), &no_limits));
+
+        // Fires once the route is old enough
+        let max_age = RouteRotationPolicy {
+            max_age_ms: Some(60_000),
+            ..no_limits.clone()
+        };
+        assert!(!stats.needs_rotation(start, &max_age));
+        assert!(!stats.needs_rotation(start + TimestampDuration::new(59_999_999), &max_age));
+        assert!(stats.needs_rotation(start + TimestampDuration::new(60_000_000), &max_age));
+
+        // Fires once failed sends and lost questions add up
+        let max_failures = RouteRotationPolicy {
+            max_recent_failures: Some(3),
+            ..no_limits.clone()
+        };
+        stats.record_send_failed();
+        stats.record_question_lost();
+        assert!(!stats.needs_rotation(start, &max_failures));
+        stats.record_question_lost();
+        assert!(stats.needs_rotation(start, &max_failures));
+
+        // A clean test clears recent failures
+        stats.record_tested(start);
+        assert!(!stats.needs_rotation(start, &max_failures));
+    }
+}
@@ -124,6 +124,7 @@ impl RoutingTableInner {
         self.with_routing_domain(domain, |rdd| rdd.dial_info_details().clone())
     }
 
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     pub fn first_filtered_dial_info_detail(
         &self,
         routing_domain_set: RoutingDomainSet,
@@ -4,6 +4,7 @@ use super::*;
 
 #[derive(Debug)]
 enum RoutingDomainChangeLocalNetwork {
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     SetLocalNetworks {
         local_networks: Vec<(IpAddr, IpAddr)>,
     },
@@ -23,6 +24,7 @@ impl RoutingDomainEditorLocalNetwork {
         }
     }
 
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     pub fn set_local_networks(&mut self, local_networks: Vec<(IpAddr, IpAddr)>) -> &mut Self {
         self.changes
             .push(RoutingDomainChangeLocalNetwork::SetLocalNetworks { local_networks });
@@ -1,4 +1,4 @@
-use crate::*;
+use super::*;
 use alloc::collections::VecDeque;
 
 // Latency entry is per round-trip packet (ping or data)
@@ -11,6 +11,12 @@ const ROLLING_LATENCIES_SIZE: usize = 10;
 const ROLLING_TRANSFERS_SIZE: usize = 10;
 pub const ROLLING_TRANSFERS_INTERVAL_SECS: u32 = 1;
 
+// Rate limit violations are counted over this window when deciding to punish a peer
+const RATE_LIMIT_VIOLATION_WINDOW_US: u64 = 60_000_000;
+
+// Tokens are kept in units of one operation per minute-microsecond so refills are exact
+const RATE_LIMIT_TOKEN_UNIT: u64 = 60_000_000;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct TransferCount {
     down: ByteCount,
@@ -112,3 +118,219 @@ impl LatencyStatsAccounting {
         ls
     }
 }
+
+#[derive(Debug, Clone)]
+struct RateLimitBucket {
+    tokens: u64,
+    last_ts: Timestamp,
+    limited: u32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitAccounting {
+    buckets: BTreeMap<&'static str, RateLimitBucket>,
+    violation_window_start: Timestamp,
+    violations: u32,
+}
+
+impl RateLimitAccounting {
+    pub fn new() -> Self {
+        Self {
+            buckets: BTreeMap::new(),
+            violation_window_start: Timestamp::default(),
+            violations: 0,
+        }
+    }
+
+    /// Take a token for an operation from its bucket, refilling the bucket first
+    /// Returns the number of violations in the current window if the operation is over its limit
+    pub fn check(
+        &mut self,
+        operation: &'static str,
+        capability: Capability,
+        limit: &VeilidConfigRPCRateLimit,
+        cur_ts: Timestamp,
+        rate_limit_stats: &mut Vec<RateLimitStats>,
+    ) -> Option<u32> {
+        if limit.rate_per_min == 0 {
+            return None;
+        }
+
+        let capacity = (limit.burst as u64) * RATE_LIMIT_TOKEN_UNIT;
+        let bucket = self
+            .buckets
+            .entry(operation)
+            .or_insert_with(|| RateLimitBucket {
+                tokens: capacity,
+                last_ts: cur_ts,
+                limited: 0,
+            });
+
+        let elapsed = cur_ts.saturating_sub(bucket.last_ts).as_u64();
+        bucket.tokens = bucket
+            .tokens
+            .saturating_add(elapsed.saturating_mul(limit.rate_per_min as u64))
+            .min(capacity);
+        bucket.last_ts = cur_ts;
+
+        let allowed = bucket.tokens >= RATE_LIMIT_TOKEN_UNIT;
+        if allowed {
+            bucket.tokens -= RATE_LIMIT_TOKEN_UNIT;
+        } else {
+            bucket.limited += 1;
+        }
+
+        let stats = RateLimitStats {
+            operation: operation.to_owned(),
+            capability,
+            available: (bucket.tokens / RATE_LIMIT_TOKEN_UNIT) as u32,
+            burst: limit.burst,
+            limited: bucket.limited,
+        };
+        match rate_limit_stats
+            .iter_mut()
+            .find(|x| x.operation == operation)
+        {
+            Some(x) => *x = stats,
+            None => rate_limit_stats.push(stats),
+        }
+
+        if allowed {
+            return None;
+        }
+
+        if cur_ts.saturating_sub(self.violation_window_start).as_u64()
+            > RATE_LIMIT_VIOLATION_WINDOW_US
+        {
+            self.violation_window_start = cur_ts;
+            self.violations = 0;
+        }
+        self.violations += 1;
+        Some(self.violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limit_accounting() {
+        let mut accounting = RateLimitAccounting::new();
+        let mut stats = Vec::new();
+        // One operation per second, bursting to two
+        let limit = VeilidConfigRPCRateLimit::new(60, 2);
+        let start = Timestamp::new(1_000_000_000);
+
+        assert_eq!(
+            accounting.check("SetValueQ", CAP_DHT, &limit, start, &mut stats),
+            None
+        );
+        assert_eq!(
+            accounting.check("SetValueQ", CAP_DHT, &limit, start, &mut stats),
+            None
+        );
+        assert_eq!(
+            accounting.check("SetValueQ", CAP_DHT, &limit, start, &mut stats),
+            Some(1)
+        );
+        // Other operation kinds have their own bucket
+        assert_eq!(
+            accounting.check("GetValueQ", CAP_DHT, &limit, start, &mut stats),
+            None
+        );
+
+        // Refills exactly, even across many small steps
+        let mut ts = start;
+        let mut violations = 1;
+        for _ in 0..999 {
+            ts += TimestampDuration::new(1_000);
+            violations += 1;
+            assert_eq!(
+                accounting.check("SetValueQ", CAP_DHT, &limit, ts, &mut stats),
+                Some(violations)
+            );
+        }
+        ts += TimestampDuration::new(1_000);
+        assert_eq!(
+            accounting.check("SetValueQ", CAP_DHT, &limit, ts, &mut stats),
+            None
+        );
+
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].operation, "SetValueQ");
+        assert_eq!(stats[0].available, 0);
+        assert_eq!(stats[0].burst, 2);
+        assert_eq!(stats[0].limited, 1000);
+        assert_eq!(stats[1].operation, "GetValueQ");
+        assert_eq!(stats[1].available, 1);
+
+        // Unlimited operations are never counted
+        let unlimited = VeilidConfigRPCRateLimit::new(0, 0);
+        assert_eq!(
+            accounting.check("AppCallQ", CAP_APPMESSAGE, &unlimited, ts, &mut stats),
+            None
+        );
+        assert_eq!(stats.len(), 2);
+    }
+
+    #[test]
+    fn test_rate_limit_escalation() {
+        let mut accounting = RateLimitAccounting::new();
+        let mut stats = Vec::new();
+        let rate_limits = VeilidConfigRPCRateLimits {
+            punish_violation_count: 4,
+            ..Default::default()
+        };
+        // One operation per minute, with no burst
+        let limit = VeilidConfigRPCRateLimit::new(1, 1);
+        let start = Timestamp::new(1_000_000_000);
+
+        for operation in ["AppCallQ", "AppMessage"] {
+            assert_eq!(
+                accounting.check(operation, CAP_APPMESSAGE, &limit, start, &mut stats),
+                None
+            );
+        }
+
+        // Violations of any operation kind add up to the punishment threshold
+        let ts = start + TimestampDuration::new(1_000_000);
+        let mut violations = vec![];
+        for operation in ["AppCallQ", "AppMessage", "AppCallQ", "AppMessage"] {
+            violations.push(
+                accounting
+                    .check(operation, CAP_APPMESSAGE, &limit, ts, &mut stats)
+                    .expect("should be limited"),
+            );
+        }
+        assert_eq!(violations, vec![1, 2, 3, 4]);
+        assert_eq!(
+            violations
+                .into_iter()
+                .map(|v| rate_limits.should_punish(v))
+                .collect::<Vec<_>>(),
+            vec![false, false, false, true]
+        );
+
+        // Violations are forgotten once the window has passed
+        let ts = ts + TimestampDuration::new(RATE_LIMIT_VIOLATION_WINDOW_US + 1);
+        for operation in ["AppCallQ", "AppMessage"] {
+            assert_eq!(
+                accounting.check(operation, CAP_APPMESSAGE, &limit, ts, &mut stats),
+                None
+            );
+        }
+        let v = accounting
+            .check("AppCallQ", CAP_APPMESSAGE, &limit, ts, &mut stats)
+            .expect("should be limited");
+        assert_eq!(v, 1);
+        assert!(!rate_limits.should_punish(v));
+
+        // Punishment can be turned off entirely
+        let no_punish = VeilidConfigRPCRateLimits {
+            punish_violation_count: 0,
+            ..Default::default()
+        };
+        assert!(!no_punish.should_punish(u32::MAX));
+    }
+}
@@ -4,6 +4,7 @@ use futures_util::stream::{FuturesUnordered, StreamExt};
 use stop_token::future::FutureExt as StopFutureExt;
 
 pub const BOOTSTRAP_TXT_VERSION_0: u8 = 0;
+pub const BOOTSTRAP_TXT_VERSION_1: u8 = 1;
 pub const MIN_BOOTSTRAP_PEERS: usize = 4;
 
 #[derive(Clone, Debug)]
@@ -13,6 +14,9 @@ pub struct BootstrapRecord {
     dial_info_details: Vec<DialInfoDetail>,
 }
 impl BootstrapRecord {
+    pub fn node_ids(&self) -> &TypedKeyGroup {
+        &self.node_ids
+    }
     pub fn merge(&mut self, other: BootstrapRecord) {
         self.node_ids.add_all(&other.node_ids);
         for x in other.envelope_support {
@@ -115,12 +119,70 @@ impl RoutingTable {
         }))
     }
 
+    /// Process bootstrap version 1
+    pub(crate) async fn process_bootstrap_records_v1(
+        &self,
+        record: &str,
+        bootstrap_keys: &[TypedKey],
+    ) -> EyreResult<Option<BootstrapRecord>> {
+        // Bootstrap TXT Record Format Version 1:
+        // txt_version|envelope_support|node_ids|hostname|dialinfoshort*|signature
+        //
+        // Same as version 0 with a typed signature appended, made by a trusted bootstrap key
+        // over the bytes of the record preceding the final '|'. Example:
+        // 1|0|VLD0:7lxDEabK_qgjbe38RtBa3IZLrud84P6NhGP-pRTZzdQ|bootstrap-1.dev.veilid.net|T5150,U5150,W5150/ws|VLD0:<signature>
+
+        let Some((signed_data, signature_str)) = record.rsplit_once('|') else {
+            bail!("missing signature in bootstrap v1 txt record");
+        };
+        let signature = TypedSignature::from_str(signature_str.trim())
+            .wrap_err("invalid signature in bootstrap v1 txt record")?;
+
+        // Verify the signature against our trusted bootstrap keys
+        let crypto = self.crypto();
+        let mut verified = false;
+        for bootstrap_key in bootstrap_keys {
+            if bootstrap_key.kind != signature.kind {
+                continue;
+            }
+            let Some(vcrypto) = crypto.get(bootstrap_key.kind) else {
+                continue;
+            };
+            if vcrypto.verify(
+                &bootstrap_key.value,
+                signed_data.as_bytes(),
+                &signature.value,
+            )? {
+                verified = true;
+                break;
+            }
+        }
+        if !verified {
+            bail!("bootstrap v1 txt record is not signed by a trusted bootstrap key");
+        }
+
+        // The remaining fields are the same as version 0
+        let records: Vec<String> = signed_data
+            .split('|')
+            .map(|x| x.trim().to_owned())
+            .collect();
+        self.process_bootstrap_records_v0(records).await
+    }
+
     // Bootstrap lookup process
     #[instrument(level = "trace", skip(self), ret, err)]
     pub(crate) async fn resolve_bootstrap(
         &self,
         bootstrap: Vec<String>,
     ) -> EyreResult<Vec<BootstrapRecord>> {
+        let (bootstrap_keys, bootstrap_allow_unsigned) = self.unlocked_inner.with_config(|c| {
+            (
+                c.network.routing_table.bootstrap_keys.clone(),
+                c.network.routing_table.bootstrap_allow_unsigned,
+            )
+        });
+        let bootstrap_keys = &bootstrap_keys;
+
         // Resolve from bootstrap root to bootstrap hostnames
         let mut bsnames = Vec::<String>::new();
         for bh in bootstrap {
@@ -170,8 +232,8 @@ impl RoutingTable {
                         }
                         Ok(v) => v,
                     };
-                    // for each record resolve into key/bootstraprecord pairs
-                    let mut bootstrap_records: Vec<BootstrapRecord> = Vec::new();
+                    // for each record resolve into key/bootstraprecord pairs, noting which were signed
+                    let mut bootstrap_records: Vec<(bool, BootstrapRecord)> = Vec::new();
                     for bsnirecord in bsnirecords {
                         // All formats split on '|' character
                         let records: Vec<String> = bsnirecord
@@ -201,7 +263,26 @@ impl RoutingTable {
                                         );
                                         continue;
                                     }
-                                    Ok(Some(v)) => v,
+                                    Ok(Some(v)) => (false, v),
+                                    Ok(None) => {
+                                        // skipping
+                                        continue;
+                                    }
+                                }
+                            }
+                            BOOTSTRAP_TXT_VERSION_1 => {
+                                match self
+                                    .process_bootstrap_records_v1(bsnirecord.trim(), bootstrap_keys)
+                                    .await
+                                {
+                                    Err(e) => {
+                                        log_rtab!(error
+                                            "couldn't process v1 bootstrap records from {}: {}",
+                                            bsname, e
+                                        );
+                                        continue;
+                                    }
+                                    Ok(Some(v)) => (true, v),
                                     Ok(None) => {
                                         // skipping
                                         continue;
@@ -222,27 +303,41 @@ impl RoutingTable {
             );
         }
 
-        let mut merged_bootstrap_records: Vec<BootstrapRecord> = Vec::new();
+        let mut all_bootstrap_records: Vec<(bool, BootstrapRecord)> = Vec::new();
         while let Some(bootstrap_records) = unord.next().await {
             let Some(bootstrap_records) = bootstrap_records else {
                 continue;
             };
-            for mut bsrec in bootstrap_records {
-                let mut mbi = 0;
-                while mbi < merged_bootstrap_records.len() {
-                    let mbr = &mut merged_bootstrap_records[mbi];
-                    if mbr.node_ids.contains_any(&bsrec.node_ids) {
-                        // Merge record, pop this one out
-                        let mbr = merged_bootstrap_records.remove(mbi);
-                        bsrec.merge(mbr);
-                    } else {
-                        // No overlap, go to next record
-                        mbi += 1;
-                    }
+            all_bootstrap_records.extend(bootstrap_records);
+        }
+
+        // Use signed records if we have any, and only fall back to unsigned records if allowed
+        let have_signed = all_bootstrap_records.iter().any(|(signed, _)| *signed);
+        if !have_signed && !bootstrap_allow_unsigned && !all_bootstrap_records.is_empty() {
+            log_rtab!(warn "ignoring unsigned bootstrap txt records");
+        }
+        let bootstrap_records = all_bootstrap_records
+            .into_iter()
+            .filter_map(|(signed, bsrec)| {
+                (signed || (!have_signed && bootstrap_allow_unsigned)).then_some(bsrec)
+            });
+
+        let mut merged_bootstrap_records: Vec<BootstrapRecord> = Vec::new();
+        for mut bsrec in bootstrap_records {
+            let mut mbi = 0;
+            while mbi < merged_bootstrap_records.len() {
+                let mbr = &mut merged_bootstrap_records[mbi];
+                if mbr.node_ids.contains_any(&bsrec.node_ids) {
+                    // Merge record, pop this one out
+                    let mbr = merged_bootstrap_records.remove(mbi);
+                    bsrec.merge(mbr);
+                } else {
+                    // No overlap, go to next record
+                    mbi += 1;
                 }
-                // Append merged record
-                merged_bootstrap_records.push(bsrec);
             }
+            // Append merged record
+            merged_bootstrap_records.push(bsrec);
         }
 
         // ensure dial infos are sorted
@@ -377,8 +472,29 @@ impl RoutingTable {
         log_rtab!(debug "--- bootstrap_task");
 
         // See if we are specifying a direct dialinfo for bootstrap, if so use the direct mechanism
+        // Bootstrap servers are specified with a pinned node id as '<node_id>@<url>'
         let mut bootstrap_dialinfos = Vec::<DialInfo>::new();
+        let mut bootstrap_servers = Vec::<(TypedKey, DialInfo)>::new();
         for b in &bootstrap {
+            if let Some((node_id_str, url)) = b.split_once('@') {
+                let node_id = match TypedKey::from_str(node_id_str.trim()) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        log_rtab!(warn "invalid bootstrap server node id in '{}': {}", b, e);
+                        continue;
+                    }
+                };
+                // Skip ourselves, in case we are a bootstrap server
+                if self.unlocked_inner.matches_own_node_id(&[node_id]) {
+                    continue;
+                }
+                if let Ok(bootstrap_di_vec) = DialInfo::try_vec_from_url(url.trim()) {
+                    for bootstrap_di in bootstrap_di_vec {
+                        bootstrap_servers.push((node_id, bootstrap_di));
+                    }
+                }
+                continue;
+            }
             if let Ok(bootstrap_di_vec) = DialInfo::try_vec_from_url(b) {
                 for bootstrap_di in bootstrap_di_vec {
                     bootstrap_dialinfos.push(bootstrap_di);
@@ -387,7 +503,7 @@ impl RoutingTable {
         }
 
         // Get a peer list from bootstrap to process
-        let peers = if !bootstrap_dialinfos.is_empty() {
+        let peers = if !bootstrap_dialinfos.is_empty() || !bootstrap_servers.is_empty() {
             // Direct bootstrap
             let network_manager = self.network_manager();
 
@@ -401,6 +517,24 @@ impl RoutingTable {
                     }
                 }
             }
+            for (bootstrap_node_id, bootstrap_di) in bootstrap_servers {
+                log_rtab!(debug "signed bootstrap with: {}@{}", bootstrap_node_id, bootstrap_di);
+                let peers = match network_manager
+                    .signed_boot_request(bootstrap_node_id, bootstrap_di.clone())
+                    .await
+                {
+                    Ok(v) => v,
+                    Err(e) => {
+                        log_rtab!(warn "signed bootstrap with {} failed: {}", bootstrap_di, e);
+                        continue;
+                    }
+                };
+                for peer in peers {
+                    if !peer_map.contains_key(peer.node_ids()) {
+                        peer_map.insert(peer.node_ids().clone(), peer);
+                    }
+                }
+            }
             peer_map.into_values().collect()
         } else {
             // If not direct, resolve bootstrap servers and recurse their TXT entries
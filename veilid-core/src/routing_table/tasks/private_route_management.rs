@@ -165,6 +165,138 @@ impl RoutingTable {
         Ok(())
     }
 
+    /// Replace published routes whose rotation policy says they are about to die
+    /// If the policy has a publish key, the new route blob is written to the DHT
+    /// Replaced routes are released once their grace period is over
+    #[instrument(level = "trace", skip(self))]
+    pub(crate) async fn rotate_routes(&self, cur_ts: Timestamp) {
+        let rss = self.route_spec_store();
+        for r in rss.release_retired_routes(cur_ts) {
+            log_rtab!(debug "Released rotated route: {}", r);
+        }
+
+        let routes_to_rotate = rss.list_allocated_routes(|k, v| {
+            let policy = v.get_rotation_policy()?;
+            if v.is_published() && v.get_stats().needs_rotation(cur_ts, policy) {
+                Some((*k, policy.clone()))
+            } else {
+                None
+            }
+        });
+
+        for (old_route_id, policy) in routes_to_rotate {
+            let (new_route_id, blob) = match rss.rotate_route(old_route_id).await {
+                Ok(v) => v,
+                Err(VeilidAPIError::TryAgain { message }) => {
+                    log_rtab!(debug "Route rotation unavailable: {}", message);
+                    continue;
+                }
+                Err(e) => {
+                    log_rtab!(error "Route rotation failed: {}", e);
+                    continue;
+                }
+            };
+            log_rtab!(debug "Rotated route {} to {}", old_route_id, new_route_id);
+
+            // If the new route can't be published, keep the old one and try again next time
+            if let Some(publish_key) = policy.publish_key {
+                match self
+                    .network_manager()
+                    .storage_manager()
+                    .set_value(publish_key, policy.publish_subkey, blob.clone(), None)
+                    .await
+                {
+                    Ok(None) => {}
+                    Ok(Some(_)) => {
+                        log_rtab!(debug "Publishing rotated route lost to a newer value");
+                        rss.release_route(new_route_id);
+                        continue;
+                    }
+                    Err(e) => {
+                        log_rtab!(error "Publishing rotated route failed: {}", e);
+                        rss.release_route(new_route_id);
+                        continue;
+                    }
+                }
+            }
+
+            rss.retire_rotated_route(
+                cur_ts,
+                VeilidRouteRotation {
+                    old_route_id,
+                    new_route_id,
+                    blob,
+                },
+            );
+        }
+    }
+
+    /// Keep mailboxes pointing at live routes
+    /// Mailboxes whose route died get a new route, and changed route blobs are written to the DHT
+    /// Mailboxes whose blob could not be written are tried again next time
+    #[instrument(level = "trace", skip(self))]
+    pub(crate) async fn publish_mailboxes(&self) {
+        let default_route_hop_count =
+            self.with_config(|c| c.network.rpc.default_route_hop_count as usize);
+        let rss = self.route_spec_store();
+        for mailbox in rss.get_mailboxes_needing_publish() {
+            let (route_id, blob) = match mailbox.route_id {
+                Some(route_id) => match rss.private_route_blob(&route_id) {
+                    Ok(blob) => (route_id, blob),
+                    Err(e) => {
+                        log_rtab!(debug "Mailbox route blob unavailable: {}", e);
+                        continue;
+                    }
+                },
+                None => {
+                    match rss
+                        .allocate_published_route(
+                            &VALID_CRYPTO_KINDS,
+                            mailbox.stability,
+                            mailbox.sequencing,
+                            default_route_hop_count,
+                            DirectionSet::all(),
+                            &[],
+                            mailbox.rotation_policy.clone(),
+                        )
+                        .await
+                    {
+                        Ok((route_id, blob)) => {
+                            rss.set_mailbox_route(&mailbox.key, mailbox.subkey, route_id);
+                            (route_id, blob)
+                        }
+                        Err(VeilidAPIError::TryAgain { message }) => {
+                            log_rtab!(debug "Mailbox route allocation unavailable: {}", message);
+                            continue;
+                        }
+                        Err(e) => {
+                            log_rtab!(error "Mailbox route allocation failed: {}", e);
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            match self
+                .network_manager()
+                .storage_manager()
+                .set_value(mailbox.key, mailbox.subkey, blob, None)
+                .await
+            {
+                Ok(None) => {
+                    log_rtab!(debug "Published mailbox {}:{} route {}", mailbox.key, mailbox.subkey, route_id);
+                    rss.set_mailbox_published(&mailbox.key, mailbox.subkey, route_id);
+                }
+                Ok(Some(_)) => {
+                    log_rtab!(debug "Publishing mailbox route lost to a newer value");
+                }
+                Err(e) => {
+                    log_rtab!(error "Publishing mailbox route failed: {}", e);
+                }
+            }
+        }
+    }
+
     /// Keep private routes assigned and accessible
     #[instrument(level = "trace", skip(self, stop_token), err)]
     pub(crate) async fn private_route_management_task_routine(
@@ -173,6 +305,9 @@ impl RoutingTable {
         _last_ts: Timestamp,
         cur_ts: Timestamp,
     ) -> EyreResult<()> {
+        // Replace published routes that are due for rotation before they are tested
+        self.rotate_routes(cur_ts).await;
+
         // Test locally allocated routes first
         // This may remove dead routes
         let routes_needing_testing = self.get_allocated_routes_to_test(cur_ts);
@@ -246,6 +381,9 @@ impl RoutingTable {
                 .await?;
         }
 
+        // Replace and republish mailbox routes that died or rotated
+        self.publish_mailboxes().await;
+
         // Send update (also may send updates for released routes done by other parts of the program)
         rss.send_route_update();
 
@@ -217,7 +217,7 @@ impl RoutingTable {
                 return false;
             }
 
-            // For all protocol types we could connect to the relay by, ensure the relay supports all address types
+            // For all protocol types we could connect to the relay by, ensure the relay supports all of our address types
             let mut address_type_mappings = HashMap::<ProtocolType, AddressTypeSet>::new();
             let dids = node_info.dial_info_detail_list();
             for did in dids {
@@ -230,7 +230,7 @@ impl RoutingTable {
             }
             for pt in outbound_dif.protocol_type_set.iter() {
                 if let Some(ats) = address_type_mappings.get(&pt) {
-                    if *ats != AddressTypeSet::all() {
+                    if !ats.is_superset(outbound_dif.address_type_set) {
                         return false;
                     }
                 }
@@ -1,5 +1,6 @@
 use super::*;
 
+pub mod test_bootstrap;
 pub mod test_serialize_routing_table;
 
 pub(crate) fn mock_routing_table() -> routing_table::RoutingTable {
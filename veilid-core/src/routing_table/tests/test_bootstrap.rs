@@ -0,0 +1,99 @@
+use super::*;
+
+const TEST_BOOTSTRAP_RECORD_V0: &str =
+    "0|0|VLD0:7lxDEabK_qgjbe38RtBa3IZLrud84P6NhGP-pRTZzdQ|127.0.0.1|T5150,U5150";
+
+fn make_bootstrap_record_v1(routing_table: &RoutingTable, keypair: &KeyPair, data: &str) -> String {
+    let vcrypto = routing_table.crypto().get(CRYPTO_KIND_VLD0).unwrap();
+    let signature = vcrypto
+        .sign(&keypair.key, &keypair.secret, data.as_bytes())
+        .unwrap();
+    format!(
+        "{}|{}",
+        data,
+        TypedSignature::new(CRYPTO_KIND_VLD0, signature)
+    )
+}
+
+pub async fn test_bootstrap_v1_good_signature() {
+    let routing_table = mock_routing_table();
+    let vcrypto = routing_table.crypto().get(CRYPTO_KIND_VLD0).unwrap();
+    let keypair = vcrypto.generate_keypair();
+    let bootstrap_keys = [TypedKey::new(CRYPTO_KIND_VLD0, keypair.key)];
+
+    let data = TEST_BOOTSTRAP_RECORD_V0.replacen('0', "1", 1);
+    let record = make_bootstrap_record_v1(&routing_table, &keypair, &data);
+
+    let bsrec = routing_table
+        .process_bootstrap_records_v1(&record, &bootstrap_keys)
+        .await
+        .expect("should verify")
+        .expect("should not be skipped");
+    assert!(bsrec.node_ids().contains(
+        &TypedKey::from_str("VLD0:7lxDEabK_qgjbe38RtBa3IZLrud84P6NhGP-pRTZzdQ").unwrap()
+    ));
+}
+
+pub async fn test_bootstrap_v1_wrong_key() {
+    let routing_table = mock_routing_table();
+    let vcrypto = routing_table.crypto().get(CRYPTO_KIND_VLD0).unwrap();
+    let keypair = vcrypto.generate_keypair();
+    let other_keypair = vcrypto.generate_keypair();
+    let bootstrap_keys = [TypedKey::new(CRYPTO_KIND_VLD0, other_keypair.key)];
+
+    let data = TEST_BOOTSTRAP_RECORD_V0.replacen('0', "1", 1);
+    let record = make_bootstrap_record_v1(&routing_table, &keypair, &data);
+
+    assert_err!(
+        routing_table
+            .process_bootstrap_records_v1(&record, &bootstrap_keys)
+            .await
+    );
+
+    // No trusted keys at all must not verify anything either
+    assert_err!(
+        routing_table
+            .process_bootstrap_records_v1(&record, &[])
+            .await
+    );
+}
+
+pub async fn test_bootstrap_v1_tampered() {
+    let routing_table = mock_routing_table();
+    let vcrypto = routing_table.crypto().get(CRYPTO_KIND_VLD0).unwrap();
+    let keypair = vcrypto.generate_keypair();
+    let bootstrap_keys = [TypedKey::new(CRYPTO_KIND_VLD0, keypair.key)];
+
+    let data = TEST_BOOTSTRAP_RECORD_V0.replacen('0', "1", 1);
+    let record = make_bootstrap_record_v1(&routing_table, &keypair, &data);
+
+    // Redirect the bootstrap node to another address
+    let tampered = record.replace("127.0.0.1", "127.0.0.2");
+    assert_ne!(tampered, record);
+    assert_err!(
+        routing_table
+            .process_bootstrap_records_v1(&tampered, &bootstrap_keys)
+            .await
+    );
+
+    // Add a dial info
+    let tampered = record.replace("T5150,U5150", "T5150,U5150,W5150/ws");
+    assert_err!(
+        routing_table
+            .process_bootstrap_records_v1(&tampered, &bootstrap_keys)
+            .await
+    );
+
+    // Drop the signature
+    assert_err!(
+        routing_table
+            .process_bootstrap_records_v1(&data, &bootstrap_keys)
+            .await
+    );
+}
+
+pub async fn test_all() {
+    test_bootstrap_v1_good_signature().await;
+    test_bootstrap_v1_wrong_key().await;
+    test_bootstrap_v1_tampered().await;
+}
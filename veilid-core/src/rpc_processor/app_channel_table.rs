@@ -0,0 +1,131 @@
+use super::*;
+
+struct AppChannelRegistration {
+    options: AppChannelOptions,
+    handler: Option<UpdateCallback>,
+}
+
+#[derive(Default)]
+struct AppChannelTableInner {
+    channels: HashMap<AppChannel, AppChannelRegistration>,
+    reject_unknown: bool,
+}
+
+/// Application channels registered by the hosting application
+/// Kept by the network manager so registrations outlive the rpc processor across attachments
+#[derive(Clone, Default)]
+pub(crate) struct AppChannelTable {
+    inner: Arc<Mutex<AppChannelTableInner>>,
+}
+
+impl fmt::Debug for AppChannelTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inner = self.inner.lock();
+        f.debug_struct("AppChannelTable")
+            .field("channels", &inner.channels.keys().collect::<Vec<_>>())
+            .field("reject_unknown", &inner.reject_unknown)
+            .finish()
+    }
+}
+
+impl AppChannelTable {
+    pub fn register(
+        &self,
+        channel: AppChannel,
+        options: AppChannelOptions,
+        handler: Option<UpdateCallback>,
+    ) {
+        self.inner
+            .lock()
+            .channels
+            .insert(channel, AppChannelRegistration { options, handler });
+    }
+
+    pub fn unregister(&self, channel: &AppChannel) -> bool {
+        self.inner.lock().channels.remove(channel).is_some()
+    }
+
+    pub fn set_reject_unknown(&self, reject_unknown: bool) {
+        self.inner.lock().reject_unknown = reject_unknown;
+    }
+
+    /// Decide if a message on a channel should be delivered
+    /// Returns the channel's own handler if it has one, or an error describing why the message was rejected
+    pub fn check(
+        &self,
+        channel: Option<&AppChannel>,
+        message_len: usize,
+    ) -> Result<Option<UpdateCallback>, String> {
+        let Some(channel) = channel else {
+            return Ok(None);
+        };
+        let inner = self.inner.lock();
+        let Some(registration) = inner.channels.get(channel) else {
+            if inner.reject_unknown {
+                return Err(format!("unknown app channel: {}", channel));
+            }
+            return Ok(None);
+        };
+        if let Some(max_message_size) = registration.options.max_message_size {
+            if message_len > max_message_size as usize {
+                return Err(format!(
+                    "message too large for app channel {}: {} > {}",
+                    channel, message_len, max_message_size
+                ));
+            }
+        }
+        Ok(registration.handler.clone())
+    }
+}
+
+impl RPCProcessor {
+    /// Check an incoming app message or app call against the registered channels
+    /// Returns the update callback to deliver it with, or an error describing why it was rejected
+    pub(super) fn app_channel_update_callback(
+        &self,
+        channel: Option<&AppChannel>,
+        message_len: usize,
+    ) -> Result<UpdateCallback, String> {
+        let opt_handler = self
+            .network_manager
+            .app_channel_table()
+            .check(channel, message_len)?;
+        Ok(opt_handler.unwrap_or_else(|| self.unlocked_inner.update_callback.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channel(s: &str) -> AppChannel {
+        AppChannel::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_app_channel_table() {
+        let table = AppChannelTable::default();
+        table.register(
+            channel("CHAT"),
+            AppChannelOptions {
+                max_message_size: Some(16),
+            },
+            None,
+        );
+
+        // Messages without a channel and on unknown channels are delivered by default
+        assert!(table.check(None, 100).is_ok());
+        assert!(table.check(Some(&channel("FILE")), 100).is_ok());
+        assert!(table.check(Some(&channel("CHAT")), 16).is_ok());
+        assert!(table.check(Some(&channel("CHAT")), 17).is_err());
+
+        table.set_reject_unknown(true);
+        assert!(table.check(None, 100).is_ok());
+        assert!(table.check(Some(&channel("FILE")), 100).is_err());
+        assert!(table.check(Some(&channel("CHAT")), 16).is_ok());
+
+        assert!(table.unregister(&channel("CHAT")));
+        assert!(!table.unregister(&channel("CHAT")));
+        assert!(table.check(Some(&channel("CHAT")), 16).is_err());
+    }
+}
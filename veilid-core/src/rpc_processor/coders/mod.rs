@@ -22,12 +22,18 @@ mod signed_relayed_node_info;
 mod signed_value_data;
 mod signed_value_descriptor;
 mod socket_address;
+mod subkey_value;
 #[cfg(feature = "unstable-tunnels")]
 mod tunnel;
 mod typed_key;
 mod typed_signature;
+mod value_subkey_range_set;
 
-pub(crate) use operations::MAX_INSPECT_VALUE_A_SEQS_LEN;
+pub(crate) use operations::{
+    subkey_values_data_len, MAX_GET_VALUES_A_DATA_LEN, MAX_GET_VALUES_Q_SUBKEYS_LEN,
+    MAX_INSPECT_VALUE_A_SEQS_LEN, MAX_SET_VALUES_A_DATA_LEN, MAX_SET_VALUES_Q_DATA_LEN,
+    MAX_SET_VALUES_Q_VALUES_LEN,
+};
 pub(in crate::rpc_processor) use operations::*;
 
 pub(crate) use address::*;
@@ -53,10 +59,12 @@ pub use signed_relayed_node_info::*;
 pub use signed_value_data::*;
 pub use signed_value_descriptor::*;
 pub use socket_address::*;
+pub use subkey_value::*;
 #[cfg(feature = "unstable-tunnels")]
 pub use tunnel::*;
 pub use typed_key::*;
 pub use typed_signature::*;
+pub use value_subkey_range_set::*;
 
 use super::*;
 
@@ -66,6 +74,8 @@ pub(in crate::rpc_processor) enum QuestionContext {
     GetValue(ValidateGetValueContext),
     SetValue(ValidateSetValueContext),
     InspectValue(ValidateInspectValueContext),
+    GetValues(ValidateGetValuesContext),
+    SetValues(ValidateSetValuesContext),
 }
 
 #[derive(Clone)]
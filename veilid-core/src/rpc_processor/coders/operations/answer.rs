@@ -41,6 +41,8 @@ pub(in crate::rpc_processor) enum RPCAnswerDetail {
     SetValueA(Box<RPCOperationSetValueA>),
     WatchValueA(Box<RPCOperationWatchValueA>),
     InspectValueA(Box<RPCOperationInspectValueA>),
+    GetValuesA(Box<RPCOperationGetValuesA>),
+    SetValuesA(Box<RPCOperationSetValuesA>),
     #[cfg(feature = "unstable-blockstore")]
     SupplyBlockA(Box<RPCOperationSupplyBlockA>),
     #[cfg(feature = "unstable-blockstore")]
@@ -63,6 +65,8 @@ impl RPCAnswerDetail {
             RPCAnswerDetail::SetValueA(_) => "SetValueA",
             RPCAnswerDetail::WatchValueA(_) => "WatchValueA",
             RPCAnswerDetail::InspectValueA(_) => "InspectValueA",
+            RPCAnswerDetail::GetValuesA(_) => "GetValuesA",
+            RPCAnswerDetail::SetValuesA(_) => "SetValuesA",
             #[cfg(feature = "unstable-blockstore")]
             RPCAnswerDetail::SupplyBlockA(_) => "SupplyBlockA",
             #[cfg(feature = "unstable-blockstore")]
@@ -84,6 +88,8 @@ impl RPCAnswerDetail {
             RPCAnswerDetail::SetValueA(r) => r.validate(validate_context),
             RPCAnswerDetail::WatchValueA(r) => r.validate(validate_context),
             RPCAnswerDetail::InspectValueA(r) => r.validate(validate_context),
+            RPCAnswerDetail::GetValuesA(r) => r.validate(validate_context),
+            RPCAnswerDetail::SetValuesA(r) => r.validate(validate_context),
             #[cfg(feature = "unstable-blockstore")]
             RPCAnswerDetail::SupplyBlockA(r) => r.validate(validate_context),
             #[cfg(feature = "unstable-blockstore")]
@@ -137,6 +143,16 @@ impl RPCAnswerDetail {
                 let out = RPCOperationInspectValueA::decode(decode_context, &op_reader)?;
                 RPCAnswerDetail::InspectValueA(Box::new(out))
             }
+            veilid_capnp::answer::detail::GetValuesA(r) => {
+                let op_reader = r.map_err(RPCError::protocol)?;
+                let out = RPCOperationGetValuesA::decode(decode_context, &op_reader)?;
+                RPCAnswerDetail::GetValuesA(Box::new(out))
+            }
+            veilid_capnp::answer::detail::SetValuesA(r) => {
+                let op_reader = r.map_err(RPCError::protocol)?;
+                let out = RPCOperationSetValuesA::decode(decode_context, &op_reader)?;
+                RPCAnswerDetail::SetValuesA(Box::new(out))
+            }
             #[cfg(feature = "unstable-blockstore")]
             veilid_capnp::answer::detail::SupplyBlockA(r) => {
                 let op_reader = r.map_err(RPCError::protocol)?;
@@ -186,6 +202,12 @@ impl RPCAnswerDetail {
             RPCAnswerDetail::InspectValueA(d) => {
                 d.encode(&mut builder.reborrow().init_inspect_value_a())
             }
+            RPCAnswerDetail::GetValuesA(d) => {
+                d.encode(&mut builder.reborrow().init_get_values_a())
+            }
+            RPCAnswerDetail::SetValuesA(d) => {
+                d.encode(&mut builder.reborrow().init_set_values_a())
+            }
             #[cfg(feature = "unstable-blockstore")]
             RPCAnswerDetail::SupplyBlockA(d) => {
                 d.encode(&mut builder.reborrow().init_supply_block_a())
@@ -4,10 +4,12 @@ mod operation_app_call;
 mod operation_app_message;
 mod operation_find_node;
 mod operation_get_value;
+mod operation_get_values;
 mod operation_inspect_value;
 mod operation_return_receipt;
 mod operation_route;
 mod operation_set_value;
+mod operation_set_values;
 mod operation_signal;
 mod operation_status;
 
@@ -36,10 +38,12 @@ pub(in crate::rpc_processor) use operation_app_call::*;
 pub(in crate::rpc_processor) use operation_app_message::*;
 pub(in crate::rpc_processor) use operation_find_node::*;
 pub(in crate::rpc_processor) use operation_get_value::*;
+pub(in crate::rpc_processor) use operation_get_values::*;
 pub(in crate::rpc_processor) use operation_inspect_value::*;
 pub(in crate::rpc_processor) use operation_return_receipt::*;
 pub(in crate::rpc_processor) use operation_route::*;
 pub(in crate::rpc_processor) use operation_set_value::*;
+pub(in crate::rpc_processor) use operation_set_values::*;
 pub(in crate::rpc_processor) use operation_signal::*;
 pub(in crate::rpc_processor) use operation_status::*;
 pub(in crate::rpc_processor) use operation_validate_dial_info::*;
@@ -63,4 +67,9 @@ pub(in crate::rpc_processor) use operation_start_tunnel::*;
 
 use super::*;
 
+pub(crate) use operation_get_values::{MAX_GET_VALUES_A_DATA_LEN, MAX_GET_VALUES_Q_SUBKEYS_LEN};
 pub(crate) use operation_inspect_value::MAX_INSPECT_VALUE_A_SEQS_LEN;
+pub(crate) use operation_set_values::{
+    subkey_values_data_len, MAX_SET_VALUES_A_DATA_LEN, MAX_SET_VALUES_Q_DATA_LEN,
+    MAX_SET_VALUES_Q_VALUES_LEN,
+};
@@ -2,18 +2,20 @@ use super::*;
 
 const MAX_APP_CALL_Q_MESSAGE_LEN: usize = 32768;
 const MAX_APP_CALL_A_MESSAGE_LEN: usize = 32768;
+const MAX_APP_CALL_A_REFUSED_LEN: usize = 256;
 
 #[derive(Debug, Clone)]
 pub(in crate::rpc_processor) struct RPCOperationAppCallQ {
+    channel: Option<AppChannel>,
     message: Vec<u8>,
 }
 
 impl RPCOperationAppCallQ {
-    pub fn new(message: Vec<u8>) -> Result<Self, RPCError> {
+    pub fn new(channel: Option<AppChannel>, message: Vec<u8>) -> Result<Self, RPCError> {
         if message.len() > MAX_APP_CALL_Q_MESSAGE_LEN {
             return Err(RPCError::protocol("AppCallQ message too long to set"));
         }
-        Ok(Self { message })
+        Ok(Self { channel, message })
     }
     pub fn validate(&mut self, _validate_context: &RPCValidateContext) -> Result<(), RPCError> {
         Ok(())
@@ -23,8 +25,16 @@ impl RPCOperationAppCallQ {
     //     &self.message
     // }
 
-    pub fn destructure(self) -> Vec<u8> {
-        self.message
+    pub fn channel(&self) -> Option<&AppChannel> {
+        self.channel.as_ref()
+    }
+
+    pub fn message_len(&self) -> usize {
+        self.message.len()
+    }
+
+    pub fn destructure(self) -> (Option<AppChannel>, Vec<u8>) {
+        (self.channel, self.message)
     }
 
     pub fn decode(
@@ -35,7 +45,14 @@ impl RPCOperationAppCallQ {
         if mr.len() > MAX_APP_CALL_Q_MESSAGE_LEN {
             return Err(RPCError::protocol("AppCallQ message too long to set"));
         }
+        let channel = if reader.has_channel() {
+            let cr = reader.get_channel().map_err(RPCError::protocol)?;
+            Some(AppChannel::new(cr).map_err(RPCError::protocol)?)
+        } else {
+            None
+        };
         Ok(Self {
+            channel,
             message: mr.to_vec(),
         })
     }
@@ -44,6 +61,9 @@ impl RPCOperationAppCallQ {
         builder: &mut veilid_capnp::operation_app_call_q::Builder,
     ) -> Result<(), RPCError> {
         builder.set_message(&self.message);
+        if let Some(channel) = &self.channel {
+            builder.set_channel(channel.bytes());
+        }
         Ok(())
     }
 }
@@ -53,6 +73,7 @@ impl RPCOperationAppCallQ {
 #[derive(Debug, Clone)]
 pub(in crate::rpc_processor) struct RPCOperationAppCallA {
     message: Vec<u8>,
+    refused: Option<String>,
 }
 
 impl RPCOperationAppCallA {
@@ -60,7 +81,22 @@ impl RPCOperationAppCallA {
         if message.len() > MAX_APP_CALL_A_MESSAGE_LEN {
             return Err(RPCError::protocol("AppCallA message too long to set"));
         }
-        Ok(Self { message })
+        Ok(Self {
+            message,
+            refused: None,
+        })
+    }
+
+    pub fn new_refused(reason: String) -> Result<Self, RPCError> {
+        if reason.len() > MAX_APP_CALL_A_REFUSED_LEN {
+            return Err(RPCError::protocol(
+                "AppCallA refused reason too long to set",
+            ));
+        }
+        Ok(Self {
+            message: Vec::new(),
+            refused: Some(reason),
+        })
     }
 
     pub fn validate(&mut self, _validate_context: &RPCValidateContext) -> Result<(), RPCError> {
@@ -71,8 +107,8 @@ impl RPCOperationAppCallA {
     //     &self.message
     // }
 
-    pub fn destructure(self) -> Vec<u8> {
-        self.message
+    pub fn destructure(self) -> (Vec<u8>, Option<String>) {
+        (self.message, self.refused)
     }
 
     pub fn decode(
@@ -83,8 +119,20 @@ impl RPCOperationAppCallA {
         if mr.len() > MAX_APP_CALL_A_MESSAGE_LEN {
             return Err(RPCError::protocol("AppCallA message too long to set"));
         }
+        let refused = if reader.has_refused() {
+            let rr = reader.get_refused().map_err(RPCError::protocol)?;
+            if rr.len() > MAX_APP_CALL_A_REFUSED_LEN {
+                return Err(RPCError::protocol(
+                    "AppCallA refused reason too long to set",
+                ));
+            }
+            Some(rr.to_string().map_err(RPCError::protocol)?)
+        } else {
+            None
+        };
         Ok(Self {
             message: mr.to_vec(),
+            refused,
         })
     }
     pub fn encode(
@@ -92,6 +140,9 @@ impl RPCOperationAppCallA {
         builder: &mut veilid_capnp::operation_app_call_a::Builder,
     ) -> Result<(), RPCError> {
         builder.set_message(&self.message);
+        if let Some(refused) = &self.refused {
+            builder.set_refused(refused.as_str());
+        }
         Ok(())
     }
 }
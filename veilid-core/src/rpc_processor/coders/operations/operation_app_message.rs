@@ -4,15 +4,16 @@ const MAX_APP_MESSAGE_MESSAGE_LEN: usize = 32768;
 
 #[derive(Debug, Clone)]
 pub(in crate::rpc_processor) struct RPCOperationAppMessage {
+    channel: Option<AppChannel>,
     message: Vec<u8>,
 }
 
 impl RPCOperationAppMessage {
-    pub fn new(message: Vec<u8>) -> Result<Self, RPCError> {
+    pub fn new(channel: Option<AppChannel>, message: Vec<u8>) -> Result<Self, RPCError> {
         if message.len() > MAX_APP_MESSAGE_MESSAGE_LEN {
             return Err(RPCError::protocol("AppMessage message too long to set"));
         }
-        Ok(Self { message })
+        Ok(Self { channel, message })
     }
 
     pub fn validate(&mut self, _validate_context: &RPCValidateContext) -> Result<(), RPCError> {
@@ -22,8 +23,16 @@ impl RPCOperationAppMessage {
     // pub fn message(&self) -> &[u8] {
     //     &self.message
     // }
-    pub fn destructure(self) -> Vec<u8> {
-        self.message
+    pub fn channel(&self) -> Option<&AppChannel> {
+        self.channel.as_ref()
+    }
+
+    pub fn message_len(&self) -> usize {
+        self.message.len()
+    }
+
+    pub fn destructure(self) -> (Option<AppChannel>, Vec<u8>) {
+        (self.channel, self.message)
     }
 
     pub fn decode(
@@ -34,7 +43,14 @@ impl RPCOperationAppMessage {
         if mr.len() > MAX_APP_MESSAGE_MESSAGE_LEN {
             return Err(RPCError::protocol("AppMessage message too long to set"));
         }
+        let channel = if reader.has_channel() {
+            let cr = reader.get_channel().map_err(RPCError::protocol)?;
+            Some(AppChannel::new(cr).map_err(RPCError::protocol)?)
+        } else {
+            None
+        };
         Ok(Self {
+            channel,
             message: mr.to_vec(),
         })
     }
@@ -43,6 +59,9 @@ impl RPCOperationAppMessage {
         builder: &mut veilid_capnp::operation_app_message::Builder,
     ) -> Result<(), RPCError> {
         builder.set_message(&self.message);
+        if let Some(channel) = &self.channel {
+            builder.set_channel(channel.bytes());
+        }
         Ok(())
     }
 }
@@ -0,0 +1,352 @@
+use super::*;
+use crate::storage_manager::{SignedValueData, SignedValueDescriptor};
+
+const MAX_GET_VALUES_Q_SUBKEY_RANGES_LEN: usize = 64;
+pub(crate) const MAX_GET_VALUES_Q_SUBKEYS_LEN: usize = 64;
+pub(crate) const MAX_GET_VALUES_A_DATA_LEN: usize = 32768;
+const MAX_GET_VALUES_A_PEERS_LEN: usize = 20;
+
+#[derive(Clone)]
+pub(in crate::rpc_processor) struct ValidateGetValuesContext {
+    pub last_descriptor: Option<SignedValueDescriptor>,
+    pub subkeys: ValueSubkeyRangeSet,
+    pub vcrypto: CryptoSystemVersion,
+}
+
+impl fmt::Debug for ValidateGetValuesContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValidateGetValuesContext")
+            .field("last_descriptor", &self.last_descriptor)
+            .field("subkeys", &self.subkeys)
+            .field("vcrypto", &self.vcrypto.kind().to_string())
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(in crate::rpc_processor) struct RPCOperationGetValuesQ {
+    key: TypedKey,
+    subkeys: ValueSubkeyRangeSet,
+    want_descriptor: bool,
+}
+
+impl RPCOperationGetValuesQ {
+    pub fn new(
+        key: TypedKey,
+        subkeys: ValueSubkeyRangeSet,
+        want_descriptor: bool,
+    ) -> Result<Self, RPCError> {
+        if subkeys.is_empty() {
+            return Err(RPCError::protocol("encoded GetValuesQ has no subkeys"));
+        }
+        if subkeys.len() as u64 > MAX_GET_VALUES_Q_SUBKEYS_LEN as u64 {
+            return Err(RPCError::protocol("encoded GetValuesQ too many subkeys"));
+        }
+        Ok(Self {
+            key,
+            subkeys,
+            want_descriptor,
+        })
+    }
+    pub fn validate(&mut self, _validate_context: &RPCValidateContext) -> Result<(), RPCError> {
+        Ok(())
+    }
+
+    // pub fn key(&self) -> &TypedKey {
+    //     &self.key
+    // }
+    // pub fn subkeys(&self) -> &ValueSubkeyRangeSet {
+    //     &self.subkeys
+    // }
+    // pub fn want_descriptor(&self) -> bool {
+    //     self.want_descriptor
+    // }
+    pub fn destructure(self) -> (TypedKey, ValueSubkeyRangeSet, bool) {
+        (self.key, self.subkeys, self.want_descriptor)
+    }
+
+    pub fn decode(
+        _decode_context: &RPCDecodeContext,
+        reader: &veilid_capnp::operation_get_values_q::Reader,
+    ) -> Result<Self, RPCError> {
+        let k_reader = reader.reborrow().get_key().map_err(RPCError::protocol)?;
+        let key = decode_typed_key(&k_reader)?;
+        let sk_reader = reader.get_subkeys().map_err(RPCError::protocol)?;
+        if sk_reader.len() as usize > MAX_GET_VALUES_Q_SUBKEY_RANGES_LEN {
+            return Err(RPCError::protocol("GetValuesQ too many subkey ranges"));
+        }
+        let subkeys = decode_value_subkey_range_set(&sk_reader)?;
+        if subkeys.is_empty() {
+            return Err(RPCError::protocol("decoded GetValuesQ has no subkeys"));
+        }
+        if subkeys.len() as u64 > MAX_GET_VALUES_Q_SUBKEYS_LEN as u64 {
+            return Err(RPCError::protocol("decoded GetValuesQ too many subkeys"));
+        }
+        let want_descriptor = reader.reborrow().get_want_descriptor();
+        Ok(Self {
+            key,
+            subkeys,
+            want_descriptor,
+        })
+    }
+    pub fn encode(
+        &self,
+        builder: &mut veilid_capnp::operation_get_values_q::Builder,
+    ) -> Result<(), RPCError> {
+        let mut k_builder = builder.reborrow().init_key();
+        encode_typed_key(&self.key, &mut k_builder);
+
+        let mut sk_builder = builder.reborrow().init_subkeys(
+            self.subkeys
+                .ranges_len()
+                .try_into()
+                .map_err(RPCError::map_internal("invalid subkey range list length"))?,
+        );
+        encode_value_subkey_range_set(&self.subkeys, &mut sk_builder)?;
+        builder.set_want_descriptor(self.want_descriptor);
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub(in crate::rpc_processor) struct RPCOperationGetValuesA {
+    subkeys: ValueSubkeyRangeSet,
+    values: Vec<(ValueSubkey, SignedValueData)>,
+    peers: Vec<Arc<PeerInfo>>,
+    descriptor: Option<SignedValueDescriptor>,
+}
+
+impl RPCOperationGetValuesA {
+    pub fn new(
+        subkeys: ValueSubkeyRangeSet,
+        values: Vec<(ValueSubkey, SignedValueData)>,
+        peers: Vec<Arc<PeerInfo>>,
+        descriptor: Option<SignedValueDescriptor>,
+    ) -> Result<Self, RPCError> {
+        if subkeys.len() as u64 > MAX_GET_VALUES_Q_SUBKEYS_LEN as u64 {
+            return Err(RPCError::protocol(
+                "encoded GetValuesA subkeys length too long",
+            ));
+        }
+        if peers.len() > MAX_GET_VALUES_A_PEERS_LEN {
+            return Err(RPCError::protocol(
+                "encoded GetValuesA peers length too long",
+            ));
+        }
+        Ok(Self {
+            subkeys,
+            values,
+            peers,
+            descriptor,
+        })
+    }
+
+    pub fn validate(&mut self, validate_context: &RPCValidateContext) -> Result<(), RPCError> {
+        let question_context = validate_context
+            .question_context
+            .as_ref()
+            .expect("GetValuesA requires question context");
+        let QuestionContext::GetValues(get_values_context) = question_context else {
+            panic!("Wrong context type for GetValuesA");
+        };
+
+        // The answer must cover at least one of the subkeys asked for and nothing else
+        if self.subkeys.is_empty() {
+            return Err(RPCError::protocol("GetValuesA covers no subkeys"));
+        }
+        if !self.subkeys.is_subset(&get_values_context.subkeys) {
+            return Err(RPCError::protocol(
+                "GetValuesA covers subkeys that were not requested",
+            ));
+        }
+
+        // Validate descriptor
+        if let Some(descriptor) = &self.descriptor {
+            // Ensure the descriptor itself validates
+            descriptor
+                .validate(get_values_context.vcrypto.clone())
+                .map_err(RPCError::protocol)?;
+
+            // Ensure descriptor matches last one
+            if let Some(last_descriptor) = &get_values_context.last_descriptor {
+                if descriptor.cmp_no_sig(last_descriptor) != cmp::Ordering::Equal {
+                    return Err(RPCError::protocol(
+                        "GetValues descriptor does not match last descriptor",
+                    ));
+                }
+            }
+        }
+
+        // Ensure the values validate
+        if !self.values.is_empty() {
+            // Get descriptor to validate with
+            let Some(descriptor) = self
+                .descriptor
+                .as_ref()
+                .or(get_values_context.last_descriptor.as_ref())
+            else {
+                return Err(RPCError::protocol(
+                    "no last descriptor, requires a descriptor",
+                ));
+            };
+
+            let mut last_subkey = None;
+            for (subkey, value) in &self.values {
+                // Values must be for covered subkeys, in ascending order
+                if !self.subkeys.contains(*subkey) {
+                    return Err(RPCError::protocol("GetValuesA value for uncovered subkey"));
+                }
+                if last_subkey.map(|l| l >= *subkey).unwrap_or_default() {
+                    return Err(RPCError::protocol("GetValuesA values out of order"));
+                }
+                last_subkey = Some(*subkey);
+
+                // And the signed value data
+                if !value
+                    .validate(
+                        descriptor.owner(),
+                        *subkey,
+                        get_values_context.vcrypto.clone(),
+                    )
+                    .map_err(RPCError::protocol)?
+                {
+                    return Err(RPCError::protocol("signed value data did not validate"));
+                }
+            }
+        }
+
+        PeerInfo::validate_vec(&mut self.peers, validate_context.crypto.clone());
+        Ok(())
+    }
+
+    // pub fn subkeys(&self) -> &ValueSubkeyRangeSet {
+    //     &self.subkeys
+    // }
+    // pub fn values(&self) -> &[(ValueSubkey, SignedValueData)] {
+    //     &self.values
+    // }
+    // pub fn peers(&self) -> &[PeerInfo] {
+    //     &self.peers
+    // }
+    // pub fn descriptor(&self) -> Option<&SignedValueDescriptor> {
+    //     self.descriptor.as_ref()
+    // }
+    #[allow(clippy::type_complexity)]
+    pub fn destructure(
+        self,
+    ) -> (
+        ValueSubkeyRangeSet,
+        Vec<(ValueSubkey, SignedValueData)>,
+        Vec<Arc<PeerInfo>>,
+        Option<SignedValueDescriptor>,
+    ) {
+        (self.subkeys, self.values, self.peers, self.descriptor)
+    }
+
+    pub fn decode(
+        decode_context: &RPCDecodeContext,
+        reader: &veilid_capnp::operation_get_values_a::Reader,
+    ) -> Result<Self, RPCError> {
+        let sk_reader = reader.get_subkeys().map_err(RPCError::protocol)?;
+        if sk_reader.len() as usize > MAX_GET_VALUES_Q_SUBKEY_RANGES_LEN {
+            return Err(RPCError::protocol("GetValuesA too many subkey ranges"));
+        }
+        let subkeys = decode_value_subkey_range_set(&sk_reader)?;
+        if subkeys.len() as u64 > MAX_GET_VALUES_Q_SUBKEYS_LEN as u64 {
+            return Err(RPCError::protocol(
+                "decoded GetValuesA subkeys length too long",
+            ));
+        }
+
+        let values_reader = reader.get_values().map_err(RPCError::protocol)?;
+        if values_reader.len() as usize > MAX_GET_VALUES_Q_SUBKEYS_LEN {
+            return Err(RPCError::protocol(
+                "decoded GetValuesA values length too long",
+            ));
+        }
+        let mut values = Vec::<(ValueSubkey, SignedValueData)>::with_capacity(
+            values_reader
+                .len()
+                .try_into()
+                .map_err(RPCError::map_internal("too many values"))?,
+        );
+        for v in values_reader.iter() {
+            values.push(decode_subkey_value(&v)?);
+        }
+
+        let peers_reader = reader.get_peers().map_err(RPCError::protocol)?;
+        if peers_reader.len() as usize > MAX_GET_VALUES_A_PEERS_LEN {
+            return Err(RPCError::protocol(
+                "decoded GetValuesA peers length too long",
+            ));
+        }
+        let mut peers = Vec::<Arc<PeerInfo>>::with_capacity(
+            peers_reader
+                .len()
+                .try_into()
+                .map_err(RPCError::map_internal("too many peers"))?,
+        );
+        for p in peers_reader.iter() {
+            let peer_info = Arc::new(decode_peer_info(decode_context, &p)?);
+            peers.push(peer_info);
+        }
+
+        let descriptor = if reader.has_descriptor() {
+            let d_reader = reader.get_descriptor().map_err(RPCError::protocol)?;
+            let descriptor = decode_signed_value_descriptor(&d_reader)?;
+            Some(descriptor)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            subkeys,
+            values,
+            peers,
+            descriptor,
+        })
+    }
+    pub fn encode(
+        &self,
+        builder: &mut veilid_capnp::operation_get_values_a::Builder,
+    ) -> Result<(), RPCError> {
+        let mut sk_builder = builder.reborrow().init_subkeys(
+            self.subkeys
+                .ranges_len()
+                .try_into()
+                .map_err(RPCError::map_internal("invalid subkey range list length"))?,
+        );
+        encode_value_subkey_range_set(&self.subkeys, &mut sk_builder)?;
+
+        let mut values_builder = builder.reborrow().init_values(
+            self.values
+                .len()
+                .try_into()
+                .map_err(RPCError::map_internal("invalid values list length"))?,
+        );
+        for (i, (subkey, value)) in self.values.iter().enumerate() {
+            let mut v_builder = values_builder.reborrow().get(i as u32);
+            encode_subkey_value(*subkey, value, &mut v_builder)?;
+        }
+
+        let mut peers_builder = builder.reborrow().init_peers(
+            self.peers
+                .len()
+                .try_into()
+                .map_err(RPCError::map_internal("invalid peers list length"))?,
+        );
+        for (i, peer) in self.peers.iter().enumerate() {
+            let mut pi_builder = peers_builder.reborrow().get(i as u32);
+            encode_peer_info(peer, &mut pi_builder)?;
+        }
+
+        if let Some(descriptor) = &self.descriptor {
+            let mut d_builder = builder.reborrow().init_descriptor();
+            encode_signed_value_descriptor(descriptor, &mut d_builder)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,368 @@
+use super::*;
+use crate::storage_manager::{SignedValueData, SignedValueDescriptor};
+
+pub(crate) const MAX_SET_VALUES_Q_VALUES_LEN: usize = 64;
+pub(crate) const MAX_SET_VALUES_Q_DATA_LEN: usize = 32768;
+const MAX_SET_VALUES_A_SUBKEY_RANGES_LEN: usize = 64;
+pub(crate) const MAX_SET_VALUES_A_DATA_LEN: usize = 32768;
+const MAX_SET_VALUES_A_PEERS_LEN: usize = 20;
+
+/// Total size of the value data in a list of subkey values
+pub(crate) fn subkey_values_data_len(values: &[(ValueSubkey, SignedValueData)]) -> usize {
+    values
+        .iter()
+        .map(|(_, v)| v.value_data().data().len())
+        .sum()
+}
+
+fn check_subkey_values_order(values: &[(ValueSubkey, SignedValueData)]) -> Result<(), RPCError> {
+    let mut last_subkey = None;
+    for (subkey, _) in values {
+        if last_subkey.map(|l| l >= *subkey).unwrap_or_default() {
+            return Err(RPCError::protocol("subkey values out of order"));
+        }
+        last_subkey = Some(*subkey);
+    }
+    Ok(())
+}
+
+#[derive(Clone)]
+pub(in crate::rpc_processor) struct ValidateSetValuesContext {
+    pub descriptor: SignedValueDescriptor,
+    pub subkeys: ValueSubkeyRangeSet,
+    pub vcrypto: CryptoSystemVersion,
+}
+
+impl fmt::Debug for ValidateSetValuesContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValidateSetValuesContext")
+            .field("descriptor", &self.descriptor)
+            .field("subkeys", &self.subkeys)
+            .field("vcrypto", &self.vcrypto.kind().to_string())
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(in crate::rpc_processor) struct RPCOperationSetValuesQ {
+    key: TypedKey,
+    values: Vec<(ValueSubkey, SignedValueData)>,
+    descriptor: Option<SignedValueDescriptor>,
+}
+
+impl RPCOperationSetValuesQ {
+    pub fn new(
+        key: TypedKey,
+        values: Vec<(ValueSubkey, SignedValueData)>,
+        descriptor: Option<SignedValueDescriptor>,
+    ) -> Result<Self, RPCError> {
+        if values.is_empty() {
+            return Err(RPCError::protocol("encoded SetValuesQ has no values"));
+        }
+        if values.len() > MAX_SET_VALUES_Q_VALUES_LEN {
+            return Err(RPCError::protocol("encoded SetValuesQ too many values"));
+        }
+        if subkey_values_data_len(&values) > MAX_SET_VALUES_Q_DATA_LEN {
+            return Err(RPCError::protocol("encoded SetValuesQ data too long"));
+        }
+        check_subkey_values_order(&values)?;
+        Ok(Self {
+            key,
+            values,
+            descriptor,
+        })
+    }
+    pub fn validate(&mut self, _validate_context: &RPCValidateContext) -> Result<(), RPCError> {
+        Ok(())
+    }
+
+    // pub fn key(&self) -> &TypedKey {
+    //     &self.key
+    // }
+
+    // pub fn values(&self) -> &[(ValueSubkey, SignedValueData)] {
+    //     &self.values
+    // }
+
+    // pub fn descriptor(&self) -> Option<&SignedValueDescriptor> {
+    //     self.descriptor.as_ref()
+    // }
+    pub fn destructure(
+        self,
+    ) -> (
+        TypedKey,
+        Vec<(ValueSubkey, SignedValueData)>,
+        Option<SignedValueDescriptor>,
+    ) {
+        (self.key, self.values, self.descriptor)
+    }
+
+    pub fn decode(
+        _decode_context: &RPCDecodeContext,
+        reader: &veilid_capnp::operation_set_values_q::Reader,
+    ) -> Result<Self, RPCError> {
+        let k_reader = reader.get_key().map_err(RPCError::protocol)?;
+        let key = decode_typed_key(&k_reader)?;
+
+        let values_reader = reader.get_values().map_err(RPCError::protocol)?;
+        if values_reader.is_empty() {
+            return Err(RPCError::protocol("decoded SetValuesQ has no values"));
+        }
+        if values_reader.len() as usize > MAX_SET_VALUES_Q_VALUES_LEN {
+            return Err(RPCError::protocol("decoded SetValuesQ too many values"));
+        }
+        let mut values = Vec::<(ValueSubkey, SignedValueData)>::with_capacity(
+            values_reader
+                .len()
+                .try_into()
+                .map_err(RPCError::map_internal("too many values"))?,
+        );
+        for v in values_reader.iter() {
+            values.push(decode_subkey_value(&v)?);
+        }
+        if subkey_values_data_len(&values) > MAX_SET_VALUES_Q_DATA_LEN {
+            return Err(RPCError::protocol("decoded SetValuesQ data too long"));
+        }
+        check_subkey_values_order(&values)?;
+
+        let descriptor = if reader.has_descriptor() {
+            let d_reader = reader.get_descriptor().map_err(RPCError::protocol)?;
+            let descriptor = decode_signed_value_descriptor(&d_reader)?;
+            Some(descriptor)
+        } else {
+            None
+        };
+        Ok(Self {
+            key,
+            values,
+            descriptor,
+        })
+    }
+    pub fn encode(
+        &self,
+        builder: &mut veilid_capnp::operation_set_values_q::Builder,
+    ) -> Result<(), RPCError> {
+        let mut k_builder = builder.reborrow().init_key();
+        encode_typed_key(&self.key, &mut k_builder);
+
+        let mut values_builder = builder.reborrow().init_values(
+            self.values
+                .len()
+                .try_into()
+                .map_err(RPCError::map_internal("invalid values list length"))?,
+        );
+        for (i, (subkey, value)) in self.values.iter().enumerate() {
+            let mut v_builder = values_builder.reborrow().get(i as u32);
+            encode_subkey_value(*subkey, value, &mut v_builder)?;
+        }
+
+        if let Some(descriptor) = &self.descriptor {
+            let mut d_builder = builder.reborrow().init_descriptor();
+            encode_signed_value_descriptor(descriptor, &mut d_builder)?;
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub(in crate::rpc_processor) struct RPCOperationSetValuesA {
+    set: bool,
+    subkeys: ValueSubkeyRangeSet,
+    values: Vec<(ValueSubkey, SignedValueData)>,
+    peers: Vec<Arc<PeerInfo>>,
+}
+
+impl RPCOperationSetValuesA {
+    pub fn new(
+        set: bool,
+        subkeys: ValueSubkeyRangeSet,
+        values: Vec<(ValueSubkey, SignedValueData)>,
+        peers: Vec<Arc<PeerInfo>>,
+    ) -> Result<Self, RPCError> {
+        if subkeys.len() as u64 > MAX_SET_VALUES_Q_VALUES_LEN as u64 {
+            return Err(RPCError::protocol(
+                "encoded SetValuesA subkeys length too long",
+            ));
+        }
+        if peers.len() > MAX_SET_VALUES_A_PEERS_LEN {
+            return Err(RPCError::protocol(
+                "encoded SetValuesA peers length too long",
+            ));
+        }
+        Ok(Self {
+            set,
+            subkeys,
+            values,
+            peers,
+        })
+    }
+
+    pub fn validate(&mut self, validate_context: &RPCValidateContext) -> Result<(), RPCError> {
+        let question_context = validate_context
+            .question_context
+            .as_ref()
+            .expect("SetValuesA requires question context");
+        let QuestionContext::SetValues(set_values_context) = question_context else {
+            panic!("Wrong context type for SetValuesA");
+        };
+
+        // If the sets were accepted, the answer must cover at least one of the subkeys sent and nothing else
+        if self.set && self.subkeys.is_empty() {
+            return Err(RPCError::protocol("SetValuesA covers no subkeys"));
+        }
+        if !self.subkeys.is_subset(&set_values_context.subkeys) {
+            return Err(RPCError::protocol(
+                "SetValuesA covers subkeys that were not sent",
+            ));
+        }
+
+        // Ensure the descriptor itself validates
+        set_values_context
+            .descriptor
+            .validate(set_values_context.vcrypto.clone())
+            .map_err(RPCError::protocol)?;
+
+        check_subkey_values_order(&self.values)?;
+        for (subkey, value) in &self.values {
+            if !self.subkeys.contains(*subkey) {
+                return Err(RPCError::protocol("SetValuesA value for uncovered subkey"));
+            }
+            // And the signed value data
+            if !value
+                .validate(
+                    set_values_context.descriptor.owner(),
+                    *subkey,
+                    set_values_context.vcrypto.clone(),
+                )
+                .map_err(RPCError::protocol)?
+            {
+                return Err(RPCError::protocol("signed value data did not validate"));
+            }
+        }
+
+        PeerInfo::validate_vec(&mut self.peers, validate_context.crypto.clone());
+        Ok(())
+    }
+
+    // pub fn set(&self) -> bool {
+    //     self.set
+    // }
+    // pub fn subkeys(&self) -> &ValueSubkeyRangeSet {
+    //     &self.subkeys
+    // }
+    // pub fn values(&self) -> &[(ValueSubkey, SignedValueData)] {
+    //     &self.values
+    // }
+    // pub fn peers(&self) -> &[PeerInfo] {
+    //     &self.peers
+    // }
+    #[allow(clippy::type_complexity)]
+    pub fn destructure(
+        self,
+    ) -> (
+        bool,
+        ValueSubkeyRangeSet,
+        Vec<(ValueSubkey, SignedValueData)>,
+        Vec<Arc<PeerInfo>>,
+    ) {
+        (self.set, self.subkeys, self.values, self.peers)
+    }
+
+    pub fn decode(
+        decode_context: &RPCDecodeContext,
+        reader: &veilid_capnp::operation_set_values_a::Reader,
+    ) -> Result<Self, RPCError> {
+        let set = reader.get_set();
+
+        let sk_reader = reader.get_subkeys().map_err(RPCError::protocol)?;
+        if sk_reader.len() as usize > MAX_SET_VALUES_A_SUBKEY_RANGES_LEN {
+            return Err(RPCError::protocol("SetValuesA too many subkey ranges"));
+        }
+        let subkeys = decode_value_subkey_range_set(&sk_reader)?;
+        if subkeys.len() as u64 > MAX_SET_VALUES_Q_VALUES_LEN as u64 {
+            return Err(RPCError::protocol(
+                "decoded SetValuesA subkeys length too long",
+            ));
+        }
+
+        let values_reader = reader.get_values().map_err(RPCError::protocol)?;
+        if values_reader.len() as usize > MAX_SET_VALUES_Q_VALUES_LEN {
+            return Err(RPCError::protocol(
+                "decoded SetValuesA values length too long",
+            ));
+        }
+        let mut values = Vec::<(ValueSubkey, SignedValueData)>::with_capacity(
+            values_reader
+                .len()
+                .try_into()
+                .map_err(RPCError::map_internal("too many values"))?,
+        );
+        for v in values_reader.iter() {
+            values.push(decode_subkey_value(&v)?);
+        }
+
+        let peers_reader = reader.get_peers().map_err(RPCError::protocol)?;
+        if peers_reader.len() as usize > MAX_SET_VALUES_A_PEERS_LEN {
+            return Err(RPCError::protocol(
+                "decoded SetValuesA peers length too long",
+            ));
+        }
+        let mut peers = Vec::<Arc<PeerInfo>>::with_capacity(
+            peers_reader
+                .len()
+                .try_into()
+                .map_err(RPCError::map_internal("too many peers"))?,
+        );
+        for p in peers_reader.iter() {
+            let peer_info = Arc::new(decode_peer_info(decode_context, &p)?);
+            peers.push(peer_info);
+        }
+
+        Ok(Self {
+            set,
+            subkeys,
+            values,
+            peers,
+        })
+    }
+    pub fn encode(
+        &self,
+        builder: &mut veilid_capnp::operation_set_values_a::Builder,
+    ) -> Result<(), RPCError> {
+        builder.set_set(self.set);
+
+        let mut sk_builder = builder.reborrow().init_subkeys(
+            self.subkeys
+                .ranges_len()
+                .try_into()
+                .map_err(RPCError::map_internal("invalid subkey range list length"))?,
+        );
+        encode_value_subkey_range_set(&self.subkeys, &mut sk_builder)?;
+
+        let mut values_builder = builder.reborrow().init_values(
+            self.values
+                .len()
+                .try_into()
+                .map_err(RPCError::map_internal("invalid values list length"))?,
+        );
+        for (i, (subkey, value)) in self.values.iter().enumerate() {
+            let mut v_builder = values_builder.reborrow().get(i as u32);
+            encode_subkey_value(*subkey, value, &mut v_builder)?;
+        }
+
+        let mut peers_builder = builder.reborrow().init_peers(
+            self.peers
+                .len()
+                .try_into()
+                .map_err(RPCError::map_internal("invalid peers list length"))?,
+        );
+        for (i, peer) in self.peers.iter().enumerate() {
+            let mut pi_builder = peers_builder.reborrow().get(i as u32);
+            encode_peer_info(peer, &mut pi_builder)?;
+        }
+
+        Ok(())
+    }
+}
@@ -57,7 +57,6 @@ impl RPCOperationValueChanged {
         Ok(())
     }
 
-    #[expect(dead_code)]
     pub fn key(&self) -> &TypedKey {
         &self.key
     }
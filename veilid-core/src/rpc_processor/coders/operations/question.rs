@@ -53,6 +53,8 @@ pub(in crate::rpc_processor) enum RPCQuestionDetail {
     SetValueQ(Box<RPCOperationSetValueQ>),
     WatchValueQ(Box<RPCOperationWatchValueQ>),
     InspectValueQ(Box<RPCOperationInspectValueQ>),
+    GetValuesQ(Box<RPCOperationGetValuesQ>),
+    SetValuesQ(Box<RPCOperationSetValuesQ>),
     #[cfg(feature = "unstable-blockstore")]
     SupplyBlockQ(Box<RPCOperationSupplyBlockQ>),
     #[cfg(feature = "unstable-blockstore")]
@@ -75,6 +77,8 @@ impl RPCQuestionDetail {
             RPCQuestionDetail::SetValueQ(_) => "SetValueQ",
             RPCQuestionDetail::WatchValueQ(_) => "WatchValueQ",
             RPCQuestionDetail::InspectValueQ(_) => "InspectValueQ",
+            RPCQuestionDetail::GetValuesQ(_) => "GetValuesQ",
+            RPCQuestionDetail::SetValuesQ(_) => "SetValuesQ",
             #[cfg(feature = "unstable-blockstore")]
             RPCQuestionDetail::SupplyBlockQ(_) => "SupplyBlockQ",
             #[cfg(feature = "unstable-blockstore")]
@@ -96,6 +100,8 @@ impl RPCQuestionDetail {
             RPCQuestionDetail::SetValueQ(r) => r.validate(validate_context),
             RPCQuestionDetail::WatchValueQ(r) => r.validate(validate_context),
             RPCQuestionDetail::InspectValueQ(r) => r.validate(validate_context),
+            RPCQuestionDetail::GetValuesQ(r) => r.validate(validate_context),
+            RPCQuestionDetail::SetValuesQ(r) => r.validate(validate_context),
             #[cfg(feature = "unstable-blockstore")]
             RPCQuestionDetail::SupplyBlockQ(r) => r.validate(validate_context),
             #[cfg(feature = "unstable-blockstore")]
@@ -150,6 +156,16 @@ impl RPCQuestionDetail {
                 let out = RPCOperationInspectValueQ::decode(decode_context, &op_reader)?;
                 RPCQuestionDetail::InspectValueQ(Box::new(out))
             }
+            veilid_capnp::question::detail::GetValuesQ(r) => {
+                let op_reader = r.map_err(RPCError::protocol)?;
+                let out = RPCOperationGetValuesQ::decode(decode_context, &op_reader)?;
+                RPCQuestionDetail::GetValuesQ(Box::new(out))
+            }
+            veilid_capnp::question::detail::SetValuesQ(r) => {
+                let op_reader = r.map_err(RPCError::protocol)?;
+                let out = RPCOperationSetValuesQ::decode(decode_context, &op_reader)?;
+                RPCQuestionDetail::SetValuesQ(Box::new(out))
+            }
             #[cfg(feature = "unstable-blockstore")]
             veilid_capnp::question::detail::SupplyBlockQ(r) => {
                 let op_reader = r.map_err(RPCError::protocol)?;
@@ -199,6 +215,12 @@ impl RPCQuestionDetail {
             RPCQuestionDetail::InspectValueQ(d) => {
                 d.encode(&mut builder.reborrow().init_inspect_value_q())
             }
+            RPCQuestionDetail::GetValuesQ(d) => {
+                d.encode(&mut builder.reborrow().init_get_values_q())
+            }
+            RPCQuestionDetail::SetValuesQ(d) => {
+                d.encode(&mut builder.reborrow().init_set_values_q())
+            }
             #[cfg(feature = "unstable-blockstore")]
             RPCQuestionDetail::SupplyBlockQ(d) => {
                 d.encode(&mut builder.reborrow().init_supply_block_q())
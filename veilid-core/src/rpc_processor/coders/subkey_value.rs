@@ -0,0 +1,22 @@
+use super::*;
+use crate::storage_manager::*;
+
+pub fn encode_subkey_value(
+    subkey: ValueSubkey,
+    signed_value_data: &SignedValueData,
+    builder: &mut veilid_capnp::subkey_value::Builder,
+) -> Result<(), RPCError> {
+    builder.set_subkey(subkey);
+    let mut vb = builder.reborrow().init_value();
+    encode_signed_value_data(signed_value_data, &mut vb)?;
+    Ok(())
+}
+
+pub fn decode_subkey_value(
+    reader: &veilid_capnp::subkey_value::Reader,
+) -> Result<(ValueSubkey, SignedValueData), RPCError> {
+    let subkey = reader.get_subkey();
+    let vr = reader.get_value().map_err(RPCError::protocol)?;
+    let signed_value_data = decode_signed_value_data(&vr)?;
+    Ok((subkey, signed_value_data))
+}
@@ -1,3 +1,4 @@
+mod app_channel_table;
 mod coders;
 mod destination;
 mod fanout_call;
@@ -8,15 +9,19 @@ mod rpc_app_message;
 mod rpc_error;
 mod rpc_find_node;
 mod rpc_get_value;
+mod rpc_get_values;
 mod rpc_inspect_value;
+mod rpc_rate_limit;
 mod rpc_return_receipt;
 mod rpc_route;
 mod rpc_set_value;
+mod rpc_set_values;
 mod rpc_signal;
 mod rpc_status;
 mod rpc_validate_dial_info;
 mod rpc_value_changed;
 mod rpc_watch_value;
+mod rpc_work_queue;
 
 #[cfg(feature = "unstable-blockstore")]
 mod rpc_find_block;
@@ -30,12 +35,16 @@ mod rpc_complete_tunnel;
 #[cfg(feature = "unstable-tunnels")]
 mod rpc_start_tunnel;
 
+pub(crate) use app_channel_table::*;
 pub(crate) use coders::*;
 pub(crate) use destination::*;
 pub(crate) use fanout_call::*;
 pub(crate) use operation_waiter::*;
 pub(crate) use rpc_error::*;
+pub(crate) use rpc_get_values::GetValuesAnswer;
+pub(crate) use rpc_set_values::SetValuesAnswer;
 pub(crate) use rpc_status::*;
+pub(crate) use rpc_work_queue::*;
 
 use super::*;
 
@@ -284,7 +293,9 @@ enum RPCKind {
 /////////////////////////////////////////////////////////////////////
 
 struct RPCProcessorInner {
-    send_channel: Option<flume::Sender<(Span, RPCMessageEncoded)>>,
+    /// Wakes a worker for each message added to the work queues
+    send_channel: Option<flume::Sender<()>>,
+    work_queues: Option<RPCWorkQueues<(Span, RPCMessageEncoded)>>,
     stop_source: Option<StopSource>,
     worker_join_handles: Vec<MustJoinHandle<()>>,
 }
@@ -292,9 +303,12 @@ struct RPCProcessorInner {
 struct RPCProcessorUnlockedInner {
     timeout_us: TimestampDuration,
     queue_size: u32,
+    queues_config: VeilidConfigRPCQueues,
+    rate_limits_config: VeilidConfigRPCRateLimits,
     concurrency: u32,
     max_route_hop_count: usize,
     #[cfg_attr(target_arch = "wasm32", expect(dead_code))]
+    #[cfg_attr(feature = "virtual-network", allow(dead_code))]
     validate_dial_info_receipt_time_ms: u32,
     update_callback: UpdateCallback,
     waiting_rpc_table: OperationWaiter<RPCMessage, Option<QuestionContext>>,
@@ -317,6 +331,7 @@ impl RPCProcessor {
     fn new_inner() -> RPCProcessorInner {
         RPCProcessorInner {
             send_channel: None,
+            work_queues: None,
             stop_source: None,
             worker_join_handles: Vec::new(),
         }
@@ -331,6 +346,8 @@ impl RPCProcessor {
         // set up channel
         let mut concurrency = c.network.rpc.concurrency;
         let queue_size = c.network.rpc.queue_size;
+        let queues_config = c.network.rpc.queues.clone();
+        let rate_limits_config = c.network.rpc.rate_limits.clone();
         let timeout_us = TimestampDuration::new(ms_to_us(c.network.rpc.timeout_ms));
         let max_route_hop_count = c.network.rpc.max_route_hop_count as usize;
         if concurrency == 0 {
@@ -347,6 +364,8 @@ impl RPCProcessor {
         RPCProcessorUnlockedInner {
             timeout_us,
             queue_size,
+            queues_config,
+            rate_limits_config,
             concurrency,
             max_route_hop_count,
             validate_dial_info_receipt_time_ms,
@@ -392,6 +411,10 @@ impl RPCProcessor {
 
             let channel = flume::bounded(self.unlocked_inner.queue_size as usize);
             inner.send_channel = Some(channel.0.clone());
+            inner.work_queues = Some(RPCWorkQueues::new(
+                self.unlocked_inner.queue_size as usize,
+                &self.unlocked_inner.queues_config,
+            ));
             inner.stop_source = Some(StopSource::new());
 
             // spin up N workers
@@ -457,6 +480,27 @@ impl RPCProcessor {
 
     //////////////////////////////////////////////////////////////////////
 
+    /// Get the RPC work queue counters for debugging purposes
+    pub(crate) fn debug_info_queues(&self) -> String {
+        let inner = self.inner.lock();
+        let Some(work_queues) = inner.work_queues.as_ref() else {
+            return "RPC Queues: not started\n".to_string();
+        };
+        let mut out = "RPC Queues:\n".to_string();
+        for (class, stats) in work_queues.stats() {
+            out += &format!(
+                "    {:<7} queued={} senders={} enqueued={} processed={} dropped={}\n",
+                class.to_string(),
+                stats.queued,
+                stats.senders,
+                stats.enqueued,
+                stats.processed,
+                stats.dropped
+            );
+        }
+        out
+    }
+
     /// Get waiting app call id for debugging purposes
     pub(crate) fn get_app_call_ids(&self) -> Vec<OperationId> {
         self.unlocked_inner
@@ -1641,6 +1685,14 @@ impl RPCProcessor {
                     );
                 }
 
+                // Drop operations that are over the sender's rate limits
+                if let Some(sender_nr) = &opt_sender_nr {
+                    if !self.check_rate_limit(routing_domain, sender_node_id, sender_nr, &operation)
+                    {
+                        return Ok(NetworkResult::service_unavailable("rate limited"));
+                    }
+                }
+
                 // Make the RPC message
                 RPCMessage {
                     header: encoded_msg.header,
@@ -1709,6 +1761,8 @@ impl RPCProcessor {
                 RPCQuestionDetail::SetValueQ(_) => self.process_set_value_q(msg).await,
                 RPCQuestionDetail::WatchValueQ(_) => self.process_watch_value_q(msg).await,
                 RPCQuestionDetail::InspectValueQ(_) => self.process_inspect_value_q(msg).await,
+                RPCQuestionDetail::GetValuesQ(_) => self.process_get_values_q(msg).await,
+                RPCQuestionDetail::SetValuesQ(_) => self.process_set_values_q(msg).await,
                 #[cfg(feature = "unstable-blockstore")]
                 RPCQuestionDetail::SupplyBlockQ(_) => self.process_supply_block_q(msg).await,
                 #[cfg(feature = "unstable-blockstore")]
@@ -1758,14 +1812,18 @@ impl RPCProcessor {
         }
     }
 
-    async fn rpc_worker(
-        self,
-        stop_token: StopToken,
-        receiver: flume::Receiver<(Span, RPCMessageEncoded)>,
-    ) {
-        while let Ok(Ok((prev_span, msg))) =
-            receiver.recv_async().timeout_at(stop_token.clone()).await
-        {
+    async fn rpc_worker(self, stop_token: StopToken, receiver: flume::Receiver<()>) {
+        while let Ok(Ok(())) = receiver.recv_async().timeout_at(stop_token.clone()).await {
+            let Some((_class, (prev_span, msg))) = self
+                .inner
+                .lock()
+                .work_queues
+                .as_mut()
+                .and_then(|work_queues| work_queues.pop())
+            else {
+                continue;
+            };
+
             let rpc_message_span = tracing::trace_span!("rpc message");
             rpc_message_span.follows_from(prev_span);
 
@@ -1820,17 +1878,8 @@ impl RPCProcessor {
             data: RPCMessageData { contents: body },
         };
 
-        let send_channel = {
-            let inner = self.inner.lock();
-            let Some(send_channel) = inner.send_channel.as_ref().cloned() else {
-                bail!("send channel is closed");
-            };
-            send_channel
-        };
-        send_channel
-            .try_send((Span::current(), msg))
-            .map_err(|e| eyre!("failed to enqueue direct RPC message: {}", e))?;
-        Ok(())
+        self.enqueue_rpc_message(msg)
+            .map_err(|e| eyre!("failed to enqueue direct RPC message: {}", e))
     }
 
     #[instrument(level = "trace", target = "rpc", skip_all)]
@@ -1855,17 +1904,9 @@ impl RPCProcessor {
             header,
             data: RPCMessageData { contents: body },
         };
-        let send_channel = {
-            let inner = self.inner.lock();
-            let Some(send_channel) = inner.send_channel.as_ref().cloned() else {
-                bail!("send channel is closed");
-            };
-            send_channel
-        };
-        send_channel
-            .try_send((Span::current(), msg))
-            .map_err(|e| eyre!("failed to enqueue safety routed RPC message: {}", e))?;
-        Ok(())
+
+        self.enqueue_rpc_message(msg)
+            .map_err(|e| eyre!("failed to enqueue safety routed RPC message: {}", e))
     }
 
     #[instrument(level = "trace", target = "rpc", skip_all)]
@@ -1893,16 +1934,32 @@ impl RPCProcessor {
             data: RPCMessageData { contents: body },
         };
 
-        let send_channel = {
-            let inner = self.inner.lock();
-            let Some(send_channel) = inner.send_channel.as_ref().cloned() else {
-                bail!("send channel is closed");
-            };
-            send_channel
+        self.enqueue_rpc_message(msg)
+            .map_err(|e| eyre!("failed to enqueue private routed RPC message: {}", e))
+    }
+
+    /// Add a message to the work queue for its priority class and wake a worker to process it
+    fn enqueue_rpc_message(&self, msg: RPCMessageEncoded) -> EyreResult<()> {
+        let class = RPCWorkClass::classify(&msg);
+        let sender = msg.header.direct_sender_node_id();
+
+        let mut inner = self.inner.lock();
+        let Some(send_channel) = inner.send_channel.as_ref().cloned() else {
+            bail!("send channel is closed");
         };
+        let Some(work_queues) = inner.work_queues.as_mut() else {
+            bail!("work queues are closed");
+        };
+        if work_queues
+            .push(class, sender, (Span::current(), msg))
+            .is_err()
+        {
+            bail!("{} queue is full", class);
+        }
+        // Never blocks, there can not be more wakeups than queued messages
         send_channel
-            .try_send((Span::current(), msg))
-            .map_err(|e| eyre!("failed to enqueue private routed RPC message: {}", e))?;
+            .try_send(())
+            .map_err(|e| eyre!("failed to wake rpc worker: {}", e))?;
         Ok(())
     }
 }
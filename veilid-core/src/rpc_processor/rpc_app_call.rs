@@ -7,6 +7,7 @@ impl RPCProcessor {
     pub async fn rpc_call_app_call(
         self,
         dest: Destination,
+        channel: Option<AppChannel>,
         message: Vec<u8>,
     ) -> RPCNetworkResult<Answer<Vec<u8>>> {
         let _guard = self
@@ -15,9 +16,14 @@ impl RPCProcessor {
             .enter()
             .map_err(RPCError::map_try_again("not started up"))?;
 
-        let debug_string = format!("AppCall(message(len)={}) => {}", message.len(), dest);
+        let debug_string = format!(
+            "AppCall(channel={:?}, message(len)={}) => {}",
+            channel,
+            message.len(),
+            dest
+        );
 
-        let app_call_q = RPCOperationAppCallQ::new(message)?;
+        let app_call_q = RPCOperationAppCallQ::new(channel, message)?;
         let question = RPCQuestion::new(
             network_result_try!(self.get_destination_respond_to(&dest)?),
             RPCQuestionDetail::AppCallQ(Box::new(app_call_q)),
@@ -45,7 +51,13 @@ impl RPCProcessor {
             _ => return Ok(NetworkResult::invalid_message("not an answer")),
         };
 
-        let a_message = app_call_a.destructure();
+        let (a_message, refused) = app_call_a.destructure();
+        if let Some(refused) = refused {
+            return Ok(NetworkResult::service_unavailable(format!(
+                "app call refused: {}",
+                refused
+            )));
+        }
 
         #[cfg(feature = "verbose-tracing")]
         tracing::Span::current().record("ret.latency", latency.as_u64());
@@ -107,6 +119,23 @@ impl RPCProcessor {
             _ => panic!("not a question"),
         };
 
+        // Refuse calls on channels the app will not accept
+        // The refusal is answered so the caller does not have to wait for a timeout
+        let update_callback = match self
+            .app_channel_update_callback(app_call_q.channel(), app_call_q.message_len())
+        {
+            Ok(v) => v,
+            Err(e) => {
+                let app_call_a = RPCOperationAppCallA::new_refused(e)?;
+                return self
+                    .answer(
+                        msg,
+                        RPCAnswer::new(RPCAnswerDetail::AppCallA(Box::new(app_call_a))),
+                    )
+                    .await;
+            }
+        };
+
         // Get the crypto kind used to send this question
         let crypto_kind = msg.header.crypto_kind();
 
@@ -123,9 +152,9 @@ impl RPCProcessor {
             .add_op_waiter(op_id, ());
 
         // Pass the call up through the update callback
-        let message_q = app_call_q.destructure();
-        (self.unlocked_inner.update_callback)(VeilidUpdate::AppCall(Box::new(VeilidAppCall::new(
-            sender, route_id, message_q, op_id,
+        let (channel, message_q) = app_call_q.destructure();
+        update_callback(VeilidUpdate::AppCall(Box::new(VeilidAppCall::new(
+            sender, route_id, channel, message_q, op_id,
         ))));
 
         // Wait for an app call answer to come back from the app
@@ -7,6 +7,7 @@ impl RPCProcessor {
     pub async fn rpc_call_app_message(
         self,
         dest: Destination,
+        channel: Option<AppChannel>,
         message: Vec<u8>,
     ) -> RPCNetworkResult<()> {
         let _guard = self
@@ -15,7 +16,7 @@ impl RPCProcessor {
             .enter()
             .map_err(RPCError::map_try_again("not started up"))?;
 
-        let app_message = RPCOperationAppMessage::new(message)?;
+        let app_message = RPCOperationAppMessage::new(channel, message)?;
         let statement = RPCStatement::new(RPCStatementDetail::AppMessage(Box::new(app_message)));
 
         // Send the app message request
@@ -70,6 +71,16 @@ impl RPCProcessor {
             _ => panic!("not a statement"),
         };
 
+        // Reject messages on channels the app will not accept
+        let update_callback = match self
+            .app_channel_update_callback(app_message.channel(), app_message.message_len())
+        {
+            Ok(v) => v,
+            Err(e) => {
+                return Ok(NetworkResult::service_unavailable(e));
+            }
+        };
+
         // Get the crypto kind used to send this question
         let crypto_kind = msg.header.crypto_kind();
 
@@ -80,10 +91,10 @@ impl RPCProcessor {
             .map(|nr| nr.node_ids().get(crypto_kind).unwrap());
 
         // Pass the message up through the update callback
-        let message = app_message.destructure();
-        (self.unlocked_inner.update_callback)(VeilidUpdate::AppMessage(Box::new(
-            VeilidAppMessage::new(sender, route_id, message),
-        )));
+        let (channel, message) = app_message.destructure();
+        update_callback(VeilidUpdate::AppMessage(Box::new(VeilidAppMessage::new(
+            sender, route_id, channel, message,
+        ))));
 
         Ok(NetworkResult::value(()))
     }
@@ -0,0 +1,102 @@
+use super::*;
+
+impl RPCProcessor {
+    /// Get the capability and limit that apply to an inbound operation, if it is rate limited
+    fn rate_limit_for_operation(
+        &self,
+        kind: &RPCOperationKind,
+    ) -> Option<(Capability, &VeilidConfigRPCRateLimit)> {
+        let rate_limits = &self.unlocked_inner.rate_limits_config;
+        match kind {
+            RPCOperationKind::Question(q) => match q.detail() {
+                RPCQuestionDetail::GetValueQ(_)
+                | RPCQuestionDetail::SetValueQ(_)
+                | RPCQuestionDetail::InspectValueQ(_)
+                | RPCQuestionDetail::GetValuesQ(_)
+                | RPCQuestionDetail::SetValuesQ(_) => Some((CAP_DHT, &rate_limits.dht)),
+                RPCQuestionDetail::WatchValueQ(_) => Some((CAP_DHT_WATCH, &rate_limits.dht_watch)),
+                RPCQuestionDetail::AppCallQ(_) => Some((CAP_APPMESSAGE, &rate_limits.app_message)),
+                _ => None,
+            },
+            RPCOperationKind::Statement(s) => match s.detail() {
+                RPCStatementDetail::ValueChanged(_) => {
+                    Some((CAP_DHT_WATCH, &rate_limits.dht_watch))
+                }
+                RPCStatementDetail::AppMessage(_) => {
+                    Some((CAP_APPMESSAGE, &rate_limits.app_message))
+                }
+                RPCStatementDetail::Route(_) => Some((CAP_ROUTE, &rate_limits.route)),
+                RPCStatementDetail::Signal(_) => Some((CAP_SIGNAL, &rate_limits.signal)),
+                RPCStatementDetail::ValidateDialInfo(_) | RPCStatementDetail::ReturnReceipt(_) => {
+                    None
+                }
+            },
+            RPCOperationKind::Answer(_) => None,
+        }
+    }
+
+    /// Check if a direct operation comes from a sender we depend on, and must never be limited
+    fn is_rate_limit_exempt(
+        &self,
+        routing_domain: RoutingDomain,
+        sender_nr: &NodeRef,
+        operation: &RPCOperation,
+    ) -> bool {
+        // Our relay forwards traffic on behalf of everyone else talking to us
+        if self
+            .routing_table()
+            .relay_node(routing_domain)
+            .is_some_and(|relay_nr| relay_nr.same_entry(sender_nr))
+        {
+            return true;
+        }
+
+        // Value changes from the node holding one of our watches are what we asked for
+        if let RPCOperationKind::Statement(s) = operation.kind() {
+            if let RPCStatementDetail::ValueChanged(vc) = s.detail() {
+                return self
+                    .storage_manager()
+                    .is_active_watch_node(*vc.key(), sender_nr);
+            }
+        }
+
+        false
+    }
+
+    /// Check a direct operation against the sender's rate limits
+    /// Returns false if the operation should be dropped, and punishes senders that keep exceeding their limits
+    pub(super) fn check_rate_limit(
+        &self,
+        routing_domain: RoutingDomain,
+        sender_node_id: TypedKey,
+        sender_nr: &NodeRef,
+        operation: &RPCOperation,
+    ) -> bool {
+        let rate_limits = &self.unlocked_inner.rate_limits_config;
+        if !rate_limits.enabled {
+            return true;
+        }
+        let Some((capability, limit)) = self.rate_limit_for_operation(operation.kind()) else {
+            return true;
+        };
+        if self.is_rate_limit_exempt(routing_domain, sender_nr, operation) {
+            return true;
+        }
+
+        let desc = operation.kind().desc();
+        let Some(violations) =
+            sender_nr.check_rate_limit(desc, capability, limit, Timestamp::now())
+        else {
+            return true;
+        };
+
+        log_rpc!(debug "Rate limited {} from {} ({} recent violations)", desc, sender_node_id, violations);
+
+        if rate_limits.should_punish(violations) {
+            self.network_manager
+                .address_filter()
+                .punish_node_id(sender_node_id, PunishmentReason::ExceededRateLimit);
+        }
+        false
+    }
+}
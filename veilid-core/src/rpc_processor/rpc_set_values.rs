@@ -0,0 +1,305 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+pub struct SetValuesAnswer {
+    pub set: bool,
+    pub subkeys: ValueSubkeyRangeSet,
+    pub values: Vec<(ValueSubkey, SignedValueData)>,
+    pub peers: Vec<Arc<PeerInfo>>,
+}
+
+impl RPCProcessor {
+    /// Sends a set values request for several subkeys of a record and wait for response
+    /// The answer may cover only some of the subkeys sent, the rest must be sent again
+    /// Can be sent via all methods including relays
+    /// Safety routes may be used, but never private routes.
+    /// Because this leaks information about the identity of the node itself,
+    /// replying to this request received over a private route will leak
+    /// the identity of the node and defeat the private route.
+    #[instrument(level = "trace", target = "rpc", skip(self, values, descriptor),
+        fields(values.len = values.len(),
+            ret.set,
+            ret.subkeys.len,
+            ret.values.len,
+            ret.peers.len,
+            ret.latency
+        ), err)]
+    pub async fn rpc_call_set_values(
+        self,
+        dest: Destination,
+        key: TypedKey,
+        values: Vec<(ValueSubkey, SignedValueData)>,
+        descriptor: SignedValueDescriptor,
+        send_descriptor: bool,
+    ) -> RPCNetworkResult<Answer<SetValuesAnswer>> {
+        let _guard = self
+            .unlocked_inner
+            .startup_lock
+            .enter()
+            .map_err(RPCError::map_try_again("not started up"))?;
+
+        // Ensure destination never has a private route
+        // and get the target noderef so we can validate the response
+        let Some(target_node_ids) = dest.get_target_node_ids() else {
+            return Err(RPCError::internal(
+                "Never send set values requests over private routes",
+            ));
+        };
+
+        // Get the target node id
+        let Some(vcrypto) = self.crypto.get(key.kind) else {
+            return Err(RPCError::internal("unsupported cryptosystem"));
+        };
+        let Some(target_node_id) = target_node_ids.get(key.kind) else {
+            return Err(RPCError::internal("No node id for crypto kind"));
+        };
+
+        let subkeys =
+            ValueSubkeyRangeSet::new_with_data(values.iter().map(|(subkey, _)| *subkey).collect());
+
+        let debug_string = format!(
+            "OUT ==> SetValuesQ({} #{} len={}{}) => {}",
+            key,
+            subkeys,
+            subkey_values_data_len(&values),
+            if send_descriptor { " +senddesc" } else { "" },
+            dest
+        );
+
+        // Send the setvalues question
+        let set_values_q = RPCOperationSetValuesQ::new(
+            key,
+            values,
+            if send_descriptor {
+                Some(descriptor.clone())
+            } else {
+                None
+            },
+        )?;
+        let question = RPCQuestion::new(
+            network_result_try!(self.get_destination_respond_to(&dest)?),
+            RPCQuestionDetail::SetValuesQ(Box::new(set_values_q)),
+        );
+        let question_context = QuestionContext::SetValues(ValidateSetValuesContext {
+            descriptor,
+            subkeys,
+            vcrypto: vcrypto.clone(),
+        });
+
+        if debug_target_enabled!("dht") {
+            log_dht!(debug "{}", debug_string);
+        }
+
+        let waitable_reply = network_result_try!(
+            self.question(dest.clone(), question, Some(question_context))
+                .await?
+        );
+
+        // Keep the reply private route that was used to return with the answer
+        let reply_private_route = waitable_reply.reply_private_route;
+
+        // Wait for reply
+        let (msg, latency) = match self.wait_for_reply(waitable_reply, debug_string).await? {
+            TimeoutOr::Timeout => return Ok(NetworkResult::Timeout),
+            TimeoutOr::Value(v) => v,
+        };
+
+        // Get the right answer type
+        let (_, _, kind) = msg.operation.destructure();
+        let set_values_a = match kind {
+            RPCOperationKind::Answer(a) => match a.destructure() {
+                RPCAnswerDetail::SetValuesA(a) => a,
+                _ => return Ok(NetworkResult::invalid_message("not a setvalues answer")),
+            },
+            _ => return Ok(NetworkResult::invalid_message("not an answer")),
+        };
+
+        let (set, subkeys, values, peers) = set_values_a.destructure();
+
+        if debug_target_enabled!("dht") {
+            let debug_string_answer = format!(
+                "OUT <== SetValuesA({} #{}{} values={} peers={}) <= {}",
+                key,
+                subkeys,
+                if set { " +set" } else { "" },
+                values.len(),
+                peers.len(),
+                dest,
+            );
+
+            log_dht!(debug "{}", debug_string_answer);
+
+            let peer_ids: Vec<String> = peers
+                .iter()
+                .filter_map(|p| p.node_ids().get(key.kind).map(|k| k.to_string()))
+                .collect();
+            log_dht!(debug "Peers: {:#?}", peer_ids);
+        }
+
+        // Validate peers returned are, in fact, closer to the key than the node we sent this to
+        let valid = match RoutingTable::verify_peers_closer(vcrypto, target_node_id, key, &peers) {
+            Ok(v) => v,
+            Err(e) => {
+                return Ok(NetworkResult::invalid_message(format!(
+                    "missing cryptosystem in peers node ids: {}",
+                    e
+                )));
+            }
+        };
+        if !valid {
+            return Ok(NetworkResult::invalid_message("non-closer peers returned"));
+        }
+
+        #[cfg(feature = "verbose-tracing")]
+        tracing::Span::current().record("ret.latency", latency.as_u64());
+        #[cfg(feature = "verbose-tracing")]
+        tracing::Span::current().record("ret.set", set);
+        #[cfg(feature = "verbose-tracing")]
+        tracing::Span::current().record("ret.subkeys.len", subkeys.len());
+        #[cfg(feature = "verbose-tracing")]
+        tracing::Span::current().record("ret.values.len", values.len());
+        #[cfg(feature = "verbose-tracing")]
+        tracing::Span::current().record("ret.peers.len", peers.len());
+
+        Ok(NetworkResult::value(Answer::new(
+            latency,
+            reply_private_route,
+            SetValuesAnswer {
+                set,
+                subkeys,
+                values,
+                peers,
+            },
+        )))
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////////////////////
+
+    #[instrument(level = "trace", target = "rpc", skip(self, msg), fields(msg.operation.op_id), ret, err)]
+    pub(crate) async fn process_set_values_q(&self, msg: RPCMessage) -> RPCNetworkResult<()> {
+        // Ensure this never came over a private route, safety route is okay though
+        match &msg.header.detail {
+            RPCMessageHeaderDetail::Direct(_) | RPCMessageHeaderDetail::SafetyRouted(_) => {}
+            RPCMessageHeaderDetail::PrivateRouted(_) => {
+                return Ok(NetworkResult::invalid_message(
+                    "not processing set values request over private route",
+                ))
+            }
+        }
+        let routing_table = self.routing_table();
+        let routing_domain = msg.header.routing_domain();
+
+        // Ignore if disabled
+        let has_capability_dht = routing_table
+            .get_published_peer_info(msg.header.routing_domain())
+            .map(|ppi| {
+                ppi.signed_node_info()
+                    .node_info()
+                    .has_all_capabilities(&[CAP_DHT, CAP_DHT_BATCH])
+            })
+            .unwrap_or(false);
+        if !has_capability_dht {
+            return Ok(NetworkResult::service_unavailable("dht batch operations are not available"));
+        }
+
+        // Get the question
+        let kind = msg.operation.kind().clone();
+        let set_values_q = match kind {
+            RPCOperationKind::Question(q) => match q.destructure() {
+                (_, RPCQuestionDetail::SetValuesQ(q)) => q,
+                _ => panic!("not a setvalues question"),
+            },
+            _ => panic!("not a question"),
+        };
+
+        // Destructure
+        let (key, values, descriptor) = set_values_q.destructure();
+        let sent_subkeys =
+            ValueSubkeyRangeSet::new_with_data(values.iter().map(|(subkey, _)| *subkey).collect());
+
+        // Get target for ValueChanged notifications
+        let dest = network_result_try!(self.get_respond_to_destination(&msg));
+        let rss = routing_table.route_spec_store();
+        let target = dest.get_target(rss)?;
+
+        // Get the nodes that we know about that are closer to the the key than our own node
+        let closer_to_key_peers = network_result_try!(
+            routing_table.find_preferred_peers_closer_to_key(routing_domain, key, vec![CAP_DHT])
+        );
+
+        let debug_string = format!(
+            "IN <=== SetValuesQ({} #{} len={}{}) <== {}",
+            key,
+            sent_subkeys,
+            subkey_values_data_len(&values),
+            if descriptor.is_some() { " +desc" } else { "" },
+            msg.header.direct_sender_node_id()
+        );
+
+        log_rpc!(debug "{}", debug_string);
+
+        // If there are less than 'set_value_count' peers that are closer, then store here too
+        let set_value_count = {
+            let c = self.config.get();
+            c.network.dht.set_value_count as usize
+        };
+        let (set, subkeys, newer_values) = if closer_to_key_peers.len() >= set_value_count {
+            // Not close enough
+            (false, sent_subkeys, vec![])
+        } else {
+            // Close enough, lets set them one subkey at a time until the newer values
+            // we have to return no longer fit in one answer
+            let storage_manager = self.storage_manager();
+            let descriptor = descriptor.map(Arc::new);
+            let mut subkeys = ValueSubkeyRangeSet::new();
+            let mut newer_values = vec![];
+            let mut newer_values_data_len = 0usize;
+            for (subkey, value) in values {
+                let new_value = network_result_try!(storage_manager
+                    .inbound_set_value(key, subkey, Arc::new(value), descriptor.clone(), target)
+                    .await
+                    .map_err(RPCError::internal)?);
+
+                if let Some(new_value) = new_value {
+                    // Nothing was set for this subkey, so leaving it uncovered is harmless
+                    let len = new_value.value_data().data().len();
+                    if !subkeys.is_empty()
+                        && newer_values_data_len + len > MAX_SET_VALUES_A_DATA_LEN
+                    {
+                        break;
+                    }
+                    newer_values_data_len += len;
+                    newer_values.push((subkey, (*new_value).clone()));
+                }
+                subkeys.insert(subkey);
+            }
+
+            (true, subkeys, newer_values)
+        };
+
+        if debug_target_enabled!("dht") {
+            let debug_string_answer = format!(
+                "IN ===> SetValuesA({} #{}{} values={} peers={}) ==> {}",
+                key,
+                subkeys,
+                if set { " +set" } else { "" },
+                newer_values.len(),
+                closer_to_key_peers.len(),
+                msg.header.direct_sender_node_id()
+            );
+
+            log_dht!(debug "{}", debug_string_answer);
+        }
+
+        // Make SetValues answer
+        let set_values_a =
+            RPCOperationSetValuesA::new(set, subkeys, newer_values, closer_to_key_peers)?;
+
+        // Send SetValues answer
+        self.answer(
+            msg,
+            RPCAnswer::new(RPCAnswerDetail::SetValuesA(Box::new(set_values_a))),
+        )
+        .await
+    }
+}
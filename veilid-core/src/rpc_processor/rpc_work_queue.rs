@@ -0,0 +1,287 @@
+use super::*;
+
+/// Priority classes for incoming RPC operations, from highest to lowest priority
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) enum RPCWorkClass {
+    /// Answers to our questions and return receipts
+    Answer,
+    /// Status, find_node, and network control operations
+    Status,
+    /// DHT and application operations
+    DHT,
+    /// Relayed route operations and anything received over a route
+    Routed,
+}
+
+impl RPCWorkClass {
+    pub const ALL: [RPCWorkClass; 4] = [
+        RPCWorkClass::Answer,
+        RPCWorkClass::Status,
+        RPCWorkClass::DHT,
+        RPCWorkClass::Routed,
+    ];
+
+    fn index(&self) -> usize {
+        match self {
+            RPCWorkClass::Answer => 0,
+            RPCWorkClass::Status => 1,
+            RPCWorkClass::DHT => 2,
+            RPCWorkClass::Routed => 3,
+        }
+    }
+
+    /// Classify an encoded RPC message by peeking at its operation kind
+    /// Messages that can not be read are put in the lowest class, and will fail to decode later
+    pub(super) fn classify(encoded_msg: &RPCMessageEncoded) -> Self {
+        let routed = !matches!(encoded_msg.header.detail, RPCMessageHeaderDetail::Direct(_));
+        let Ok(reader) = encoded_msg.data.get_reader() else {
+            return RPCWorkClass::Routed;
+        };
+        let Ok(op_reader) = reader.get_root::<veilid_capnp::operation::Reader>() else {
+            return RPCWorkClass::Routed;
+        };
+        match op_reader.get_kind().which() {
+            Ok(veilid_capnp::operation::kind::Which::Answer(_)) => RPCWorkClass::Answer,
+            Ok(veilid_capnp::operation::kind::Which::Question(Ok(q_reader))) => {
+                if routed {
+                    return RPCWorkClass::Routed;
+                }
+                match q_reader.get_detail().which() {
+                    Ok(veilid_capnp::question::detail::Which::StatusQ(_))
+                    | Ok(veilid_capnp::question::detail::Which::FindNodeQ(_)) => {
+                        RPCWorkClass::Status
+                    }
+                    Ok(_) => RPCWorkClass::DHT,
+                    Err(_) => RPCWorkClass::Routed,
+                }
+            }
+            Ok(veilid_capnp::operation::kind::Which::Statement(Ok(s_reader))) => {
+                match s_reader.get_detail().which() {
+                    Ok(veilid_capnp::statement::detail::Which::ReturnReceipt(_)) => {
+                        RPCWorkClass::Answer
+                    }
+                    Ok(_) if routed => RPCWorkClass::Routed,
+                    Ok(veilid_capnp::statement::detail::Which::ValidateDialInfo(_))
+                    | Ok(veilid_capnp::statement::detail::Which::Signal(_)) => RPCWorkClass::Status,
+                    Ok(veilid_capnp::statement::detail::Which::Route(_)) => RPCWorkClass::Routed,
+                    Ok(_) => RPCWorkClass::DHT,
+                    Err(_) => RPCWorkClass::Routed,
+                }
+            }
+            _ => RPCWorkClass::Routed,
+        }
+    }
+}
+
+impl fmt::Display for RPCWorkClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RPCWorkClass::Answer => write!(f, "answer"),
+            RPCWorkClass::Status => write!(f, "status"),
+            RPCWorkClass::DHT => write!(f, "dht"),
+            RPCWorkClass::Routed => write!(f, "routed"),
+        }
+    }
+}
+
+/// Counters for one RPC work queue class
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct RPCWorkQueueStats {
+    /// Operations currently waiting in the queue
+    pub queued: usize,
+    /// Number of distinct senders with operations waiting in the queue
+    pub senders: usize,
+    /// Total operations accepted into the queue
+    pub enqueued: u64,
+    /// Total operations taken from the queue by a worker
+    pub processed: u64,
+    /// Total operations dropped because the queue or the sender's share of it was full
+    pub dropped: u64,
+}
+
+/// A queue that takes items from each sender in turn
+#[derive(Debug)]
+struct FairQueue<T> {
+    max_len: usize,
+    max_per_sender: usize,
+    senders: HashMap<TypedKey, VecDeque<T>>,
+    order: VecDeque<TypedKey>,
+    stats: RPCWorkQueueStats,
+}
+
+impl<T> FairQueue<T> {
+    fn new(max_len: usize, max_per_sender: usize) -> Self {
+        Self {
+            max_len,
+            max_per_sender,
+            senders: HashMap::new(),
+            order: VecDeque::new(),
+            stats: RPCWorkQueueStats::default(),
+        }
+    }
+
+    fn push(&mut self, sender: TypedKey, item: T) -> Result<(), T> {
+        if self.stats.queued >= self.max_len {
+            self.stats.dropped += 1;
+            return Err(item);
+        }
+        let sender_queue = self.senders.entry(sender).or_default();
+        if sender_queue.len() >= self.max_per_sender {
+            self.stats.dropped += 1;
+            return Err(item);
+        }
+        if sender_queue.is_empty() {
+            self.order.push_back(sender);
+        }
+        sender_queue.push_back(item);
+        self.stats.queued += 1;
+        self.stats.enqueued += 1;
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let sender = self.order.pop_front()?;
+        let sender_queue = self.senders.get_mut(&sender)?;
+        let item = sender_queue.pop_front()?;
+        if sender_queue.is_empty() {
+            self.senders.remove(&sender);
+        } else {
+            // Go to the back of the line
+            self.order.push_back(sender);
+        }
+        self.stats.queued -= 1;
+        self.stats.processed += 1;
+        Some(item)
+    }
+
+    fn stats(&self) -> RPCWorkQueueStats {
+        RPCWorkQueueStats {
+            senders: self.senders.len(),
+            ..self.stats
+        }
+    }
+}
+
+/// Incoming RPC work, queued by priority class with per-sender fairness within each class
+#[derive(Debug)]
+pub(crate) struct RPCWorkQueues<T> {
+    max_len: usize,
+    len: usize,
+    queues: [FairQueue<T>; 4],
+}
+
+impl<T> RPCWorkQueues<T> {
+    pub fn new(max_len: usize, c: &VeilidConfigRPCQueues) -> Self {
+        let max_per_sender = c.max_queued_per_sender as usize;
+        Self {
+            max_len,
+            len: 0,
+            queues: [
+                FairQueue::new(c.answer_queue_size as usize, max_per_sender),
+                FairQueue::new(c.status_queue_size as usize, max_per_sender),
+                FairQueue::new(c.dht_queue_size as usize, max_per_sender),
+                FairQueue::new(c.routed_queue_size as usize, max_per_sender),
+            ],
+        }
+    }
+
+    /// Queue an item from a sender, returning it if there is no room for it
+    pub fn push(&mut self, class: RPCWorkClass, sender: TypedKey, item: T) -> Result<(), T> {
+        let queue = &mut self.queues[class.index()];
+        if self.len >= self.max_len {
+            queue.stats.dropped += 1;
+            return Err(item);
+        }
+        queue.push(sender, item)?;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Take the next item from the highest priority class that has one
+    pub fn pop(&mut self) -> Option<(RPCWorkClass, T)> {
+        for class in RPCWorkClass::ALL {
+            if let Some(item) = self.queues[class.index()].pop() {
+                self.len -= 1;
+                return Some((class, item));
+            }
+        }
+        None
+    }
+
+    /// Get the counters for each class
+    pub fn stats(&self) -> Vec<(RPCWorkClass, RPCWorkQueueStats)> {
+        RPCWorkClass::ALL
+            .iter()
+            .map(|class| (*class, self.queues[class.index()].stats()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sender(n: u8) -> TypedKey {
+        TypedKey::new(CRYPTO_KIND_VLD0, CryptoKey::new([n; CRYPTO_KEY_LENGTH]))
+    }
+
+    fn config() -> VeilidConfigRPCQueues {
+        VeilidConfigRPCQueues {
+            answer_queue_size: 4,
+            status_queue_size: 4,
+            dht_queue_size: 4,
+            routed_queue_size: 4,
+            max_queued_per_sender: 2,
+        }
+    }
+
+    #[test]
+    fn test_priority_order() {
+        let mut queues = RPCWorkQueues::new(16, &config());
+        queues.push(RPCWorkClass::Routed, sender(1), 1).unwrap();
+        queues.push(RPCWorkClass::DHT, sender(1), 2).unwrap();
+        queues.push(RPCWorkClass::Status, sender(1), 3).unwrap();
+        queues.push(RPCWorkClass::Answer, sender(1), 4).unwrap();
+
+        assert_eq!(queues.pop(), Some((RPCWorkClass::Answer, 4)));
+        assert_eq!(queues.pop(), Some((RPCWorkClass::Status, 3)));
+        assert_eq!(queues.pop(), Some((RPCWorkClass::DHT, 2)));
+        assert_eq!(queues.pop(), Some((RPCWorkClass::Routed, 1)));
+        assert_eq!(queues.pop(), None);
+    }
+
+    #[test]
+    fn test_sender_fairness() {
+        let mut queues = RPCWorkQueues::new(16, &config());
+        queues.push(RPCWorkClass::DHT, sender(1), 10).unwrap();
+        queues.push(RPCWorkClass::DHT, sender(1), 11).unwrap();
+        // Sender's share of the class is full
+        assert_eq!(queues.push(RPCWorkClass::DHT, sender(1), 12), Err(12));
+        queues.push(RPCWorkClass::DHT, sender(2), 20).unwrap();
+
+        assert_eq!(queues.pop(), Some((RPCWorkClass::DHT, 10)));
+        assert_eq!(queues.pop(), Some((RPCWorkClass::DHT, 20)));
+        assert_eq!(queues.pop(), Some((RPCWorkClass::DHT, 11)));
+        assert_eq!(queues.pop(), None);
+
+        let stats = queues.stats();
+        let (_, dht_stats) = stats[RPCWorkClass::DHT.index()];
+        assert_eq!(dht_stats.enqueued, 3);
+        assert_eq!(dht_stats.processed, 3);
+        assert_eq!(dht_stats.dropped, 1);
+        assert_eq!(dht_stats.queued, 0);
+    }
+
+    #[test]
+    fn test_queue_limits() {
+        let mut queues = RPCWorkQueues::new(5, &config());
+        for n in 0..4 {
+            queues.push(RPCWorkClass::Routed, sender(n), n).unwrap();
+        }
+        // Class is full
+        assert_eq!(queues.push(RPCWorkClass::Routed, sender(9), 9), Err(9));
+        queues.push(RPCWorkClass::Status, sender(9), 9).unwrap();
+        // Total is full
+        assert_eq!(queues.push(RPCWorkClass::Answer, sender(9), 10), Err(10));
+    }
+}
@@ -1,19 +1,22 @@
 use super::*;
 
 impl StorageManager {
-    pub(crate) async fn debug_local_records(&self) -> String {
+    pub(crate) async fn debug_records(
+        &self,
+        scope: DebugRecordScope,
+    ) -> VeilidAPIResult<DebugRecordStore> {
         let inner = self.inner.lock().await;
-        let Some(local_record_store) = &inner.local_record_store else {
-            return "not initialized".to_owned();
+        let record_store = match scope {
+            DebugRecordScope::Local => inner.local_record_store.as_ref().map(|x| x.debug_records()),
+            DebugRecordScope::Remote => inner
+                .remote_record_store
+                .as_ref()
+                .map(|x| x.debug_records()),
         };
-        local_record_store.debug_records()
-    }
-    pub(crate) async fn debug_remote_records(&self) -> String {
-        let inner = self.inner.lock().await;
-        let Some(remote_record_store) = &inner.remote_record_store else {
-            return "not initialized".to_owned();
+        let Some(record_store) = record_store else {
+            apibail_not_initialized!();
         };
-        remote_record_store.debug_records()
+        Ok(record_store)
     }
     pub(crate) async fn debug_opened_records(&self) -> String {
         let inner = self.inner.lock().await;
@@ -89,27 +92,25 @@ impl StorageManager {
             .debug_record_subkey_info(key, subkey)
             .await
     }
-    pub(crate) async fn debug_local_record_info(&self, key: TypedKey) -> String {
+    pub(crate) async fn debug_record(
+        &self,
+        scope: DebugRecordScope,
+        key: TypedKey,
+    ) -> VeilidAPIResult<Option<DebugRecord>> {
         let inner = self.inner.lock().await;
-        let Some(local_record_store) = &inner.local_record_store else {
-            return "not initialized".to_owned();
-        };
-        let local_debug = local_record_store.debug_record_info(key);
-
-        let opened_debug = if let Some(o) = inner.opened_records.get(&key) {
-            format!("Opened Record: {:#?}\n", o)
-        } else {
-            "".to_owned()
+        let record = match scope {
+            DebugRecordScope::Local => inner
+                .local_record_store
+                .as_ref()
+                .map(|x| x.debug_record(key)),
+            DebugRecordScope::Remote => inner
+                .remote_record_store
+                .as_ref()
+                .map(|x| x.debug_record(key)),
         };
-
-        format!("{}\n{}", local_debug, opened_debug)
-    }
-
-    pub(crate) async fn debug_remote_record_info(&self, key: TypedKey) -> String {
-        let inner = self.inner.lock().await;
-        let Some(remote_record_store) = &inner.remote_record_store else {
-            return "not initialized".to_owned();
+        let Some(record) = record else {
+            apibail_not_initialized!();
         };
-        remote_record_store.debug_record_info(key)
+        Ok(record)
     }
 }
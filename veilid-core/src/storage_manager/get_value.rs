@@ -377,6 +377,7 @@ impl StorageManager {
                     key,
                     subkey,
                     get_result_value.clone(),
+                    None,
                     WatchUpdateMode::UpdateAll,
                 )
                 .await?;
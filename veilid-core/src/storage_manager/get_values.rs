@@ -0,0 +1,319 @@
+use super::*;
+
+/// Info tracked per subkey
+struct SubkeyValueCount {
+    /// The newest value found for a subkey, may be the value passed in
+    pub value: Option<Arc<SignedValueData>>,
+    /// The nodes that have returned the value so far (up to the consensus count)
+    pub value_nodes: Vec<NodeRef>,
+}
+
+/// The context of the outbound_get_values operation
+struct OutboundGetValuesContext {
+    /// The newest values and result counts so far, one for each subkey being retrieved
+    pub values: Vec<SubkeyValueCount>,
+}
+
+/// The result of the outbound_get_values operation
+pub(super) struct OutboundGetValuesResult {
+    /// Fanout results for each subkey
+    pub fanout_results: Vec<FanoutResult>,
+    /// The newest value found for each subkey
+    pub values: Vec<Option<Arc<SignedValueData>>>,
+}
+
+impl StorageManager {
+    /// Perform a 'get values' query on the network
+    /// Each node visited is asked for all of the subkeys that do not have consensus yet
+    /// The number of subkeys must not exceed what fits in a single request
+    #[instrument(level = "trace", target = "dht", skip_all, err)]
+    pub(super) async fn outbound_get_values(
+        &self,
+        rpc_processor: RPCProcessor,
+        key: TypedKey,
+        subkeys: ValueSubkeyRangeSet,
+        safety_selection: SafetySelection,
+        descriptor: Arc<SignedValueDescriptor>,
+        last_values: Vec<Option<Arc<SignedValueData>>>,
+    ) -> VeilidAPIResult<OutboundGetValuesResult> {
+        let routing_table = rpc_processor.routing_table();
+        let routing_domain = RoutingDomain::PublicInternet;
+
+        // Get the DHT parameters for 'GetValue'
+        let (key_count, consensus_count, fanout, timeout_us) = {
+            let c = self.unlocked_inner.config.get();
+            (
+                c.network.dht.max_find_node_count as usize,
+                c.network.dht.get_value_count as usize,
+                c.network.dht.get_value_fanout as usize,
+                TimestampDuration::from(ms_to_us(c.network.dht.get_value_timeout_ms)),
+            )
+        };
+
+        // Get the nodes we know are caching this value to seed the fanout
+        let init_fanout_queue = {
+            let inner = self.inner.lock().await;
+            inner
+                .get_value_nodes(key)?
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|x| {
+                    x.node_info(routing_domain)
+                        .map(|ni| ni.has_all_capabilities(&[CAP_DHT]))
+                        .unwrap_or_default()
+                })
+                .collect()
+        };
+
+        // Parse the schema
+        let schema = descriptor.schema()?;
+
+        // Make do-get-values answer context
+        let context = Arc::new(Mutex::new(OutboundGetValuesContext {
+            values: last_values
+                .into_iter()
+                .map(|value| SubkeyValueCount {
+                    value,
+                    value_nodes: vec![],
+                })
+                .collect(),
+        }));
+
+        // Routine to call to generate fanout
+        let call_routine = |next_node: NodeRef| {
+            let rpc_processor = rpc_processor.clone();
+            let context = context.clone();
+            let descriptor = descriptor.clone();
+            let schema = schema.clone();
+            let subkeys = subkeys.clone();
+            async move {
+                // Ask for every subkey this fanout has not reached consensus on yet
+                let mut pending_subkeys = {
+                    let ctx = context.lock();
+                    let mut pending_subkeys = ValueSubkeyRangeSet::new();
+                    for (subkey, svc) in subkeys.iter().zip(ctx.values.iter()) {
+                        if svc.value_nodes.len() < consensus_count {
+                            pending_subkeys.insert(subkey);
+                        }
+                    }
+                    pending_subkeys
+                };
+
+                // Nodes that can not decode batched questions are asked for one subkey at a time
+                let can_batch = next_node
+                    .node_info(routing_domain)
+                    .map(|ni| ni.has_capability(CAP_DHT_BATCH))
+                    .unwrap_or_default();
+
+                // Keep asking this node until it has covered all of the subkeys we want from it
+                let mut peer_info_list = vec![];
+                while !pending_subkeys.is_empty() {
+                    let dest = Destination::direct(next_node.routing_domain_filtered(routing_domain))
+                        .with_safety(safety_selection);
+                    let answer = if can_batch {
+                        network_result_try!(
+                            rpc_processor
+                                .clone()
+                                .rpc_call_get_values(
+                                    dest,
+                                    key,
+                                    pending_subkeys.clone(),
+                                    (*descriptor).clone(),
+                                )
+                                .await?
+                        )
+                        .answer
+                    } else {
+                        let Some(subkey) = pending_subkeys.first() else {
+                            break;
+                        };
+                        let answer = network_result_try!(
+                            rpc_processor
+                                .clone()
+                                .rpc_call_get_value(dest, key, subkey, Some((*descriptor).clone()))
+                                .await?
+                        )
+                        .answer;
+                        GetValuesAnswer {
+                            subkeys: ValueSubkeyRangeSet::single(subkey),
+                            values: answer.value.map(|v| (subkey, v)).into_iter().collect(),
+                            peers: answer.peers,
+                        }
+                    };
+                    peer_info_list = answer.peers;
+
+                    // Answers always cover at least one pending subkey so this terminates
+                    pending_subkeys = pending_subkeys.difference(&answer.subkeys);
+
+                    if !answer.values.is_empty() {
+                        log_dht!(debug "GetValues got values back: count={}", answer.values.len());
+                    }
+
+                    let mut ctx = context.lock();
+                    for (subkey, value) in answer.values {
+                        // Validate with schema
+                        if !schema.check_subkey_value_data(
+                            descriptor.owner(),
+                            subkey,
+                            value.value_data(),
+                        ) {
+                            // Validation failed, ignore this node
+                            // Move to the next node
+                            return Ok(NetworkResult::invalid_message(format!(
+                                "Schema validation failed on subkey {}",
+                                subkey
+                            )));
+                        }
+
+                        let Some(idx) = subkeys.idx_of_subkey(subkey) else {
+                            return Err(RPCError::internal("answer covered subkey not requested"));
+                        };
+                        let svc = &mut ctx.values[idx];
+
+                        // If we have a prior value, see if this is a newer sequence number
+                        if let Some(prior_value) = &svc.value {
+                            let prior_seq = prior_value.value_data().seq();
+                            let new_seq = value.value_data().seq();
+
+                            if new_seq == prior_seq {
+                                // If sequence number is the same, the data should be the same
+                                if prior_value.value_data() != value.value_data() {
+                                    // Move to the next node
+                                    return Ok(NetworkResult::invalid_message(
+                                        "value data mismatch",
+                                    ));
+                                }
+                                // Increase the consensus count for the existing value
+                                svc.value_nodes.push(next_node.clone());
+                            } else if new_seq > prior_seq {
+                                // If the sequence number is greater, start over with the new value
+                                svc.value = Some(Arc::new(value));
+                                // One node has shown us this value so far
+                                svc.value_nodes = vec![next_node.clone()];
+                            } else {
+                                // If the sequence number is older, ignore it
+                            }
+                        } else {
+                            // If we have no prior value, keep it
+                            svc.value = Some(Arc::new(value));
+                            // One node has shown us this value so far
+                            svc.value_nodes = vec![next_node.clone()];
+                        }
+                    }
+                }
+
+                // Return peers if we have some
+                log_network_result!(debug "GetValues fanout call returned peers {}", peer_info_list.len());
+
+                Ok(NetworkResult::value(FanoutCallOutput { peer_info_list }))
+            }.instrument(tracing::trace_span!("outbound_get_values fanout call"))
+        };
+
+        // Routine to call to check if we're done at each step
+        let check_done = |_closest_nodes: &[NodeRef]| {
+            // If we have reached sufficient consensus on all subkeys, return done
+            let ctx = context.lock();
+            if ctx
+                .values
+                .iter()
+                .all(|svc| svc.value.is_some() && svc.value_nodes.len() >= consensus_count)
+            {
+                return Some(());
+            }
+            None
+        };
+
+        // Call the fanout
+        let fanout_call = FanoutCall::new(
+            routing_table.clone(),
+            key,
+            key_count,
+            fanout,
+            timeout_us,
+            // Nodes without batch support are still visited, and asked for each subkey in turn
+            capability_fanout_node_info_filter(vec![CAP_DHT]),
+            call_routine,
+            check_done,
+        );
+
+        let kind = match fanout_call.run(init_fanout_queue).await {
+            // If we don't finish in the timeout (too much time passed checking for consensus)
+            TimeoutOr::Timeout => FanoutResultKind::Timeout,
+            // If we finished with or without consensus (enough nodes returning the same value)
+            TimeoutOr::Value(Ok(Some(()))) => FanoutResultKind::Finished,
+            // If we ran out of nodes before getting consensus)
+            TimeoutOr::Value(Ok(None)) => FanoutResultKind::Exhausted,
+            // Failed
+            TimeoutOr::Value(Err(e)) => {
+                // If we finished with an error, return that
+                log_dht!(debug "GetValues Fanout Error: {}", e);
+                return Err(e.into());
+            }
+        };
+
+        let ctx = context.lock();
+        let mut fanout_results = vec![];
+        for svc in &ctx.values {
+            let has_consensus = svc.value_nodes.len() >= consensus_count;
+            let fanout_result = FanoutResult {
+                kind: if has_consensus {
+                    FanoutResultKind::Finished
+                } else {
+                    kind
+                },
+                value_nodes: svc.value_nodes.clone(),
+            };
+            fanout_results.push(fanout_result);
+        }
+
+        log_network_result!(debug "GetValues Fanout ({:?}):\n{}", kind, debug_fanout_results(&fanout_results));
+
+        Ok(OutboundGetValuesResult {
+            fanout_results,
+            values: ctx.values.iter().map(|svc| svc.value.clone()).collect(),
+        })
+    }
+
+    /// Handle a received 'Get Values' query
+    /// Covers as many of the requested subkeys, in order, as fit in a single answer
+    #[instrument(level = "trace", target = "dht", skip_all)]
+    pub async fn inbound_get_values(
+        &self,
+        key: TypedKey,
+        subkeys: ValueSubkeyRangeSet,
+        want_descriptor: bool,
+    ) -> VeilidAPIResult<NetworkResult<GetValuesResult>> {
+        let mut inner = self.lock().await?;
+
+        let mut get_values_result = GetValuesResult::default();
+        let mut values_data_len = 0usize;
+        for subkey in subkeys.iter() {
+            // See if the subkey we are getting has a last known local value
+            let mut last_get_result = inner.handle_get_local_value(key, subkey, true).await?;
+            // If this is local, it must have a descriptor already
+            if last_get_result.opt_descriptor.is_none() {
+                // See if the subkey we are getting has a last known remote value
+                last_get_result = inner.handle_get_remote_value(key, subkey, true).await?;
+            }
+
+            if let Some(value) = last_get_result.opt_value {
+                // Always return at least one subkey, and stop when the next value would not fit
+                let len = value.value_data().data().len();
+                if !get_values_result.subkeys.is_empty()
+                    && values_data_len + len > MAX_GET_VALUES_A_DATA_LEN
+                {
+                    break;
+                }
+                values_data_len += len;
+                get_values_result.values.push((subkey, value));
+            }
+            get_values_result.subkeys.insert(subkey);
+
+            if want_descriptor && get_values_result.opt_descriptor.is_none() {
+                get_values_result.opt_descriptor = last_get_result.opt_descriptor;
+            }
+        }
+
+        Ok(NetworkResult::value(get_values_result))
+    }
+}
@@ -0,0 +1,300 @@
+use super::*;
+
+/// Identifies a subkey that holds a large value manifest
+const LARGE_VALUE_MANIFEST_MAGIC: &[u8; 4] = b"LVM0";
+/// The length of an encoded large value manifest
+const LARGE_VALUE_MANIFEST_LENGTH: usize = 4 + 4 + 4 + HASH_DIGEST_LENGTH;
+/// The size of each chunk of a large value, only the last chunk may be shorter
+/// Leaves room for the chunks to be sealed if the record is encrypted
+const LARGE_VALUE_CHUNK_SIZE: usize = ValueData::MAX_LEN - RECORD_SEAL_MAX_OVERHEAD;
+
+/// Describes a large value stored in the subkeys that follow the manifest subkey
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LargeValueManifest {
+    /// The total length of the value in bytes
+    total_len: u32,
+    /// The number of chunk subkeys that follow the manifest subkey
+    chunk_count: u32,
+    /// The hash of the whole value, using the record's cryptosystem
+    hash: HashDigest,
+}
+
+impl LargeValueManifest {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(LARGE_VALUE_MANIFEST_LENGTH);
+        bytes.extend_from_slice(LARGE_VALUE_MANIFEST_MAGIC);
+        bytes.extend_from_slice(&self.total_len.to_le_bytes());
+        bytes.extend_from_slice(&self.chunk_count.to_le_bytes());
+        bytes.extend_from_slice(&self.hash.bytes);
+        bytes
+    }
+
+    fn try_from_bytes(bytes: &[u8]) -> VeilidAPIResult<Self> {
+        if bytes.len() != LARGE_VALUE_MANIFEST_LENGTH || &bytes[0..4] != LARGE_VALUE_MANIFEST_MAGIC
+        {
+            apibail_parse_error!("not a large value manifest", format!("len={}", bytes.len()));
+        }
+        let total_len = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        let chunk_count = u32::from_le_bytes(bytes[8..12].try_into().unwrap());
+        let hash = HashDigest::try_from(&bytes[12..LARGE_VALUE_MANIFEST_LENGTH])?;
+
+        // The chunk count must be the one the writer would have used for this length
+        if chunk_count as usize != (total_len as usize).div_ceil(LARGE_VALUE_CHUNK_SIZE) {
+            apibail_parse_error!(
+                "large value manifest chunk count mismatch",
+                format!("total_len={} chunk_count={}", total_len, chunk_count)
+            );
+        }
+
+        Ok(Self {
+            total_len,
+            chunk_count,
+            hash,
+        })
+    }
+}
+
+impl StorageManager {
+    /// Write a value that may be larger than a single subkey to an opened local record
+    /// The value is split into chunks written to the subkeys following 'subkey', and a manifest
+    /// with the length and hash of the value is written to 'subkey' itself once the chunks are written
+    /// Chunks left over from a longer value that was previously written at 'subkey' are cleared along with them
+    /// If the write fails before the manifest is written, the old manifest still says which chunks to clear on a retry
+    /// Returns the range of subkeys used, including the manifest subkey
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn set_large_value(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        data: Vec<u8>,
+        writer: Option<KeyPair>,
+    ) -> VeilidAPIResult<ValueSubkeyRangeSet> {
+        // Get cryptosystem
+        let Some(vcrypto) = self.unlocked_inner.crypto.get(key.kind) else {
+            apibail_generic!("unsupported cryptosystem");
+        };
+
+        let Ok(total_len) = u32::try_from(data.len()) else {
+            apibail_invalid_argument!("value too large", "data.len", data.len());
+        };
+
+        // Split the value into chunks, one per subkey after the manifest subkey
+        let chunk_count = data.len().div_ceil(LARGE_VALUE_CHUNK_SIZE) as u32;
+        let Some(last_subkey) = subkey.checked_add(chunk_count) else {
+            apibail_invalid_argument!("value does not fit after subkey", "subkey", subkey);
+        };
+        let mut chunks: Vec<(ValueSubkey, Vec<u8>)> = (subkey + 1..=last_subkey)
+            .zip(data.chunks(LARGE_VALUE_CHUNK_SIZE))
+            .map(|(chunk_subkey, chunk)| (chunk_subkey, chunk.to_vec()))
+            .collect();
+
+        let manifest = LargeValueManifest {
+            total_len,
+            chunk_count,
+            hash: vcrypto.generate_hash(&data),
+        };
+
+        // Find how many chunks the value we are replacing used, if any
+        // Offline with nothing stored locally there is no earlier value, and the write is queued like any other
+        let old_chunk_count = match self
+            .get_value(key, subkey, false)
+            .await
+            .ok_try_again()?
+            .flatten()
+        {
+            Some(old_manifest_value) => {
+                LargeValueManifest::try_from_bytes(old_manifest_value.data())
+                    .map(|old_manifest| old_manifest.chunk_count)
+                    .unwrap_or_default()
+            }
+            None => 0,
+        };
+
+        // Clear the trailing chunks of a longer previous value in the same write as the new chunks
+        // The old manifest was written after them, so they fit in the schema
+        if old_chunk_count > chunk_count {
+            chunks.extend(
+                (last_subkey + 1..=subkey.saturating_add(old_chunk_count))
+                    .map(|chunk_subkey| (chunk_subkey, Vec::new())),
+            );
+        }
+
+        // Write the chunks before the manifest so the manifest never refers to chunks that were not written
+        // If the network had newer values for any of them, the local copies are now up to date and the write can be retried
+        if !chunks.is_empty() {
+            let results = self.set_values(key, chunks, writer).await?;
+            if results.iter().any(|(_, opt_value)| opt_value.is_some()) {
+                apibail_try_again!("newer large value chunks were found on the network");
+            }
+        }
+
+        let opt_newer_manifest = self
+            .set_value(
+                key,
+                subkey,
+                manifest.to_bytes(),
+                Some(SetDHTValueOptions {
+                    writer,
+                    expected_seq: None,
+                }),
+            )
+            .await?;
+        if opt_newer_manifest.is_some() {
+            apibail_try_again!("newer large value manifest was found on the network");
+        }
+
+        Ok(ValueSubkeyRangeSet::single_range(subkey, last_subkey))
+    }
+
+    /// Read a value written with set_large_value from an opened local record
+    /// The chunks are reassembled and checked against the length and hash in the manifest at 'subkey'
+    /// If the local chunks do not match the manifest they are refreshed from the network
+    /// Returns None if the manifest subkey has not been set
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn get_large_value(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        force_refresh: bool,
+    ) -> VeilidAPIResult<Option<Vec<u8>>> {
+        // Get cryptosystem
+        let Some(vcrypto) = self.unlocked_inner.crypto.get(key.kind) else {
+            apibail_generic!("unsupported cryptosystem");
+        };
+
+        let Some(manifest_value) = self.get_value(key, subkey, force_refresh).await? else {
+            return Ok(None);
+        };
+        let manifest = LargeValueManifest::try_from_bytes(manifest_value.data())?;
+
+        if let Some(data) = self
+            .get_large_value_chunks(vcrypto.clone(), key, subkey, &manifest, force_refresh)
+            .await?
+        {
+            return Ok(Some(data));
+        }
+
+        // The chunks we had locally may be from an older write, so try again with the newest ones
+        if !force_refresh {
+            if let Some(data) = self
+                .get_large_value_chunks(vcrypto, key, subkey, &manifest, true)
+                .await?
+            {
+                return Ok(Some(data));
+            }
+        }
+
+        // A write may still be in progress, or was interrupted before completing
+        apibail_try_again!("large value does not match its manifest");
+    }
+
+    /// Reassemble the chunks of a large value and verify them against its manifest
+    /// Returns None if the chunks do not match the manifest
+    async fn get_large_value_chunks(
+        &self,
+        vcrypto: CryptoSystemVersion,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        manifest: &LargeValueManifest,
+        force_refresh: bool,
+    ) -> VeilidAPIResult<Option<Vec<u8>>> {
+        let mut data = Vec::with_capacity(manifest.total_len as usize);
+        if manifest.chunk_count != 0 {
+            let Some(last_subkey) = subkey.checked_add(manifest.chunk_count) else {
+                apibail_parse_error!(
+                    "large value manifest has too many chunks",
+                    manifest.chunk_count
+                );
+            };
+            let chunks = self
+                .get_values(
+                    key,
+                    ValueSubkeyRangeSet::single_range(subkey + 1, last_subkey),
+                    force_refresh,
+                )
+                .await?;
+            if chunks.len() != manifest.chunk_count as usize {
+                apibail_generic!("large value extends past the end of the schema");
+            }
+            for (_, opt_chunk) in chunks {
+                let Some(chunk) = opt_chunk else {
+                    return Ok(None);
+                };
+                data.extend_from_slice(chunk.data());
+            }
+        }
+
+        if data.len() != manifest.total_len as usize
+            || !vcrypto.validate_hash(&data, &manifest.hash)
+        {
+            return Ok(None);
+        }
+
+        Ok(Some(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock_routing_table;
+
+    #[test]
+    fn large_value_chunk_size_fits_sealed() {
+        let crypto = mock_routing_table().crypto();
+        for ck in VALID_CRYPTO_KINDS {
+            let vcrypto = crypto.get(ck).unwrap();
+            assert!(record_seal_overhead(&vcrypto) <= RECORD_SEAL_MAX_OVERHEAD);
+            let sealed = vcrypto
+                .encrypt_aead(
+                    &vec![0u8; LARGE_VALUE_CHUNK_SIZE],
+                    &vcrypto.random_nonce(),
+                    &vcrypto.random_shared_secret(),
+                    None,
+                )
+                .unwrap();
+            assert!(NONCE_LENGTH + sealed.len() <= ValueData::MAX_LEN);
+        }
+    }
+
+    #[test]
+    fn large_value_manifest_round_trip() {
+        let manifest = LargeValueManifest {
+            total_len: (LARGE_VALUE_CHUNK_SIZE * 2 + 1) as u32,
+            chunk_count: 3,
+            hash: CryptoKey { bytes: [7; 32] },
+        };
+        let bytes = manifest.to_bytes();
+        assert_eq!(bytes.len(), LARGE_VALUE_MANIFEST_LENGTH);
+        assert_eq!(
+            LargeValueManifest::try_from_bytes(&bytes).unwrap(),
+            manifest
+        );
+    }
+
+    #[test]
+    fn large_value_manifest_invalid() {
+        let manifest = LargeValueManifest {
+            total_len: 1,
+            chunk_count: 1,
+            hash: CryptoKey { bytes: [0; 32] },
+        };
+
+        // Wrong length
+        let bytes = manifest.to_bytes();
+        assert!(LargeValueManifest::try_from_bytes(&bytes[1..]).is_err());
+
+        // Wrong magic
+        let mut bytes = manifest.to_bytes();
+        bytes[0] = b'X';
+        assert!(LargeValueManifest::try_from_bytes(&bytes).is_err());
+
+        // Chunk count does not match the length
+        let bytes = LargeValueManifest {
+            chunk_count: 2,
+            ..manifest
+        }
+        .to_bytes();
+        assert!(LargeValueManifest::try_from_bytes(&bytes).is_err());
+    }
+}
@@ -1,20 +1,28 @@
 mod debug;
 mod get_value;
+mod get_values;
 mod inspect_value;
+mod large_value;
+mod record_encryption;
 mod record_store;
+mod record_successor;
 mod set_value;
+mod set_values;
 mod storage_manager_inner;
 mod tasks;
+#[doc(hidden)]
+pub mod tests;
 mod types;
 mod watch_value;
 
 use super::*;
+use record_encryption::*;
 use record_store::*;
 use routing_table::*;
 use rpc_processor::*;
 use storage_manager_inner::*;
 
-pub use record_store::{WatchParameters, WatchResult};
+pub use record_store::{GetValuesResult, WatchParameters, WatchResult};
 pub use types::*;
 
 /// The maximum size of a single subkey
@@ -59,6 +67,9 @@ struct StorageManagerUnlockedInner {
 
     // Anonymous watch keys
     anonymous_watch_keys: TypedKeyPairGroup,
+
+    // The node holding each of our active watches, readable without locking the inner state
+    active_watch_nodes: Mutex<HashMap<TypedKey, NodeRef>>,
 }
 
 #[derive(Clone)]
@@ -110,6 +121,7 @@ impl StorageManager {
             ),
 
             anonymous_watch_keys,
+            active_watch_nodes: Mutex::new(HashMap::new()),
         }
     }
     fn new_inner(unlocked_inner: Arc<StorageManagerUnlockedInner>) -> StorageManagerInner {
@@ -154,9 +166,10 @@ impl StorageManager {
         log_stor!(debug "starting storage manager shutdown");
 
         // Stop the background ticker process
-        {
-            let mut inner = self.inner.lock().await;
-            inner.stop_ticker().await;
+        // Wait for it without holding the lock, since a tick in progress may need it
+        let tick_future = self.inner.lock().await.stop_ticker();
+        if let Some(f) = tick_future {
+            f.await;
         }
 
         // Cancel all tasks
@@ -168,6 +181,7 @@ impl StorageManager {
             inner.terminate().await;
             *inner = Self::new_inner(self.unlocked_inner.clone());
         }
+        self.unlocked_inner.active_watch_nodes.lock().clear();
 
         log_stor!(debug "finished storage manager shutdown");
     }
@@ -216,6 +230,29 @@ impl StorageManager {
             .collect()
     }
 
+    /// Check if a node is the one that accepted our active watch on a record
+    /// Does not lock the inner state, so it is cheap enough to do for every inbound value change
+    pub fn is_active_watch_node(&self, key: TypedKey, node_ref: &NodeRef) -> bool {
+        self.unlocked_inner
+            .active_watch_nodes
+            .lock()
+            .get(&key)
+            .is_some_and(|watch_node| watch_node.same_entry(node_ref))
+    }
+
+    /// Keep the active watch node for a record in step with its opened record's active watch
+    fn set_active_watch_node(&self, key: TypedKey, opt_watch_node: Option<NodeRef>) {
+        let mut active_watch_nodes = self.unlocked_inner.active_watch_nodes.lock();
+        match opt_watch_node {
+            Some(watch_node) => {
+                active_watch_nodes.insert(key, watch_node);
+            }
+            None => {
+                active_watch_nodes.remove(&key);
+            }
+        }
+    }
+
     /// Create a local record from scratch with a new owner key, open it, and return the opened descriptor
     #[instrument(level = "trace", target = "stor", skip_all)]
     pub async fn create_record(
@@ -333,6 +370,7 @@ impl StorageManager {
             let mut inner = self.lock().await?;
             (inner.close_record(key)?, Self::online_ready_inner(&inner))
         };
+        self.set_active_watch_node(key, None);
 
         // Send a one-time cancel request for the watch if we have one and we're online
         if let Some(opened_record) = opt_opened_record {
@@ -395,12 +433,25 @@ impl StorageManager {
     }
 
     /// Get the value of a subkey from an opened local record
+    /// Values of encrypted records are opened with the record's encryption key
     #[instrument(level = "trace", target = "stor", skip_all)]
     pub async fn get_value(
         &self,
         key: TypedKey,
         subkey: ValueSubkey,
         force_refresh: bool,
+    ) -> VeilidAPIResult<Option<ValueData>> {
+        let opt_value = self.get_stored_value(key, subkey, force_refresh).await?;
+        self.open_opt_value_data(key, subkey, opt_value).await
+    }
+
+    // Get the value of a subkey as it is stored, without opening sealed values
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    async fn get_stored_value(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        force_refresh: bool,
     ) -> VeilidAPIResult<Option<ValueData>> {
         let mut inner = self.lock().await?;
         let safety_selection = {
@@ -480,14 +531,54 @@ impl StorageManager {
     }
 
     /// Set the value of a subkey on an opened local record
+    /// With an expected sequence number, the write is a compare-and-set that fails
+    /// with a conflict carrying the winning value if the subkey has moved on
+    /// Values of encrypted records are sealed with the record's encryption key
     #[instrument(level = "trace", target = "stor", skip_all)]
     pub async fn set_value(
         &self,
         key: TypedKey,
         subkey: ValueSubkey,
         data: Vec<u8>,
-        writer: Option<KeyPair>,
+        options: Option<SetDHTValueOptions>,
+    ) -> VeilidAPIResult<Option<ValueData>> {
+        // Sealed values always differ, so compare with the opened value to see if anything is changing
+        let (writer, expected_seq) = options
+            .as_ref()
+            .map(|o| (o.writer, o.expected_seq))
+            .unwrap_or_default();
+        if expected_seq.is_none()
+            && self
+                .sealed_value_unchanged(key, subkey, &data, writer)
+                .await?
+        {
+            return Ok(None);
+        }
+
+        let data = self.seal_value_data(key, subkey, data).await?;
+        match self.set_stored_value(key, subkey, data, options).await {
+            Ok(opt_value) => self.open_opt_value_data(key, subkey, opt_value).await,
+            Err(VeilidAPIError::Conflict { value }) => Err(VeilidAPIError::conflict(
+                self.open_value_data(key, subkey, value).await?,
+            )),
+            Err(e) => Err(e),
+        }
+    }
+
+    // Set the value of a subkey to data as it is to be stored, without sealing it
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    async fn set_stored_value(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        data: Vec<u8>,
+        options: Option<SetDHTValueOptions>,
     ) -> VeilidAPIResult<Option<ValueData>> {
+        let SetDHTValueOptions {
+            writer,
+            expected_seq,
+        } = options.unwrap_or_default();
+
         let mut inner = self.lock().await?;
 
         // Get cryptosystem
@@ -522,8 +613,18 @@ impl StorageManager {
         };
         let schema = descriptor.schema()?;
 
+        // A compare-and-set needs the network to find out if anyone else got there first
+        let opt_rpc_processor = Self::online_ready_inner(&inner);
+        if expected_seq.is_some() && opt_rpc_processor.is_none() {
+            apibail_try_again!("offline, can not compare-and-set");
+        }
+
         // Make new subkey data
-        let value_data = if let Some(last_signed_value_data) = last_get_result.opt_value {
+        let value_data = if let Some(expected_seq) = expected_seq {
+            // Write the sequence number after the expected one, even if our local copy is behind
+            // It is checked against the last known value before it goes out, and again when it is committed
+            ValueData::new_with_seq(expected_seq.wrapping_add(1), data, writer.key)?
+        } else if let Some(last_signed_value_data) = &last_get_result.opt_value {
             if last_signed_value_data.value_data().data() == data
                 && last_signed_value_data.value_data().writer() == &writer.key
             {
@@ -552,19 +653,32 @@ impl StorageManager {
             writer.secret,
         )?);
 
-        // Write the value locally first
-        log_stor!(debug "Writing subkey locally: {}:{} len={}", key, subkey, signed_value_data.value_data().data().len() );
-        inner
-            .handle_set_local_value(
-                key,
-                subkey,
-                signed_value_data.clone(),
-                WatchUpdateMode::NoUpdate,
-            )
-            .await?;
+        if let Some(expected_seq) = expected_seq {
+            // A compare-and-set is only written locally once the network takes it,
+            // so check it against the last known value without writing it yet
+            // ValueSeqNum::MAX expects the subkey to have no value at all
+            if let Some(last_signed_value_data) = &last_get_result.opt_value {
+                let last_value_data = last_signed_value_data.value_data();
+                if expected_seq == ValueSeqNum::MAX || last_value_data.seq() > expected_seq {
+                    return Err(VeilidAPIError::conflict(last_value_data.clone()));
+                }
+            }
+        } else {
+            // Write the value locally first
+            log_stor!(debug "Writing subkey locally: {}:{} len={}", key, subkey, signed_value_data.value_data().data().len() );
+            inner
+                .handle_set_local_value(
+                    key,
+                    subkey,
+                    signed_value_data.clone(),
+                    None,
+                    WatchUpdateMode::NoUpdate,
+                )
+                .await?;
+        }
 
         // Get rpc processor and drop mutex so we don't block while getting the value from the network
-        let Some(rpc_processor) = Self::online_ready_inner(&inner) else {
+        let Some(rpc_processor) = opt_rpc_processor else {
             log_stor!(debug "Writing subkey offline: {}:{} len={}", key, subkey, signed_value_data.value_data().data().len() );
             // Add to offline writes to flush
             inner.add_offline_subkey_write(key, subkey, safety_selection);
@@ -590,6 +704,10 @@ impl StorageManager {
         {
             Ok(v) => v,
             Err(e) => {
+                // A failed compare-and-set must be retried by the caller against a fresh value
+                if expected_seq.is_some() {
+                    apibail_try_again!(format!("compare-and-set not written: {}", e));
+                }
                 // Failed to write, try again later
                 let mut inner = self.lock().await?;
                 inner.add_offline_subkey_write(key, subkey, safety_selection);
@@ -601,7 +719,26 @@ impl StorageManager {
         let Ok(result) = res_rx.recv_async().await else {
             apibail_internal!("failed to receive results");
         };
-        let result = result?;
+        let mut result = result?;
+
+        // A compare-and-set waits for the whole fanout so a conflicting value is not missed
+        if let Some(expected_seq) = expected_seq {
+            while result.fanout_result.kind.is_partial() {
+                let Ok(next_result) = res_rx.recv_async().await else {
+                    apibail_internal!("failed to receive results");
+                };
+                result = next_result?;
+            }
+            return self
+                .process_outbound_compare_and_set_result(
+                    key,
+                    subkey,
+                    signed_value_data,
+                    expected_seq,
+                    result,
+                )
+                .await;
+        }
         let partial = result.fanout_result.kind.is_partial();
 
         // Process the returned result
@@ -632,6 +769,353 @@ impl StorageManager {
         Ok(out)
     }
 
+    /// Get the values of several subkeys from an opened local record
+    /// Subkeys missing locally, or all of them if forcing a refresh, are fetched from the network
+    /// in batches of many subkeys per request to each node
+    /// Returns a value or None for each in-schema subkey requested, in ascending subkey order
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn get_values(
+        &self,
+        key: TypedKey,
+        subkeys: ValueSubkeyRangeSet,
+        force_refresh: bool,
+    ) -> VeilidAPIResult<Vec<(ValueSubkey, Option<ValueData>)>> {
+        let results = self.get_stored_values(key, subkeys, force_refresh).await?;
+        self.open_value_data_results(key, results).await
+    }
+
+    // Get the values of several subkeys as they are stored, without opening sealed values
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    async fn get_stored_values(
+        &self,
+        key: TypedKey,
+        subkeys: ValueSubkeyRangeSet,
+        force_refresh: bool,
+    ) -> VeilidAPIResult<Vec<(ValueSubkey, Option<ValueData>)>> {
+        let subkeys = if subkeys.is_empty() {
+            ValueSubkeyRangeSet::full()
+        } else {
+            subkeys
+        };
+
+        let mut inner = self.lock().await?;
+        let safety_selection = {
+            let Some(opened_record) = inner.opened_records.get(&key) else {
+                apibail_generic!("record not open");
+            };
+            opened_record.safety_selection()
+        };
+
+        // Get the descriptor and schema for the key
+        let Some(descriptor) = inner
+            .handle_get_local_value(key, 0, true)
+            .await?
+            .opt_descriptor
+        else {
+            apibail_generic!("must have a descriptor");
+        };
+        let subkeys = descriptor.schema()?.truncate_subkeys(&subkeys, None);
+
+        // See which of the requested subkeys are in our local record store
+        let mut last_values = Vec::with_capacity(subkeys.len());
+        for subkey in subkeys.iter() {
+            let last_get_result = inner.handle_get_local_value(key, subkey, false).await?;
+            last_values.push((subkey, last_get_result.opt_value));
+        }
+
+        // Refresh the subkeys we don't have unless we are forcing a refresh of all of them
+        let refresh: Vec<usize> = last_values
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, opt_value))| force_refresh || opt_value.is_none())
+            .map(|(n, _)| n)
+            .collect();
+        if refresh.is_empty() {
+            return Ok(last_values
+                .into_iter()
+                .map(|(subkey, opt_value)| (subkey, opt_value.map(|v| v.value_data().clone())))
+                .collect());
+        }
+
+        // Get rpc processor and drop mutex so we don't block while getting the values from the network
+        let Some(rpc_processor) = Self::online_ready_inner(&inner) else {
+            // Return the existing values if we have all of them if we aren't online
+            if last_values.iter().all(|(_, opt_value)| opt_value.is_some()) {
+                return Ok(last_values
+                    .into_iter()
+                    .map(|(subkey, opt_value)| (subkey, opt_value.map(|v| v.value_data().clone())))
+                    .collect());
+            }
+            apibail_try_again!("offline, try again later");
+        };
+
+        // Drop the lock for network access
+        drop(inner);
+
+        // Use the safety selection we opened the record with
+        for chunk in refresh.chunks(MAX_GET_VALUES_Q_SUBKEYS_LEN) {
+            let chunk_subkeys = ValueSubkeyRangeSet::new_with_data(
+                chunk.iter().map(|n| last_values[*n].0).collect(),
+            );
+            let chunk_last_values = chunk.iter().map(|n| last_values[*n].1.clone()).collect();
+            let result = self
+                .outbound_get_values(
+                    rpc_processor.clone(),
+                    key,
+                    chunk_subkeys,
+                    safety_selection,
+                    descriptor.clone(),
+                    chunk_last_values,
+                )
+                .await?;
+
+            // Keep the list of nodes that returned a value for later reference
+            let mut inner = self.lock().await?;
+            let results_iter = chunk
+                .iter()
+                .map(|n| last_values[*n].0)
+                .zip(result.fanout_results.iter());
+            inner.process_fanout_results(key, results_iter, false);
+
+            // If we got a new value back then write it to the opened record
+            for (n, opt_value) in chunk.iter().zip(result.values) {
+                let Some(value) = opt_value else {
+                    continue;
+                };
+                let (subkey, last_value) = &mut last_values[*n];
+                let last_seq = last_value.as_ref().map(|v| v.value_data().seq());
+                if Some(value.value_data().seq()) != last_seq {
+                    inner
+                        .handle_set_local_value(
+                            key,
+                            *subkey,
+                            value.clone(),
+                            None,
+                            WatchUpdateMode::UpdateAll,
+                        )
+                        .await?;
+                }
+                *last_value = Some(value);
+            }
+        }
+
+        Ok(last_values
+            .into_iter()
+            .map(|(subkey, opt_value)| (subkey, opt_value.map(|v| v.value_data().clone())))
+            .collect())
+    }
+
+    /// Set the values of several subkeys on an opened local record
+    /// Values are written locally and then pushed to the network in batches of many subkeys per request to each node
+    /// Returns, for each subkey in ascending subkey order, None if the value was set,
+    /// or the newer value if the one set was older than the one available on the network
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn set_values(
+        &self,
+        key: TypedKey,
+        values: Vec<(ValueSubkey, Vec<u8>)>,
+        writer: Option<KeyPair>,
+    ) -> VeilidAPIResult<Vec<(ValueSubkey, Option<ValueData>)>> {
+        let mut sealed_values = Vec::with_capacity(values.len());
+        for (subkey, data) in values {
+            sealed_values.push((subkey, self.seal_value_data(key, subkey, data).await?));
+        }
+        let results = self.set_stored_values(key, sealed_values, writer).await?;
+        self.open_value_data_results(key, results).await
+    }
+
+    // Set the values of several subkeys to data as it is to be stored, without sealing it
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    async fn set_stored_values(
+        &self,
+        key: TypedKey,
+        mut values: Vec<(ValueSubkey, Vec<u8>)>,
+        writer: Option<KeyPair>,
+    ) -> VeilidAPIResult<Vec<(ValueSubkey, Option<ValueData>)>> {
+        // Subkeys are sent in ascending order and each may only be set once
+        values.sort_by_key(|(subkey, _)| *subkey);
+        if let Some(w) = values.windows(2).find(|w| w[0].0 == w[1].0) {
+            apibail_invalid_argument!("duplicate subkey", "subkey", w[0].0);
+        }
+
+        let mut inner = self.lock().await?;
+
+        // Get cryptosystem
+        let Some(vcrypto) = self.unlocked_inner.crypto.get(key.kind) else {
+            apibail_generic!("unsupported cryptosystem");
+        };
+
+        let (safety_selection, opt_writer) = {
+            let Some(opened_record) = inner.opened_records.get(&key) else {
+                apibail_generic!("record not open");
+            };
+            (
+                opened_record.safety_selection(),
+                opened_record.writer().cloned(),
+            )
+        };
+
+        // Use the specified writer, or if not specified, the default writer when the record was opened
+        let opt_writer = writer.or(opt_writer);
+
+        // If we don't have a writer then we can't write
+        let Some(writer) = opt_writer else {
+            apibail_generic!("value is not writable");
+        };
+
+        // Make all of the new subkey data before writing any of it
+        let mut out = Vec::with_capacity(values.len());
+        let mut signed_values = vec![];
+        let mut opt_descriptor = None;
+        let mut prior_data_size = 0usize;
+        let mut new_data_size = 0usize;
+        for (subkey, data) in values {
+            // See if the subkey we are modifying has a last known local value
+            let last_get_result = inner.handle_get_local_value(key, subkey, true).await?;
+
+            // Get the descriptor and schema for the key
+            let Some(descriptor) = last_get_result.opt_descriptor else {
+                apibail_generic!("must have a descriptor");
+            };
+            let schema = descriptor.schema()?;
+
+            // Make new subkey data
+            out.push((subkey, None));
+            let value_data = if let Some(last_signed_value_data) = last_get_result.opt_value {
+                if last_signed_value_data.value_data().data() == data
+                    && last_signed_value_data.value_data().writer() == &writer.key
+                {
+                    // Data and writer is the same, nothing is changing for this subkey
+                    continue;
+                }
+                prior_data_size += last_signed_value_data.data_size();
+                let seq = last_signed_value_data.value_data().seq();
+                ValueData::new_with_seq(seq + 1, data, writer.key)?
+            } else {
+                ValueData::new(data, writer.key)?
+            };
+
+            // Validate with schema
+            if !schema.check_subkey_value_data(descriptor.owner(), subkey, &value_data) {
+                // Validation failed, ignore this value
+                apibail_generic!("failed schema validation");
+            }
+
+            // Sign the new value data with the writer
+            let signed_value_data = Arc::new(SignedValueData::make_signature(
+                value_data,
+                descriptor.owner(),
+                subkey,
+                vcrypto.clone(),
+                writer.secret,
+            )?);
+            new_data_size += signed_value_data.data_size();
+            signed_values.push((out.len() - 1, subkey, signed_value_data));
+            opt_descriptor = Some(descriptor);
+        }
+        let Some(descriptor) = opt_descriptor else {
+            // Nothing is changing, no network activity needs to happen
+            return Ok(out);
+        };
+
+        // Don't write any of the values if they would not all fit in the record
+        inner.check_local_record_data_size(key, prior_data_size, new_data_size)?;
+
+        // Write the values locally first
+        for (_, subkey, signed_value_data) in &signed_values {
+            log_stor!(debug "Writing subkey locally: {}:{} len={}", key, subkey, signed_value_data.value_data().data().len() );
+            inner
+                .handle_set_local_value(
+                    key,
+                    *subkey,
+                    signed_value_data.clone(),
+                    None,
+                    WatchUpdateMode::NoUpdate,
+                )
+                .await?;
+        }
+
+        // Get rpc processor and drop mutex so we don't block while getting the value from the network
+        let Some(rpc_processor) = Self::online_ready_inner(&inner) else {
+            // Add to offline writes to flush
+            for (_, subkey, signed_value_data) in &signed_values {
+                log_stor!(debug "Writing subkey offline: {}:{} len={}", key, subkey, signed_value_data.value_data().data().len() );
+                inner.add_offline_subkey_write(key, *subkey, safety_selection);
+            }
+            return Ok(out);
+        };
+
+        // Drop the lock for network access
+        drop(inner);
+
+        // Use the safety selection we opened the record with
+        let mut chunks = signed_values.chunks(MAX_SET_VALUES_Q_VALUES_LEN);
+        while let Some(chunk) = chunks.next() {
+            log_stor!(debug "Writing subkeys to the network: {}:{} count={}", key, chunk[0].1, chunk.len());
+
+            let result = match self
+                .outbound_set_values(
+                    rpc_processor.clone(),
+                    key,
+                    safety_selection,
+                    chunk
+                        .iter()
+                        .map(|(_, subkey, signed_value_data)| (*subkey, signed_value_data.clone()))
+                        .collect(),
+                    descriptor.clone(),
+                )
+                .await
+            {
+                Ok(v) => v,
+                Err(e) => {
+                    // Failed to write, try again later
+                    let mut inner = self.lock().await?;
+                    for (_, subkey, _) in chunk.iter().chain(chunks.by_ref().flatten()) {
+                        inner.add_offline_subkey_write(key, *subkey, safety_selection);
+                    }
+                    return Err(e);
+                }
+            };
+
+            // Regain the lock after network access
+            let mut inner = self.lock().await?;
+
+            // Report on fanout results offline
+            for ((_, subkey, _), fanout_result) in chunk.iter().zip(result.fanout_results.iter()) {
+                if self.check_fanout_set_offline(key, *subkey, fanout_result) {
+                    // Failed to write, try again later
+                    inner.add_offline_subkey_write(key, *subkey, safety_selection);
+                }
+            }
+
+            // Keep the list of nodes that returned a value for later reference
+            let results_iter = chunk
+                .iter()
+                .map(|(_, subkey, _)| *subkey)
+                .zip(result.fanout_results.iter());
+            inner.process_fanout_results(key, results_iter, true);
+
+            // Return the new values that differ from what was asked to set
+            for ((n, subkey, signed_value_data), value) in chunk.iter().zip(result.values) {
+                if value.value_data() != signed_value_data.value_data() {
+                    // Record the newer value and send and update since it is different than what we just set
+                    inner
+                        .handle_set_local_value(
+                            key,
+                            *subkey,
+                            value.clone(),
+                            None,
+                            WatchUpdateMode::UpdateAll,
+                        )
+                        .await?;
+                    out[*n].1 = Some(value.value_data().clone());
+                }
+            }
+        }
+
+        Ok(out)
+    }
+
     /// Create,update or cancel an outbound watch to a DHT value
     #[instrument(level = "trace", target = "stor", skip_all)]
     pub async fn watch_values(
@@ -709,6 +1193,7 @@ impl StorageManager {
             apibail_generic!("record not open");
         };
         opened_record.clear_active_watch();
+        self.set_active_watch_node(key, None);
 
         // Get the minimum expiration timestamp we will accept
         let (rpc_timeout_us, max_watch_expiration_us) = {
@@ -747,6 +1232,7 @@ impl StorageManager {
         }
 
         // Keep a record of the watch
+        self.set_active_watch_node(key, Some(owvresult.watch_node.clone()));
         opened_record.set_active_watch(ActiveWatch {
             id: owvresult.watch_id,
             expiration_ts,
@@ -937,6 +1423,129 @@ impl StorageManager {
         ))
     }
 
+    /// Set how many prior values of each subkey an opened local record keeps, zero keeps none
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn set_value_history_length(
+        &self,
+        key: TypedKey,
+        history_length: u32,
+    ) -> VeilidAPIResult<()> {
+        let mut inner = self.lock().await?;
+        if !inner.opened_records.contains_key(&key) {
+            apibail_generic!("record not open");
+        }
+        let Some(local_record_store) = inner.local_record_store.as_mut() else {
+            apibail_not_initialized!();
+        };
+        local_record_store
+            .set_history_length(key, history_length)
+            .await
+    }
+
+    /// Get the values a subkey of an opened local record has held whose sequence numbers are within an inclusive range
+    /// Only values seen locally while the record was keeping a history are returned, oldest first
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn get_value_history(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        seqs: (ValueSeqNum, ValueSeqNum),
+    ) -> VeilidAPIResult<Vec<ValueData>> {
+        let mut inner = self.lock().await?;
+        if !inner.opened_records.contains_key(&key) {
+            apibail_generic!("record not open");
+        }
+        let Some(local_record_store) = inner.local_record_store.as_mut() else {
+            apibail_not_initialized!();
+        };
+        let Some(values) = local_record_store
+            .get_subkey_history(key, subkey, seqs)
+            .await?
+        else {
+            apibail_key_not_found!(key);
+        };
+        drop(inner);
+
+        let mut out = Vec::with_capacity(values.len());
+        for value in values {
+            out.push(
+                self.open_value_data(key, subkey, value.value_data().clone())
+                    .await?,
+            );
+        }
+        Ok(out)
+    }
+
+    /// Get the subkeys of a record that were written locally and are still waiting to be written to the network
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn get_offline_subkey_writes(
+        &self,
+        key: TypedKey,
+    ) -> VeilidAPIResult<ValueSubkeyRangeSet> {
+        let inner = self.lock().await?;
+        Ok(inner
+            .offline_subkey_writes
+            .get(&key)
+            .map(|o| o.subkeys.union(&o.subkeys_in_flight))
+            .unwrap_or_default())
+    }
+
+    /// Stop trying to write some subkeys of a record to the network, an empty subkey set cancels them all
+    /// The locally written values are kept, and a write already in progress is allowed to complete
+    /// Returns the subkeys that are still waiting to be written
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn cancel_offline_subkey_writes(
+        &self,
+        key: TypedKey,
+        subkeys: ValueSubkeyRangeSet,
+    ) -> VeilidAPIResult<ValueSubkeyRangeSet> {
+        let subkeys = if subkeys.is_empty() {
+            ValueSubkeyRangeSet::full()
+        } else {
+            subkeys
+        };
+
+        let mut inner = self.lock().await?;
+        let std::collections::hash_map::Entry::Occupied(mut o) =
+            inner.offline_subkey_writes.entry(key)
+        else {
+            return Ok(ValueSubkeyRangeSet::new());
+        };
+
+        let osw = o.get_mut();
+        osw.subkeys = osw.subkeys.difference(&subkeys);
+        let remaining = osw.subkeys.union(&osw.subkeys_in_flight);
+
+        // Entries with subkeys in flight are removed by the offline subkey writes task when it finishes with them
+        if remaining.is_empty() {
+            o.remove();
+        }
+
+        Ok(remaining)
+    }
+
+    /// Write the queued offline subkeys of a record to the network now instead of waiting for the next retry
+    /// Returns the subkeys that are still waiting to be written
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn flush_offline_subkey_writes(
+        &self,
+        key: TypedKey,
+    ) -> VeilidAPIResult<ValueSubkeyRangeSet> {
+        {
+            let inner = self.lock().await?;
+            if Self::online_ready_inner(&inner).is_none() {
+                apibail_try_again!("offline, try again later");
+            }
+        }
+
+        self.clone()
+            .flush_offline_subkey_writes_for_key(key)
+            .await
+            .map_err(VeilidAPIError::internal)?;
+
+        self.get_offline_subkey_writes(key).await
+    }
+
     // Send single value change out to the network
     #[instrument(level = "trace", target = "stor", skip(self), err)]
     async fn send_value_change(&self, vc: ValueChangedInfo) -> VeilidAPIResult<()> {
@@ -974,6 +1583,14 @@ impl StorageManager {
         count: u32,
         value: Option<ValueData>,
     ) -> Result<(), VeilidAPIError> {
+        // The value is for the first changed subkey
+        let value = match (value, subkeys.first()) {
+            (Some(value), Some(subkey)) => {
+                Some(self.open_update_value_data(key, subkey, value).await)
+            }
+            (value, _) => value,
+        };
+
         let opt_update_callback = {
             let inner = self.lock().await?;
             inner.update_callback.clone()
@@ -990,6 +1607,42 @@ impl StorageManager {
         Ok(())
     }
 
+    // Send the outcome of a queued offline write up through the callback
+    #[instrument(
+        level = "trace",
+        target = "stor",
+        skip(self, value, superseded_by),
+        err
+    )]
+    async fn update_callback_offline_write(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        value: ValueData,
+        superseded_by: Option<ValueData>,
+    ) -> Result<(), VeilidAPIError> {
+        let value = self.open_update_value_data(key, subkey, value).await;
+        let superseded_by = match superseded_by {
+            Some(value) => Some(self.open_update_value_data(key, subkey, value).await),
+            None => None,
+        };
+
+        let opt_update_callback = {
+            let inner = self.lock().await?;
+            inner.update_callback.clone()
+        };
+
+        if let Some(update_callback) = opt_update_callback {
+            update_callback(VeilidUpdate::OfflineWrite(Box::new(VeilidOfflineWrite {
+                key,
+                subkey,
+                value,
+                superseded_by,
+            })));
+        }
+        Ok(())
+    }
+
     #[instrument(level = "trace", target = "stor", skip_all)]
     fn check_fanout_set_offline(
         &self,
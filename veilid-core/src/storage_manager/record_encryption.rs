@@ -0,0 +1,466 @@
+use super::*;
+
+/// Identifies a subkey that holds shared record encryption keys
+const RECORD_KEY_SHARE_MAGIC: &[u8; 4] = b"RKS0";
+/// The length of an encoded record key share before its wrapped keys
+const RECORD_KEY_SHARE_HEADER_LENGTH: usize = 4 + PUBLIC_KEY_LENGTH + NONCE_LENGTH + 4;
+
+/// The record encryption key wrapped for each member of a record by one of its writers
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RecordKeyShare {
+    /// The writer that wrapped the keys, which members need for their side of the key exchange
+    sharer: PublicKey,
+    /// The nonce every key in this share was wrapped with
+    nonce: Nonce,
+    /// Each member and the record encryption key wrapped for it
+    wrapped_keys: Vec<(PublicKey, Vec<u8>)>,
+}
+
+impl RecordKeyShare {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            RECORD_KEY_SHARE_HEADER_LENGTH
+                + self
+                    .wrapped_keys
+                    .iter()
+                    .map(|(_, wrapped_key)| PUBLIC_KEY_LENGTH + wrapped_key.len())
+                    .sum::<usize>(),
+        );
+        bytes.extend_from_slice(RECORD_KEY_SHARE_MAGIC);
+        bytes.extend_from_slice(&self.sharer.bytes);
+        bytes.extend_from_slice(&self.nonce.bytes);
+        bytes.extend_from_slice(&(self.wrapped_keys.len() as u32).to_le_bytes());
+        for (member, wrapped_key) in &self.wrapped_keys {
+            bytes.extend_from_slice(&member.bytes);
+            bytes.extend_from_slice(wrapped_key);
+        }
+        bytes
+    }
+
+    fn try_from_bytes(bytes: &[u8], wrapped_key_len: usize) -> VeilidAPIResult<Self> {
+        if bytes.len() < RECORD_KEY_SHARE_HEADER_LENGTH || &bytes[0..4] != RECORD_KEY_SHARE_MAGIC {
+            apibail_parse_error!("not a record key share", format!("len={}", bytes.len()));
+        }
+        let mut offset = 4;
+        let sharer = PublicKey::try_from(&bytes[offset..offset + PUBLIC_KEY_LENGTH])?;
+        offset += PUBLIC_KEY_LENGTH;
+        let nonce = Nonce::try_from(&bytes[offset..offset + NONCE_LENGTH])?;
+        offset += NONCE_LENGTH;
+        let count = u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+
+        let entry_len = PUBLIC_KEY_LENGTH + wrapped_key_len;
+        if count.checked_mul(entry_len) != Some(bytes.len() - offset) {
+            apibail_parse_error!(
+                "record key share length mismatch",
+                format!("len={} count={}", bytes.len(), count)
+            );
+        }
+
+        let wrapped_keys = bytes[offset..]
+            .chunks_exact(entry_len)
+            .map(|entry| {
+                Ok((
+                    PublicKey::try_from(&entry[0..PUBLIC_KEY_LENGTH])?,
+                    entry[PUBLIC_KEY_LENGTH..].to_vec(),
+                ))
+            })
+            .collect::<VeilidAPIResult<Vec<_>>>()?;
+
+        Ok(Self {
+            sharer,
+            nonce,
+            wrapped_keys,
+        })
+    }
+}
+
+/// The most that sealing can add to a value, the nonce and the largest aead overhead of any cryptosystem
+/// Fixed so the size of large value chunks does not depend on the cryptosystem
+pub(super) const RECORD_SEAL_MAX_OVERHEAD: usize = NONCE_LENGTH + PUBLIC_KEY_LENGTH;
+
+/// What sealing adds to a value with this cryptosystem, the nonce and the aead overhead
+pub(super) fn record_seal_overhead(vcrypto: &CryptoSystemVersion) -> usize {
+    NONCE_LENGTH + vcrypto.aead_overhead()
+}
+
+impl StorageManager {
+    // Get the encryption key of a local record if its values are sealed
+    async fn record_encryption(&self, key: TypedKey) -> VeilidAPIResult<Option<RecordEncryption>> {
+        let inner = self.lock().await?;
+        let Some(local_record_store) = inner.local_record_store.as_ref() else {
+            apibail_not_initialized!();
+        };
+        Ok(local_record_store
+            .peek_record(key, |r| r.detail().encryption.clone())
+            .flatten())
+    }
+
+    // Bind a sealed value to the subkey it was written to so it can not be moved to another one
+    fn seal_associated_data(key: TypedKey, subkey: ValueSubkey) -> Vec<u8> {
+        let mut associated_data = key.value.bytes.to_vec();
+        associated_data.extend_from_slice(&subkey.to_le_bytes());
+        associated_data
+    }
+
+    /// Seal the data for a subkey of a local record if the record is encrypted
+    pub(super) async fn seal_value_data(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        data: Vec<u8>,
+    ) -> VeilidAPIResult<Vec<u8>> {
+        let Some(encryption) = self.record_encryption(key).await? else {
+            return Ok(data);
+        };
+        if encryption.share_subkey == Some(subkey) {
+            return Ok(data);
+        }
+
+        // Get cryptosystem
+        let Some(vcrypto) = self.unlocked_inner.crypto.get(key.kind) else {
+            apibail_generic!("unsupported cryptosystem");
+        };
+
+        if data.len() + record_seal_overhead(&vcrypto) > ValueData::MAX_LEN {
+            apibail_invalid_argument!("value too large to seal", "data.len", data.len());
+        }
+
+        let nonce = vcrypto.random_nonce();
+        let mut sealed = nonce.bytes.to_vec();
+        sealed.extend(vcrypto.encrypt_aead(
+            &data,
+            &nonce,
+            &encryption.secret,
+            Some(&Self::seal_associated_data(key, subkey)),
+        )?);
+        Ok(sealed)
+    }
+
+    /// Returns true if a subkey of an encrypted local record already holds this data from this writer
+    /// Sealing uses a fresh nonce every time, so the sealed values can never be compared directly
+    pub(super) async fn sealed_value_unchanged(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        data: &[u8],
+        writer: Option<KeyPair>,
+    ) -> VeilidAPIResult<bool> {
+        let Some(encryption) = self.record_encryption(key).await? else {
+            return Ok(false);
+        };
+        if encryption.share_subkey == Some(subkey) {
+            return Ok(false);
+        }
+
+        let (opt_writer, opt_last_value) = {
+            let mut inner = self.lock().await?;
+            let Some(opened_record) = inner.opened_records.get(&key) else {
+                apibail_generic!("record not open");
+            };
+            let opt_writer = writer.or(opened_record.writer().cloned());
+            let last_get_result = inner.handle_get_local_value(key, subkey, false).await?;
+            (opt_writer, last_get_result.opt_value)
+        };
+        let (Some(writer), Some(last_value)) = (opt_writer, opt_last_value) else {
+            return Ok(false);
+        };
+        if last_value.value_data().writer() != &writer.key {
+            return Ok(false);
+        }
+
+        // A current value that can not be opened is always replaced
+        match self
+            .open_value_data(key, subkey, last_value.value_data().clone())
+            .await
+        {
+            Ok(last_value) => Ok(last_value.data() == data),
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// Open a value from a subkey of a local record if the record is encrypted
+    pub(super) async fn open_value_data(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        value: ValueData,
+    ) -> VeilidAPIResult<ValueData> {
+        let Some(encryption) = self.record_encryption(key).await? else {
+            return Ok(value);
+        };
+        if encryption.share_subkey == Some(subkey) {
+            return Ok(value);
+        }
+
+        // Get cryptosystem
+        let Some(vcrypto) = self.unlocked_inner.crypto.get(key.kind) else {
+            apibail_generic!("unsupported cryptosystem");
+        };
+
+        if value.data().len() < NONCE_LENGTH {
+            apibail_generic!("sealed value is too short");
+        }
+        let nonce = Nonce::try_from(&value.data()[0..NONCE_LENGTH])?;
+        let data = vcrypto.decrypt_aead(
+            &value.data()[NONCE_LENGTH..],
+            &nonce,
+            &encryption.secret,
+            Some(&Self::seal_associated_data(key, subkey)),
+        )?;
+        ValueData::new_with_seq(value.seq(), data, *value.writer())
+    }
+
+    pub(super) async fn open_opt_value_data(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        opt_value: Option<ValueData>,
+    ) -> VeilidAPIResult<Option<ValueData>> {
+        match opt_value {
+            Some(value) => Ok(Some(self.open_value_data(key, subkey, value).await?)),
+            None => Ok(None),
+        }
+    }
+
+    pub(super) async fn open_value_data_results(
+        &self,
+        key: TypedKey,
+        results: Vec<(ValueSubkey, Option<ValueData>)>,
+    ) -> VeilidAPIResult<Vec<(ValueSubkey, Option<ValueData>)>> {
+        let mut out = Vec::with_capacity(results.len());
+        for (subkey, opt_value) in results {
+            out.push((
+                subkey,
+                self.open_opt_value_data(key, subkey, opt_value).await?,
+            ));
+        }
+        Ok(out)
+    }
+
+    // Open a value for an update, passing it on sealed if it can not be opened
+    pub(super) async fn open_update_value_data(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        value: ValueData,
+    ) -> ValueData {
+        match self.open_value_data(key, subkey, value.clone()).await {
+            Ok(value) => value,
+            Err(e) => {
+                log_stor!(debug "Failed to open value for update: {}:{} {}", key, subkey, e);
+                value
+            }
+        }
+    }
+
+    // Set the encryption key of a local record, keeping the subkey it is shared in
+    async fn set_record_encryption(
+        &self,
+        key: TypedKey,
+        opt_secret: Option<SharedSecret>,
+        opt_share_subkey: Option<ValueSubkey>,
+    ) -> VeilidAPIResult<()> {
+        let mut inner = self.lock().await?;
+        let Some(local_record_store) = inner.local_record_store.as_mut() else {
+            apibail_not_initialized!();
+        };
+        if local_record_store
+            .with_record_mut(key, |r| {
+                let detail = r.detail_mut();
+                let share_subkey = opt_share_subkey.or(detail
+                    .encryption
+                    .as_ref()
+                    .and_then(|encryption| encryption.share_subkey));
+                detail.encryption = opt_secret.map(|secret| RecordEncryption {
+                    secret,
+                    share_subkey,
+                });
+            })
+            .is_none()
+        {
+            apibail_key_not_found!(key);
+        }
+        Ok(())
+    }
+
+    /// Set the key the values of an opened local record are sealed with, or stop sealing them with None
+    /// Values already written are not resealed and can only be opened with the key they were sealed with
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn set_record_encryption_key(
+        &self,
+        key: TypedKey,
+        opt_secret: Option<SharedSecret>,
+    ) -> VeilidAPIResult<()> {
+        {
+            let inner = self.lock().await?;
+            if !inner.opened_records.contains_key(&key) {
+                apibail_generic!("record not open");
+            }
+        }
+        self.set_record_encryption(key, opt_secret, None).await
+    }
+
+    /// Share the encryption key of an opened local record with members of the record, generating a key if the record has none
+    /// The key is wrapped for each member and for the writer the record was opened with, and written unsealed to 'subkey'
+    /// Returns the encryption key
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn share_record_encryption_key(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        members: Vec<PublicKey>,
+    ) -> VeilidAPIResult<SharedSecret> {
+        // Get cryptosystem
+        let Some(vcrypto) = self.unlocked_inner.crypto.get(key.kind) else {
+            apibail_generic!("unsupported cryptosystem");
+        };
+
+        let writer = {
+            let inner = self.lock().await?;
+            let Some(opened_record) = inner.opened_records.get(&key) else {
+                apibail_generic!("record not open");
+            };
+            let Some(writer) = opened_record.writer().cloned() else {
+                apibail_generic!("value is not writable");
+            };
+            writer
+        };
+        let secret = match self.record_encryption(key).await? {
+            Some(encryption) => encryption.secret,
+            None => vcrypto.random_shared_secret(),
+        };
+
+        // Wrap the key for the writer itself first so other nodes with the same writer can use it
+        let nonce = vcrypto.random_nonce();
+        let mut wrapped_keys = Vec::<(PublicKey, Vec<u8>)>::with_capacity(members.len() + 1);
+        for member in std::iter::once(writer.key).chain(members) {
+            if wrapped_keys.iter().any(|(m, _)| *m == member) {
+                continue;
+            }
+            let dh_secret = vcrypto.cached_dh(&member, &writer.secret)?;
+            let wrapped_key =
+                vcrypto.encrypt_aead(&secret.bytes, &nonce, &dh_secret, Some(&key.value.bytes))?;
+            wrapped_keys.push((member, wrapped_key));
+        }
+        let share = RecordKeyShare {
+            sharer: writer.key,
+            nonce,
+            wrapped_keys,
+        }
+        .to_bytes();
+        if share.len() > ValueData::MAX_LEN {
+            apibail_invalid_argument!("too many members to share with", "share.len", share.len());
+        }
+
+        // Write the share before starting to seal with the key so the share is never sealed
+        self.set_record_encryption(key, Some(secret), Some(subkey))
+            .await?;
+        let opt_newer_share = self
+            .set_stored_value(
+                key,
+                subkey,
+                share,
+                Some(SetDHTValueOptions {
+                    writer: Some(writer),
+                    expected_seq: None,
+                }),
+            )
+            .await?;
+        if opt_newer_share.is_some() {
+            apibail_try_again!("newer record key share was found on the network");
+        }
+
+        Ok(secret)
+    }
+
+    /// Get the encryption key of an opened local record from the share written to 'subkey',
+    /// using the writer the record was opened with, and seal and open the record's values with it
+    /// Returns the encryption key
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn import_record_encryption_key(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+    ) -> VeilidAPIResult<SharedSecret> {
+        // Get cryptosystem
+        let Some(vcrypto) = self.unlocked_inner.crypto.get(key.kind) else {
+            apibail_generic!("unsupported cryptosystem");
+        };
+
+        let writer = {
+            let inner = self.lock().await?;
+            let Some(opened_record) = inner.opened_records.get(&key) else {
+                apibail_generic!("record not open");
+            };
+            let Some(writer) = opened_record.writer().cloned() else {
+                apibail_generic!("record was not opened with a writer");
+            };
+            writer
+        };
+
+        // The share may have been rewritten with new members since we last saw it
+        let Some(share_value) = self.get_stored_value(key, subkey, true).await? else {
+            apibail_generic!("no record key share in subkey");
+        };
+        let share = RecordKeyShare::try_from_bytes(
+            share_value.data(),
+            SHARED_SECRET_LENGTH + vcrypto.aead_overhead(),
+        )?;
+        // Anyone allowed to write the subkey could claim to be the sharer, so it must be the writer
+        if share.sharer != *share_value.writer() {
+            apibail_generic!("record key share was not written by its sharer");
+        }
+        let Some((_, wrapped_key)) = share
+            .wrapped_keys
+            .iter()
+            .find(|(member, _)| *member == writer.key)
+        else {
+            apibail_generic!("record key was not shared with this writer");
+        };
+
+        let dh_secret = vcrypto.cached_dh(&share.sharer, &writer.secret)?;
+        let secret_bytes = vcrypto.decrypt_aead(
+            wrapped_key,
+            &share.nonce,
+            &dh_secret,
+            Some(&key.value.bytes),
+        )?;
+        let secret = SharedSecret::try_from(secret_bytes.as_slice())?;
+
+        self.set_record_encryption(key, Some(secret), Some(subkey))
+            .await?;
+
+        Ok(secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_key_share_round_trip() {
+        let share = RecordKeyShare {
+            sharer: CryptoKey { bytes: [1; 32] },
+            nonce: Nonce {
+                bytes: [2; NONCE_LENGTH],
+            },
+            wrapped_keys: vec![
+                (CryptoKey { bytes: [3; 32] }, vec![4; 48]),
+                (CryptoKey { bytes: [5; 32] }, vec![6; 48]),
+            ],
+        };
+        let bytes = share.to_bytes();
+        assert_eq!(bytes.len(), RECORD_KEY_SHARE_HEADER_LENGTH + 2 * (32 + 48));
+        assert_eq!(RecordKeyShare::try_from_bytes(&bytes, 48).unwrap(), share);
+
+        // Wrong wrapped key length for the cryptosystem
+        assert!(RecordKeyShare::try_from_bytes(&bytes, 64).is_err());
+
+        // Wrong magic
+        let mut bytes = bytes;
+        bytes[0] = b'X';
+        assert!(RecordKeyShare::try_from_bytes(&bytes, 48).is_err());
+    }
+}
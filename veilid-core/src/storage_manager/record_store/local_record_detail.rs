@@ -8,6 +8,15 @@ pub(in crate::storage_manager) struct PerNodeRecordDetail {
     pub subkeys: ValueSubkeyRangeSet,
 }
 
+/// The key the values of a local record are sealed with
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(in crate::storage_manager) struct RecordEncryption {
+    /// The symmetric key shared by everyone who can read the record
+    pub secret: SharedSecret,
+    /// The subkey holding the shared copies of the key, which is not sealed itself
+    pub share_subkey: Option<ValueSubkey>,
+}
+
 /// Information required to handle locally opened records
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub(in crate::storage_manager) struct LocalRecordDetail {
@@ -17,6 +26,9 @@ pub(in crate::storage_manager) struct LocalRecordDetail {
     /// The nodes that we have seen this record cached on recently
     #[serde(default)]
     pub nodes: HashMap<PublicKey, PerNodeRecordDetail>,
+    /// The encryption key if the values of this record are sealed
+    #[serde(default)]
+    pub encryption: Option<RecordEncryption>,
 }
 
 impl LocalRecordDetail {
@@ -24,6 +36,7 @@ impl LocalRecordDetail {
         Self {
             safety_selection,
             nodes: Default::default(),
+            encryption: None,
         }
     }
 }
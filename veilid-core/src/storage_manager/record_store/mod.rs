@@ -57,6 +57,8 @@ where
     record_table: Option<TableDB>,
     /// The tabledb used for subkey data
     subkey_table: Option<TableDB>,
+    /// The tabledb used for prior subkey data of records that keep a value history
+    history_table: Option<TableDB>,
     /// The in-memory index that keeps track of what records are in the tabledb
     record_index: LruCache<RecordTableKey, Record<D>>,
     /// The in-memory cache of commonly accessed subkey data so we don't have to keep hitting the db
@@ -88,6 +90,17 @@ pub struct GetResult {
     pub opt_descriptor: Option<Arc<SignedValueDescriptor>>,
 }
 
+/// The result of the do_get_values_operation
+#[derive(Default, Clone, Debug)]
+pub struct GetValuesResult {
+    /// The subkeys this result covers, whether or not a value was found for them
+    pub subkeys: ValueSubkeyRangeSet,
+    /// The subkey values we have, in ascending subkey order
+    pub values: Vec<(ValueSubkey, Arc<SignedValueData>)>,
+    /// The descriptor if we got a fresh one or empty if no descriptor was needed
+    pub opt_descriptor: Option<Arc<SignedValueDescriptor>>,
+}
+
 /// The result of the do_inspect_value_operation
 #[derive(Default, Clone, Debug)]
 pub struct InspectResult {
@@ -118,6 +131,7 @@ where
             limits,
             record_table: None,
             subkey_table: None,
+            history_table: None,
             record_index: LruCache::new(limits.max_records.unwrap_or(usize::MAX)),
             subkey_cache: LruCache::new(subkey_cache_size),
             inspect_cache: InspectCache::new(subkey_cache_size),
@@ -148,6 +162,10 @@ where
             .table_store
             .open(&format!("{}_subkeys", self.name), 1)
             .await?;
+        let history_table = self
+            .table_store
+            .open(&format!("{}_history", self.name), 1)
+            .await?;
 
         // Pull record index from table into a vector to ensure we sort them
         let record_table_keys = record_table.get_keys(0).await?;
@@ -205,6 +223,7 @@ where
 
         self.record_table = Some(record_table);
         self.subkey_table = Some(subkey_table);
+        self.history_table = Some(history_table);
         Ok(())
     }
 
@@ -285,9 +304,11 @@ where
 
         let record_table = self.record_table.clone().unwrap();
         let subkey_table = self.subkey_table.clone().unwrap();
+        let history_table = self.history_table.clone().unwrap();
 
         let rt_xact = record_table.transact();
         let st_xact = subkey_table.transact();
+        let ht_xact = history_table.transact();
         let dead_records = mem::take(&mut self.dead_records);
         for dr in dead_records {
             // Record should already be gone from index
@@ -323,6 +344,13 @@ where
                     log_stor!(error "subkey could not be deleted: {}", e);
                 }
 
+                // Along with its history
+                if dr.record.history_data_size() != 0 {
+                    if let Err(e) = ht_xact.delete(0, &stkb) {
+                        log_stor!(error "subkey history could not be deleted: {}", e);
+                    }
+                }
+
                 // From cache
                 self.remove_from_subkey_cache(stk);
             }
@@ -341,6 +369,9 @@ where
         if let Err(e) = st_xact.commit().await {
             log_stor!(error "failed to commit subkey table transaction: {}", e);
         }
+        if let Err(e) = ht_xact.commit().await {
+            log_stor!(error "failed to commit history table transaction: {}", e);
+        }
     }
 
     #[instrument(level = "trace", target = "stor", skip_all)]
@@ -694,12 +725,33 @@ where
         }
     }
 
+    /// Check that replacing subkey data of 'prior_data_size' bytes with 'new_data_size' bytes
+    /// would keep a record within its size limit
+    pub fn check_record_data_size(
+        &mut self,
+        key: TypedKey,
+        prior_data_size: usize,
+        new_data_size: usize,
+    ) -> VeilidAPIResult<()> {
+        let Some(record_data_size) = self.with_record(key, |record| record.record_data_size())
+        else {
+            apibail_invalid_argument!("no record at this key", "key", key);
+        };
+        if record_data_size.saturating_sub(prior_data_size) + new_data_size
+            > self.limits.max_record_total_size
+        {
+            apibail_generic!("dht record too large");
+        }
+        Ok(())
+    }
+
     #[instrument(level = "trace", target = "stor", skip_all, err)]
     pub async fn set_subkey(
         &mut self,
         key: TypedKey,
         subkey: ValueSubkey,
         signed_value_data: Arc<SignedValueData>,
+        expected_seq: Option<ValueSeqNum>,
         watch_update_mode: WatchUpdateMode,
     ) -> VeilidAPIResult<()> {
         // Check size limit for data
@@ -712,9 +764,16 @@ where
         }
 
         // Get record subkey count and total size of all record subkey data exclusive of structures
-        let Some((subkey_count, prior_record_data_size)) = self.with_record(key, |record| {
-            (record.subkey_count(), record.record_data_size())
-        }) else {
+        let Some((subkey_count, prior_record_data_size, history_length, prior_history_data_size)) =
+            self.with_record(key, |record| {
+                (
+                    record.subkey_count(),
+                    record.record_data_size(),
+                    record.history_length(),
+                    record.history_data_size(),
+                )
+            })
+        else {
             apibail_invalid_argument!("no record at this key", "key", key);
         };
 
@@ -735,16 +794,45 @@ where
         let stk = SubkeyTableKey { key, subkey };
         let stk_bytes = stk.bytes();
 
-        if let Some(record_data) = self.subkey_cache.peek(&stk) {
-            prior_subkey_size = record_data.data_size();
+        let prior_record_data = if let Some(record_data) = self.subkey_cache.peek(&stk) {
+            Some(record_data.clone())
         } else {
             // If not in cache, try to pull from table store
-            if let Some(record_data) = subkey_table
+            subkey_table
                 .load_json::<RecordData>(0, &stk_bytes)
                 .await
                 .map_err(VeilidAPIError::internal)?
-            {
-                prior_subkey_size = record_data.data_size();
+        };
+        if let Some(record_data) = &prior_record_data {
+            prior_subkey_size = record_data.data_size();
+        }
+
+        // For a compare-and-set, the subkey must not have moved past the expected sequence number
+        // ValueSeqNum::MAX expects the subkey to have no value at all
+        if let Some(expected_seq) = expected_seq {
+            if let Some(record_data) = &prior_record_data {
+                let prior_signed_value_data = record_data.signed_value_data();
+                let prior_value_data = prior_signed_value_data.value_data();
+                if expected_seq == ValueSeqNum::MAX || prior_value_data.seq() > expected_seq {
+                    return Err(VeilidAPIError::conflict(prior_value_data.clone()));
+                }
+            }
+        }
+
+        // If the record keeps a history, the value being replaced goes to the end of it
+        let mut opt_history = None;
+        let mut new_history_data_size = prior_history_data_size;
+        if history_length != 0 {
+            if let Some(record_data) = prior_record_data.filter(|x| {
+                x.signed_value_data().value_data().seq() != signed_value_data.value_data().seq()
+            }) {
+                let mut history = self.load_subkey_history(stk).await?;
+                history.push(record_data);
+                let excess = history.len().saturating_sub(history_length as usize);
+                let removed_size = Self::history_data_size(&history[0..excess]);
+                history.drain(0..excess);
+                new_history_data_size = prior_history_data_size + prior_subkey_size - removed_size;
+                opt_history = Some(history);
             }
         }
 
@@ -760,10 +848,10 @@ where
 
         // Check new total storage space
         self.total_storage_space
-            .sub(prior_subkey_size as u64)
+            .sub((prior_subkey_size + prior_history_data_size) as u64)
             .unwrap();
         self.total_storage_space
-            .add(new_subkey_size as u64)
+            .add((new_subkey_size + new_history_data_size) as u64)
             .unwrap();
         if !self.total_storage_space.check_limit() {
             apibail_try_again!("out of storage space");
@@ -775,6 +863,14 @@ where
             .await
             .map_err(VeilidAPIError::internal)?;
 
+        // Write history
+        if let Some(history) = opt_history {
+            let Some(history_table) = self.history_table.clone() else {
+                apibail_internal!("record store not initialized");
+            };
+            history_table.store_json(0, &stk_bytes, &history).await?;
+        }
+
         // Write to inspect cache
         self.inspect_cache.replace_subkey_seq(
             &stk.key,
@@ -789,6 +885,7 @@ where
         self.with_record_mut(key, |record| {
             record.store_subkey(subkey);
             record.set_record_data_size(new_record_data_size);
+            record.set_history_data_size(new_history_data_size);
         })
         .expect("record should still be here");
 
@@ -802,6 +899,104 @@ where
         Ok(())
     }
 
+    fn history_data_size(history: &[RecordData]) -> usize {
+        history.iter().map(|x| x.data_size()).sum()
+    }
+
+    async fn load_subkey_history(&self, stk: SubkeyTableKey) -> VeilidAPIResult<Vec<RecordData>> {
+        let Some(history_table) = self.history_table.clone() else {
+            apibail_internal!("record store not initialized");
+        };
+        Ok(history_table
+            .load_json::<Vec<RecordData>>(0, &stk.bytes())
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Set how many prior values of each subkey a record keeps, zero keeps none
+    /// Lowering the length drops the oldest values right away
+    #[instrument(level = "trace", target = "stor", skip_all, err)]
+    pub async fn set_history_length(
+        &mut self,
+        key: TypedKey,
+        history_length: u32,
+    ) -> VeilidAPIResult<()> {
+        let Some((stored_subkeys, prior_history_data_size)) = self.with_record(key, |record| {
+            (record.stored_subkeys().clone(), record.history_data_size())
+        }) else {
+            apibail_invalid_argument!("no record at this key", "key", key);
+        };
+
+        let Some(history_table) = self.history_table.clone() else {
+            apibail_internal!("record store not initialized");
+        };
+
+        // Trim the history of every subkey that has more values than we now keep
+        let mut new_history_data_size = prior_history_data_size;
+        if prior_history_data_size != 0 {
+            let ht_xact = history_table.transact();
+            for subkey in stored_subkeys.iter() {
+                let stk = SubkeyTableKey { key, subkey };
+                let mut history = self.load_subkey_history(stk).await?;
+                let excess = history.len().saturating_sub(history_length as usize);
+                if excess == 0 {
+                    continue;
+                }
+                new_history_data_size -= Self::history_data_size(&history[0..excess]);
+                history.drain(0..excess);
+                if history.is_empty() {
+                    ht_xact.delete(0, &stk.bytes())?;
+                } else {
+                    ht_xact.store_json(0, &stk.bytes(), &history)?;
+                }
+            }
+            ht_xact.commit().await?;
+        }
+
+        // Update record
+        self.with_record_mut(key, |record| {
+            record.set_history_length(history_length);
+            record.set_history_data_size(new_history_data_size);
+        })
+        .expect("record should still be here");
+
+        // Update storage space
+        self.total_storage_space
+            .saturating_sub((prior_history_data_size - new_history_data_size) as u64);
+        self.total_storage_space.commit().unwrap();
+
+        Ok(())
+    }
+
+    /// Get the values a subkey has held whose sequence numbers are within an inclusive range, in ascending order
+    /// The current value is included along with the prior values kept in the history
+    #[instrument(level = "trace", target = "stor", skip_all, err)]
+    pub async fn get_subkey_history(
+        &mut self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        seqs: (ValueSeqNum, ValueSeqNum),
+    ) -> VeilidAPIResult<Option<Vec<Arc<SignedValueData>>>> {
+        let Some(get_result) = self.get_subkey(key, subkey, false).await? else {
+            // Record not available
+            return Ok(None);
+        };
+        let Some(current_value) = get_result.opt_value else {
+            return Ok(Some(vec![]));
+        };
+
+        let mut values: Vec<Arc<SignedValueData>> = self
+            .load_subkey_history(SubkeyTableKey { key, subkey })
+            .await?
+            .into_iter()
+            .map(|x| x.signed_value_data())
+            .collect();
+        values.push(current_value);
+        values.retain(|x| (seqs.0..=seqs.1).contains(&x.value_data().seq()));
+
+        Ok(Some(values))
+    }
+
     #[instrument(level = "trace", target = "stor", skip_all, err)]
     pub async fn inspect_record(
         &mut self,
@@ -1259,49 +1454,36 @@ where
         reclaimed
     }
 
-    pub fn debug_records(&self) -> String {
-        // Dump fields in an abbreviated way
-        let mut out = String::new();
-
-        out += "Record Index:\n";
-        for (rik, rec) in &self.record_index {
-            out += &format!(
-                "  {} age={} len={} subkeys={}\n",
-                rik.key,
-                debug_duration(get_timestamp() - rec.last_touched().as_u64()),
-                rec.record_data_size(),
-                rec.stored_subkeys(),
-            );
-        }
-        out += &format!("Subkey Cache Count: {}\n", self.subkey_cache.len());
-        out += &format!(
-            "Subkey Cache Total Size: {}\n",
-            self.subkey_cache_total_size.get()
-        );
-        out += &format!("Total Storage Space: {}\n", self.total_storage_space.get());
-        out += &format!("Dead Records: {}\n", self.dead_records.len());
-        for dr in &self.dead_records {
-            out += &format!("  {}\n", dr.key.key);
-        }
-        out += &format!("Changed Records: {}\n", self.changed_records.len());
-        for cr in &self.changed_records {
-            out += &format!("  {}\n", cr.key);
+    fn make_debug_record(key: TypedKey, record: &Record<D>) -> DebugRecord {
+        DebugRecord {
+            key,
+            owner: *record.owner(),
+            schema: record.schema(),
+            subkey_count: record.subkey_count() as u32,
+            stored_subkeys: record.stored_subkeys().clone(),
+            last_touched: record.last_touched(),
+            record_data_size: record.record_data_size() as u64,
+            history_length: record.history_length(),
         }
+    }
 
-        out
+    pub fn debug_records(&self) -> DebugRecordStore {
+        DebugRecordStore {
+            records: self
+                .record_index
+                .iter()
+                .map(|(rik, rec)| Self::make_debug_record(rik.key, rec))
+                .collect(),
+            subkey_cache_count: self.subkey_cache.len() as u32,
+            subkey_cache_total_size: self.subkey_cache_total_size.get() as u64,
+            total_storage_space: self.total_storage_space.get(),
+            dead_records: self.dead_records.iter().map(|dr| dr.key.key).collect(),
+            changed_records: self.changed_records.iter().map(|cr| cr.key).collect(),
+        }
     }
 
-    pub fn debug_record_info(&self, key: TypedKey) -> String {
-        let record_info = self
-            .peek_record(key, |r| format!("{:#?}", r))
-            .unwrap_or("Not found".to_owned());
-        let watched_record = match self.watched_records.get(&RecordTableKey { key }) {
-            Some(w) => {
-                format!("Remote Watches: {:#?}", w)
-            }
-            None => "No remote watches".to_owned(),
-        };
-        format!("{}\n{}\n", record_info, watched_record)
+    pub fn debug_record(&self, key: TypedKey) -> Option<DebugRecord> {
+        self.peek_record(key, |r| Self::make_debug_record(key, r))
     }
 
     pub async fn debug_record_subkey_info(&self, key: TypedKey, subkey: ValueSubkey) -> String {
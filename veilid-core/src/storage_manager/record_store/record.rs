@@ -10,6 +10,10 @@ where
     stored_subkeys: ValueSubkeyRangeSet,
     last_touched_ts: Timestamp,
     record_data_size: usize,
+    #[serde(default)]
+    history_length: u32,
+    #[serde(default)]
+    history_data_size: usize,
     detail: D,
 }
 
@@ -30,6 +34,8 @@ where
             stored_subkeys: ValueSubkeyRangeSet::new(),
             last_touched_ts: cur_ts,
             record_data_size: 0,
+            history_length: 0,
+            history_data_size: 0,
             detail,
         })
     }
@@ -68,6 +74,22 @@ where
         self.record_data_size
     }
 
+    pub fn set_history_length(&mut self, history_length: u32) {
+        self.history_length = history_length;
+    }
+
+    pub fn history_length(&self) -> u32 {
+        self.history_length
+    }
+
+    pub fn set_history_data_size(&mut self, size: usize) {
+        self.history_data_size = size;
+    }
+
+    pub fn history_data_size(&self) -> usize {
+        self.history_data_size
+    }
+
     pub fn schema(&self) -> DHTSchema {
         // unwrap is safe here because descriptor is immutable and set in new()
         self.descriptor.schema().unwrap()
@@ -77,6 +99,7 @@ where
         (mem::size_of::<Self>() - mem::size_of::<Arc<SignedValueDescriptor>>())
             + self.descriptor.total_size()
             + self.record_data_size
+            + self.history_data_size
     }
 
     pub fn detail(&self) -> &D {
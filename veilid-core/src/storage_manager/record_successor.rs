@@ -0,0 +1,199 @@
+use super::*;
+
+/// The most successors that will be followed when opening a record
+const MAX_RECORD_SUCCESSOR_CHAIN_LENGTH: usize = 16;
+
+impl StorageManager {
+    /// Publish a successor for an opened local record, pointing to the record that replaces it
+    /// The record must be opened with its owner as the writer, and the successor record must
+    /// exist locally so its owner can be included in the signed successor
+    /// The successor is written unsealed to 'subkey' so it can be followed without the record's encryption key
+    /// Successors are write-once: publishing a different successor once one is set fails, and publishing the same one does nothing
+    /// 'subkey' must never have been written, as only the first value written to it is trusted as a successor
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn set_record_successor(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        successor: TypedKey,
+    ) -> VeilidAPIResult<()> {
+        // Get cryptosystem
+        let Some(vcrypto) = self.unlocked_inner.crypto.get(key.kind) else {
+            apibail_generic!("unsupported cryptosystem");
+        };
+
+        let (writer, new_owner) = {
+            let inner = self.lock().await?;
+            let Some(opened_record) = inner.opened_records.get(&key) else {
+                apibail_generic!("record not open");
+            };
+            let Some(writer) = opened_record.writer().cloned() else {
+                apibail_generic!("value is not writable");
+            };
+            let Some(local_record_store) = inner.local_record_store.as_ref() else {
+                apibail_not_initialized!();
+            };
+            let Some(owner) = local_record_store.peek_record(key, |r| *r.owner()) else {
+                apibail_key_not_found!(key);
+            };
+            if writer.key != owner {
+                apibail_generic!("only the owner can set a record successor");
+            }
+            let Some(new_owner) = local_record_store.peek_record(successor, |r| *r.owner()) else {
+                apibail_key_not_found!(successor);
+            };
+            (writer, new_owner)
+        };
+
+        // Check the newest successor on the network so a published successor is not redirected
+        if let Some(existing) = self.get_record_successor(key, subkey, true).await? {
+            if *existing.successor() == successor && *existing.new_owner() == new_owner {
+                return Ok(());
+            }
+            apibail_generic!("record successor is already set");
+        }
+
+        let signed_successor = SignedRecordSuccessor::make_signature(
+            key,
+            successor,
+            new_owner,
+            vcrypto,
+            writer.key,
+            writer.secret,
+        )?;
+
+        // Only write to an empty subkey, so the successor is the first value it ever holds
+        let successor_bytes = signed_successor.to_bytes();
+        match self
+            .set_stored_value(
+                key,
+                subkey,
+                successor_bytes.clone(),
+                Some(SetDHTValueOptions {
+                    writer: Some(writer),
+                    expected_seq: Some(ValueSeqNum::MAX),
+                }),
+            )
+            .await
+        {
+            Ok(_) => Ok(()),
+            // Someone else publishing the same successor first is fine
+            Err(VeilidAPIError::Conflict { value }) if value.data() == successor_bytes => Ok(()),
+            Err(VeilidAPIError::Conflict { .. }) => {
+                apibail_generic!("record successor subkey is already in use");
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get the successor of an opened local record from 'subkey' if one has been published
+    /// The successor is validated against the owner of the record
+    /// Only the first value written to 'subkey' is trusted, so a successor that replaced another value is an error
+    /// Returns None if 'subkey' is unset or holds something other than a successor
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn get_record_successor(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        force_refresh: bool,
+    ) -> VeilidAPIResult<Option<SignedRecordSuccessor>> {
+        // Get cryptosystem
+        let Some(vcrypto) = self.unlocked_inner.crypto.get(key.kind) else {
+            apibail_generic!("unsupported cryptosystem");
+        };
+
+        let Some(value) = self.get_stored_value(key, subkey, force_refresh).await? else {
+            return Ok(None);
+        };
+        if !SignedRecordSuccessor::is_record_successor(value.data()) {
+            return Ok(None);
+        }
+        if value.seq() != 0 {
+            apibail_generic!("record successor was not the first value written to its subkey");
+        }
+        let signed_successor = SignedRecordSuccessor::try_from_bytes(value.data())?;
+
+        let descriptor = {
+            let inner = self.lock().await?;
+            let Some(local_record_store) = inner.local_record_store.as_ref() else {
+                apibail_not_initialized!();
+            };
+            let Some(descriptor) = local_record_store.peek_record(key, |r| r.descriptor()) else {
+                apibail_key_not_found!(key);
+            };
+            descriptor
+        };
+        descriptor.validate_successor(vcrypto, key, &signed_successor)?;
+
+        Ok(Some(signed_successor))
+    }
+
+    /// Open a record and follow the successors published to 'subkey' of each record in the chain,
+    /// returning the descriptor of the last record, which is left open
+    /// Each successor must be owned by the owner named by the record before it
+    /// Records in the chain that were not already open are closed once they have been followed
+    #[instrument(level = "trace", target = "stor", skip_all)]
+    pub async fn open_record_following_successors(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        writer: Option<KeyPair>,
+        safety_selection: SafetySelection,
+    ) -> VeilidAPIResult<DHTRecordDescriptor> {
+        let mut visited = HashSet::<TypedKey>::new();
+        let mut key = key;
+        let mut expected_owner: Option<PublicKey> = None;
+
+        loop {
+            let was_open = self.lock().await?.opened_records.contains_key(&key);
+            let descriptor = self.open_record(key, writer, safety_selection).await?;
+            visited.insert(key);
+
+            let res = self
+                .check_record_successor(&descriptor, subkey, expected_owner, &visited)
+                .await;
+            if let Ok(None) = res {
+                return Ok(descriptor);
+            }
+
+            // This record has been followed or failed, so put it back the way we found it
+            if !was_open {
+                self.close_record(key).await?;
+            }
+
+            let Some(successor) = res? else {
+                apibail_internal!("record successor disappeared while following it");
+            };
+            key = *successor.successor();
+            expected_owner = Some(*successor.new_owner());
+        }
+    }
+
+    /// Check an opened record in a successor chain and get the successor it points to
+    async fn check_record_successor(
+        &self,
+        descriptor: &DHTRecordDescriptor,
+        subkey: ValueSubkey,
+        expected_owner: Option<PublicKey>,
+        visited: &HashSet<TypedKey>,
+    ) -> VeilidAPIResult<Option<SignedRecordSuccessor>> {
+        if let Some(expected_owner) = expected_owner {
+            if *descriptor.owner() != expected_owner {
+                apibail_generic!("record successor is not owned by the expected owner");
+            }
+        }
+        let Some(successor) = self
+            .get_record_successor(*descriptor.key(), subkey, true)
+            .await?
+        else {
+            return Ok(None);
+        };
+        if visited.contains(successor.successor()) {
+            apibail_generic!("record successor chain has a cycle");
+        }
+        if visited.len() >= MAX_RECORD_SUCCESSOR_CHAIN_LENGTH {
+            apibail_generic!("record successor chain is too long");
+        }
+        Ok(Some(successor))
+    }
+}
@@ -389,6 +389,7 @@ impl StorageManager {
                     key,
                     subkey,
                     result.signed_value_data.clone(),
+                    None,
                     WatchUpdateMode::UpdateAll,
                 )
                 .await?;
@@ -400,6 +401,59 @@ impl StorageManager {
         Ok(None)
     }
 
+    /// Handle the result of a compare-and-set once its whole fanout has finished
+    /// The value is only written locally once enough of the network took it,
+    /// so a failed compare-and-set leaves nothing behind for a retry to conflict with
+    #[instrument(level = "trace", target = "dht", skip_all)]
+    pub(super) async fn process_outbound_compare_and_set_result(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        signed_value_data: Arc<SignedValueData>,
+        expected_seq: ValueSeqNum,
+        result: set_value::OutboundSetValueResult,
+    ) -> VeilidAPIResult<Option<ValueData>> {
+        // Regain the lock after network access
+        let mut inner = self.lock().await?;
+
+        // A compare-and-set is never queued, as the offline write would be pushed without the check
+        if self.check_fanout_set_offline(key, subkey, &result.fanout_result) {
+            apibail_try_again!("compare-and-set did not reach enough nodes");
+        }
+
+        // Keep the list of nodes that returned a value for later reference
+        inner.process_fanout_results(key, core::iter::once((subkey, &result.fanout_result)), true);
+
+        // Any value that won over ours is a conflict
+        if result.signed_value_data.value_data() != signed_value_data.value_data() {
+            inner
+                .handle_set_local_value(
+                    key,
+                    subkey,
+                    result.signed_value_data.clone(),
+                    None,
+                    WatchUpdateMode::UpdateAll,
+                )
+                .await?;
+            return Err(VeilidAPIError::conflict(
+                result.signed_value_data.value_data().clone(),
+            ));
+        }
+
+        // The network took our value, so commit it locally, unless a newer value arrived meanwhile
+        inner
+            .handle_set_local_value(
+                key,
+                subkey,
+                signed_value_data,
+                Some(expected_seq),
+                WatchUpdateMode::NoUpdate,
+            )
+            .await?;
+
+        Ok(None)
+    }
+
     /// Handle a received 'Set Value' query
     /// Returns a None if the value passed in was set
     /// Returns a Some(current value) if the value was older and the current value was kept
@@ -484,7 +538,13 @@ impl StorageManager {
         // Do the set and return no new value
         let res = if is_local {
             inner
-                .handle_set_local_value(key, subkey, value, WatchUpdateMode::ExcludeTarget(target))
+                .handle_set_local_value(
+                    key,
+                    subkey,
+                    value,
+                    None,
+                    WatchUpdateMode::ExcludeTarget(target),
+                )
                 .await
         } else {
             inner
@@ -0,0 +1,344 @@
+use super::*;
+
+/// Info tracked per subkey
+struct SubkeySetCount {
+    /// The subkey being set
+    pub subkey: ValueSubkey,
+    /// The latest value of the subkey, may be the value passed in
+    pub value: Arc<SignedValueData>,
+    /// The nodes that have set the value so far (up to the consensus count)
+    pub value_nodes: Vec<NodeRef>,
+    /// The number of non-sets since the last set we have received
+    pub missed_since_last_set: usize,
+}
+
+impl SubkeySetCount {
+    /// Same completion criteria as a single subkey 'set value'
+    fn is_done(&self, get_consensus_count: usize, set_consensus_count: usize) -> bool {
+        self.value_nodes.len() >= set_consensus_count
+            || (self.value_nodes.len() >= get_consensus_count
+                && self.missed_since_last_set >= get_consensus_count)
+    }
+}
+
+/// The context of the outbound_set_values operation
+struct OutboundSetValuesContext {
+    /// The latest values and result counts so far, one for each subkey being set
+    pub values: Vec<SubkeySetCount>,
+}
+
+/// The result of the outbound_set_values operation
+pub(super) struct OutboundSetValuesResult {
+    /// Fanout results for each subkey
+    pub fanout_results: Vec<FanoutResult>,
+    /// The value of each subkey after the set, which may be newer than the value that was set
+    pub values: Vec<Arc<SignedValueData>>,
+}
+
+impl StorageManager {
+    /// Perform a 'set values' query on the network
+    /// Each node visited is sent all of the subkeys that are not done yet, in as many requests as needed
+    /// The values must be in ascending subkey order and their number must not exceed what fits in a single request
+    #[instrument(level = "trace", target = "dht", skip_all, err)]
+    pub(super) async fn outbound_set_values(
+        &self,
+        rpc_processor: RPCProcessor,
+        key: TypedKey,
+        safety_selection: SafetySelection,
+        values: Vec<(ValueSubkey, Arc<SignedValueData>)>,
+        descriptor: Arc<SignedValueDescriptor>,
+    ) -> VeilidAPIResult<OutboundSetValuesResult> {
+        let routing_table = rpc_processor.routing_table();
+        let routing_domain = RoutingDomain::PublicInternet;
+
+        // Get the DHT parameters for 'SetValue'
+        let (key_count, get_consensus_count, set_consensus_count, fanout, timeout_us) = {
+            let c = self.unlocked_inner.config.get();
+            (
+                c.network.dht.max_find_node_count as usize,
+                c.network.dht.get_value_count as usize,
+                c.network.dht.set_value_count as usize,
+                c.network.dht.set_value_fanout as usize,
+                TimestampDuration::from(ms_to_us(c.network.dht.set_value_timeout_ms)),
+            )
+        };
+
+        // Get the nodes we know are caching this value to seed the fanout
+        let init_fanout_queue = {
+            let inner = self.inner.lock().await;
+            inner
+                .get_value_nodes(key)?
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|x| {
+                    x.node_info(routing_domain)
+                        .map(|ni| ni.has_all_capabilities(&[CAP_DHT]))
+                        .unwrap_or_default()
+                })
+                .collect()
+        };
+
+        // Make do-set-values answer context
+        let schema = descriptor.schema()?;
+        let context = Arc::new(Mutex::new(OutboundSetValuesContext {
+            values: values
+                .into_iter()
+                .map(|(subkey, value)| SubkeySetCount {
+                    subkey,
+                    value,
+                    value_nodes: vec![],
+                    missed_since_last_set: 0,
+                })
+                .collect(),
+        }));
+
+        // Routine to call to generate fanout
+        let call_routine = |next_node: NodeRef| {
+            let rpc_processor = rpc_processor.clone();
+            let context = context.clone();
+            let descriptor = descriptor.clone();
+            let schema = schema.clone();
+            async move {
+                // The node may not have the record yet, so the first request carries the descriptor
+                let mut send_descriptor = true;
+
+                // Nodes that can not decode batched questions are sent one subkey at a time
+                let can_batch = next_node
+                    .node_info(routing_domain)
+                    .map(|ni| ni.has_capability(CAP_DHT_BATCH))
+                    .unwrap_or_default();
+
+                // Send every subkey this fanout is not done with yet
+                let mut pending_subkeys = {
+                    let ctx = context.lock();
+                    let mut pending_subkeys = ValueSubkeyRangeSet::new();
+                    for ssc in &ctx.values {
+                        if !ssc.is_done(get_consensus_count, set_consensus_count) {
+                            pending_subkeys.insert(ssc.subkey);
+                        }
+                    }
+                    pending_subkeys
+                };
+
+                // Keep sending to this node until it has covered all of the subkeys we want it to set
+                let mut peer_info_list = vec![];
+                while !pending_subkeys.is_empty() {
+                    // Get the most recent values to send, as many as fit in one request
+                    let send_values = {
+                        let ctx = context.lock();
+                        let mut send_values = vec![];
+                        let mut send_data_len = 0usize;
+                        for ssc in &ctx.values {
+                            if !pending_subkeys.contains(ssc.subkey) {
+                                continue;
+                            }
+                            let len = ssc.value.value_data().data().len();
+                            if !send_values.is_empty()
+                                && send_data_len + len > MAX_SET_VALUES_Q_DATA_LEN
+                            {
+                                break;
+                            }
+                            send_data_len += len;
+                            send_values.push((ssc.subkey, (*ssc.value).clone()));
+                            if !can_batch {
+                                break;
+                            }
+                        }
+                        send_values
+                    };
+
+                    // send across the wire
+                    let dest = Destination::direct(next_node.routing_domain_filtered(routing_domain))
+                        .with_safety(safety_selection);
+                    let answer = if can_batch {
+                        network_result_try!(
+                            rpc_processor
+                                .clone()
+                                .rpc_call_set_values(
+                                    dest,
+                                    key,
+                                    send_values,
+                                    (*descriptor).clone(),
+                                    send_descriptor,
+                                )
+                                .await?
+                        )
+                        .answer
+                    } else {
+                        let Some((subkey, value)) = send_values.into_iter().next() else {
+                            break;
+                        };
+                        let answer = network_result_try!(
+                            rpc_processor
+                                .clone()
+                                .rpc_call_set_value(
+                                    dest,
+                                    key,
+                                    subkey,
+                                    value,
+                                    (*descriptor).clone(),
+                                    send_descriptor,
+                                )
+                                .await?
+                        )
+                        .answer;
+                        SetValuesAnswer {
+                            set: answer.set,
+                            subkeys: ValueSubkeyRangeSet::single(subkey),
+                            values: answer.value.map(|v| (subkey, v)).into_iter().collect(),
+                            peers: answer.peers,
+                        }
+                    };
+                    peer_info_list = answer.peers;
+
+                    let mut ctx = context.lock();
+
+                    // If the node was not close enough to possibly set the values, it will not set any of them
+                    if !answer.set {
+                        for ssc in ctx.values.iter_mut() {
+                            if pending_subkeys.contains(ssc.subkey) {
+                                ssc.missed_since_last_set += 1;
+                            }
+                        }
+
+                        // Return peers if we have some
+                        log_network_result!(debug "SetValues missed, fanout call returned peers {}", peer_info_list.len());
+                        return Ok(NetworkResult::value(FanoutCallOutput { peer_info_list }));
+                    }
+
+                    // Having accepted a set, the node holds the record and needs no descriptor for the rest
+                    send_descriptor = false;
+
+                    // Answers always cover at least one sent subkey so this terminates
+                    pending_subkeys = pending_subkeys.difference(&answer.subkeys);
+
+                    let mut newer_values = answer.values.into_iter().peekable();
+                    for ssc in ctx.values.iter_mut() {
+                        if !answer.subkeys.contains(ssc.subkey) {
+                            continue;
+                        }
+
+                        // See if we got a value back for this subkey
+                        let Some((_, value)) =
+                            newer_values.next_if(|(subkey, _)| *subkey == ssc.subkey)
+                        else {
+                            // No newer value was found and returned, so increase our consensus count
+                            ssc.value_nodes.push(next_node.clone());
+                            ssc.missed_since_last_set = 0;
+                            continue;
+                        };
+
+                        // Keep the value if we got one and it is newer and it passes schema validation
+                        log_dht!(debug "SetValues got value back: subkey={} len={} seq={}", ssc.subkey, value.value_data().data().len(), value.value_data().seq());
+
+                        // Validate with schema
+                        if !schema.check_subkey_value_data(
+                            descriptor.owner(),
+                            ssc.subkey,
+                            value.value_data(),
+                        ) {
+                            // Validation failed, ignore this value and pretend we never saw this node
+                            return Ok(NetworkResult::invalid_message(format!(
+                                "Schema validation failed on subkey {}",
+                                ssc.subkey
+                            )));
+                        }
+
+                        // If we got a value back it should be different than the one we are setting
+                        // But in the case of a benign bug, we can just move on
+                        if ssc.value.value_data() == value.value_data() {
+                            ssc.value_nodes.push(next_node.clone());
+                            ssc.missed_since_last_set = 0;
+                            continue;
+                        }
+
+                        // We have a prior value, ensure this is a newer sequence number
+                        let prior_seq = ssc.value.value_data().seq();
+                        let new_seq = value.value_data().seq();
+                        if new_seq < prior_seq {
+                            // If the sequence number is older node should have not returned a value here.
+                            // Skip this node and its closer list because it is misbehaving
+                            // Ignore this value and pretend we never saw this node
+                            return Ok(NetworkResult::invalid_message("Sequence number is older"));
+                        }
+
+                        // If the sequence number is greater or equal, keep it
+                        // even if the sequence number is the same, accept all conflicts in an attempt to resolve them
+                        ssc.value = Arc::new(value);
+                        // One node has shown us this value so far
+                        ssc.value_nodes = vec![next_node.clone()];
+                        ssc.missed_since_last_set = 0;
+                    }
+                }
+
+                // Return peers if we have some
+                log_network_result!(debug "SetValues fanout call returned peers {}", peer_info_list.len());
+
+                Ok(NetworkResult::value(FanoutCallOutput { peer_info_list }))
+            }.instrument(tracing::trace_span!("outbound_set_values fanout call"))
+        };
+
+        // Routine to call to check if we're done at each step
+        let check_done = |_closest_nodes: &[NodeRef]| {
+            // If every subkey has reached the consensus we care about, return done
+            let ctx = context.lock();
+            if ctx
+                .values
+                .iter()
+                .all(|ssc| ssc.is_done(get_consensus_count, set_consensus_count))
+            {
+                return Some(());
+            }
+            None
+        };
+
+        // Call the fanout
+        let fanout_call = FanoutCall::new(
+            routing_table.clone(),
+            key,
+            key_count,
+            fanout,
+            timeout_us,
+            // Nodes without batch support are still visited, and sent each subkey in turn
+            capability_fanout_node_info_filter(vec![CAP_DHT]),
+            call_routine,
+            check_done,
+        );
+
+        let kind = match fanout_call.run(init_fanout_queue).await {
+            // If we don't finish in the timeout (too much time passed checking for consensus)
+            TimeoutOr::Timeout => FanoutResultKind::Timeout,
+            // If we finished with or without consensus (enough nodes returning the same value)
+            TimeoutOr::Value(Ok(Some(()))) => FanoutResultKind::Finished,
+            // If we ran out of nodes before getting consensus)
+            TimeoutOr::Value(Ok(None)) => FanoutResultKind::Exhausted,
+            // Failed
+            TimeoutOr::Value(Err(e)) => {
+                // If we finished with an error, return that
+                log_dht!(debug "SetValues Fanout Error: {}", e);
+                return Err(e.into());
+            }
+        };
+
+        let ctx = context.lock();
+        let mut fanout_results = vec![];
+        for ssc in &ctx.values {
+            let fanout_result = FanoutResult {
+                kind: if ssc.is_done(get_consensus_count, set_consensus_count) {
+                    FanoutResultKind::Finished
+                } else {
+                    kind
+                },
+                value_nodes: ssc.value_nodes.clone(),
+            };
+            fanout_results.push(fanout_result);
+        }
+
+        log_network_result!(debug "SetValues Fanout ({:?}):\n{}", kind, debug_fanout_results(&fanout_results));
+
+        Ok(OutboundSetValuesResult {
+            fanout_results,
+            values: ctx.values.iter().map(|ssc| ssc.value.clone()).collect(),
+        })
+    }
+}
@@ -150,12 +150,9 @@ impl StorageManagerInner {
         Ok(())
     }
 
-    pub async fn stop_ticker(&mut self) {
-        // Stop ticker
-        let tick_future = self.tick_future.take();
-        if let Some(f) = tick_future {
-            f.await;
-        }
+    /// Stop the ticker, returning the future that completes once the last tick has finished
+    pub fn stop_ticker(&mut self) -> Option<SendPinBoxFuture<()>> {
+        self.tick_future.take()
     }
 
     pub async fn terminate(&mut self) {
@@ -318,7 +315,7 @@ impl StorageManagerInner {
                 continue;
             };
             local_record_store
-                .set_subkey(key, subkey, subkey_data, WatchUpdateMode::NoUpdate)
+                .set_subkey(key, subkey, subkey_data, None, WatchUpdateMode::NoUpdate)
                 .await?;
         }
 
@@ -449,7 +446,13 @@ impl StorageManagerInner {
         if let Some(signed_value_data) = get_result.opt_value {
             // Write subkey to local store
             local_record_store
-                .set_subkey(key, subkey, signed_value_data, WatchUpdateMode::NoUpdate)
+                .set_subkey(
+                    key,
+                    subkey,
+                    signed_value_data,
+                    None,
+                    WatchUpdateMode::NoUpdate,
+                )
                 .await?;
         }
 
@@ -578,6 +581,7 @@ impl StorageManagerInner {
         key: TypedKey,
         subkey: ValueSubkey,
         signed_value_data: Arc<SignedValueData>,
+        expected_seq: Option<ValueSeqNum>,
         watch_update_mode: WatchUpdateMode,
     ) -> VeilidAPIResult<()> {
         // See if it's in the local record store
@@ -587,12 +591,32 @@ impl StorageManagerInner {
 
         // Write subkey to local store
         local_record_store
-            .set_subkey(key, subkey, signed_value_data, watch_update_mode)
+            .set_subkey(
+                key,
+                subkey,
+                signed_value_data,
+                expected_seq,
+                watch_update_mode,
+            )
             .await?;
 
         Ok(())
     }
 
+    /// Check that replacing local subkey data of 'prior_data_size' bytes with 'new_data_size' bytes
+    /// would keep the record within its size limit
+    pub(super) fn check_local_record_data_size(
+        &mut self,
+        key: TypedKey,
+        prior_data_size: usize,
+        new_data_size: usize,
+    ) -> VeilidAPIResult<()> {
+        let Some(local_record_store) = self.local_record_store.as_mut() else {
+            apibail_not_initialized!();
+        };
+        local_record_store.check_record_data_size(key, prior_data_size, new_data_size)
+    }
+
     #[instrument(level = "trace", target = "stor", skip_all, err)]
     pub(super) async fn handle_inspect_local_value(
         &mut self,
@@ -671,7 +695,7 @@ impl StorageManagerInner {
 
         // Write subkey to remote store
         remote_record_store
-            .set_subkey(key, subkey, signed_value_data, watch_update_mode)
+            .set_subkey(key, subkey, signed_value_data, None, watch_update_mode)
             .await?;
 
         Ok(())
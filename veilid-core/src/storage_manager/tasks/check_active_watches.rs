@@ -49,6 +49,7 @@ impl StorageManager {
 
                 if is_dead {
                     v.clear_active_watch();
+                    self.set_active_watch_node(*k, None);
 
                     if let Some(update_callback) = opt_update_callback.clone() {
                         // Send valuechange with dead count and no subkeys
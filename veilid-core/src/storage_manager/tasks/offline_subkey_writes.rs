@@ -4,7 +4,10 @@ use stop_token::future::FutureExt as _;
 
 #[derive(Debug)]
 enum OfflineSubkeyWriteResult {
-    Finished(set_value::OutboundSetValueResult),
+    Finished {
+        queued_value: Arc<SignedValueData>,
+        result: set_value::OutboundSetValueResult,
+    },
     Cancelled,
     Dropped,
 }
@@ -86,12 +89,16 @@ impl StorageManager {
                                         key,
                                         subkey,
                                         result.signed_value_data.clone(),
+                                        None,
                                         WatchUpdateMode::UpdateAll,
                                     )
                                     .await?;
                             }
 
-                            return Ok(OfflineSubkeyWriteResult::Finished(result));
+                            return Ok(OfflineSubkeyWriteResult::Finished {
+                                queued_value: value,
+                                result,
+                            });
                         }
                         Err(e) => {
                             log_stor!(debug "failed to get offline subkey write result: {}:{} {}", key, subkey, e);
@@ -124,7 +131,7 @@ impl StorageManager {
                 break;
             }
 
-            let result = match self
+            let (queued_value, result) = match self
                 .clone()
                 .write_single_offline_subkey(
                     stop_token.clone(),
@@ -134,7 +141,10 @@ impl StorageManager {
                 )
                 .await?
             {
-                OfflineSubkeyWriteResult::Finished(r) => r,
+                OfflineSubkeyWriteResult::Finished {
+                    queued_value,
+                    result,
+                } => (queued_value, result),
                 OfflineSubkeyWriteResult::Cancelled => {
                     // Stop now and return what we have
                     break;
@@ -147,10 +157,27 @@ impl StorageManager {
             };
 
             // Process non-partial setvalue result
+            // A newer value replacing the queued one leaves nothing of ours to write, even if we are offline again
+            let superseded = result.signed_value_data.value_data() != queued_value.value_data();
             let was_offline =
                 self.check_fanout_set_offline(work_item.key, subkey, &result.fanout_result);
-            if !was_offline {
+            if superseded || !was_offline {
                 written_subkeys.insert(subkey);
+
+                // Tell the application what became of the queued write
+                let superseded_by =
+                    superseded.then(|| result.signed_value_data.value_data().clone());
+                if let Err(e) = self
+                    .update_callback_offline_write(
+                        work_item.key,
+                        subkey,
+                        queued_value.value_data().clone(),
+                        superseded_by,
+                    )
+                    .await
+                {
+                    log_stor!(debug "Failed to send offline write update: {}", e);
+                }
             }
             fanout_results.push((subkey, result.fanout_result));
         }
@@ -242,6 +269,72 @@ impl StorageManager {
         Ok(())
     }
 
+    // Write the queued subkeys of a single key now rather than on the next task tick
+    // Subkeys already in flight are left to the offline subkey writes task
+    #[instrument(level = "trace", target = "stor", skip_all, err)]
+    pub(crate) async fn flush_offline_subkey_writes_for_key(self, key: TypedKey) -> EyreResult<()> {
+        // Take the queued subkeys so the task does not write them at the same time
+        let work_item = {
+            let mut inner = self.lock().await?;
+            let Some(osw) = inner.offline_subkey_writes.get_mut(&key) else {
+                return Ok(());
+            };
+            WorkItem {
+                key,
+                safety_selection: osw.safety_selection,
+                subkeys: mem::take(&mut osw.subkeys),
+            }
+        };
+        if work_item.subkeys.is_empty() {
+            return Ok(());
+        }
+        let safety_selection = work_item.safety_selection;
+        let subkeys = work_item.subkeys.clone();
+
+        // Nothing stops a flush early, it runs until each subkey is written or found to be offline
+        let stop_source = StopSource::new();
+        let res = self
+            .clone()
+            .process_work_item(stop_source.token(), work_item)
+            .await;
+
+        // Put back anything that was not written, even due to an error
+        let mut inner = self.lock().await?;
+        let subkeys_still_offline = match &res {
+            Ok(result) => subkeys.difference(&result.written_subkeys),
+            Err(_) => subkeys,
+        };
+        match inner.offline_subkey_writes.entry(key) {
+            std::collections::hash_map::Entry::Occupied(mut o) => {
+                let osw = o.get_mut();
+                osw.subkeys = osw.subkeys.union(&subkeys_still_offline);
+                if osw.subkeys.is_empty() && osw.subkeys_in_flight.is_empty() {
+                    log_stor!(debug "Offline write flushed key {}", key);
+                    o.remove();
+                }
+            }
+            std::collections::hash_map::Entry::Vacant(v) => {
+                // The task may have removed the entry while it had nothing queued
+                if !subkeys_still_offline.is_empty() {
+                    v.insert(OfflineSubkeyWrite {
+                        safety_selection,
+                        subkeys: subkeys_still_offline,
+                        subkeys_in_flight: ValueSubkeyRangeSet::new(),
+                    });
+                }
+            }
+        }
+
+        let result = res?;
+        inner.process_fanout_results(
+            result.key,
+            result.fanout_results.iter().map(|x| (x.0, &x.1)),
+            true,
+        );
+
+        Ok(())
+    }
+
     // Best-effort write subkeys to the network that were written offline
     #[instrument(level = "trace", target = "stor", skip_all, err)]
     pub(crate) async fn offline_subkey_writes_task_routine(
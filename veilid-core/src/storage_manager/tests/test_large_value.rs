@@ -0,0 +1,148 @@
+use crate::tests::test_veilid_config::*;
+use crate::*;
+
+async fn startup() -> VeilidAPI {
+    trace!("test_large_value: starting");
+    let (update_callback, config_callback) = setup_veilid_core();
+    api_startup(update_callback, config_callback)
+        .await
+        .expect("startup failed")
+}
+
+async fn shutdown(api: VeilidAPI) {
+    trace!("test_large_value: shutting down");
+    api.shutdown().await;
+    trace!("test_large_value: finished");
+}
+
+fn routing_context(api: &VeilidAPI) -> RoutingContext {
+    api.routing_context()
+        .unwrap()
+        .with_safety(SafetySelection::Unsafe(Sequencing::NoPreference))
+        .unwrap()
+}
+
+/// A value that fills more than 'len_subkeys' subkeys, so it takes 'len_subkeys' + 1 chunks
+fn make_large_value(len_subkeys: usize, fill: u8) -> Vec<u8> {
+    (0..ValueData::MAX_LEN * len_subkeys)
+        .map(|n| fill.wrapping_add((n % 251) as u8))
+        .collect()
+}
+
+async fn assert_large_value(rc: &RoutingContext, key: TypedKey, subkey: ValueSubkey, data: &[u8]) {
+    let get_result = rc.get_dht_large_value(key, subkey, false).await;
+    assert_eq!(
+        get_result
+            .expect("should not be error")
+            .expect("should hold a value"),
+        data
+    );
+}
+
+async fn assert_cleared(rc: &RoutingContext, key: TypedKey, subkeys: ValueSubkeyRangeSet) {
+    for subkey in subkeys.iter() {
+        let get_result = rc.get_dht_value(key, subkey, false).await;
+        assert!(get_result
+            .expect("should not be error")
+            .expect("should hold a value")
+            .data()
+            .is_empty());
+    }
+}
+
+pub async fn test_large_value_shrink(api: VeilidAPI) {
+    trace!("test_large_value_shrink");
+    let rc = routing_context(&api);
+
+    let rec = rc
+        .create_dht_record(DHTSchema::dflt(8).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let key = *rec.key();
+
+    let long_data = make_large_value(3, 0);
+    let set_result = rc
+        .set_dht_large_value(key, 1, long_data.clone(), None)
+        .await;
+    assert_eq!(
+        set_result.expect("should not be error"),
+        ValueSubkeyRangeSet::single_range(1, 5)
+    );
+    assert_large_value(&rc, key, 1, &long_data).await;
+
+    // Fewer chunks, and the ones the longer value no longer uses are cleared
+    let short_data = make_large_value(1, 1);
+    let set_result = rc
+        .set_dht_large_value(key, 1, short_data.clone(), None)
+        .await;
+    assert_eq!(
+        set_result.expect("should not be error"),
+        ValueSubkeyRangeSet::single_range(1, 3)
+    );
+    assert_large_value(&rc, key, 1, &short_data).await;
+    assert_cleared(&rc, key, ValueSubkeyRangeSet::single_range(4, 5)).await;
+
+    // The shorter value is what is stored, not just what is cached for the open record
+    rc.close_dht_record(key).await.unwrap();
+    let keypair = KeyPair::new(*rec.owner(), *rec.owner_secret().unwrap());
+    rc.open_dht_record(key, Some(keypair)).await.unwrap();
+    assert_large_value(&rc, key, 1, &short_data).await;
+
+    rc.close_dht_record(key).await.unwrap();
+    rc.delete_dht_record(key).await.unwrap();
+}
+
+pub async fn test_large_value_failed_write(api: VeilidAPI) {
+    trace!("test_large_value_failed_write");
+    let rc = routing_context(&api);
+
+    let rec = rc
+        .create_dht_record(DHTSchema::dflt(40).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let key = *rec.key();
+
+    // A value, and another one that leaves too little room in the record for the first to grow much
+    let data = make_large_value(3, 0);
+    rc.set_dht_large_value(key, 0, data.clone(), None)
+        .await
+        .unwrap();
+    let other_data = make_large_value(24, 1);
+    rc.set_dht_large_value(key, 10, other_data.clone(), None)
+        .await
+        .unwrap();
+
+    // A longer value that does not fit fails without writing any of its chunks over the old ones
+    let longer_data = make_large_value(8, 2);
+    let set_result = rc
+        .set_dht_large_value(key, 0, longer_data.clone(), None)
+        .await;
+    assert_err!(set_result);
+    assert_large_value(&rc, key, 0, &data).await;
+    assert_large_value(&rc, key, 10, &other_data).await;
+
+    // The record can still be written afterward, and the old value's extra chunks are cleared
+    let shorter_data = make_large_value(1, 3);
+    let set_result = rc
+        .set_dht_large_value(key, 0, shorter_data.clone(), None)
+        .await;
+    assert_eq!(
+        set_result.expect("should not be error"),
+        ValueSubkeyRangeSet::single_range(0, 2)
+    );
+    assert_large_value(&rc, key, 0, &shorter_data).await;
+    assert_cleared(&rc, key, ValueSubkeyRangeSet::single_range(3, 4)).await;
+    assert_large_value(&rc, key, 10, &other_data).await;
+
+    rc.close_dht_record(key).await.unwrap();
+    rc.delete_dht_record(key).await.unwrap();
+}
+
+pub async fn test_all() {
+    let api = startup().await;
+
+    test_large_value_shrink(api.clone()).await;
+    test_large_value_failed_write(api.clone()).await;
+
+    shutdown(api).await;
+}
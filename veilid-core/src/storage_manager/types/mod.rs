@@ -1,7 +1,9 @@
+mod signed_record_successor;
 mod signed_value_data;
 mod signed_value_descriptor;
 
 use super::*;
 
+pub use signed_record_successor::*;
 pub use signed_value_data::*;
 pub use signed_value_descriptor::*;
@@ -0,0 +1,135 @@
+use super::*;
+
+/////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Identifies a subkey that holds a record successor
+const RECORD_SUCCESSOR_MAGIC: &[u8; 4] = b"RSC0";
+/// The length of an encoded typed key
+const TYPED_KEY_LENGTH: usize = 4 + PUBLIC_KEY_LENGTH;
+/// The length of an encoded record successor
+const SIGNED_RECORD_SUCCESSOR_LENGTH: usize =
+    4 + TYPED_KEY_LENGTH + PUBLIC_KEY_LENGTH + SIGNATURE_LENGTH;
+
+/// A pointer from a record to the record that replaces it and the owner of that record,
+/// signed by the owner of the record being replaced
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedRecordSuccessor {
+    successor: TypedKey,
+    new_owner: PublicKey,
+    signature: Signature,
+}
+impl SignedRecordSuccessor {
+    pub fn successor(&self) -> &TypedKey {
+        &self.successor
+    }
+
+    pub fn new_owner(&self) -> &PublicKey {
+        &self.new_owner
+    }
+
+    pub fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// The data the owner signs, which includes the record being replaced
+    /// so a successor can not be copied from one record to another
+    pub(super) fn signed_data(key: TypedKey, successor: TypedKey, new_owner: PublicKey) -> Vec<u8> {
+        let mut data = Vec::with_capacity(4 + 2 * TYPED_KEY_LENGTH + PUBLIC_KEY_LENGTH);
+        data.extend_from_slice(RECORD_SUCCESSOR_MAGIC);
+        data.extend_from_slice(&key.kind.0);
+        data.extend_from_slice(&key.value.bytes);
+        data.extend_from_slice(&successor.kind.0);
+        data.extend_from_slice(&successor.value.bytes);
+        data.extend_from_slice(&new_owner.bytes);
+        data
+    }
+
+    pub fn make_signature(
+        key: TypedKey,
+        successor: TypedKey,
+        new_owner: PublicKey,
+        vcrypto: CryptoSystemVersion,
+        owner: PublicKey,
+        owner_secret: SecretKey,
+    ) -> VeilidAPIResult<Self> {
+        if successor == key {
+            apibail_invalid_argument!("record can not succeed itself", "successor", successor);
+        }
+        // create signature
+        let signature = vcrypto.sign(
+            &owner,
+            &owner_secret,
+            &Self::signed_data(key, successor, new_owner),
+        )?;
+        Ok(Self {
+            successor,
+            new_owner,
+            signature,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(SIGNED_RECORD_SUCCESSOR_LENGTH);
+        bytes.extend_from_slice(RECORD_SUCCESSOR_MAGIC);
+        bytes.extend_from_slice(&self.successor.kind.0);
+        bytes.extend_from_slice(&self.successor.value.bytes);
+        bytes.extend_from_slice(&self.new_owner.bytes);
+        bytes.extend_from_slice(&self.signature.bytes);
+        bytes
+    }
+
+    /// Check if a subkey value claims to be a record successor, without validating it
+    pub fn is_record_successor(bytes: &[u8]) -> bool {
+        bytes.starts_with(RECORD_SUCCESSOR_MAGIC)
+    }
+
+    pub fn try_from_bytes(bytes: &[u8]) -> VeilidAPIResult<Self> {
+        if bytes.len() != SIGNED_RECORD_SUCCESSOR_LENGTH || &bytes[0..4] != RECORD_SUCCESSOR_MAGIC {
+            apibail_parse_error!("not a record successor", format!("len={}", bytes.len()));
+        }
+        let mut offset = 4;
+        let kind = FourCC::try_from(&bytes[offset..offset + 4])?;
+        offset += 4;
+        let value = PublicKey::try_from(&bytes[offset..offset + PUBLIC_KEY_LENGTH])?;
+        offset += PUBLIC_KEY_LENGTH;
+        let new_owner = PublicKey::try_from(&bytes[offset..offset + PUBLIC_KEY_LENGTH])?;
+        offset += PUBLIC_KEY_LENGTH;
+        let signature = Signature::try_from(&bytes[offset..])?;
+
+        Ok(Self {
+            successor: TypedKey::new(kind, value),
+            new_owner,
+            signature,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signed_record_successor_round_trip() {
+        let successor = SignedRecordSuccessor {
+            successor: TypedKey::new(CRYPTO_KIND_VLD0, CryptoKey { bytes: [1; 32] }),
+            new_owner: CryptoKey { bytes: [2; 32] },
+            signature: Signature { bytes: [3; 64] },
+        };
+        let bytes = successor.to_bytes();
+        assert_eq!(bytes.len(), SIGNED_RECORD_SUCCESSOR_LENGTH);
+        assert_eq!(
+            SignedRecordSuccessor::try_from_bytes(&bytes).unwrap(),
+            successor
+        );
+
+        // Wrong length
+        assert!(SignedRecordSuccessor::try_from_bytes(&bytes[1..]).is_err());
+
+        // Wrong magic
+        let mut bytes = bytes;
+        assert!(SignedRecordSuccessor::is_record_successor(&bytes));
+        bytes[0] = b'X';
+        assert!(!SignedRecordSuccessor::is_record_successor(&bytes));
+        assert!(SignedRecordSuccessor::try_from_bytes(&bytes).is_err());
+    }
+}
@@ -30,6 +30,28 @@ impl SignedValueDescriptor {
         Ok(())
     }
 
+    /// Validate that a successor for the record with this descriptor was signed by its owner
+    pub fn validate_successor(
+        &self,
+        vcrypto: CryptoSystemVersion,
+        key: TypedKey,
+        successor: &SignedRecordSuccessor,
+    ) -> VeilidAPIResult<()> {
+        if *successor.successor() == key {
+            apibail_parse_error!("record can not succeed itself", key);
+        }
+        // validate signature
+        let signed_data =
+            SignedRecordSuccessor::signed_data(key, *successor.successor(), *successor.new_owner());
+        if !vcrypto.verify(&self.owner, &signed_data, successor.signature())? {
+            apibail_parse_error!(
+                "failed to validate signature of record successor",
+                successor.signature()
+            );
+        }
+        Ok(())
+    }
+
     pub fn owner(&self) -> &PublicKey {
         &self.owner
     }
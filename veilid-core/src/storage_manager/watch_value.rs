@@ -459,10 +459,12 @@ impl StorageManager {
                 // Force count to zero
                 count = 0;
                 opened_record.clear_active_watch();
+                self.set_active_watch_node(key, None);
             } else if count == 0 {
                 // If count is zero, we're done, cancel the watch and the app can renew it if it wants
                 log_stor!(debug "watch count finished: {}", key);
                 opened_record.clear_active_watch();
+                self.set_active_watch_node(key, None);
             } else {
                 log_stor!(debug
                     "watch count decremented: {}: {}/{}",
@@ -519,6 +521,7 @@ impl StorageManager {
                             key,
                             first_subkey,
                             value.clone(),
+                            None,
                             WatchUpdateMode::NoUpdate,
                         )
                         .await?;
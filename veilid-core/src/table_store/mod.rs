@@ -652,6 +652,11 @@ impl TableStore {
     /// Get the description of a TableDB table
     #[instrument(level = "trace", target = "tstore", skip_all)]
     pub async fn info(&self, name: &str) -> VeilidAPIResult<Option<TableInfo>> {
+        // Don't create the table if it doesn't exist
+        if self.name_get(name).await?.is_none() {
+            return Ok(None);
+        }
+
         // Open with the default number of columns
         let tdb = self.open(name, 0).await?;
         let internal_name = tdb.table_name();
@@ -1,4 +1,6 @@
 pub mod test_dht;
 pub mod test_protected_store;
+#[cfg(all(feature = "simulation", feature = "virtual-network"))]
+pub mod test_simulation;
 pub mod test_veilid_config;
 pub mod test_veilid_core;
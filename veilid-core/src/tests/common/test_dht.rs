@@ -1,4 +1,4 @@
-use super::test_veilid_config::*;
+use crate::storage_manager::SignedRecordSuccessor;
 use crate::*;
 
 use lazy_static::*;
@@ -14,7 +14,7 @@ pub async fn test_get_dht_value_unopened(api: VeilidAPI) {
     let rc = api
         .routing_context()
         .unwrap()
-        .with_safety(SafetySelection::Unsafe(Sequencing::EnsureOrdered))
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
         .unwrap();
 
     let result = rc.get_dht_value(*BOGUS_KEY, 0, false).await;
@@ -25,7 +25,7 @@ pub async fn test_open_dht_record_nonexistent_no_writer(api: VeilidAPI) {
     let rc = api
         .routing_context()
         .unwrap()
-        .with_safety(SafetySelection::Unsafe(Sequencing::EnsureOrdered))
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
         .unwrap();
 
     let result = rc.get_dht_value(*BOGUS_KEY, 0, false).await;
@@ -36,7 +36,7 @@ pub async fn test_close_dht_record_nonexistent(api: VeilidAPI) {
     let rc = api
         .routing_context()
         .unwrap()
-        .with_safety(SafetySelection::Unsafe(Sequencing::EnsureOrdered))
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
         .unwrap();
 
     let result = rc.close_dht_record(*BOGUS_KEY).await;
@@ -47,7 +47,7 @@ pub async fn test_delete_dht_record_nonexistent(api: VeilidAPI) {
     let rc = api
         .routing_context()
         .unwrap()
-        .with_safety(SafetySelection::Unsafe(Sequencing::EnsureOrdered))
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
         .unwrap();
 
     let result = rc.delete_dht_record(*BOGUS_KEY).await;
@@ -58,7 +58,7 @@ pub async fn test_create_delete_dht_record_simple(api: VeilidAPI) {
     let rc = api
         .routing_context()
         .unwrap()
-        .with_safety(SafetySelection::Unsafe(Sequencing::EnsureOrdered))
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
         .unwrap();
 
     let rec = rc
@@ -75,7 +75,7 @@ pub async fn test_get_dht_value_nonexistent(api: VeilidAPI) {
     let rc = api
         .routing_context()
         .unwrap()
-        .with_safety(SafetySelection::Unsafe(Sequencing::EnsureOrdered))
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
         .unwrap();
 
     let rec = rc
@@ -94,7 +94,7 @@ pub async fn test_set_get_dht_value(api: VeilidAPI) {
     let rc = api
         .routing_context()
         .unwrap()
-        .with_safety(SafetySelection::Unsafe(Sequencing::EnsureOrdered))
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
         .unwrap();
 
     let rec = rc
@@ -142,11 +142,81 @@ pub async fn test_set_get_dht_value(api: VeilidAPI) {
     rc.delete_dht_record(dht_key).await.unwrap();
 }
 
+pub async fn test_set_get_dht_values(api: VeilidAPI) {
+    let rc = api
+        .routing_context()
+        .unwrap()
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
+        .unwrap();
+
+    let rec = rc
+        .create_dht_record(DHTSchema::dflt(4).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let dht_key = *rec.key();
+
+    // Subkeys may be given in any order, and come back in ascending order
+    let set_dht_values_result = rc
+        .set_dht_values(
+            dht_key,
+            vec![(2, b"TWO".to_vec()), (0, b"ZERO".to_vec())],
+            None,
+        )
+        .await;
+    assert_eq!(
+        set_dht_values_result.expect("should not be error"),
+        vec![(0, None), (2, None)]
+    );
+
+    // Each subkey may only be set once per call
+    let set_dht_values_result = rc
+        .set_dht_values(
+            dht_key,
+            vec![(1, b"ONE".to_vec()), (1, b"UNO".to_vec())],
+            None,
+        )
+        .await;
+    assert_err!(set_dht_values_result);
+
+    // Batched reads give the same results locally and from the network
+    for force_refresh in [false, true] {
+        let get_dht_values_result = rc
+            .get_dht_values(dht_key, ValueSubkeyRangeSet::full(), force_refresh)
+            .await
+            .expect("should not be error");
+        assert_eq!(
+            get_dht_values_result
+                .iter()
+                .map(|(subkey, value)| (*subkey, value.as_ref().map(|v| v.data().to_vec())))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, Some(b"ZERO".to_vec())),
+                (1, None),
+                (2, Some(b"TWO".to_vec())),
+                (3, None),
+            ]
+        );
+    }
+
+    // Batched writes are seen by single subkey reads
+    let get_dht_value_result = rc.get_dht_value(dht_key, 2, true).await;
+    assert_eq!(
+        get_dht_value_result
+            .expect("should not be error")
+            .expect("should hold a value")
+            .data(),
+        b"TWO"
+    );
+
+    rc.close_dht_record(dht_key).await.unwrap();
+    rc.delete_dht_record(dht_key).await.unwrap();
+}
+
 pub async fn test_open_writer_dht_value(api: VeilidAPI) {
     let rc = api
         .routing_context()
         .unwrap()
-        .with_safety(SafetySelection::Unsafe(Sequencing::EnsureOrdered))
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
         .unwrap();
 
     let rec = rc
@@ -298,7 +368,15 @@ pub async fn test_open_writer_dht_value(api: VeilidAPI) {
 
     // Verify subkey 0 can be set because we have overridden with the correct writer
     let set_dht_test_value_0_result = rc
-        .set_dht_value(key, 0, test_value_1.clone(), Some(keypair))
+        .set_dht_value(
+            key,
+            0,
+            test_value_1.clone(),
+            Some(SetDHTValueOptions {
+                writer: Some(keypair),
+                expected_seq: None,
+            }),
+        )
         .await;
     assert!(set_dht_test_value_0_result.is_ok());
 
@@ -308,6 +386,773 @@ pub async fn test_open_writer_dht_value(api: VeilidAPI) {
 
 // Network-related code to make sure veilid node is connetected to other peers
 
+pub async fn test_compare_and_set_dht_value(api: VeilidAPI) {
+    let rc = api
+        .routing_context()
+        .unwrap()
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
+        .unwrap();
+
+    let rec = rc
+        .create_dht_record(DHTSchema::dflt(1).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let key = *rec.key();
+
+    let test_data_1 = String::from("first").as_bytes().to_vec();
+    let test_data_2 = String::from("second").as_bytes().to_vec();
+    let test_data_3 = String::from("third").as_bytes().to_vec();
+
+    // Expecting no value yet succeeds on an empty subkey
+    let cas_result = rc
+        .set_dht_value(
+            key,
+            0,
+            test_data_1.clone(),
+            Some(SetDHTValueOptions {
+                writer: None,
+                expected_seq: Some(ValueSeqNum::MAX),
+            }),
+        )
+        .await;
+    assert_eq!(cas_result.expect("should be Ok(None)"), None);
+
+    // Expecting the current sequence number succeeds
+    let cas_result = rc
+        .set_dht_value(
+            key,
+            0,
+            test_data_2.clone(),
+            Some(SetDHTValueOptions {
+                writer: None,
+                expected_seq: Some(0),
+            }),
+        )
+        .await;
+    assert_eq!(cas_result.expect("should be Ok(None)"), None);
+
+    // Expecting a stale sequence number fails with the winning value
+    let cas_result = rc
+        .set_dht_value(
+            key,
+            0,
+            test_data_3.clone(),
+            Some(SetDHTValueOptions {
+                writer: None,
+                expected_seq: Some(0),
+            }),
+        )
+        .await;
+    match cas_result {
+        Err(VeilidAPIError::Conflict { value }) => {
+            assert_eq!(value.seq(), 1);
+            assert_eq!(value.data(), test_data_2);
+        }
+        _ => panic!("should be a conflict"),
+    }
+
+    // The conflicting write must not have changed the value
+    let get_result = rc.get_dht_value(key, 0, false).await;
+    assert_eq!(
+        get_result
+            .expect("should not be error")
+            .expect("should hold a value")
+            .data(),
+        test_data_2
+    );
+
+    rc.close_dht_record(key).await.unwrap();
+    rc.delete_dht_record(key).await.unwrap();
+}
+
+pub async fn test_set_get_dht_large_value(api: VeilidAPI) {
+    let rc = api
+        .routing_context()
+        .unwrap()
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
+        .unwrap();
+
+    let rec = rc
+        .create_dht_record(DHTSchema::dflt(8).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let key = *rec.key();
+
+    // Nothing written yet
+    let get_result = rc.get_dht_large_value(key, 1, false).await;
+    assert_eq!(get_result.expect("should not be error"), None);
+
+    // Three full chunks and a partial one, plus the manifest
+    let test_data: Vec<u8> = (0..ValueData::MAX_LEN * 3 + 100)
+        .map(|n| (n % 251) as u8)
+        .collect();
+    let set_result = rc
+        .set_dht_large_value(key, 1, test_data.clone(), None)
+        .await;
+    assert_eq!(
+        set_result.expect("should not be error"),
+        ValueSubkeyRangeSet::single_range(1, 5)
+    );
+
+    let get_result = rc.get_dht_large_value(key, 1, false).await;
+    assert_eq!(
+        get_result
+            .expect("should not be error")
+            .expect("should hold a value"),
+        test_data
+    );
+
+    let get_result = rc.get_dht_large_value(key, 1, true).await;
+    assert_eq!(
+        get_result
+            .expect("should not be error")
+            .expect("should hold a value"),
+        test_data
+    );
+
+    // A value that runs past the end of the schema can not be written
+    let set_result = rc
+        .set_dht_large_value(key, 6, test_data.clone(), None)
+        .await;
+    assert_err!(set_result);
+
+    // A shorter value clears the chunks the longer value left behind
+    let short_data = test_data[0..ValueData::MAX_LEN / 2].to_vec();
+    let set_result = rc
+        .set_dht_large_value(key, 1, short_data.clone(), None)
+        .await;
+    assert_eq!(
+        set_result.expect("should not be error"),
+        ValueSubkeyRangeSet::single_range(1, 2)
+    );
+    let get_result = rc.get_dht_large_value(key, 1, false).await;
+    assert_eq!(
+        get_result
+            .expect("should not be error")
+            .expect("should hold a value"),
+        short_data
+    );
+    for subkey in 3..=5 {
+        let get_result = rc.get_dht_value(key, subkey, false).await;
+        assert!(get_result
+            .expect("should not be error")
+            .expect("should hold a value")
+            .data()
+            .is_empty());
+    }
+
+    rc.close_dht_record(key).await.unwrap();
+    rc.delete_dht_record(key).await.unwrap();
+}
+
+pub async fn test_dht_record_encryption(api: VeilidAPI) {
+    let rc = api
+        .routing_context()
+        .unwrap()
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
+        .unwrap();
+
+    let rec = rc
+        .create_dht_record(DHTSchema::dflt(2).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let key = *rec.key();
+
+    // Share a new key in subkey 0, which stays readable
+    let secret = rc
+        .share_dht_record_encryption_key(key, 0, vec![])
+        .await
+        .expect("should not be error");
+    let share = rc.get_dht_value(key, 0, false).await.unwrap().unwrap();
+    assert!(share.data().starts_with(b"RKS0"));
+
+    let set_result = rc.set_dht_value(key, 1, b"SECRET".to_vec(), None).await;
+    assert_eq!(set_result.expect("should not be error"), None);
+    let value = rc.get_dht_value(key, 1, true).await.unwrap().unwrap();
+    assert_eq!(value.data(), b"SECRET");
+
+    // Writing the same data again does not make a new sealed value
+    let set_result = rc.set_dht_value(key, 1, b"SECRET".to_vec(), None).await;
+    assert_eq!(set_result.expect("should not be error"), None);
+    let unchanged = rc.get_dht_value(key, 1, false).await.unwrap().unwrap();
+    assert_eq!(unchanged.seq(), value.seq());
+
+    // Without the key, only the sealed value is visible
+    rc.set_dht_record_encryption_key(key, None).await.unwrap();
+    let value = rc.get_dht_value(key, 1, false).await.unwrap().unwrap();
+    assert_ne!(value.data(), b"SECRET");
+
+    // The owner can get the key back from the share
+    let imported = rc
+        .import_dht_record_encryption_key(key, 0)
+        .await
+        .expect("should not be error");
+    assert_eq!(imported, secret);
+    let value = rc.get_dht_value(key, 1, false).await.unwrap().unwrap();
+    assert_eq!(value.data(), b"SECRET");
+
+    // A share naming someone other than the writer of its subkey as the sharer is not trusted
+    let mut forged = share.data().to_vec();
+    let other_key = api
+        .crypto()
+        .unwrap()
+        .get(CRYPTO_KIND_VLD0)
+        .unwrap()
+        .generate_keypair()
+        .key;
+    forged[4..4 + PUBLIC_KEY_LENGTH].copy_from_slice(&other_key.bytes);
+    rc.set_dht_value(key, 0, forged, None).await.unwrap();
+    assert_err!(rc.import_dht_record_encryption_key(key, 0).await);
+
+    rc.close_dht_record(key).await.unwrap();
+    rc.delete_dht_record(key).await.unwrap();
+}
+
+pub async fn test_dht_record_successor(api: VeilidAPI) {
+    let rc = api
+        .routing_context()
+        .unwrap()
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
+        .unwrap();
+
+    let old_rec = rc
+        .create_dht_record(DHTSchema::dflt(1).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let old_key = *old_rec.key();
+    let new_rec = rc
+        .create_dht_record(DHTSchema::dflt(1).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let new_key = *new_rec.key();
+    assert_ne!(old_rec.owner(), new_rec.owner());
+
+    // No successor until one is published
+    assert_eq!(
+        rc.get_dht_record_successor(old_key, 0, false)
+            .await
+            .unwrap(),
+        None
+    );
+
+    // A record can not succeed itself
+    assert!(rc
+        .set_dht_record_successor(old_key, 0, old_key)
+        .await
+        .is_err());
+
+    rc.set_dht_record_successor(old_key, 0, new_key)
+        .await
+        .expect("should not be error");
+    assert_eq!(
+        rc.get_dht_record_successor(old_key, 0, true).await.unwrap(),
+        Some(new_key)
+    );
+
+    // Successors are write-once
+    rc.set_dht_record_successor(old_key, 0, new_key)
+        .await
+        .expect("should not be error");
+    let other_rec = rc
+        .create_dht_record(DHTSchema::dflt(1).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let other_key = *other_rec.key();
+    assert!(rc
+        .set_dht_record_successor(old_key, 0, other_key)
+        .await
+        .is_err());
+    rc.close_dht_record(other_key).await.unwrap();
+    rc.delete_dht_record(other_key).await.unwrap();
+
+    // A subkey holding other data has no successor
+    rc.set_dht_value(new_key, 0, b"not a successor".to_vec(), None)
+        .await
+        .unwrap();
+    assert_eq!(
+        rc.get_dht_record_successor(new_key, 0, false)
+            .await
+            .unwrap(),
+        None
+    );
+
+    // A successor can only be published to a subkey that has never been written
+    let late_rec = rc
+        .create_dht_record(DHTSchema::dflt(1).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let late_key = *late_rec.key();
+    rc.set_dht_value(late_key, 0, b"not a successor".to_vec(), None)
+        .await
+        .unwrap();
+    assert!(rc
+        .set_dht_record_successor(late_key, 0, new_key)
+        .await
+        .is_err());
+
+    // A successor that replaced another value is not trusted
+    let vcrypto = api.crypto().unwrap().get(CRYPTO_KIND_VLD0).unwrap();
+    let late_successor = SignedRecordSuccessor::make_signature(
+        late_key,
+        new_key,
+        *new_rec.owner(),
+        vcrypto,
+        *late_rec.owner(),
+        *late_rec.owner_secret().unwrap(),
+    )
+    .unwrap();
+    rc.set_dht_value(late_key, 0, late_successor.to_bytes(), None)
+        .await
+        .unwrap();
+    assert!(rc
+        .get_dht_record_successor(late_key, 0, false)
+        .await
+        .is_err());
+    rc.close_dht_record(late_key).await.unwrap();
+    rc.delete_dht_record(late_key).await.unwrap();
+
+    // Following the chain ends at the new record with its owner
+    let rec = rc
+        .open_dht_record_following_successors(old_key, 0, None)
+        .await
+        .expect("should not be error");
+    assert_eq!(*rec.key(), new_key);
+    assert_eq!(rec.owner(), new_rec.owner());
+
+    rc.close_dht_record(old_key).await.unwrap();
+    rc.delete_dht_record(old_key).await.unwrap();
+    rc.close_dht_record(new_key).await.unwrap();
+    rc.delete_dht_record(new_key).await.unwrap();
+}
+
+pub async fn test_dht_value_history(api: VeilidAPI) {
+    let rc = api
+        .routing_context()
+        .unwrap()
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
+        .unwrap();
+
+    let rec = rc
+        .create_dht_record(DHTSchema::dflt(1).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let key = *rec.key();
+
+    // Nothing is kept until history is turned on
+    rc.set_dht_value(key, 0, b"ZERO".to_vec(), None)
+        .await
+        .unwrap();
+    rc.set_dht_value_history_length(key, 2).await.unwrap();
+    for data in [b"ONE".as_slice(), b"TWO", b"THREE"] {
+        rc.set_dht_value(key, 0, data.to_vec(), None).await.unwrap();
+    }
+
+    let history = rc
+        .get_dht_value_history(key, 0, (0, ValueSeqNum::MAX))
+        .await
+        .expect("should not be error");
+    assert_eq!(
+        history.iter().map(|v| v.data()).collect::<Vec<_>>(),
+        vec![b"ONE".as_slice(), b"TWO", b"THREE"]
+    );
+    assert_eq!(
+        history.iter().map(|v| v.seq()).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+
+    let history = rc
+        .get_dht_value_history(key, 0, (2, 2))
+        .await
+        .expect("should not be error");
+    assert_eq!(
+        history.iter().map(|v| v.data()).collect::<Vec<_>>(),
+        vec![b"TWO".as_slice()]
+    );
+
+    // Turning history off drops what was kept
+    rc.set_dht_value_history_length(key, 0).await.unwrap();
+    let history = rc
+        .get_dht_value_history(key, 0, (0, ValueSeqNum::MAX))
+        .await
+        .expect("should not be error");
+    assert_eq!(
+        history.iter().map(|v| v.data()).collect::<Vec<_>>(),
+        vec![b"THREE".as_slice()]
+    );
+
+    rc.close_dht_record(key).await.unwrap();
+    rc.delete_dht_record(key).await.unwrap();
+}
+
+pub async fn test_dht_offline_writes(api: VeilidAPI) {
+    let rc = api
+        .routing_context()
+        .unwrap()
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
+        .unwrap();
+
+    let rec = rc
+        .create_dht_record(DHTSchema::dflt(2).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let key = *rec.key();
+
+    let set_result = rc.set_dht_value(key, 0, b"BLAH".to_vec(), None).await;
+    assert_eq!(set_result.expect("should not be error"), None);
+
+    // Writes that reached the network are not queued, so there is nothing to cancel or flush
+    let offline_writes = rc.get_dht_offline_writes(key).await;
+    assert!(offline_writes.expect("should not be error").is_empty());
+    let offline_writes = rc
+        .cancel_dht_offline_writes(key, ValueSubkeyRangeSet::single(1))
+        .await;
+    assert!(offline_writes.expect("should not be error").is_empty());
+    let offline_writes = rc.flush_dht_offline_writes(key).await;
+    assert!(offline_writes.expect("should not be error").is_empty());
+
+    rc.close_dht_record(key).await.unwrap();
+    rc.delete_dht_record(key).await.unwrap();
+}
+
+pub async fn test_dht_offline_compare_and_set(api: VeilidAPI) {
+    let rc = api
+        .routing_context()
+        .unwrap()
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
+        .unwrap();
+
+    let rec = rc
+        .create_dht_record(DHTSchema::dflt(1).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let key = *rec.key();
+
+    let set_result = rc.set_dht_value(key, 0, b"BLAH".to_vec(), None).await;
+    assert_eq!(set_result.expect("should not be error"), None);
+
+    api.detach().await.unwrap();
+    wait_for_detached(&api).await;
+
+    // A compare-and-set can not be checked while offline, so it fails rather than being queued
+    let cas_result = rc
+        .set_dht_value(
+            key,
+            0,
+            b"BLAH BLAH".to_vec(),
+            Some(SetDHTValueOptions {
+                writer: None,
+                expected_seq: Some(0),
+            }),
+        )
+        .await;
+    assert!(matches!(cas_result, Err(VeilidAPIError::TryAgain { .. })));
+    let offline_writes = rc.get_dht_offline_writes(key).await;
+    assert!(offline_writes.expect("should not be error").is_empty());
+
+    api.attach().await.unwrap();
+    wait_for_public_internet_ready(&api).await;
+
+    // The value on the network is unchanged
+    let get_result = rc.get_dht_value(key, 0, true).await;
+    assert_eq!(
+        get_result
+            .expect("should not be error")
+            .expect("should have value")
+            .data(),
+        b"BLAH"
+    );
+
+    rc.close_dht_record(key).await.unwrap();
+    rc.delete_dht_record(key).await.unwrap();
+}
+
+pub async fn test_dht_offline_write_update(api: VeilidAPI, updates: flume::Receiver<VeilidUpdate>) {
+    let rc = api
+        .routing_context()
+        .unwrap()
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
+        .unwrap();
+
+    let rec = rc
+        .create_dht_record(DHTSchema::dflt(3).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let key = *rec.key();
+
+    api.detach().await.unwrap();
+    wait_for_detached(&api).await;
+
+    // Writes made while detached are kept locally and queued
+    let set_result = rc.set_dht_value(key, 0, b"ZERO".to_vec(), None).await;
+    assert_eq!(set_result.expect("should not be error"), None);
+    let set_result = rc.set_dht_value(key, 2, b"TWO".to_vec(), None).await;
+    assert_eq!(set_result.expect("should not be error"), None);
+
+    let offline_writes = rc.get_dht_offline_writes(key).await;
+    let mut expected = ValueSubkeyRangeSet::single(0);
+    expected.insert(2);
+    assert_eq!(offline_writes.expect("should not be error"), expected);
+
+    let get_result = rc.get_dht_value(key, 2, false).await;
+    assert_eq!(
+        get_result
+            .expect("should not be error")
+            .expect("should have value")
+            .data(),
+        b"TWO"
+    );
+
+    // Nothing can be flushed until we are back online
+    assert!(matches!(
+        rc.flush_dht_offline_writes(key).await,
+        Err(VeilidAPIError::TryAgain { .. })
+    ));
+
+    updates.drain();
+    api.attach().await.unwrap();
+    wait_for_public_internet_ready(&api).await;
+
+    // Each queued subkey is reported once it reaches the network
+    let mut written = ValueSubkeyRangeSet::new();
+    while written != expected {
+        let update = timeout(60_000, updates.recv_async())
+            .await
+            .expect("timed out waiting for offline write")
+            .unwrap();
+        let VeilidUpdate::OfflineWrite(offline_write) = update else {
+            continue;
+        };
+        if offline_write.key != key {
+            continue;
+        }
+        assert_eq!(offline_write.superseded_by, None);
+        match offline_write.subkey {
+            0 => assert_eq!(offline_write.value.data(), b"ZERO"),
+            2 => assert_eq!(offline_write.value.data(), b"TWO"),
+            subkey => panic!("unexpected offline write for subkey {}", subkey),
+        }
+        written.insert(offline_write.subkey);
+    }
+
+    let offline_writes = rc.get_dht_offline_writes(key).await;
+    assert!(offline_writes.expect("should not be error").is_empty());
+
+    rc.close_dht_record(key).await.unwrap();
+    rc.delete_dht_record(key).await.unwrap();
+}
+
+async fn wait_for_mailbox_route_change(
+    rc: &RoutingContext,
+    key: TypedKey,
+    subkey: ValueSubkey,
+    old_route_id: RouteId,
+) -> RouteId {
+    let deadline = get_timestamp() + 120_000_000u64;
+    loop {
+        // The mailbox is rewritten in the background, so the subkey may briefly be stale
+        if let Ok(Target::PrivateRoute(route_id)) = rc.resolve_mailbox(key, subkey, true).await {
+            if route_id != old_route_id {
+                return route_id;
+            }
+        }
+        assert!(
+            get_timestamp() < deadline,
+            "timed out waiting for mailbox to be rewritten"
+        );
+        sleep(1000).await;
+    }
+}
+
+pub async fn test_dht_mailbox(api: VeilidAPI) {
+    let rc = api
+        .routing_context()
+        .unwrap()
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
+        .unwrap();
+
+    let rec = rc
+        .create_dht_record(DHTSchema::dflt(1).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let key = *rec.key();
+
+    // Rotate often enough that the test sees it happen
+    let route_id = api
+        .publish_mailbox(
+            key,
+            0,
+            Stability::LowLatency,
+            Sequencing::NoPreference,
+            Some(RouteRotationPolicy {
+                max_age_ms: Some(30_000),
+                max_recent_failures: None,
+                publish_key: None,
+                publish_subkey: 0,
+            }),
+        )
+        .await
+        .expect("should publish mailbox");
+
+    // Publishing the same subkey twice is not allowed
+    assert_err!(
+        api.publish_mailbox(
+            key,
+            0,
+            Stability::LowLatency,
+            Sequencing::NoPreference,
+            None
+        )
+        .await
+    );
+
+    // The mailbox resolves to the route bound to it
+    let target = rc.resolve_mailbox(key, 0, true).await;
+    assert_eq!(
+        target.expect("should resolve mailbox"),
+        Target::PrivateRoute(route_id)
+    );
+
+    // Releasing the route gets the mailbox a new one
+    api.release_private_route(route_id).unwrap();
+    let released_route_id = wait_for_mailbox_route_change(&rc, key, 0, route_id).await;
+
+    // Rotation writes the replacement route too
+    let rotated_route_id = wait_for_mailbox_route_change(&rc, key, 0, released_route_id).await;
+    assert_ne!(rotated_route_id, route_id);
+
+    // A blob that can't be written is tried again until it is
+    let routing_table = api.routing_table().unwrap();
+    let rss = routing_table.route_spec_store();
+    let needs_publish = || {
+        rss.get_mailboxes_needing_publish()
+            .iter()
+            .any(|mailbox| mailbox.key == key && mailbox.subkey == 0)
+    };
+    let Ok(Target::PrivateRoute(route_id)) = rc.resolve_mailbox(key, 0, false).await else {
+        panic!("should resolve mailbox");
+    };
+    let writer = KeyPair::new(*rec.owner(), *rec.owner_secret().unwrap());
+    rc.close_dht_record(key).await.unwrap();
+    rss.set_mailbox_route(&key, 0, route_id);
+    routing_table.publish_mailboxes().await;
+    assert!(needs_publish());
+    rc.open_dht_record(key, Some(writer)).await.unwrap();
+    routing_table.publish_mailboxes().await;
+    assert!(!needs_publish());
+
+    // The last blob stays in the subkey once unpublished
+    api.unpublish_mailbox(key, 0).unwrap();
+    assert_err!(api.unpublish_mailbox(key, 0));
+    let target = rc.resolve_mailbox(key, 0, false).await;
+    assert!(target.is_ok());
+
+    rc.close_dht_record(key).await.unwrap();
+    rc.delete_dht_record(key).await.unwrap();
+}
+
+pub async fn test_route_rotation(api: VeilidAPI) {
+    let routing_table = api.routing_table().unwrap();
+    let rss = routing_table.route_spec_store();
+    let rotating_routes = || rss.list_allocated_routes(|k, v| v.get_rotation_policy().map(|_| *k));
+
+    let (route_id, _blob) = api
+        .new_custom_private_route(
+            &[CRYPTO_KIND_VLD0],
+            Stability::LowLatency,
+            Sequencing::NoPreference,
+        )
+        .await
+        .unwrap();
+    api.set_route_rotation_policy(
+        route_id,
+        Some(RouteRotationPolicy {
+            max_age_ms: Some(3_600_000),
+            max_recent_failures: None,
+            publish_key: None,
+            publish_subkey: 0,
+        }),
+    )
+    .unwrap();
+
+    // Nothing happens before the policy fires
+    routing_table.rotate_routes(Timestamp::now()).await;
+    assert_eq!(rotating_routes(), vec![route_id]);
+
+    // Once the route is too old it is replaced, retrying if no replacement can be allocated yet
+    let cur_ts = Timestamp::now() + TimestampDuration::new(3_600_000_000);
+    let deadline = get_timestamp() + 60_000_000u64;
+    let new_route_id = loop {
+        routing_table.rotate_routes(cur_ts).await;
+        let routes = rotating_routes();
+        if routes != vec![route_id] {
+            assert_eq!(routes.len(), 1);
+            break routes[0];
+        }
+        assert!(
+            get_timestamp() < deadline,
+            "timed out waiting for route rotation"
+        );
+        sleep(1000).await;
+    };
+    assert_ne!(new_route_id, route_id);
+
+    // The old route stays allocated for its grace period
+    assert!(rss.private_route_blob(&route_id).is_ok());
+    assert_eq!(rss.release_retired_routes(cur_ts), vec![]);
+    assert_eq!(
+        rss.release_retired_routes(cur_ts + TimestampDuration::new(3_600_000_000)),
+        vec![route_id]
+    );
+    assert!(rss.private_route_blob(&route_id).is_err());
+
+    // A replacement that can't be published is dropped and the route is kept for the next try
+    let rc = api
+        .routing_context()
+        .unwrap()
+        .with_safety(SafetySelection::Unsafe(DHT_TEST_SEQUENCING))
+        .unwrap();
+    let rec = rc
+        .create_dht_record(DHTSchema::dflt(1).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let key = *rec.key();
+    rc.close_dht_record(key).await.unwrap();
+    api.set_route_rotation_policy(
+        new_route_id,
+        Some(RouteRotationPolicy {
+            max_age_ms: Some(3_600_000),
+            max_recent_failures: None,
+            publish_key: Some(key),
+            publish_subkey: 0,
+        }),
+    )
+    .unwrap();
+    let allocated_routes = rss.list_allocated_routes(|k, _| Some(*k)).len();
+    routing_table
+        .rotate_routes(cur_ts + TimestampDuration::new(3_600_000_000))
+        .await;
+    assert_eq!(rotating_routes(), vec![new_route_id]);
+    assert_eq!(
+        rss.list_allocated_routes(|k, _| Some(*k)).len(),
+        allocated_routes
+    );
+    rc.delete_dht_record(key).await.unwrap();
+
+    api.release_private_route(new_route_id).unwrap();
+}
+
+async fn wait_for_detached(api: &VeilidAPI) {
+    info!("wait_for_detached");
+    loop {
+        let state = api.get_state().await.unwrap();
+        if state.attachment.state == AttachmentState::Detached {
+            break;
+        }
+        sleep(1000).await;
+    }
+    info!("wait_for_detached, done");
+}
+
 async fn wait_for_public_internet_ready(api: &VeilidAPI) {
     info!("wait_for_public_internet_ready");
     loop {
@@ -320,18 +1165,66 @@ async fn wait_for_public_internet_ready(api: &VeilidAPI) {
     info!("wait_for_public_internet_ready, done");
 }
 
-pub async fn test_all() {
-    if intf::env_variable_is_defined("CI") {
-        info!("skipping DHT test in CI");
-        return;
-    }
+cfg_if! {
+    if #[cfg(all(not(target_arch = "wasm32"), feature = "virtual-network"))] {
+        use crate::tests::test_virtual_network::*;
 
-    let (update_callback, config_callback) = setup_veilid_core();
-    let api = api_startup(update_callback, config_callback)
-        .await
-        .expect("startup failed");
+        /// Enough nodes to hold every copy of a value and build routes between them
+        const DHT_VIRTUAL_NODE_COUNT: usize = 8;
+
+        /// The virtual network only carries UDP, so nothing can be ordered
+        const DHT_TEST_SEQUENCING: Sequencing = Sequencing::NoPreference;
+
+        type DHTTestNodes = Vec<(VeilidAPI, flume::Receiver<VeilidUpdate>)>;
 
-    let _ = api.attach().await;
+        // Run against a private virtual network, so the tests do not depend on the public one
+        async fn startup_dht_test_node(
+        ) -> Option<(VeilidAPI, flume::Receiver<VeilidUpdate>, DHTTestNodes)> {
+            reset_virtual_router();
+            let mut nodes = startup_virtual_network(DHT_VIRTUAL_NODE_COUNT).await;
+            let (api, updates) = nodes.pop().unwrap();
+            wait_for_peers(&api, DHT_VIRTUAL_NODE_COUNT - 1).await;
+            Some((api, updates, nodes))
+        }
+
+        async fn shutdown_dht_test_nodes(nodes: DHTTestNodes) {
+            shutdown_virtual_network(nodes).await;
+        }
+    } else {
+        use super::test_veilid_config::*;
+
+        const DHT_TEST_SEQUENCING: Sequencing = Sequencing::EnsureOrdered;
+
+        type DHTTestNodes = ();
+
+        // Run against the public network, which is not reachable from CI
+        async fn startup_dht_test_node(
+        ) -> Option<(VeilidAPI, flume::Receiver<VeilidUpdate>, DHTTestNodes)> {
+            if intf::env_variable_is_defined("CI") {
+                info!("skipping DHT test in CI");
+                return None;
+            }
+
+            let (_, config_callback) = setup_veilid_core();
+            let (sender, updates) = flume::unbounded();
+            let update_callback: UpdateCallback = Arc::new(move |update| {
+                let _ = sender.send(update);
+            });
+            let api = api_startup(update_callback, config_callback)
+                .await
+                .expect("startup failed");
+            let _ = api.attach().await;
+            Some((api, updates, ()))
+        }
+
+        async fn shutdown_dht_test_nodes(_nodes: DHTTestNodes) {}
+    }
+}
+
+pub async fn test_all() {
+    let Some((api, updates, nodes)) = startup_dht_test_node().await else {
+        return;
+    };
     wait_for_public_internet_ready(&api).await;
 
     test_get_dht_value_unopened(api.clone()).await;
@@ -341,7 +1234,19 @@ pub async fn test_all() {
     test_get_dht_value_nonexistent(api.clone()).await;
     test_create_delete_dht_record_simple(api.clone()).await;
     test_set_get_dht_value(api.clone()).await;
+    test_set_get_dht_values(api.clone()).await;
     test_open_writer_dht_value(api.clone()).await;
+    test_compare_and_set_dht_value(api.clone()).await;
+    test_set_get_dht_large_value(api.clone()).await;
+    test_dht_offline_writes(api.clone()).await;
+    test_dht_offline_compare_and_set(api.clone()).await;
+    test_dht_offline_write_update(api.clone(), updates).await;
+    test_dht_value_history(api.clone()).await;
+    test_dht_record_encryption(api.clone()).await;
+    test_dht_record_successor(api.clone()).await;
+    test_route_rotation(api.clone()).await;
+    test_dht_mailbox(api.clone()).await;
 
     api.shutdown().await;
+    shutdown_dht_test_nodes(nodes).await;
 }
@@ -0,0 +1,229 @@
+use crate::tests::test_virtual_network::*;
+use crate::*;
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+const VIRTUAL_NODE_COUNT: usize = 4;
+/// How far the clock moves each real millisecond while the nodes are just running
+const RUNNING_STEP_US: u64 = 2_000;
+/// How far the clock moves each real millisecond while fast-forwarding
+const FAST_FORWARD_STEP_US: u64 = 20_000;
+
+/// Moves a simulation clock forward from a thread of its own, so the nodes see time pass
+struct ClockDriver {
+    step_us: Arc<AtomicU64>,
+    stopped: Arc<AtomicBool>,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl ClockDriver {
+    fn start(clock: SimulationClock) -> Self {
+        let step_us = Arc::new(AtomicU64::new(RUNNING_STEP_US));
+        let stopped = Arc::new(AtomicBool::new(false));
+        let thread = std::thread::spawn({
+            let step_us = step_us.clone();
+            let stopped = stopped.clone();
+            move || {
+                crate::tests::native::block_on(async move {
+                    while !stopped.load(Ordering::Acquire) {
+                        clock.advance_us(step_us.load(Ordering::Acquire)).await;
+                        std::thread::sleep(std::time::Duration::from_millis(1));
+                    }
+                })
+            }
+        });
+        Self {
+            step_us,
+            stopped,
+            thread: Some(thread),
+        }
+    }
+
+    fn set_step_us(&self, step_us: u64) {
+        self.step_us.store(step_us, Ordering::Release);
+    }
+}
+
+impl Drop for ClockDriver {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Release);
+        if let Some(thread) = self.thread.take() {
+            thread.join().unwrap();
+        }
+    }
+}
+
+/// Make a keypair for the first node, and keypairs that all land in the same bucket of its
+/// routing table, which holds only one entry
+fn same_bucket_keypairs(count: usize) -> (TypedKeyPair, Vec<TypedKeyPair>) {
+    let first = Crypto::generate_keypair(CRYPTO_KIND_VLD0).unwrap();
+    let mut others = Vec::new();
+    while others.len() < count {
+        let kp = Crypto::generate_keypair(CRYPTO_KIND_VLD0).unwrap();
+        // The first differing bit is bit 8, so the bucket index is 8
+        let distance = [
+            kp.value.key.bytes[0] ^ first.value.key.bytes[0],
+            kp.value.key.bytes[1] ^ first.value.key.bytes[1],
+        ];
+        if distance[0] == 0 && distance[1] & 0x80 != 0 {
+            others.push(kp);
+        }
+    }
+    (first, others)
+}
+
+/// The state of a node in another node's routing table, or None if it has no entry for it
+fn entry_state(api: &VeilidAPI, node_id: TypedKey) -> Option<RoutingTableEntryState> {
+    api.get_routing_table_info(RoutingTableEntryState::Punished)
+        .unwrap()
+        .entries
+        .into_iter()
+        .find(|e| e.node_ids.contains(&node_id))
+        .map(|e| e.state)
+}
+
+/// Wait for a value change update, returning None if none arrives in 'timeout_ms' of simulated time
+async fn recv_value_change(
+    updates: &flume::Receiver<VeilidUpdate>,
+    timeout_ms: u32,
+) -> Option<VeilidValueChange> {
+    timeout(timeout_ms, async {
+        loop {
+            if let VeilidUpdate::ValueChange(change) = updates.recv_async().await.unwrap() {
+                return *change;
+            }
+        }
+    })
+    .await
+    .ok()
+}
+
+pub async fn test_simulation_watch_expiration() {
+    info!("--- test_simulation_watch_expiration ---");
+    reset_virtual_router();
+
+    let clock = SimulationClock::new(get_timestamp());
+    set_simulation_clock(Some(clock.clone()));
+    let driver = ClockDriver::start(clock.clone());
+
+    let nodes = startup_virtual_network(VIRTUAL_NODE_COUNT).await;
+    for (api, _) in &nodes {
+        wait_for_peers(api, VIRTUAL_NODE_COUNT - 1).await;
+    }
+    let rc = |index: usize| {
+        nodes[index]
+            .0
+            .routing_context()
+            .unwrap()
+            .with_safety(SafetySelection::Unsafe(Sequencing::NoPreference))
+            .unwrap()
+    };
+    let writer_rc = rc(1);
+    let watcher_rc = rc(2);
+    let watcher_updates = &nodes[2].1;
+
+    let rec = writer_rc
+        .create_dht_record(DHTSchema::dflt(1).unwrap(), Some(CRYPTO_KIND_VLD0))
+        .await
+        .unwrap();
+    let key = *rec.key();
+    writer_rc
+        .set_dht_value(key, 0, b"WATCHED".to_vec(), None)
+        .await
+        .unwrap();
+
+    watcher_rc.open_dht_record(key, None).await.unwrap();
+    let watch_ms = 2 * 60 * 1_000u32;
+    let expiration = watcher_rc
+        .watch_dht_values(
+            key,
+            ValueSubkeyRangeSet::full(),
+            Timestamp::new(clock.now_us() + u64::from(watch_ms) * 1_000u64),
+            u32::MAX,
+        )
+        .await
+        .unwrap();
+    assert_ne!(expiration, Timestamp::default());
+
+    // Minutes of simulated time pass in seconds, and the watch ends with a zero count change
+    let wall_start = std::time::Instant::now();
+    let sim_start_us = clock.now_us();
+    driver.set_step_us(FAST_FORWARD_STEP_US);
+    let change = recv_value_change(watcher_updates, watch_ms + 60_000)
+        .await
+        .expect("watch should have expired");
+    driver.set_step_us(RUNNING_STEP_US);
+    assert_eq!(change.key, key);
+    assert_eq!(change.count, 0);
+    assert!(clock.now_us() >= expiration.as_u64());
+    assert!(clock.now_us() - sim_start_us >= u64::from(watch_ms) * 1_000u64 / 2);
+    info!(
+        "watch expired after {}s of simulated time in {}s",
+        (clock.now_us() - sim_start_us) / 1_000_000,
+        wall_start.elapsed().as_secs()
+    );
+
+    watcher_rc.close_dht_record(key).await.unwrap();
+    writer_rc.close_dht_record(key).await.unwrap();
+
+    shutdown_virtual_network(nodes).await;
+    drop(driver);
+    set_simulation_clock(None);
+}
+
+pub async fn test_simulation_bucket_kick() {
+    info!("--- test_simulation_bucket_kick ---");
+    reset_virtual_router();
+
+    let clock = SimulationClock::new(get_timestamp());
+    set_simulation_clock(Some(clock.clone()));
+    let driver = ClockDriver::start(clock.clone());
+
+    let (first_keypair, bucket_keypairs) = same_bucket_keypairs(2);
+    let first = startup_virtual_node(0, vec![], vec![], Some(first_keypair)).await;
+    let bootstrap = virtual_node_bootstrap(0, &first.0);
+    let other = startup_virtual_node(1, bootstrap.clone(), vec![], None).await;
+    let stale = startup_virtual_node(2, bootstrap.clone(), vec![], Some(bucket_keypairs[0])).await;
+    wait_for_peers(&first.0, 2).await;
+    let stale_id = node_id(&stale.0);
+    stale.0.shutdown().await;
+
+    // The stopped node goes dead after minutes of simulated time, which pass in seconds
+    let wall_start = std::time::Instant::now();
+    let sim_start_us = clock.now_us();
+    driver.set_step_us(FAST_FORWARD_STEP_US);
+    timeout(10 * 60 * 1_000, async {
+        while entry_state(&first.0, stale_id) != Some(RoutingTableEntryState::Dead) {
+            sleep(1_000).await;
+        }
+    })
+    .await
+    .expect("stopped node should have gone dead");
+    driver.set_step_us(RUNNING_STEP_US);
+    info!(
+        "stopped node went dead after {}s of simulated time in {}s",
+        (clock.now_us() - sim_start_us) / 1_000_000,
+        wall_start.elapsed().as_secs()
+    );
+
+    // A new node in the full bucket gets the dead node kicked to make room for it
+    let fresh = startup_virtual_node(3, bootstrap, vec![], Some(bucket_keypairs[1])).await;
+    let fresh_id = node_id(&fresh.0);
+    timeout(60 * 1_000, async {
+        while entry_state(&first.0, stale_id).is_some() || entry_state(&first.0, fresh_id).is_none()
+        {
+            sleep(250).await;
+        }
+    })
+    .await
+    .expect("dead node should have been kicked for the new one");
+
+    shutdown_virtual_network(vec![first, other, fresh]).await;
+    drop(driver);
+    set_simulation_clock(None);
+}
+
+pub async fn test_all() {
+    test_simulation_watch_expiration().await;
+    test_simulation_bucket_kick().await;
+}
@@ -213,6 +213,8 @@ pub fn config_callback(key: String) -> ConfigCallbackReturn {
         "network.routing_table.bootstrap" => Ok(Box::new(vec![
             "ws://bootstrap.veilid.net:5150/ws".to_string(),
         ])),
+        "network.routing_table.bootstrap_keys" => Ok(Box::new(Vec::<TypedKey>::new())),
+        "network.routing_table.bootstrap_allow_unsigned" => Ok(Box::new(true)),
         "network.routing_table.limit_over_attached" => Ok(Box::new(64u32)),
         "network.routing_table.limit_fully_attached" => Ok(Box::new(32u32)),
         "network.routing_table.limit_attached_strong" => Ok(Box::new(16u32)),
@@ -220,6 +222,23 @@ pub fn config_callback(key: String) -> ConfigCallbackReturn {
         "network.routing_table.limit_attached_weak" => Ok(Box::new(4u32)),
         "network.rpc.concurrency" => Ok(Box::new(0u32)),
         "network.rpc.queue_size" => Ok(Box::new(1024u32)),
+        "network.rpc.queues.answer_queue_size" => Ok(Box::new(256u32)),
+        "network.rpc.queues.status_queue_size" => Ok(Box::new(256u32)),
+        "network.rpc.queues.dht_queue_size" => Ok(Box::new(512u32)),
+        "network.rpc.queues.routed_queue_size" => Ok(Box::new(512u32)),
+        "network.rpc.queues.max_queued_per_sender" => Ok(Box::new(64u32)),
+        "network.rpc.rate_limits.enabled" => Ok(Box::new(true)),
+        "network.rpc.rate_limits.punish_violation_count" => Ok(Box::new(64u32)),
+        "network.rpc.rate_limits.dht.rate_per_min" => Ok(Box::new(1200u32)),
+        "network.rpc.rate_limits.dht.burst" => Ok(Box::new(128u32)),
+        "network.rpc.rate_limits.dht_watch.rate_per_min" => Ok(Box::new(120u32)),
+        "network.rpc.rate_limits.dht_watch.burst" => Ok(Box::new(16u32)),
+        "network.rpc.rate_limits.app_message.rate_per_min" => Ok(Box::new(1200u32)),
+        "network.rpc.rate_limits.app_message.burst" => Ok(Box::new(128u32)),
+        "network.rpc.rate_limits.route.rate_per_min" => Ok(Box::new(6000u32)),
+        "network.rpc.rate_limits.route.burst" => Ok(Box::new(512u32)),
+        "network.rpc.rate_limits.signal.rate_per_min" => Ok(Box::new(120u32)),
+        "network.rpc.rate_limits.signal.burst" => Ok(Box::new(16u32)),
         "network.rpc.max_timestamp_behind_ms" => Ok(Box::new(Some(10_000u32))),
         "network.rpc.max_timestamp_ahead_ms" => Ok(Box::new(Some(10_000u32))),
         "network.rpc.timeout_ms" => Ok(Box::new(5_000u32)),
@@ -250,6 +269,14 @@ pub fn config_callback(key: String) -> ConfigCallbackReturn {
         "network.upnp" => Ok(Box::new(false)),
         "network.detect_address_changes" => Ok(Box::new(true)),
         "network.restricted_nat_retries" => Ok(Box::new(0u32)),
+        "network.lan_discovery.enabled" => Ok(Box::new(true)),
+        "network.lan_discovery.interval_ms" => Ok(Box::new(10_000u32)),
+        "network.lan_discovery.port" => Ok(Box::new(5149u16)),
+        "network.lan_discovery.multicast_group_v4" => Ok(Box::new("239.255.86.76".to_owned())),
+        "network.lan_discovery.multicast_group_v6" => Ok(Box::new("ff02::7665:696c".to_owned())),
+        "network.lan_discovery.max_beacons_per_min" => Ok(Box::new(12u32)),
+        "network.bootstrap_server.enabled" => Ok(Box::new(true)),
+        "network.bootstrap_server.max_peers_per_type" => Ok(Box::new(2u32)),
         "network.tls.certificate_path" => Ok(Box::new(get_certfile_path())),
         "network.tls.private_key_path" => Ok(Box::new(get_keyfile_path())),
         "network.tls.connection_initial_timeout_ms" => Ok(Box::new(2_000u32)),
@@ -344,6 +371,26 @@ pub async fn test_config() {
     assert_eq!(inner.network.network_key_password, Option::<String>::None);
     assert_eq!(inner.network.rpc.concurrency, 0u32);
     assert_eq!(inner.network.rpc.queue_size, 1024u32);
+    assert_eq!(inner.network.rpc.queues.answer_queue_size, 256u32);
+    assert_eq!(inner.network.rpc.queues.status_queue_size, 256u32);
+    assert_eq!(inner.network.rpc.queues.dht_queue_size, 512u32);
+    assert_eq!(inner.network.rpc.queues.routed_queue_size, 512u32);
+    assert_eq!(inner.network.rpc.queues.max_queued_per_sender, 64u32);
+    assert!(inner.network.rpc.rate_limits.enabled);
+    assert_eq!(inner.network.rpc.rate_limits.punish_violation_count, 64u32);
+    assert_eq!(inner.network.rpc.rate_limits.dht.rate_per_min, 1200u32);
+    assert_eq!(inner.network.rpc.rate_limits.dht.burst, 128u32);
+    assert_eq!(inner.network.rpc.rate_limits.dht_watch.rate_per_min, 120u32);
+    assert_eq!(inner.network.rpc.rate_limits.dht_watch.burst, 16u32);
+    assert_eq!(
+        inner.network.rpc.rate_limits.app_message.rate_per_min,
+        1200u32
+    );
+    assert_eq!(inner.network.rpc.rate_limits.app_message.burst, 128u32);
+    assert_eq!(inner.network.rpc.rate_limits.route.rate_per_min, 6000u32);
+    assert_eq!(inner.network.rpc.rate_limits.route.burst, 512u32);
+    assert_eq!(inner.network.rpc.rate_limits.signal.rate_per_min, 120u32);
+    assert_eq!(inner.network.rpc.rate_limits.signal.burst, 16u32);
     assert_eq!(inner.network.rpc.timeout_ms, 5_000u32);
     assert_eq!(inner.network.rpc.max_route_hop_count, 4u8);
     assert_eq!(inner.network.rpc.default_route_hop_count, 1u8);
@@ -359,6 +406,8 @@ pub async fn test_config() {
         inner.network.routing_table.bootstrap,
         vec!["ws://bootstrap.veilid.net:5150/ws"],
     );
+    assert_eq!(inner.network.routing_table.bootstrap_keys.len(), 0);
+    assert!(inner.network.routing_table.bootstrap_allow_unsigned);
     assert_eq!(inner.network.routing_table.limit_over_attached, 64u32);
     assert_eq!(inner.network.routing_table.limit_fully_attached, 32u32);
     assert_eq!(inner.network.routing_table.limit_attached_strong, 16u32);
@@ -385,6 +434,20 @@ pub async fn test_config() {
     assert!(!inner.network.upnp);
     assert!(inner.network.detect_address_changes);
     assert_eq!(inner.network.restricted_nat_retries, 0u32);
+    assert!(inner.network.lan_discovery.enabled);
+    assert_eq!(inner.network.lan_discovery.interval_ms, 10_000u32);
+    assert_eq!(inner.network.lan_discovery.port, 5149u16);
+    assert_eq!(
+        inner.network.lan_discovery.multicast_group_v4,
+        "239.255.86.76".to_string()
+    );
+    assert_eq!(
+        inner.network.lan_discovery.multicast_group_v6,
+        "ff02::7665:696c".to_string()
+    );
+    assert_eq!(inner.network.lan_discovery.max_beacons_per_min, 12u32);
+    assert!(inner.network.bootstrap_server.enabled);
+    assert_eq!(inner.network.bootstrap_server.max_peers_per_type, 2u32);
     assert_eq!(inner.network.tls.certificate_path, get_certfile_path());
     assert_eq!(inner.network.tls.private_key_path, get_keyfile_path());
     assert_eq!(inner.network.tls.connection_initial_timeout_ms, 2_000u32);
@@ -419,6 +482,23 @@ pub async fn test_config() {
     assert_eq!(inner.network.protocol.wss.listen_address, "");
     assert_eq!(inner.network.protocol.wss.path, "ws");
     assert_eq!(inner.network.protocol.wss.url, None);
+    drop(inner);
+
+    // Trusting bootstrap keys while allowing unsigned bootstrap records is refused
+    let bootstrap_key = TypedKey::from_str("VLD0:7lxDEabK_qgjbe38RtBa3IZLrud84P6NhGP-pRTZzdQ")
+        .expect("should parse");
+    assert!(vc
+        .with_mut(|inner| {
+            inner.network.routing_table.bootstrap_keys = vec![bootstrap_key];
+            Ok(())
+        })
+        .is_err());
+    vc.with_mut(|inner| {
+        inner.network.routing_table.bootstrap_keys = vec![bootstrap_key];
+        inner.network.routing_table.bootstrap_allow_unsigned = false;
+        Ok(())
+    })
+    .expect("should be valid");
 }
 
 pub async fn test_all() {
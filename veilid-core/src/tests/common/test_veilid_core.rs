@@ -192,6 +192,87 @@ pub async fn test_attach_detach_multiple() {
     }
 }
 
+pub async fn test_debug_request() {
+    trace!("test_debug_request: starting");
+    let (update_callback, config_callback) = setup_veilid_core();
+    let api = api_startup(update_callback, config_callback)
+        .await
+        .expect("startup failed");
+
+    let res = api
+        .debug_request(DebugRequest::Buckets {
+            min_state: RoutingTableEntryState::Dead,
+        })
+        .await
+        .unwrap();
+    let DebugResponse::Buckets { buckets } = &res else {
+        panic!("wrong response: {:?}", res);
+    };
+    assert_eq!(buckets.len(), VALID_CRYPTO_KINDS.len());
+    assert!(buckets.iter().all(|b| b.counts.iter().all(|c| *c == 0)));
+
+    let res = api
+        .debug_request(DebugRequest::RecordList {
+            scope: DebugRecordScope::Local,
+        })
+        .await
+        .unwrap();
+    let DebugResponse::RecordList { store } = &res else {
+        panic!("wrong response: {:?}", res);
+    };
+    assert!(store.records.is_empty());
+
+    let res = api
+        .debug_request(DebugRequest::TableInfo {
+            name: "nonexistent".to_owned(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(res, DebugResponse::TableInfo { info: None });
+
+    // The text commands are formatted from the typed responses
+    assert!(api
+        .debug("buckets".to_owned())
+        .await
+        .unwrap()
+        .starts_with("Buckets:"));
+    assert!(api
+        .debug("route list".to_owned())
+        .await
+        .unwrap()
+        .starts_with("Allocated Routes: (count = 0)"));
+
+    trace!("test_debug_request: shutting down");
+    api.shutdown().await;
+    trace!("test_debug_request: finished");
+}
+
+pub async fn test_routing_table_info() {
+    trace!("test_routing_table_info: starting");
+    let (update_callback, config_callback) = setup_veilid_core();
+    let api = api_startup(update_callback, config_callback)
+        .await
+        .expect("startup failed");
+
+    let rti = api
+        .get_routing_table_info(RoutingTableEntryState::Dead)
+        .expect("should get routing table info");
+    assert_eq!(rti.node_ids.len(), VALID_CRYPTO_KINDS.len());
+    assert_eq!(rti.buckets.len(), VALID_CRYPTO_KINDS.len());
+    assert!(rti.public_internet_relay.is_empty());
+    assert!(rti.entries.is_empty());
+
+    // Our own node is never one of the closest peers
+    let peers = api
+        .get_closest_peers(rti.node_ids[0], 4)
+        .expect("should get closest peers");
+    assert!(peers.is_empty());
+
+    trace!("test_routing_table_info: shutting down");
+    api.shutdown().await;
+    trace!("test_routing_table_info: finished");
+}
+
 pub async fn test_all() {
     test_startup_shutdown().await;
     test_startup_shutdown_from_config().await;
@@ -199,4 +280,6 @@ pub async fn test_all() {
     test_startup_shutdown_multiple().await;
     test_startup_shutdown_from_config_multiple().await;
     test_attach_detach_multiple().await;
+    test_debug_request().await;
+    test_routing_table_info().await;
 }
@@ -16,10 +16,17 @@ pub async fn run_all_tests() {
     test_veilid_config::test_all().await;
     info!("TEST: test_connection_table");
     test_connection_table::test_all().await;
+    #[cfg(not(feature = "virtual-network"))]
+    {
+        info!("TEST: test_lan_discovery");
+        test_lan_discovery::test_all().await;
+    }
     info!("TEST: test_signed_node_info");
     test_signed_node_info::test_all().await;
     info!("TEST: test_table_store");
     test_table_store::test_all().await;
+    info!("TEST: test_large_value");
+    test_large_value::test_all().await;
     info!("TEST: test_protected_store");
     test_protected_store::test_all().await;
     info!("TEST: test_crypto");
@@ -30,8 +37,18 @@ pub async fn run_all_tests() {
     veilid_api::tests::test_serialize_json::test_all().await;
     info!("TEST: routing_table::test_serialize_routing_table");
     routing_table::tests::test_serialize_routing_table::test_all().await;
-    // info!("TEST: test_dht");
-    // test_dht::test_all().await;
+    info!("TEST: routing_table::test_bootstrap");
+    routing_table::tests::test_bootstrap::test_all().await;
+    #[cfg(feature = "virtual-network")]
+    {
+        info!("TEST: test_dht");
+        test_dht::test_all().await;
+    }
+    #[cfg(all(feature = "simulation", feature = "virtual-network"))]
+    {
+        info!("TEST: test_simulation");
+        test_simulation::test_all().await;
+    }
 
     info!("Finished unit tests");
 }
@@ -117,10 +134,18 @@ cfg_if! {
 
         run_test!(test_connection_table);
 
+        #[cfg(not(feature = "virtual-network"))]
+        run_test!(test_lan_discovery);
+
         run_test!(test_signed_node_info);
 
+        #[cfg(feature = "virtual-network")]
+        run_test!(test_virtual_network);
+
         run_test!(test_table_store);
 
+        run_test!(test_large_value);
+
         run_test!(test_protected_store);
 
         run_test!(test_crypto);
@@ -131,6 +156,12 @@ cfg_if! {
 
         run_test!(routing_table, test_serialize_routing_table);
 
-        // run_test!(test_dht);
+        run_test!(routing_table, test_bootstrap);
+
+        #[cfg(feature = "virtual-network")]
+        run_test!(test_dht);
+
+        #[cfg(all(feature = "simulation", feature = "virtual-network"))]
+        run_test!(test_simulation);
     }
 }
@@ -204,6 +204,39 @@ impl VeilidAPI {
         Ok(())
     }
 
+    ////////////////////////////////////////////////////////////////
+    // Routing Table
+
+    /// Get the structure of this node's routing table.
+    ///
+    /// Returns the number of entries in each bucket and the details of each entry, including only
+    /// the entries that are in at least `min_state`.
+    #[instrument(target = "veilid_api", level = "debug", skip(self), err)]
+    pub fn get_routing_table_info(
+        &self,
+        min_state: RoutingTableEntryState,
+    ) -> VeilidAPIResult<RoutingTableInfo> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "VeilidAPI::get_routing_table_info(min_state: {:?})", min_state);
+        let routing_table = self.routing_table()?;
+        Ok(routing_table.routing_table_info(min_state.into()))
+    }
+
+    /// Get up to `count` nodes from this node's routing table that are closest to a key.
+    ///
+    /// Reliable nodes are preferred. This does not ask the network for closer nodes.
+    #[instrument(target = "veilid_api", level = "debug", skip(self), err)]
+    pub fn get_closest_peers(
+        &self,
+        key: TypedKey,
+        count: u32,
+    ) -> VeilidAPIResult<Vec<RoutingTableEntry>> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "VeilidAPI::get_closest_peers(key: {:?}, count: {})", key, count);
+        let routing_table = self.routing_table()?;
+        routing_table.closest_peers(key, count as usize)
+    }
+
     ////////////////////////////////////////////////////////////////
     // Routing Context
 
@@ -363,6 +396,149 @@ impl VeilidAPI {
         Ok(())
     }
 
+    /// Get the health of either a locally allocated or remotely imported private route.
+    ///
+    /// Returns `None` if the route is not known to this node.
+    #[instrument(target = "veilid_api", level = "debug", skip(self), ret, err)]
+    pub fn get_route_health(&self, route_id: RouteId) -> VeilidAPIResult<Option<RouteHealth>> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "VeilidAPI::get_route_health(route_id: {:?})", route_id);
+        let rss = self.routing_table()?.route_spec_store();
+        Ok(rss.get_route_health(&route_id))
+    }
+
+    /// List the health of all locally allocated and remotely imported private routes.
+    #[instrument(target = "veilid_api", level = "debug", skip(self), ret, err)]
+    pub fn list_route_health(&self) -> VeilidAPIResult<Vec<RouteHealth>> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "VeilidAPI::list_route_health()");
+        let rss = self.routing_table()?.route_spec_store();
+        Ok(rss.list_route_health())
+    }
+
+    /// Set or clear the rotation policy of a locally allocated private route.
+    ///
+    /// Once published, a route with a rotation policy is replaced with a freshly allocated and
+    /// tested route when it gets too old or fails too often. The replacement is reported with its
+    /// private route blob in [VeilidRouteChange::rotated_routes]. The old route keeps working for a
+    /// short grace period so senders can switch over, and is then reported in
+    /// [VeilidRouteChange::dead_routes].
+    /// If the policy has a `publish_key`, the new blob is also written to that opened DHT record.
+    #[instrument(target = "veilid_api", level = "debug", skip(self), ret, err)]
+    pub fn set_route_rotation_policy(
+        &self,
+        route_id: RouteId,
+        policy: Option<RouteRotationPolicy>,
+    ) -> VeilidAPIResult<()> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "VeilidAPI::set_route_rotation_policy(route_id: {:?}, policy: {:?})", route_id, policy);
+        let rss = self.routing_table()?.route_spec_store();
+        rss.set_route_rotation_policy(&route_id, policy)
+    }
+
+    ////////////////////////////////////////////////////////////////
+    // Mailboxes
+
+    /// Publish a managed private route 'mailbox' to a DHT record subkey.
+    ///
+    /// Allocates a new private route, writes its blob to the subkey, and keeps the subkey current:
+    /// when the route dies a replacement is allocated, and when it is rotated by its `rotation_policy`
+    /// the new blob is written. Remote parties can use [RoutingContext::resolve_mailbox()] to send to it.
+    /// The DHT record must be opened with a writer via open_dht_record or create_dht_record, and stay
+    /// open for as long as the mailbox is published. Mailboxes are not persisted across restarts.
+    ///
+    /// Returns the route id of the route currently bound to the mailbox.
+    #[instrument(target = "veilid_api", level = "debug", skip(self), ret, err)]
+    pub async fn publish_mailbox(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        stability: Stability,
+        sequencing: Sequencing,
+        rotation_policy: Option<RouteRotationPolicy>,
+    ) -> VeilidAPIResult<RouteId> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "VeilidAPI::publish_mailbox(key: {:?}, subkey: {:?}, stability: {:?}, sequencing: {:?}, rotation_policy: {:?})",
+            key,
+            subkey,
+            stability,
+            sequencing,
+            rotation_policy);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+
+        // The mailbox writes the blobs itself
+        let rotation_policy = rotation_policy.map(|p| RouteRotationPolicy {
+            publish_key: None,
+            publish_subkey: 0,
+            ..p
+        });
+
+        let default_route_hop_count: usize = {
+            let config = self.config()?;
+            let c = config.get();
+            c.network.rpc.default_route_hop_count.into()
+        };
+
+        let rss = self.routing_table()?.route_spec_store();
+        let (route_id, blob) = rss
+            .allocate_published_route(
+                &VALID_CRYPTO_KINDS,
+                stability,
+                sequencing,
+                default_route_hop_count,
+                DirectionSet::all(),
+                &[],
+                rotation_policy.clone(),
+            )
+            .await?;
+
+        let mailbox = Mailbox {
+            key,
+            subkey,
+            route_id: Some(route_id),
+            stability,
+            sequencing,
+            rotation_policy,
+            needs_publish: false,
+        };
+        if let Err(e) = rss.add_mailbox(mailbox) {
+            rss.release_route(route_id);
+            return Err(e);
+        }
+
+        let storage_manager = self.storage_manager()?;
+        let res = match storage_manager.set_value(key, subkey, blob, None).await {
+            Ok(None) => Ok(route_id),
+            Ok(Some(_)) => Err(VeilidAPIError::try_again(
+                "newer mailbox value was found on the network",
+            )),
+            Err(e) => Err(e),
+        };
+        if res.is_err() {
+            rss.remove_mailbox(&key, subkey);
+            rss.release_route(route_id);
+        }
+        res
+    }
+
+    /// Stop keeping a mailbox current and release the route bound to it.
+    ///
+    /// The last published route blob is left in the DHT record subkey.
+    #[instrument(target = "veilid_api", level = "debug", skip(self), ret, err)]
+    pub fn unpublish_mailbox(&self, key: TypedKey, subkey: ValueSubkey) -> VeilidAPIResult<()> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "VeilidAPI::unpublish_mailbox(key: {:?}, subkey: {:?})", key, subkey);
+        let rss = self.routing_table()?.route_spec_store();
+        let Some(mailbox) = rss.remove_mailbox(&key, subkey) else {
+            apibail_invalid_argument!("unpublish_mailbox", "key", key);
+        };
+        if let Some(route_id) = mailbox.route_id {
+            rss.release_route(route_id);
+        }
+        Ok(())
+    }
+
     ////////////////////////////////////////////////////////////////
     // App Calls
 
@@ -385,6 +561,72 @@ impl VeilidAPI {
             .map_err(|e| e.into())
     }
 
+    /// Register an application channel for [RoutingContext::app_message_on_channel()] and [RoutingContext::app_call_on_channel()].
+    ///
+    /// Messages on a registered channel are delivered through the update callback with their channel set.
+    /// Messages on refused channels, or larger than the channel's `max_message_size`, are rejected
+    /// at the RPC layer. A rejected app call is answered with the refusal, so the caller gets an error right away.
+    /// Registering an already registered channel replaces its options.
+    #[instrument(target = "veilid_api", level = "debug", skip(self), ret, err)]
+    pub fn register_app_channel(
+        &self,
+        channel: AppChannel,
+        options: Option<AppChannelOptions>,
+    ) -> VeilidAPIResult<()> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "VeilidAPI::register_app_channel(channel: {:?}, options: {:?})", channel, options);
+        self.network_manager()?.app_channel_table().register(
+            channel,
+            options.unwrap_or_default(),
+            None,
+        );
+        Ok(())
+    }
+
+    /// Register an application channel with its own handler.
+    ///
+    /// Same as [VeilidAPI::register_app_channel()], but the [VeilidUpdate::AppMessage] and [VeilidUpdate::AppCall]
+    /// updates for this channel are sent to `handler` instead of the update callback.
+    #[instrument(target = "veilid_api", level = "debug", skip(self, handler), ret, err)]
+    pub fn register_app_channel_handler(
+        &self,
+        channel: AppChannel,
+        options: Option<AppChannelOptions>,
+        handler: UpdateCallback,
+    ) -> VeilidAPIResult<()> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "VeilidAPI::register_app_channel_handler(channel: {:?}, options: {:?})", channel, options);
+        self.network_manager()?.app_channel_table().register(
+            channel,
+            options.unwrap_or_default(),
+            Some(handler),
+        );
+        Ok(())
+    }
+
+    /// Unregister an application channel.
+    ///
+    /// Returns false if the channel was not registered.
+    #[instrument(target = "veilid_api", level = "debug", skip(self), ret, err)]
+    pub fn unregister_app_channel(&self, channel: AppChannel) -> VeilidAPIResult<bool> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "VeilidAPI::unregister_app_channel(channel: {:?})", channel);
+        Ok(self.network_manager()?.app_channel_table().unregister(&channel))
+    }
+
+    /// Choose whether messages on channels that are not registered are rejected at the RPC layer.
+    ///
+    /// Messages sent without a channel are always delivered. Defaults to false.
+    #[instrument(target = "veilid_api", level = "debug", skip(self), ret, err)]
+    pub fn set_reject_unknown_app_channels(&self, reject_unknown: bool) -> VeilidAPIResult<()> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "VeilidAPI::set_reject_unknown_app_channels(reject_unknown: {:?})", reject_unknown);
+        self.network_manager()?
+            .app_channel_table()
+            .set_reject_unknown(reject_unknown);
+        Ok(())
+    }
+
     ////////////////////////////////////////////////////////////////
     // Tunnel Building
 
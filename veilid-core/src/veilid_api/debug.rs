@@ -43,20 +43,244 @@ pub fn format_opt_bps(bps: Option<ByteCount>) -> String {
     }
 }
 
-fn get_bucket_entry_state(text: &str) -> Option<BucketEntryState> {
-    if text == "punished" {
-        Some(BucketEntryState::Punished)
-    } else if text == "dead" {
-        Some(BucketEntryState::Dead)
-    } else if text == "reliable" {
-        Some(BucketEntryState::Reliable)
-    } else if text == "unreliable" {
-        Some(BucketEntryState::Unreliable)
-    } else {
-        None
+fn format_columns(columns: &[ColumnInfo]) -> String {
+    let mut out = String::new();
+    for (n, col) in columns.iter().enumerate() {
+        //
+        out += &format!("Column {}:\n", n);
+        out += &format!("  Key Count: {}\n", col.key_count);
+    }
+    out
+}
+
+fn format_age(ts: Timestamp) -> String {
+    debug_duration(get_timestamp().saturating_sub(ts.as_u64()))
+}
+
+impl fmt::Display for DebugResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DebugResponse::Buckets { buckets } => {
+                const COLS: usize = 16;
+                writeln!(f, "Buckets:")?;
+                for b in buckets {
+                    writeln!(f, "  {}:", b.kind)?;
+                    for (r, row) in b.counts.chunks_exact(COLS).enumerate() {
+                        write!(f, "    {:>3}: ", r * COLS)?;
+                        for cnt in row {
+                            write!(f, "{:>3} ", cnt)?;
+                        }
+                        writeln!(f)?;
+                    }
+                }
+                Ok(())
+            }
+            DebugResponse::Entries {
+                entry_count,
+                buckets,
+            } => {
+                writeln!(f, "Entries: {}", entry_count)?;
+                for ck in &VALID_CRYPTO_KINDS {
+                    let mut filtered_total = 0;
+                    for b in buckets.iter().filter(|b| b.kind == *ck) {
+                        filtered_total += b.entries.len();
+                        writeln!(f, "{} Bucket #{}:", ck, b.index)?;
+                        for e in &b.entries {
+                            let node_id = e
+                                .node_ids
+                                .iter()
+                                .find(|x| x.kind == *ck)
+                                .map(|x| x.value.encode())
+                                .unwrap_or_default();
+                            let latency = e
+                                .peer_stats
+                                .latency
+                                .as_ref()
+                                .map(|l| {
+                                    format!(
+                                        "{:.2}ms",
+                                        timestamp_to_secs(l.average.as_u64()) * 1000.0
+                                    )
+                                })
+                                .unwrap_or_else(|| "???.??ms".to_string());
+                            let capabilities = e
+                                .public_internet
+                                .as_ref()
+                                .map(|ni| {
+                                    ni.capabilities
+                                        .iter()
+                                        .map(|x| x.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(",")
+                                })
+                                .unwrap_or_else(|| "???".to_owned());
+                            writeln!(
+                                f,
+                                "    {} [{}] {} [{}]",
+                                node_id, e.state_reason, latency, capabilities
+                            )?;
+                        }
+                    }
+                    writeln!(f, "{} Filtered Total: {}", ck, filtered_total)?;
+                }
+                Ok(())
+            }
+            DebugResponse::Entry { entry } => {
+                let Some(e) = entry else {
+                    return writeln!(f, "Entry does not exist");
+                };
+                writeln!(
+                    f,
+                    "node_ids: {}",
+                    e.node_ids
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<String>>()
+                        .join(",")
+                )?;
+                writeln!(f, "state: {:?} ({})", e.state, e.state_reason)?;
+                if let Some(ni) = &e.public_internet {
+                    writeln!(
+                        f,
+                        "capabilities: {}",
+                        ni.capabilities
+                            .iter()
+                            .map(|x| x.to_string())
+                            .collect::<Vec<String>>()
+                            .join(",")
+                    )?;
+                }
+                writeln!(f, "peer_stats: {:#?}", e.peer_stats)
+            }
+            DebugResponse::RouteList { allocated, remote } => {
+                writeln!(f, "Allocated Routes: (count = {}):", allocated.len())?;
+                for r in allocated {
+                    writeln!(f, "{}", r.encode())?;
+                }
+                writeln!(f, "Remote Routes: (count = {}):", remote.len())?;
+                for r in remote {
+                    writeln!(f, "{}", r.encode())?;
+                }
+                Ok(())
+            }
+            DebugResponse::Route { route } => match route {
+                Some(rh) => write!(f, "{:#?}", rh),
+                None => write!(f, "Route does not exist"),
+            },
+            DebugResponse::RouteTest { success } => match success {
+                Some(true) => write!(f, "SUCCESS"),
+                Some(false) => write!(f, "FAILED"),
+                None => write!(f, "UNTESTED"),
+            },
+            DebugResponse::RoutePublish { route_id, blob } => write!(
+                f,
+                "Published route {} as {} bytes:\n{}",
+                route_id.encode(),
+                blob.len(),
+                BASE64URL_NOPAD.encode(blob)
+            ),
+            DebugResponse::RecordList { store } => {
+                writeln!(f, "Record Index:")?;
+                for r in &store.records {
+                    writeln!(
+                        f,
+                        "  {} age={} len={} subkeys={}",
+                        r.key,
+                        format_age(r.last_touched),
+                        r.record_data_size,
+                        r.stored_subkeys,
+                    )?;
+                }
+                writeln!(f, "Subkey Cache Count: {}", store.subkey_cache_count)?;
+                writeln!(
+                    f,
+                    "Subkey Cache Total Size: {}",
+                    store.subkey_cache_total_size
+                )?;
+                writeln!(f, "Total Storage Space: {}", store.total_storage_space)?;
+                writeln!(f, "Dead Records: {}", store.dead_records.len())?;
+                for k in &store.dead_records {
+                    writeln!(f, "  {}", k)?;
+                }
+                writeln!(f, "Changed Records: {}", store.changed_records.len())?;
+                for k in &store.changed_records {
+                    writeln!(f, "  {}", k)?;
+                }
+                Ok(())
+            }
+            DebugResponse::RecordInfo { record } => match record {
+                Some(r) => write!(f, "{:#?}", r),
+                None => write!(f, "Not found"),
+            },
+            DebugResponse::TableList { tables } => {
+                write!(
+                    f,
+                    "TableStore tables:\n{}",
+                    tables
+                        .iter()
+                        .map(|t| format!("{} ({})", t.name, t.internal_name))
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                )
+            }
+            DebugResponse::TableInfo { info } => {
+                let Some(info) = info else {
+                    return write!(f, "Table does not exist");
+                };
+                write!(
+                    f,
+                    "Table info for '{}':\n\
+                    Table Name: {}\n\
+                    Column Count: {}\n\
+                    IO Stats (since previous query):\n{}\n\
+                    IO Stats (overall):\n{}\n\
+                    Columns:\n{}\n",
+                    info.table_name,
+                    info.table_name,
+                    info.column_count,
+                    indent::indent_all_by(4, format!("{:#?}", info.io_stats_since_previous)),
+                    indent::indent_all_by(4, format!("{:#?}", info.io_stats_overall)),
+                    format_columns(&info.columns),
+                )
+            }
+            DebugResponse::PunishList { punishments } => {
+                writeln!(f, "Address filter punishments:")?;
+                for p in punishments {
+                    writeln!(
+                        f,
+                        "  {} {} age={}",
+                        p.target,
+                        p.reason,
+                        format_age(p.timestamp)
+                    )?;
+                }
+                Ok(())
+            }
+            DebugResponse::CommandSchema { commands } => {
+                for c in commands {
+                    writeln!(f, "{} - {}", c.usage(), c.description)?;
+                }
+                Ok(())
+            }
+        }
     }
 }
 
+/// The entry states accepted by the debug commands, shared with the command schema
+const DEBUG_ENTRY_STATES: [(&str, RoutingTableEntryState); 4] = [
+    ("punished", RoutingTableEntryState::Punished),
+    ("dead", RoutingTableEntryState::Dead),
+    ("unreliable", RoutingTableEntryState::Unreliable),
+    ("reliable", RoutingTableEntryState::Reliable),
+];
+
+fn get_debug_entry_state(text: &str) -> Option<RoutingTableEntryState> {
+    DEBUG_ENTRY_STATES
+        .iter()
+        .find(|(name, _)| *name == text)
+        .map(|(_, state)| *state)
+}
+
 fn get_string(text: &str) -> Option<String> {
     Some(text.to_owned())
 }
@@ -527,21 +751,377 @@ pub fn print_data(data: &[u8], truncate_len: Option<usize>) -> String {
     }
 }
 
+fn debug_arg(name: &str, kind: DebugArgKind) -> DebugCommandArg {
+    DebugCommandArg {
+        name: name.to_owned(),
+        kind,
+        optional: false,
+    }
+}
+
+fn debug_opt_arg(name: &str, kind: DebugArgKind) -> DebugCommandArg {
+    DebugCommandArg {
+        optional: true,
+        ..debug_arg(name, kind)
+    }
+}
+
+fn debug_choice(values: &[&str]) -> DebugArgKind {
+    DebugArgKind::Choice {
+        values: values.iter().map(|v| (*v).to_owned()).collect(),
+    }
+}
+
+fn debug_command(words: &str, args: Vec<DebugCommandArg>, description: &str) -> DebugCommand {
+    DebugCommand {
+        words: words.split(' ').map(|w| w.to_owned()).collect(),
+        args,
+        description: description.to_owned(),
+    }
+}
+
+/// Runs a top level debug command with the rest of the command line
+type DebugCommandHandler =
+    for<'a> fn(&'a VeilidAPI, String) -> SendPinBoxFutureLifetime<'a, VeilidAPIResult<String>>;
+
+/// The handlers for the top level words of [debug_command_schema]
+const DEBUG_COMMAND_HANDLERS: &[(&str, DebugCommandHandler)] = &[
+    ("help", |api, args| Box::pin(api.debug_help(args))),
+    ("nodeid", |api, args| Box::pin(api.debug_nodeid(args))),
+    ("buckets", |api, args| Box::pin(api.debug_buckets(args))),
+    ("dialinfo", |api, args| Box::pin(api.debug_dialinfo(args))),
+    ("peerinfo", |api, args| Box::pin(api.debug_peerinfo(args))),
+    ("txtrecord", |api, args| Box::pin(api.debug_txtrecord(args))),
+    ("keypair", |api, args| Box::pin(api.debug_keypair(args))),
+    ("entries", |api, args| Box::pin(api.debug_entries(args))),
+    ("entry", |api, args| Box::pin(api.debug_entry(args))),
+    ("relay", |api, args| Box::pin(api.debug_relay(args))),
+    ("ping", |api, args| Box::pin(api.debug_ping(args))),
+    ("appmessage", |api, args| {
+        Box::pin(api.debug_app_message(args))
+    }),
+    ("appcall", |api, args| Box::pin(api.debug_app_call(args))),
+    ("appreply", |api, args| Box::pin(api.debug_app_reply(args))),
+    ("resolve", |api, args| Box::pin(api.debug_resolve(args))),
+    ("contact", |api, args| Box::pin(api.debug_contact(args))),
+    ("nodeinfo", |api, args| Box::pin(api.debug_nodeinfo(args))),
+    ("purge", |api, args| Box::pin(api.debug_purge(args))),
+    ("attach", |api, args| Box::pin(api.debug_attach(args))),
+    ("detach", |api, args| Box::pin(api.debug_detach(args))),
+    ("config", |api, args| Box::pin(api.debug_config(args))),
+    ("restart", |api, args| Box::pin(api.debug_restart(args))),
+    ("route", |api, args| Box::pin(api.debug_route(args))),
+    ("record", |api, args| Box::pin(api.debug_record(args))),
+    ("punish", |api, args| Box::pin(api.debug_punish(args))),
+    ("table", |api, args| Box::pin(api.debug_table(args))),
+];
+
+/// The commands accepted by [VeilidAPI::debug], kept in step with the help text
+fn debug_command_schema() -> Vec<DebugCommand> {
+    use DebugArgKind::*;
+    let entry_state = || debug_choice(&DEBUG_ENTRY_STATES.map(|(name, _)| name));
+    let route_domain = || debug_choice(&["public", "local"]);
+    vec![
+        debug_command("help", vec![], "Display the debug command help"),
+        debug_command("nodeid", vec![], "Display a node's id(s)"),
+        debug_command(
+            "nodeinfo",
+            vec![],
+            "Display detailed information about this node",
+        ),
+        debug_command(
+            "dialinfo",
+            vec![],
+            "Display the dialinfo in the routing domains of this node",
+        ),
+        debug_command(
+            "peerinfo",
+            vec![
+                debug_opt_arg("routingdomain", route_domain()),
+                debug_opt_arg("published", debug_choice(&["published", "current"])),
+            ],
+            "Display the full PeerInfo for a routing domain of this node",
+        ),
+        debug_command(
+            "buckets",
+            vec![debug_opt_arg("state", entry_state())],
+            "Display the routing table bucket statistics",
+        ),
+        debug_command(
+            "entries",
+            vec![
+                debug_opt_arg("state", entry_state()),
+                debug_opt_arg("capabilities", Text),
+            ],
+            "Display the index of nodes in the routing table",
+        ),
+        debug_command(
+            "entry",
+            vec![debug_arg("node", NodeId)],
+            "Display all the details about a particular node in the routing table",
+        ),
+        debug_command(
+            "contact",
+            vec![debug_arg("node", NodeId)],
+            "Explain what mechanism would be used to contact a particular node",
+        ),
+        debug_command(
+            "resolve",
+            vec![debug_arg("destination", Destination)],
+            "Search the network for a particular node or private route",
+        ),
+        debug_command(
+            "relay",
+            vec![
+                debug_arg("relay", NodeId),
+                debug_opt_arg("routingdomain", route_domain()),
+            ],
+            "Change the relay in use for this node",
+        ),
+        debug_command(
+            "punish list",
+            vec![],
+            "List all punishments this node has assigned to other nodes / networks",
+        ),
+        debug_command(
+            "punish clear",
+            vec![],
+            "Clear all punishments from this node",
+        ),
+        debug_command(
+            "route allocate",
+            vec![
+                debug_opt_arg("sequencing", debug_choice(&["ord", "*ord"])),
+                debug_opt_arg("stability", debug_choice(&["rel"])),
+                debug_opt_arg("count", Number),
+                debug_opt_arg("directions", debug_choice(&["in", "out"])),
+            ],
+            "Allocate a route",
+        ),
+        debug_command(
+            "route release",
+            vec![debug_arg("route", RouteId)],
+            "Release a route",
+        ),
+        debug_command(
+            "route publish",
+            vec![
+                debug_arg("route", RouteId),
+                debug_opt_arg("full", debug_choice(&["full"])),
+            ],
+            "Publish a route 'blob' that can be imported on another machine",
+        ),
+        debug_command(
+            "route unpublish",
+            vec![debug_arg("route", RouteId)],
+            "Mark a route as 'no longer published'",
+        ),
+        debug_command(
+            "route print",
+            vec![debug_arg("route", RouteId)],
+            "Display details about a route",
+        ),
+        debug_command("route list", vec![], "List allocated routes"),
+        debug_command(
+            "route import",
+            vec![debug_arg("blob", Text)],
+            "Import a remote route blob generated by another node's 'publish' command",
+        ),
+        debug_command(
+            "route test",
+            vec![debug_arg("route", RouteId)],
+            "Test an allocated or imported remote route",
+        ),
+        debug_command(
+            "config",
+            vec![
+                debug_opt_arg("insecure", debug_choice(&["insecure"])),
+                debug_opt_arg("configkey", Text),
+                debug_opt_arg("value", Text),
+            ],
+            "Display or temporarily change the node config",
+        ),
+        debug_command(
+            "txtrecord",
+            vec![debug_opt_arg("signing_keypair", Text)],
+            "Generate a TXT record for making this node into a bootstrap node",
+        ),
+        debug_command(
+            "keypair",
+            vec![debug_opt_arg("cryptokind", debug_choice(&["VLD0"]))],
+            "Generate and display a random public/private keypair",
+        ),
+        debug_command(
+            "purge",
+            vec![debug_arg(
+                "target",
+                debug_choice(&["buckets", "connections", "routes"]),
+            )],
+            "Throw away the node's routing table, connections, or routes",
+        ),
+        debug_command(
+            "attach",
+            vec![],
+            "Attach the node to the network if it is detached",
+        ),
+        debug_command(
+            "detach",
+            vec![],
+            "Detach the node from the network if it is attached",
+        ),
+        debug_command("restart network", vec![], "Restart the low level network"),
+        debug_command(
+            "ping",
+            vec![debug_arg("destination", Destination)],
+            "Send a 'Status' RPC question to a destination node",
+        ),
+        debug_command(
+            "appmessage",
+            vec![
+                debug_arg("destination", Destination),
+                debug_arg("data", Text),
+            ],
+            "Send an 'App Message' RPC statement to a destination node",
+        ),
+        debug_command(
+            "appcall",
+            vec![
+                debug_arg("destination", Destination),
+                debug_arg("data", Text),
+            ],
+            "Send a 'App Call' RPC question to a destination node",
+        ),
+        debug_command(
+            "appreply",
+            vec![debug_opt_arg("#id", Text), debug_arg("data", Text)],
+            "Reply to an 'App Call' RPC received by this node",
+        ),
+        debug_command(
+            "record list",
+            vec![debug_arg(
+                "store",
+                debug_choice(&["local", "remote", "opened", "offline"]),
+            )],
+            "Display the dht records in the store",
+        ),
+        debug_command(
+            "record purge",
+            vec![
+                debug_arg("store", debug_choice(&["local", "remote"])),
+                debug_opt_arg("bytes", Number),
+            ],
+            "Clear all dht records optionally down to some total size",
+        ),
+        debug_command(
+            "record create",
+            vec![
+                debug_arg("dhtschema", Text),
+                debug_opt_arg("cryptokind", debug_choice(&["VLD0"])),
+                debug_opt_arg("safety", Text),
+            ],
+            "Create a new dht record",
+        ),
+        debug_command(
+            "record open",
+            vec![debug_arg("key", RecordKey), debug_opt_arg("writer", Text)],
+            "Open an existing dht record",
+        ),
+        debug_command(
+            "record close",
+            vec![debug_opt_arg("key", RecordKey)],
+            "Close an opened/created dht record",
+        ),
+        debug_command(
+            "record set",
+            vec![
+                debug_opt_arg("key", RecordKey),
+                debug_arg("subkey", Number),
+                debug_arg("data", Text),
+            ],
+            "Write a value to a dht record subkey",
+        ),
+        debug_command(
+            "record get",
+            vec![
+                debug_opt_arg("key", RecordKey),
+                debug_arg("subkey", Number),
+                debug_opt_arg("force", debug_choice(&["force"])),
+            ],
+            "Read a value from a dht record subkey",
+        ),
+        debug_command(
+            "record delete",
+            vec![debug_arg("key", RecordKey)],
+            "Delete the local copy of a dht record",
+        ),
+        debug_command(
+            "record info",
+            vec![
+                debug_opt_arg("key", RecordKey),
+                debug_opt_arg("subkey", Number),
+            ],
+            "Display information about a dht record or subkey",
+        ),
+        debug_command(
+            "record watch",
+            vec![
+                debug_opt_arg("key", RecordKey),
+                debug_opt_arg("subkeys", Text),
+                debug_opt_arg("expiration", Number),
+                debug_opt_arg("count", Number),
+            ],
+            "Watch a record for changes",
+        ),
+        debug_command(
+            "record cancel",
+            vec![
+                debug_opt_arg("key", RecordKey),
+                debug_opt_arg("subkeys", Text),
+            ],
+            "Cancel a dht record watch",
+        ),
+        debug_command(
+            "record inspect",
+            vec![
+                debug_opt_arg("key", RecordKey),
+                debug_opt_arg(
+                    "scope",
+                    debug_choice(&["local", "syncget", "syncset", "updateget", "updateset"]),
+                ),
+                debug_opt_arg("subkeys", Text),
+            ],
+            "Display a dht record's subkey status",
+        ),
+        debug_command(
+            "table list",
+            vec![],
+            "List the names of all the tables in the TableDB",
+        ),
+        debug_command(
+            "table info",
+            vec![debug_arg("name", TableName)],
+            "Display the columns and statistics of a table in the TableDB",
+        ),
+    ]
+}
+
 impl VeilidAPI {
     async fn debug_buckets(&self, args: String) -> VeilidAPIResult<String> {
         let args: Vec<String> = args.split_whitespace().map(|s| s.to_owned()).collect();
-        let mut min_state = BucketEntryState::Unreliable;
+        let mut min_state = RoutingTableEntryState::default();
         if args.len() == 1 {
             min_state = get_debug_argument(
                 &args[0],
                 "debug_buckets",
                 "min_state",
-                get_bucket_entry_state,
+                get_debug_entry_state,
             )?;
         }
         // Dump routing table bucket info
-        let routing_table = self.network_manager()?.routing_table();
-        Ok(routing_table.debug_info_buckets(min_state))
+        let res = self
+            .debug_request(DebugRequest::Buckets { min_state })
+            .await?;
+        Ok(res.to_string())
     }
 
     async fn debug_dialinfo(&self, _args: String) -> VeilidAPIResult<String> {
@@ -581,10 +1161,26 @@ impl VeilidAPI {
         Ok(routing_table.debug_info_peerinfo(routing_domain, published))
     }
 
-    async fn debug_txtrecord(&self, _args: String) -> VeilidAPIResult<String> {
+    async fn debug_txtrecord(&self, args: String) -> VeilidAPIResult<String> {
+        let args: Vec<String> = args.split_whitespace().map(|s| s.to_owned()).collect();
+
+        let opt_signing_key_pair = if args.is_empty() {
+            None
+        } else {
+            Some(get_debug_argument_at(
+                &args,
+                0,
+                "debug_txtrecord",
+                "signing_key_pair",
+                |s| TypedKeyPair::from_str(s).ok(),
+            )?)
+        };
+
         // Dump routing table txt record
         let routing_table = self.network_manager()?.routing_table();
-        Ok(routing_table.debug_info_txtrecord().await)
+        Ok(routing_table
+            .debug_info_txtrecord(opt_signing_key_pair)
+            .await)
     }
 
     async fn debug_keypair(&self, args: String) -> VeilidAPIResult<String> {
@@ -608,10 +1204,10 @@ impl VeilidAPI {
     async fn debug_entries(&self, args: String) -> VeilidAPIResult<String> {
         let args: Vec<String> = args.split_whitespace().map(|s| s.to_owned()).collect();
 
-        let mut min_state = BucketEntryState::Unreliable;
+        let mut min_state = RoutingTableEntryState::default();
         let mut capabilities = vec![];
         for arg in args {
-            if let Some(ms) = get_bucket_entry_state(&arg) {
+            if let Some(ms) = get_debug_entry_state(&arg) {
                 min_state = ms;
             } else {
                 for cap in arg.split(',') {
@@ -625,8 +1221,13 @@ impl VeilidAPI {
         }
 
         // Dump routing table entries
-        let routing_table = self.network_manager()?.routing_table();
-        Ok(routing_table.debug_info_entries(min_state, capabilities))
+        let res = self
+            .debug_request(DebugRequest::Entries {
+                min_state,
+                capabilities,
+            })
+            .await?;
+        Ok(res.to_string())
     }
 
     async fn debug_entry(&self, args: String) -> VeilidAPIResult<String> {
@@ -642,8 +1243,12 @@ impl VeilidAPI {
         )?;
 
         // Dump routing table entry
-        let routing_table = self.network_manager()?.routing_table();
-        Ok(routing_table.debug_info_entry(node_ref))
+        let res = self
+            .debug_request(DebugRequest::Entry {
+                node_id: node_ref.best_node_id(),
+            })
+            .await?;
+        Ok(res.to_string())
     }
 
     async fn debug_relay(&self, args: String) -> VeilidAPIResult<String> {
@@ -716,7 +1321,13 @@ impl VeilidAPI {
         // Dump connection table
         let connman = connection_manager.debug_print().await;
 
-        Ok(format!("{}\n\n{}\n\n{}\n\n", nodeinfo, peertable, connman))
+        // Dump rpc work queues
+        let rpcqueues = self.rpc_processor()?.debug_info_queues();
+
+        Ok(format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n",
+            nodeinfo, peertable, connman, rpcqueues
+        ))
     }
 
     async fn debug_nodeid(&self, _args: String) -> VeilidAPIResult<String> {
@@ -916,7 +1527,9 @@ impl VeilidAPI {
             } => Ok(format!(
                 "Destination: {:#?}\nTarget Entry:\n{}\n",
                 &dest,
-                routing_table.debug_info_entry(target.unfiltered())
+                DebugResponse::Entry {
+                    entry: Some(Box::new(routing_table.debug_entry(target.unfiltered()))),
+                }
             )),
             Destination::Relay {
                 relay,
@@ -925,8 +1538,12 @@ impl VeilidAPI {
             } => Ok(format!(
                 "Destination: {:#?}\nTarget Entry:\n{}\nRelay Entry:\n{}\n",
                 &dest,
-                routing_table.clone().debug_info_entry(target.clone()),
-                routing_table.debug_info_entry(relay.unfiltered())
+                DebugResponse::Entry {
+                    entry: Some(Box::new(routing_table.debug_entry(target.clone()))),
+                },
+                DebugResponse::Entry {
+                    entry: Some(Box::new(routing_table.debug_entry(relay.unfiltered()))),
+                }
             )),
             Destination::PrivateRoute {
                 private_route: _,
@@ -991,7 +1608,7 @@ impl VeilidAPI {
 
         // Send a AppMessage
         let out = match rpc
-            .rpc_call_app_message(dest, data)
+            .rpc_call_app_message(dest, None, data)
             .await
             .map_err(VeilidAPIError::internal)?
         {
@@ -1027,7 +1644,7 @@ impl VeilidAPI {
 
         // Send a AppMessage
         let out = match rpc
-            .rpc_call_app_call(dest, data)
+            .rpc_call_app_call(dest, None, data)
             .await
             .map_err(VeilidAPIError::internal)?
         {
@@ -1197,26 +1814,12 @@ impl VeilidAPI {
         };
 
         // Publish route
-        let out = match rss.assemble_private_routes(&route_id, Some(!full)) {
-            Ok(private_routes) => {
-                if let Err(e) = rss.mark_route_published(&route_id, true) {
-                    return Ok(format!("Couldn't mark route published: {}", e));
-                }
-                // Convert to blob
-                let blob_data = RouteSpecStore::private_routes_to_blob(&private_routes)
-                    .map_err(VeilidAPIError::internal)?;
-                let out = BASE64URL_NOPAD.encode(&blob_data);
-                info!(
-                    "Published route {} as {} bytes:\n{}",
-                    route_id.encode(),
-                    blob_data.len(),
-                    out
-                );
-                format!("Published route {}", route_id.encode())
-            }
-            Err(e) => {
-                format!("Couldn't assemble private route: {}", e)
-            }
+        let out = match self
+            .debug_request(DebugRequest::RoutePublish { route_id, full })
+            .await
+        {
+            Ok(res) => res.to_string(),
+            Err(e) => format!("Couldn't publish route: {}", e),
         };
 
         Ok(out)
@@ -1257,33 +1860,13 @@ impl VeilidAPI {
             get_route_id(rss.clone(), true, true),
         )?;
 
-        match rss.debug_route(&route_id) {
-            Some(s) => Ok(s),
-            None => Ok("Route does not exist".to_owned()),
-        }
+        let res = self.debug_request(DebugRequest::Route { route_id }).await?;
+        Ok(res.to_string())
     }
     async fn debug_route_list(&self, _args: Vec<String>) -> VeilidAPIResult<String> {
         //
-        let netman = self.network_manager()?;
-        let routing_table = netman.routing_table();
-        let rss = routing_table.route_spec_store();
-
-        let routes = rss.list_allocated_routes(|k, _| Some(*k));
-        let mut out = format!("Allocated Routes: (count = {}):\n", routes.len());
-        for r in routes {
-            out.push_str(&format!("{}\n", r.encode()));
-        }
-
-        let remote_routes = rss.list_remote_routes(|k, _| Some(*k));
-        out.push_str(&format!(
-            "Remote Routes: (count = {}):\n",
-            remote_routes.len()
-        ));
-        for r in remote_routes {
-            out.push_str(&format!("{}\n", r.encode()));
-        }
-
-        Ok(out)
+        let res = self.debug_request(DebugRequest::RouteList).await?;
+        Ok(res.to_string())
     }
     async fn debug_route_import(&self, args: Vec<String>) -> VeilidAPIResult<String> {
         // <blob>
@@ -1320,18 +1903,8 @@ impl VeilidAPI {
             get_route_id(rss.clone(), true, true),
         )?;
 
-        let success = rss
-            .test_route(route_id)
-            .await
-            .map_err(VeilidAPIError::internal)?;
-
-        let out = match success {
-            Some(true) => "SUCCESS".to_owned(),
-            Some(false) => "FAILED".to_owned(),
-            None => "UNTESTED".to_owned(),
-        };
-
-        Ok(out)
+        let res = self.debug_request(DebugRequest::RouteTest { route_id }).await?;
+        Ok(res.to_string())
     }
 
     async fn debug_route(&self, args: String) -> VeilidAPIResult<String> {
@@ -1367,14 +1940,20 @@ impl VeilidAPI {
         let scope = get_debug_argument_at(&args, 1, "debug_record_list", "scope", get_string)?;
         let out = match scope.as_str() {
             "local" => {
-                let mut out = "Local Records:\n".to_string();
-                out += &storage_manager.debug_local_records().await;
-                out
+                let res = self
+                    .debug_request(DebugRequest::RecordList {
+                        scope: DebugRecordScope::Local,
+                    })
+                    .await?;
+                format!("Local Records:\n{}", res)
             }
             "remote" => {
-                let mut out = "Remote Records:\n".to_string();
-                out += &storage_manager.debug_remote_records().await;
-                out
+                let res = self
+                    .debug_request(DebugRequest::RecordList {
+                        scope: DebugRecordScope::Remote,
+                    })
+                    .await?;
+                format!("Remote Records:\n{}", res)
             }
             "opened" => {
                 let mut out = "Opened Records:\n".to_string();
@@ -1549,7 +2128,15 @@ impl VeilidAPI {
 
         // Do a record set
         let value = match rc
-            .set_dht_value(key, subkey as ValueSubkey, data, writer)
+            .set_dht_value(
+                key,
+                subkey as ValueSubkey,
+                data,
+                Some(SetDHTValueOptions {
+                    writer,
+                    expected_seq: None,
+                }),
+            )
             .await
         {
             Err(e) => {
@@ -1667,8 +2254,18 @@ impl VeilidAPI {
                 li, ri
             )
         } else {
-            let li = storage_manager.debug_local_record_info(key).await;
-            let ri = storage_manager.debug_remote_record_info(key).await;
+            let li = self
+                .debug_request(DebugRequest::RecordInfo {
+                    scope: DebugRecordScope::Local,
+                    key,
+                })
+                .await?;
+            let ri = self
+                .debug_request(DebugRequest::RecordInfo {
+                    scope: DebugRecordScope::Remote,
+                    key,
+                })
+                .await?;
             format!("Local Info:\n{}\n\nRemote Info:\n{}\n", li, ri)
         };
         Ok(out)
@@ -1875,54 +2472,16 @@ impl VeilidAPI {
 
     async fn debug_table_list(&self, _args: Vec<String>) -> VeilidAPIResult<String> {
         //
-        let table_store = self.table_store()?;
-        let table_names = table_store.list_all();
-        let out = format!(
-            "TableStore tables:\n{}",
-            table_names
-                .iter()
-                .map(|(k, v)| format!("{} ({})", k, v))
-                .collect::<Vec<String>>()
-                .join("\n")
-        );
-        Ok(out)
-    }
-
-    fn _format_columns(columns: &[table_store::ColumnInfo]) -> String {
-        let mut out = String::new();
-        for (n, col) in columns.iter().enumerate() {
-            //
-            out += &format!("Column {}:\n", n);
-            out += &format!("  Key Count: {}\n", col.key_count);
-        }
-        out
+        let res = self.debug_request(DebugRequest::TableList).await?;
+        Ok(res.to_string())
     }
 
     async fn debug_table_info(&self, args: Vec<String>) -> VeilidAPIResult<String> {
         //
-        let table_store = self.table_store()?;
-
-        let table_name = get_debug_argument_at(&args, 1, "debug_table_info", "name", get_string)?;
-
-        let Some(info) = table_store.info(&table_name).await? else {
-            return Ok(format!("Table '{}' does not exist", table_name));
-        };
-
-        let info_str = format!(
-            "Table Name: {}\n\
-            Column Count: {}\n\
-            IO Stats (since previous query):\n{}\n\
-            IO Stats (overall):\n{}\n\
-            Columns:\n{}\n",
-            info.table_name,
-            info.column_count,
-            indent::indent_all_by(4, format!("{:#?}", info.io_stats_since_previous)),
-            indent::indent_all_by(4, format!("{:#?}", info.io_stats_overall)),
-            Self::_format_columns(&info.columns),
-        );
+        let name = get_debug_argument_at(&args, 1, "debug_table_info", "name", get_string)?;
 
-        let out = format!("Table info for '{}':\n{}", table_name, info_str);
-        Ok(out)
+        let res = self.debug_request(DebugRequest::TableInfo { name }).await?;
+        Ok(res.to_string())
     }
 
     async fn debug_table(&self, args: String) -> VeilidAPIResult<String> {
@@ -1942,11 +2501,8 @@ impl VeilidAPI {
 
     async fn debug_punish_list(&self, _args: Vec<String>) -> VeilidAPIResult<String> {
         //
-        let network_manager = self.network_manager()?;
-        let address_filter = network_manager.address_filter();
-
-        let out = format!("Address filter punishments:\n{:#?}", address_filter);
-        Ok(out)
+        let res = self.debug_request(DebugRequest::PunishList).await?;
+        Ok(res.to_string())
     }
 
     async fn debug_punish_clear(&self, _args: Vec<String>) -> VeilidAPIResult<String> {
@@ -1983,8 +2539,8 @@ impl VeilidAPI {
     peerinfo [routingdomain] [published|current] - display the full PeerInfo for a routing domain of this node
 
 Routing:
-    buckets [dead|reliable] - Display the routing table bucket statistics (default is only non-dead nodes)
-    entries [dead|reliable] [<capabilities>] - Display the index of nodes in the routing table
+    buckets [punished|dead|unreliable|reliable] - Display the routing table bucket statistics (default is only non-dead nodes)
+    entries [punished|dead|unreliable|reliable] [<capabilities>] - Display the index of nodes in the routing table
     entry <node> - Display all the details about a particular node in the routing table
     contact <node>[<modifiers>] - Explain what mechanism would be used to contact a particular node
     resolve <destination> - Search the network for a particular node or private route
@@ -2003,7 +2559,8 @@ Routing:
 Utilities: 
     config [insecure] [configkey [new value]] - Display or temporarily change the node config 
                                                 (most values should not be changed this way, careful!)
-    txtrecord - Generate a TXT record for making this node into a bootstrap node capable of DNS bootstrap
+    txtrecord [signing_keypair] - Generate a TXT record for making this node into a bootstrap node capable of DNS bootstrap
+                                  Signs the record with the bootstrap signing keypair if one is specified
     keypair [cryptokind] - Generate and display a random public/private keypair
     purge <buckets|connections|routes> - Throw away the node's routing table, connections, or routes
     attach - Attach the node to the network if it is detached
@@ -2032,11 +2589,12 @@ DHT Operations:
 
 TableDB Operations:
     table list - list the names of all the tables in the TableDB
+          info <name> - display the columns and statistics of a table in the TableDB
 
 --------------------------------------------------------------------
 <key> is: VLD0:GsgXCRPrzSK6oBNgxhNpm-rTYFd02R0ySx6j9vbQBG4
     * also <node>, <relay>, <target>, <route>
-<capabilities> is: a list of FourCC codes: ROUT,SGNL,RLAY,DIAL,DHTV,DHTW,APPM etc.
+<capabilities> is: a list of FourCC codes: ROUT,SGNL,RLAY,DIAL,DHTV,DHTW,DHTB,APPM etc.
 <configkey> is: dot path like network.protocol.udp.enabled
 <destination> is:
     * direct:  <node>[+<safety>][<modifiers>]
@@ -2067,6 +2625,112 @@ TableDB Operations:
         .to_owned())
     }
 
+    /// Execute a typed debug query.
+    ///
+    /// This is the structured form of the routing table, route, record, table and punishment
+    /// introspection commands of [VeilidAPI::debug], whose text is formatted from the
+    /// [DebugResponse] returned here.
+    pub async fn debug_request(&self, request: DebugRequest) -> VeilidAPIResult<DebugResponse> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "VeilidAPI::debug_request(request: {:?})", request);
+        match request {
+            DebugRequest::Buckets { min_state } => {
+                let routing_table = self.routing_table()?;
+                Ok(DebugResponse::Buckets {
+                    buckets: routing_table.debug_buckets(min_state.into()),
+                })
+            }
+            DebugRequest::Entries {
+                min_state,
+                capabilities,
+            } => {
+                let routing_table = self.routing_table()?;
+                let (entry_count, buckets) =
+                    routing_table.debug_entries(min_state.into(), capabilities);
+                Ok(DebugResponse::Entries {
+                    entry_count,
+                    buckets,
+                })
+            }
+            DebugRequest::Entry { node_id } => {
+                let routing_table = self.routing_table()?;
+                let entry = routing_table
+                    .lookup_node_ref(node_id)
+                    .map_err(VeilidAPIError::invalid_target)?
+                    .map(|node_ref| Box::new(routing_table.debug_entry(node_ref)));
+                Ok(DebugResponse::Entry { entry })
+            }
+            DebugRequest::RouteList => {
+                let rss = self.routing_table()?.route_spec_store();
+                Ok(DebugResponse::RouteList {
+                    allocated: rss.list_allocated_routes(|k, _| Some(*k)),
+                    remote: rss.list_remote_routes(|k, _| Some(*k)),
+                })
+            }
+            DebugRequest::Route { route_id } => {
+                let rss = self.routing_table()?.route_spec_store();
+                Ok(DebugResponse::Route {
+                    route: rss.get_route_health(&route_id),
+                })
+            }
+            DebugRequest::RouteTest { route_id } => {
+                let rss = self.routing_table()?.route_spec_store();
+                Ok(DebugResponse::RouteTest {
+                    success: rss.test_route(route_id).await?,
+                })
+            }
+            DebugRequest::RoutePublish { route_id, full } => {
+                let rss = self.routing_table()?.route_spec_store();
+                let private_routes = rss.assemble_private_routes(&route_id, Some(!full))?;
+                rss.mark_route_published(&route_id, true)?;
+                Ok(DebugResponse::RoutePublish {
+                    route_id,
+                    blob: RouteSpecStore::private_routes_to_blob(&private_routes)?,
+                })
+            }
+            DebugRequest::RecordList { scope } => {
+                let storage_manager = self.storage_manager()?;
+                Ok(DebugResponse::RecordList {
+                    store: storage_manager.debug_records(scope).await?,
+                })
+            }
+            DebugRequest::RecordInfo { scope, key } => {
+                let storage_manager = self.storage_manager()?;
+                Ok(DebugResponse::RecordInfo {
+                    record: storage_manager.debug_record(scope, key).await?,
+                })
+            }
+            DebugRequest::TableList => {
+                let table_store = self.table_store()?;
+                Ok(DebugResponse::TableList {
+                    tables: table_store
+                        .list_all()
+                        .into_iter()
+                        .map(|(name, internal_name)| DebugTableName {
+                            name,
+                            internal_name,
+                        })
+                        .collect(),
+                })
+            }
+            DebugRequest::TableInfo { name } => {
+                let table_store = self.table_store()?;
+                Ok(DebugResponse::TableInfo {
+                    info: table_store.info(&name).await?,
+                })
+            }
+            DebugRequest::PunishList => {
+                let address_filter = self.network_manager()?.address_filter();
+                Ok(DebugResponse::PunishList {
+                    punishments: address_filter.debug_punishments(),
+                })
+            }
+            DebugRequest::CommandSchema => Ok(DebugResponse::CommandSchema {
+                commands: debug_command_schema(),
+            }),
+        }
+    }
+
     /// Execute an 'internal debug command'.
     pub async fn debug(&self, args: String) -> VeilidAPIResult<String> {
         let res = {
@@ -2078,61 +2742,11 @@ TableDB Operations:
             let (arg, rest) = args.split_once(' ').unwrap_or((args, ""));
             let rest = rest.trim_start().to_owned();
 
-            if arg == "help" {
-                self.debug_help(rest).await
-            } else if arg == "nodeid" {
-                self.debug_nodeid(rest).await
-            } else if arg == "buckets" {
-                self.debug_buckets(rest).await
-            } else if arg == "dialinfo" {
-                self.debug_dialinfo(rest).await
-            } else if arg == "peerinfo" {
-                self.debug_peerinfo(rest).await
-            } else if arg == "txtrecord" {
-                self.debug_txtrecord(rest).await
-            } else if arg == "keypair" {
-                self.debug_keypair(rest).await
-            } else if arg == "entries" {
-                self.debug_entries(rest).await
-            } else if arg == "entry" {
-                self.debug_entry(rest).await
-            } else if arg == "relay" {
-                self.debug_relay(rest).await
-            } else if arg == "ping" {
-                self.debug_ping(rest).await
-            } else if arg == "appmessage" {
-                self.debug_app_message(rest).await
-            } else if arg == "appcall" {
-                self.debug_app_call(rest).await
-            } else if arg == "appreply" {
-                self.debug_app_reply(rest).await
-            } else if arg == "resolve" {
-                self.debug_resolve(rest).await
-            } else if arg == "contact" {
-                self.debug_contact(rest).await
-            } else if arg == "nodeinfo" {
-                self.debug_nodeinfo(rest).await
-            } else if arg == "purge" {
-                self.debug_purge(rest).await
-            } else if arg == "attach" {
-                self.debug_attach(rest).await
-            } else if arg == "detach" {
-                self.debug_detach(rest).await
-            } else if arg == "config" {
-                self.debug_config(rest).await
-            } else if arg == "restart" {
-                self.debug_restart(rest).await
-            } else if arg == "route" {
-                self.debug_route(rest).await
-            } else if arg == "record" {
-                self.debug_record(rest).await
-            } else if arg == "punish" {
-                self.debug_punish(rest).await
-            } else if arg == "table" {
-                self.debug_table(rest).await
-            } else {
-                Err(VeilidAPIError::generic("Unknown debug command"))
-            }
+            let Some((_, handler)) = DEBUG_COMMAND_HANDLERS.iter().find(|(word, _)| *word == arg)
+            else {
+                return Err(VeilidAPIError::generic("Unknown debug command"));
+            };
+            handler(self, rest).await
         };
         res
     }
@@ -2243,3 +2857,85 @@ TableDB Operations:
         Ok((key, rc))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema_choices(commands: &[DebugCommand], arg_name: &str) -> Vec<Vec<String>> {
+        commands
+            .iter()
+            .flat_map(|c| c.args.iter())
+            .filter(|a| a.name == arg_name)
+            .map(|a| match &a.kind {
+                DebugArgKind::Choice { values } => values.clone(),
+                _ => panic!("argument '{}' should be a choice", arg_name),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_debug_command_schema_matches_parser() {
+        let commands = debug_command_schema();
+        let dispatched: Vec<String> = DEBUG_COMMAND_HANDLERS
+            .iter()
+            .map(|(word, _)| (*word).to_owned())
+            .collect();
+        assert!(dispatched.contains(&"help".to_owned()));
+
+        // Every dispatched command is described, and every described command is dispatched
+        for word in &dispatched {
+            assert!(
+                commands.iter().any(|c| c.words[0] == *word),
+                "debug command '{}' is missing from the schema",
+                word
+            );
+        }
+        for c in &commands {
+            assert!(
+                dispatched.contains(&c.words[0]),
+                "schema command '{}' is not dispatched",
+                c.words.join(" ")
+            );
+        }
+    }
+
+    #[test]
+    fn test_debug_command_schema_choices() {
+        let commands = debug_command_schema();
+
+        // Entry states offer every state the parser accepts
+        let states = schema_choices(&commands, "state");
+        assert!(!states.is_empty());
+        for values in states {
+            let parsed: Vec<RoutingTableEntryState> = values
+                .iter()
+                .map(|v| get_debug_entry_state(v).expect("state should parse"))
+                .collect();
+            for state in [
+                RoutingTableEntryState::Punished,
+                RoutingTableEntryState::Dead,
+                RoutingTableEntryState::Unreliable,
+                RoutingTableEntryState::Reliable,
+            ] {
+                assert!(parsed.contains(&state), "missing state {:?}", state);
+            }
+        }
+
+        for values in schema_choices(&commands, "routingdomain") {
+            for v in values {
+                assert!(get_routing_domain(&v).is_some(), "bad routing domain {}", v);
+            }
+        }
+        for values in schema_choices(&commands, "published") {
+            for v in values {
+                assert!(get_published(&v).is_some(), "bad published {}", v);
+            }
+        }
+        for values in schema_choices(&commands, "scope") {
+            for v in values {
+                assert!(get_dht_report_scope(&v).is_some(), "bad scope {}", v);
+            }
+        }
+    }
+}
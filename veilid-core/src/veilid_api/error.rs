@@ -145,6 +145,8 @@ pub enum VeilidAPIError {
     MissingArgument { context: String, argument: String },
     #[error("Generic: {message}")]
     Generic { message: String },
+    #[error("Conflict: subkey is at seq {}", .value.seq())]
+    Conflict { value: ValueData },
 }
 from_impl_to_jsvalue!(VeilidAPIError);
 
@@ -217,6 +219,9 @@ impl VeilidAPIError {
             message: msg.to_string(),
         }
     }
+    pub fn conflict(value: ValueData) -> Self {
+        Self::Conflict { value }
+    }
 
     pub(crate) fn from_network_result<T>(nr: NetworkResult<T>) -> Result<T, Self> {
         match nr {
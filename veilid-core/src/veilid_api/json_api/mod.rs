@@ -66,6 +66,54 @@ pub enum RequestOp {
         #[schemars(with = "String")]
         route_id: RouteId,
     },
+    PublishMailbox {
+        #[schemars(with = "String")]
+        key: TypedKey,
+        subkey: ValueSubkey,
+        #[serde(default)]
+        stability: Stability,
+        #[serde(default)]
+        sequencing: Sequencing,
+        #[serde(default)]
+        rotation_policy: Option<RouteRotationPolicy>,
+    },
+    UnpublishMailbox {
+        #[schemars(with = "String")]
+        key: TypedKey,
+        subkey: ValueSubkey,
+    },
+    GetRouteHealth {
+        #[schemars(with = "String")]
+        route_id: RouteId,
+    },
+    ListRouteHealth,
+    GetRoutingTableInfo {
+        #[serde(default)]
+        min_state: RoutingTableEntryState,
+    },
+    GetClosestPeers {
+        #[schemars(with = "String")]
+        key: TypedKey,
+        count: u32,
+    },
+    SetRouteRotationPolicy {
+        #[schemars(with = "String")]
+        route_id: RouteId,
+        policy: Option<RouteRotationPolicy>,
+    },
+    RegisterAppChannel {
+        #[schemars(with = "String")]
+        channel: AppChannel,
+        #[serde(default)]
+        options: Option<AppChannelOptions>,
+    },
+    UnregisterAppChannel {
+        #[schemars(with = "String")]
+        channel: AppChannel,
+    },
+    SetRejectUnknownAppChannels {
+        reject_unknown: bool,
+    },
     AppCallReply {
         #[schemars(with = "String")]
         call_id: OperationId,
@@ -118,6 +166,9 @@ pub enum RequestOp {
     Debug {
         command: String,
     },
+    DebugRequest {
+        request: DebugRequest,
+    },
     VeilidVersionString,
     VeilidVersion,
     DefaultVeilidConfig,
@@ -168,6 +219,47 @@ pub enum ResponseOp {
         #[serde(flatten)]
         result: ApiResult<()>,
     },
+    PublishMailbox {
+        #[serde(flatten)]
+        #[schemars(with = "ApiResult<String>")]
+        result: ApiResultWithString<RouteId>,
+    },
+    UnpublishMailbox {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    GetRouteHealth {
+        #[serde(flatten)]
+        result: ApiResult<Option<Box<RouteHealth>>>,
+    },
+    ListRouteHealth {
+        #[serde(flatten)]
+        result: ApiResult<Vec<RouteHealth>>,
+    },
+    GetRoutingTableInfo {
+        #[serde(flatten)]
+        result: ApiResult<Box<RoutingTableInfo>>,
+    },
+    GetClosestPeers {
+        #[serde(flatten)]
+        result: ApiResult<Vec<RoutingTableEntry>>,
+    },
+    SetRouteRotationPolicy {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    RegisterAppChannel {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    UnregisterAppChannel {
+        #[serde(flatten)]
+        result: ApiResult<bool>,
+    },
+    SetRejectUnknownAppChannels {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
     AppCallReply {
         #[serde(flatten)]
         result: ApiResult<()>,
@@ -223,6 +315,10 @@ pub enum ResponseOp {
         #[serde(flatten)]
         result: ApiResult<String>,
     },
+    DebugRequest {
+        #[serde(flatten)]
+        result: ApiResult<Box<DebugResponse>>,
+    },
     VeilidVersionString {
         value: String,
     },
@@ -274,24 +274,48 @@ impl JsonRequestProcessor {
             RoutingContextRequestOp::Safety => RoutingContextResponseOp::Safety {
                 value: routing_context.safety(),
             },
-            RoutingContextRequestOp::AppCall { target, message } => {
-                RoutingContextResponseOp::AppCall {
-                    result: to_json_api_result_with_vec_u8(
-                        self.parse_target(target)
-                            .then(|tr| async { routing_context.app_call(tr?, message).await })
-                            .await,
-                    ),
-                }
-            }
-            RoutingContextRequestOp::AppMessage { target, message } => {
-                RoutingContextResponseOp::AppMessage {
-                    result: to_json_api_result(
-                        self.parse_target(target)
-                            .then(|tr| async { routing_context.app_message(tr?, message).await })
-                            .await,
-                    ),
-                }
-            }
+            RoutingContextRequestOp::AppCall {
+                target,
+                channel,
+                message,
+            } => RoutingContextResponseOp::AppCall {
+                result: to_json_api_result_with_vec_u8(
+                    self.parse_target(target)
+                        .then(|tr| async {
+                            let target = tr?;
+                            match channel {
+                                Some(channel) => {
+                                    routing_context
+                                        .app_call_on_channel(target, channel, message)
+                                        .await
+                                }
+                                None => routing_context.app_call(target, message).await,
+                            }
+                        })
+                        .await,
+                ),
+            },
+            RoutingContextRequestOp::AppMessage {
+                target,
+                channel,
+                message,
+            } => RoutingContextResponseOp::AppMessage {
+                result: to_json_api_result(
+                    self.parse_target(target)
+                        .then(|tr| async {
+                            let target = tr?;
+                            match channel {
+                                Some(channel) => {
+                                    routing_context
+                                        .app_message_on_channel(target, channel, message)
+                                        .await
+                                }
+                                None => routing_context.app_message(target, message).await,
+                            }
+                        })
+                        .await,
+                ),
+            },
             RoutingContextRequestOp::CreateDhtRecord { schema, kind } => {
                 RoutingContextResponseOp::CreateDhtRecord {
                     result: to_json_api_result(
@@ -337,14 +361,150 @@ impl JsonRequestProcessor {
                 key,
                 subkey,
                 data,
-                writer,
+                options,
             } => RoutingContextResponseOp::SetDhtValue {
                 result: to_json_api_result(
                     routing_context
-                        .set_dht_value(key, subkey, data, writer)
+                        .set_dht_value(key, subkey, data, options)
+                        .await,
+                ),
+            },
+            RoutingContextRequestOp::GetDhtValues {
+                key,
+                subkeys,
+                force_refresh,
+            } => RoutingContextResponseOp::GetDhtValues {
+                result: to_json_api_result(
+                    routing_context
+                        .get_dht_values(key, subkeys, force_refresh)
+                        .await,
+                ),
+            },
+            RoutingContextRequestOp::SetDhtValues {
+                key,
+                values,
+                writer,
+            } => RoutingContextResponseOp::SetDhtValues {
+                result: to_json_api_result(
+                    routing_context
+                        .set_dht_values(
+                            key,
+                            values
+                                .into_iter()
+                                .map(|(subkey, data)| (subkey, data.value))
+                                .collect(),
+                            writer,
+                        )
+                        .await,
+                ),
+            },
+            RoutingContextRequestOp::SetDhtLargeValue {
+                key,
+                subkey,
+                data,
+                writer,
+            } => RoutingContextResponseOp::SetDhtLargeValue {
+                result: to_json_api_result(
+                    routing_context
+                        .set_dht_large_value(key, subkey, data, writer)
+                        .await,
+                ),
+            },
+            RoutingContextRequestOp::GetDhtLargeValue {
+                key,
+                subkey,
+                force_refresh,
+            } => RoutingContextResponseOp::GetDhtLargeValue {
+                result: to_json_api_result(
+                    routing_context
+                        .get_dht_large_value(key, subkey, force_refresh)
+                        .await
+                        .map(|vopt| vopt.map(|v| VecU8 { value: v })),
+                ),
+            },
+            RoutingContextRequestOp::SetDhtRecordEncryptionKey {
+                key,
+                encryption_key,
+            } => RoutingContextResponseOp::SetDhtRecordEncryptionKey {
+                result: to_json_api_result(
+                    routing_context
+                        .set_dht_record_encryption_key(key, encryption_key)
+                        .await,
+                ),
+            },
+            RoutingContextRequestOp::ShareDhtRecordEncryptionKey {
+                key,
+                subkey,
+                members,
+            } => RoutingContextResponseOp::ShareDhtRecordEncryptionKey {
+                result: to_json_api_result_with_string(
+                    routing_context
+                        .share_dht_record_encryption_key(key, subkey, members)
+                        .await,
+                ),
+            },
+            RoutingContextRequestOp::ImportDhtRecordEncryptionKey { key, subkey } => {
+                RoutingContextResponseOp::ImportDhtRecordEncryptionKey {
+                    result: to_json_api_result_with_string(
+                        routing_context
+                            .import_dht_record_encryption_key(key, subkey)
+                            .await,
+                    ),
+                }
+            }
+            RoutingContextRequestOp::SetDhtRecordSuccessor {
+                key,
+                subkey,
+                successor,
+            } => RoutingContextResponseOp::SetDhtRecordSuccessor {
+                result: to_json_api_result(
+                    routing_context
+                        .set_dht_record_successor(key, subkey, successor)
+                        .await,
+                ),
+            },
+            RoutingContextRequestOp::GetDhtRecordSuccessor {
+                key,
+                subkey,
+                force_refresh,
+            } => RoutingContextResponseOp::GetDhtRecordSuccessor {
+                result: to_json_api_result_with_string(
+                    routing_context
+                        .get_dht_record_successor(key, subkey, force_refresh)
+                        .await,
+                ),
+            },
+            RoutingContextRequestOp::OpenDhtRecordFollowingSuccessors {
+                key,
+                subkey,
+                writer,
+            } => RoutingContextResponseOp::OpenDhtRecordFollowingSuccessors {
+                result: to_json_api_result(
+                    routing_context
+                        .open_dht_record_following_successors(key, subkey, writer)
+                        .await
+                        .map(Box::new),
+                ),
+            },
+            RoutingContextRequestOp::SetDhtValueHistoryLength {
+                key,
+                history_length,
+            } => RoutingContextResponseOp::SetDhtValueHistoryLength {
+                result: to_json_api_result(
+                    routing_context
+                        .set_dht_value_history_length(key, history_length)
                         .await,
                 ),
             },
+            RoutingContextRequestOp::GetDhtValueHistory { key, subkey, range } => {
+                RoutingContextResponseOp::GetDhtValueHistory {
+                    result: to_json_api_result(
+                        routing_context
+                            .get_dht_value_history(key, subkey, range)
+                            .await,
+                    ),
+                }
+            }
             RoutingContextRequestOp::WatchDhtValues {
                 key,
                 subkeys,
@@ -376,6 +536,42 @@ impl JsonRequestProcessor {
                         .map(Box::new),
                 ),
             },
+            RoutingContextRequestOp::ResolveMailbox {
+                key,
+                subkey,
+                force_refresh,
+            } => RoutingContextResponseOp::ResolveMailbox {
+                result: to_json_api_result_with_string(
+                    routing_context
+                        .resolve_mailbox(key, subkey, force_refresh)
+                        .await
+                        .and_then(|target| match target {
+                            Target::PrivateRoute(route_id) => Ok(route_id),
+                            Target::NodeId(_) => {
+                                Err(VeilidAPIError::internal("mailbox resolved to a node id"))
+                            }
+                        }),
+                ),
+            },
+            RoutingContextRequestOp::GetDhtOfflineWrites { key } => {
+                RoutingContextResponseOp::GetDhtOfflineWrites {
+                    result: to_json_api_result(routing_context.get_dht_offline_writes(key).await),
+                }
+            }
+            RoutingContextRequestOp::CancelDhtOfflineWrites { key, subkeys } => {
+                RoutingContextResponseOp::CancelDhtOfflineWrites {
+                    result: to_json_api_result(
+                        routing_context
+                            .cancel_dht_offline_writes(key, subkeys)
+                            .await,
+                    ),
+                }
+            }
+            RoutingContextRequestOp::FlushDhtOfflineWrites { key } => {
+                RoutingContextResponseOp::FlushDhtOfflineWrites {
+                    result: to_json_api_result(routing_context.flush_dht_offline_writes(key).await),
+                }
+            }
         };
         RoutingContextResponse {
             rc_id: rcr.rc_id,
@@ -644,6 +840,60 @@ impl JsonRequestProcessor {
             RequestOp::ReleasePrivateRoute { route_id } => ResponseOp::ReleasePrivateRoute {
                 result: to_json_api_result(self.api.release_private_route(route_id)),
             },
+            RequestOp::PublishMailbox {
+                key,
+                subkey,
+                stability,
+                sequencing,
+                rotation_policy,
+            } => ResponseOp::PublishMailbox {
+                result: to_json_api_result_with_string(
+                    self.api
+                        .publish_mailbox(key, subkey, stability, sequencing, rotation_policy)
+                        .await,
+                ),
+            },
+            RequestOp::UnpublishMailbox { key, subkey } => ResponseOp::UnpublishMailbox {
+                result: to_json_api_result(self.api.unpublish_mailbox(key, subkey)),
+            },
+            RequestOp::GetRouteHealth { route_id } => ResponseOp::GetRouteHealth {
+                result: to_json_api_result(
+                    self.api
+                        .get_route_health(route_id)
+                        .map(|opt_rh| opt_rh.map(Box::new)),
+                ),
+            },
+            RequestOp::ListRouteHealth => ResponseOp::ListRouteHealth {
+                result: to_json_api_result(self.api.list_route_health()),
+            },
+            RequestOp::GetRoutingTableInfo { min_state } => ResponseOp::GetRoutingTableInfo {
+                result: to_json_api_result(
+                    self.api.get_routing_table_info(min_state).map(Box::new),
+                ),
+            },
+            RequestOp::GetClosestPeers { key, count } => ResponseOp::GetClosestPeers {
+                result: to_json_api_result(self.api.get_closest_peers(key, count)),
+            },
+            RequestOp::SetRouteRotationPolicy { route_id, policy } => {
+                ResponseOp::SetRouteRotationPolicy {
+                    result: to_json_api_result(
+                        self.api.set_route_rotation_policy(route_id, policy),
+                    ),
+                }
+            }
+            RequestOp::RegisterAppChannel { channel, options } => ResponseOp::RegisterAppChannel {
+                result: to_json_api_result(self.api.register_app_channel(channel, options)),
+            },
+            RequestOp::UnregisterAppChannel { channel } => ResponseOp::UnregisterAppChannel {
+                result: to_json_api_result(self.api.unregister_app_channel(channel)),
+            },
+            RequestOp::SetRejectUnknownAppChannels { reject_unknown } => {
+                ResponseOp::SetRejectUnknownAppChannels {
+                    result: to_json_api_result(
+                        self.api.set_reject_unknown_app_channels(reject_unknown),
+                    ),
+                }
+            }
             RequestOp::AppCallReply { call_id, message } => ResponseOp::AppCallReply {
                 result: to_json_api_result(self.api.app_call_reply(call_id, message).await),
             },
@@ -821,6 +1071,9 @@ impl JsonRequestProcessor {
             RequestOp::Debug { command } => ResponseOp::Debug {
                 result: to_json_api_result(self.api.debug(command).await),
             },
+            RequestOp::DebugRequest { request } => ResponseOp::DebugRequest {
+                result: to_json_api_result(self.api.debug_request(request).await.map(Box::new)),
+            },
             RequestOp::VeilidVersionString => ResponseOp::VeilidVersionString {
                 value: veilid_version_string(),
             },
@@ -28,12 +28,18 @@ pub enum RoutingContextRequestOp {
     Safety,
     AppCall {
         target: String,
+        #[serde(default)]
+        #[schemars(with = "Option<String>")]
+        channel: Option<AppChannel>,
         #[serde(with = "as_human_base64")]
         #[schemars(with = "String")]
         message: Vec<u8>,
     },
     AppMessage {
         target: String,
+        #[serde(default)]
+        #[schemars(with = "Option<String>")]
+        channel: Option<AppChannel>,
         #[serde(with = "as_human_base64")]
         #[schemars(with = "String")]
         message: Vec<u8>,
@@ -70,9 +76,87 @@ pub enum RoutingContextRequestOp {
         #[serde(with = "as_human_base64")]
         #[schemars(with = "String")]
         data: Vec<u8>,
+        options: Option<SetDHTValueOptions>,
+    },
+    GetDhtValues {
+        #[schemars(with = "String")]
+        key: TypedKey,
+        subkeys: ValueSubkeyRangeSet,
+        force_refresh: bool,
+    },
+    SetDhtValues {
+        #[schemars(with = "String")]
+        key: TypedKey,
+        #[schemars(with = "Vec<(ValueSubkey, String)>")]
+        values: Vec<(ValueSubkey, VecU8)>,
         #[schemars(with = "Option<String>")]
         writer: Option<KeyPair>,
     },
+    SetDhtLargeValue {
+        #[schemars(with = "String")]
+        key: TypedKey,
+        subkey: ValueSubkey,
+        #[serde(with = "as_human_base64")]
+        #[schemars(with = "String")]
+        data: Vec<u8>,
+        #[schemars(with = "Option<String>")]
+        writer: Option<KeyPair>,
+    },
+    GetDhtLargeValue {
+        #[schemars(with = "String")]
+        key: TypedKey,
+        subkey: ValueSubkey,
+        force_refresh: bool,
+    },
+    SetDhtRecordEncryptionKey {
+        #[schemars(with = "String")]
+        key: TypedKey,
+        #[schemars(with = "Option<String>")]
+        encryption_key: Option<SharedSecret>,
+    },
+    ShareDhtRecordEncryptionKey {
+        #[schemars(with = "String")]
+        key: TypedKey,
+        subkey: ValueSubkey,
+        #[schemars(with = "Vec<String>")]
+        members: Vec<PublicKey>,
+    },
+    ImportDhtRecordEncryptionKey {
+        #[schemars(with = "String")]
+        key: TypedKey,
+        subkey: ValueSubkey,
+    },
+    SetDhtRecordSuccessor {
+        #[schemars(with = "String")]
+        key: TypedKey,
+        subkey: ValueSubkey,
+        #[schemars(with = "String")]
+        successor: TypedKey,
+    },
+    GetDhtRecordSuccessor {
+        #[schemars(with = "String")]
+        key: TypedKey,
+        subkey: ValueSubkey,
+        force_refresh: bool,
+    },
+    OpenDhtRecordFollowingSuccessors {
+        #[schemars(with = "String")]
+        key: TypedKey,
+        subkey: ValueSubkey,
+        #[schemars(with = "Option<String>")]
+        writer: Option<KeyPair>,
+    },
+    SetDhtValueHistoryLength {
+        #[schemars(with = "String")]
+        key: TypedKey,
+        history_length: u32,
+    },
+    GetDhtValueHistory {
+        #[schemars(with = "String")]
+        key: TypedKey,
+        subkey: ValueSubkey,
+        range: (ValueSeqNum, ValueSeqNum),
+    },
     WatchDhtValues {
         #[schemars(with = "String")]
         key: TypedKey,
@@ -91,6 +175,25 @@ pub enum RoutingContextRequestOp {
         subkeys: ValueSubkeyRangeSet,
         scope: DHTReportScope,
     },
+    ResolveMailbox {
+        #[schemars(with = "String")]
+        key: TypedKey,
+        subkey: ValueSubkey,
+        force_refresh: bool,
+    },
+    GetDhtOfflineWrites {
+        #[schemars(with = "String")]
+        key: TypedKey,
+    },
+    CancelDhtOfflineWrites {
+        #[schemars(with = "String")]
+        key: TypedKey,
+        subkeys: ValueSubkeyRangeSet,
+    },
+    FlushDhtOfflineWrites {
+        #[schemars(with = "String")]
+        key: TypedKey,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -145,6 +248,58 @@ pub enum RoutingContextResponseOp {
         #[serde(flatten)]
         result: ApiResult<Option<ValueData>>,
     },
+    GetDhtValues {
+        #[serde(flatten)]
+        result: ApiResult<Vec<(ValueSubkey, Option<ValueData>)>>,
+    },
+    SetDhtValues {
+        #[serde(flatten)]
+        result: ApiResult<Vec<(ValueSubkey, Option<ValueData>)>>,
+    },
+    SetDhtLargeValue {
+        #[serde(flatten)]
+        result: ApiResult<ValueSubkeyRangeSet>,
+    },
+    GetDhtLargeValue {
+        #[serde(flatten)]
+        #[schemars(with = "ApiResult<Option<String>>")]
+        result: ApiResult<Option<VecU8>>,
+    },
+    SetDhtRecordEncryptionKey {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    ShareDhtRecordEncryptionKey {
+        #[serde(flatten)]
+        #[schemars(with = "ApiResult<String>")]
+        result: ApiResultWithString<SharedSecret>,
+    },
+    ImportDhtRecordEncryptionKey {
+        #[serde(flatten)]
+        #[schemars(with = "ApiResult<String>")]
+        result: ApiResultWithString<SharedSecret>,
+    },
+    SetDhtRecordSuccessor {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    GetDhtRecordSuccessor {
+        #[serde(flatten)]
+        #[schemars(with = "ApiResult<Option<String>>")]
+        result: ApiResultWithString<Option<TypedKey>>,
+    },
+    OpenDhtRecordFollowingSuccessors {
+        #[serde(flatten)]
+        result: ApiResult<Box<DHTRecordDescriptor>>,
+    },
+    SetDhtValueHistoryLength {
+        #[serde(flatten)]
+        result: ApiResult<()>,
+    },
+    GetDhtValueHistory {
+        #[serde(flatten)]
+        result: ApiResult<Vec<ValueData>>,
+    },
     WatchDhtValues {
         #[serde(flatten)]
         result: ApiResult<Timestamp>,
@@ -157,4 +312,21 @@ pub enum RoutingContextResponseOp {
         #[serde(flatten)]
         result: ApiResult<Box<DHTRecordReport>>,
     },
+    ResolveMailbox {
+        #[serde(flatten)]
+        #[schemars(with = "ApiResult<String>")]
+        result: ApiResultWithString<RouteId>,
+    },
+    GetDhtOfflineWrites {
+        #[serde(flatten)]
+        result: ApiResult<ValueSubkeyRangeSet>,
+    },
+    CancelDhtOfflineWrites {
+        #[serde(flatten)]
+        result: ApiResult<ValueSubkeyRangeSet>,
+    },
+    FlushDhtOfflineWrites {
+        #[serde(flatten)]
+        result: ApiResult<ValueSubkeyRangeSet>,
+    },
 }
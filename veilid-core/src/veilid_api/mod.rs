@@ -22,14 +22,15 @@ pub use crypto::*;
 #[cfg(feature = "unstable-blockstore")]
 pub use intf::BlockStore;
 pub use intf::ProtectedStore;
-pub use table_store::{TableDB, TableDBTransaction, TableStore};
+pub use network_manager::{DialInfoClass, NetworkClass};
+pub use table_store::{ColumnInfo, IOStatsInfo, TableDB, TableDBTransaction, TableInfo, TableStore};
 
 use crate::*;
 use attachment_manager::AttachmentManager;
 use core::fmt;
 use core_context::{api_shutdown, VeilidCoreContext};
 use network_manager::NetworkManager;
-use routing_table::{DirectionSet, RouteSpecStore, RoutingTable};
+use routing_table::{DirectionSet, Mailbox, RouteSpecStore, RoutingTable};
 use rpc_processor::*;
 use storage_manager::StorageManager;
 
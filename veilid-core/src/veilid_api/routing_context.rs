@@ -166,13 +166,47 @@ impl RoutingContext {
         event!(target: "veilid_api", Level::DEBUG, 
             "RoutingContext::app_call(self: {:?}, target: {:?}, message: {:?})", self, target, message);
 
+        self.send_app_call(target, None, message).await
+    }
+
+    /// App-level bidirectional call on an application channel that expects a response to be returned.
+    ///
+    /// * `target` - can be either a direct node id or a private route.
+    /// * `channel` - the application channel to call on, see [VeilidAPI::register_app_channel()].
+    /// * `message` - an arbitrary message blob of up to 32768 bytes.
+    ///
+    /// Returns an answer blob of up to 32768 bytes.
+    /// If the target refuses the channel, or the message is larger than the channel allows,
+    /// this returns an InvalidTarget error as soon as the refusal is received.
+    #[instrument(target = "veilid_api", level = "debug", ret, err)]
+    pub async fn app_call_on_channel(
+        &self,
+        target: Target,
+        channel: AppChannel,
+        message: Vec<u8>,
+    ) -> VeilidAPIResult<Vec<u8>> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::app_call_on_channel(self: {:?}, target: {:?}, channel: {:?}, message: {:?})", self, target, channel, message);
+
+        self.send_app_call(target, Some(channel), message).await
+    }
+
+    async fn send_app_call(
+        &self,
+        target: Target,
+        channel: Option<AppChannel>,
+        message: Vec<u8>,
+    ) -> VeilidAPIResult<Vec<u8>> {
         let rpc_processor = self.api.rpc_processor()?;
 
         // Get destination
         let dest = self.get_destination(target).await?;
 
         // Send app message
-        let answer = match rpc_processor.rpc_call_app_call(dest, message).await {
+        let answer = match rpc_processor
+            .rpc_call_app_call(dest, channel, message)
+            .await
+        {
             Ok(NetworkResult::Value(v)) => v,
             Ok(NetworkResult::Timeout) => apibail_timeout!(),
             Ok(NetworkResult::ServiceUnavailable(e)) => apibail_invalid_target!(e),
@@ -200,13 +234,47 @@ impl RoutingContext {
         event!(target: "veilid_api", Level::DEBUG, 
             "RoutingContext::app_message(self: {:?}, target: {:?}, message: {:?})", self, target, message);
 
+        self.send_app_message(target, None, message).await
+    }
+
+    /// App-level unidirectional message on an application channel that does not expect any value to be returned.
+    ///
+    /// * `target` - can be either a direct node id or a private route.
+    /// * `channel` - the application channel to send on, see [VeilidAPI::register_app_channel()].
+    /// * `message` - an arbitrary message blob of up to 32768 bytes.
+    ///
+    /// If the target refuses the channel, or the message is larger than the channel allows,
+    /// the message is silently dropped by the target. Use [RoutingContext::app_call_on_channel()]
+    /// to find out if a channel is refused.
+    #[instrument(target = "veilid_api", level = "debug", ret, err)]
+    pub async fn app_message_on_channel(
+        &self,
+        target: Target,
+        channel: AppChannel,
+        message: Vec<u8>,
+    ) -> VeilidAPIResult<()> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::app_message_on_channel(self: {:?}, target: {:?}, channel: {:?}, message: {:?})", self, target, channel, message);
+
+        self.send_app_message(target, Some(channel), message).await
+    }
+
+    async fn send_app_message(
+        &self,
+        target: Target,
+        channel: Option<AppChannel>,
+        message: Vec<u8>,
+    ) -> VeilidAPIResult<()> {
         let rpc_processor = self.api.rpc_processor()?;
 
         // Get destination
         let dest = self.get_destination(target).await?;
 
         // Send app message
-        match rpc_processor.rpc_call_app_message(dest, message).await {
+        match rpc_processor
+            .rpc_call_app_message(dest, channel, message)
+            .await
+        {
             Ok(NetworkResult::Value(())) => {}
             Ok(NetworkResult::Timeout) => apibail_timeout!(),
             Ok(NetworkResult::ServiceUnavailable(e)) => apibail_invalid_target!(e),
@@ -252,7 +320,7 @@ impl RoutingContext {
     /// Opens a DHT record at a specific key.
     ///
     /// Associates a 'default_writer' secret if one is provided to provide writer capability. The
-    /// writer can be overridden if specified here via the set_dht_value writer.
+    /// writer can be overridden if specified here via the set_dht_value options.
     ///
     /// Records may only be opened or created. If a record is re-opened it will use the new writer and routing context
     /// ignoring the settings of the last time it was opened. This allows one to open a record a second time
@@ -325,27 +393,366 @@ impl RoutingContext {
         storage_manager.get_value(key, subkey, force_refresh).await
     }
 
+    /// Resolves a mailbox published with [VeilidAPI::publish_mailbox()] to a [Target].
+    /// The DHT record must first by opened via open_dht_record.
+    ///
+    /// The private route blob in the subkey is imported automatically.
+    /// By setting 'force_refresh' you can force a network data refresh, which is useful
+    /// when sending to a previously resolved mailbox target has started failing.
+    #[instrument(target = "veilid_api", level = "debug", ret, err)]
+    pub async fn resolve_mailbox(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        force_refresh: bool,
+    ) -> VeilidAPIResult<Target> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::resolve_mailbox(self: {:?}, key: {:?}, subkey: {:?}, force_refresh: {:?})", self, key, subkey, force_refresh);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+        let storage_manager = self.api.storage_manager()?;
+        let Some(value) = storage_manager
+            .get_value(key, subkey, force_refresh)
+            .await?
+        else {
+            apibail_key_not_found!(key);
+        };
+        let route_id = self
+            .api
+            .import_remote_private_route(value.data().to_vec())?;
+        Ok(Target::PrivateRoute(route_id))
+    }
+
     /// Pushes a changed subkey value to the network.
     /// The DHT record must first by opened via open_dht_record or create_dht_record.
     ///
-    /// The writer, if specified, will override the 'default_writer' specified when the record is opened.
+    /// The writer, if specified in the options, will override the 'default_writer' specified when the record is opened.
+    /// If an 'expected_seq' is specified in the options, the value is only written if the subkey is still at that sequence number.
     ///
     /// Returns `None` if the value was successfully put.
     /// Returns `Some(data)` if the value put was older than the one available on the network.
+    /// Returns a [VeilidAPIError::Conflict] carrying the winning value if an 'expected_seq' was specified and the subkey had moved on.
+    /// Returns a [VeilidAPIError::TryAgain] if an 'expected_seq' was specified and the value could not be written to the network,
+    /// as compare-and-set writes are never queued for writing later like other offline writes.
     #[instrument(target = "veilid_api", level = "debug", skip(data), fields(data = print_data(&data, Some(64))), ret, err)]
     pub async fn set_dht_value(
         &self,
         key: TypedKey,
         subkey: ValueSubkey,
         data: Vec<u8>,
-        writer: Option<KeyPair>,
+        options: Option<SetDHTValueOptions>,
     ) -> VeilidAPIResult<Option<ValueData>> {
         event!(target: "veilid_api", Level::DEBUG, 
-            "RoutingContext::set_dht_value(self: {:?}, key: {:?}, subkey: {:?}, data: len={}, writer: {:?})", self, key, subkey, data.len(), writer);
+            "RoutingContext::set_dht_value(self: {:?}, key: {:?}, subkey: {:?}, data: len={}, options: {:?})", self, key, subkey, data.len(), options);
 
         Crypto::validate_crypto_kind(key.kind)?;
         let storage_manager = self.api.storage_manager()?;
-        storage_manager.set_value(key, subkey, data, writer).await
+        storage_manager.set_value(key, subkey, data, options).await
+    }
+
+    /// Gets the latest values of several subkeys at once.
+    /// The DHT record must first by opened via open_dht_record or create_dht_record.
+    ///
+    /// Subkeys are requested from each node in batches rather than one at a time.
+    /// May pull the latest values from the network, but by setting 'force_refresh' you can force a network data refresh.
+    /// If no subkeys are specified, this is equivalent to getting the entire range of subkeys in the schema.
+    ///
+    /// Returns each in-schema subkey requested in ascending order, with `None` if the subkey has not yet been set
+    /// or `Some(data)` if the subkey has valid data.
+    #[instrument(target = "veilid_api", level = "debug", ret, err)]
+    pub async fn get_dht_values(
+        &self,
+        key: TypedKey,
+        subkeys: ValueSubkeyRangeSet,
+        force_refresh: bool,
+    ) -> VeilidAPIResult<Vec<(ValueSubkey, Option<ValueData>)>> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::get_dht_values(self: {:?}, key: {:?}, subkeys: {:?}, force_refresh: {:?})", self, key, subkeys, force_refresh);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+        let storage_manager = self.api.storage_manager()?;
+        storage_manager
+            .get_values(key, subkeys, force_refresh)
+            .await
+    }
+
+    /// Pushes several changed subkey values to the network at once.
+    /// The DHT record must first by opened via open_dht_record or create_dht_record.
+    ///
+    /// Values are sent to each node in batches rather than one at a time. Each subkey may only appear once.
+    /// The writer, if specified, will override the 'default_writer' specified when the record is opened.
+    ///
+    /// Returns each subkey in ascending order, with `None` if the value was successfully put
+    /// or `Some(data)` if the value put was older than the one available on the network.
+    #[instrument(target = "veilid_api", level = "debug", skip(values), fields(values.len = values.len()), ret, err)]
+    pub async fn set_dht_values(
+        &self,
+        key: TypedKey,
+        values: Vec<(ValueSubkey, Vec<u8>)>,
+        writer: Option<KeyPair>,
+    ) -> VeilidAPIResult<Vec<(ValueSubkey, Option<ValueData>)>> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::set_dht_values(self: {:?}, key: {:?}, values: count={}, writer: {:?})", self, key, values.len(), writer);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+        let storage_manager = self.api.storage_manager()?;
+        storage_manager.set_values(key, values, writer).await
+    }
+
+    /// Pushes a value that may be larger than a single subkey to the network.
+    /// The DHT record must first by opened via open_dht_record or create_dht_record.
+    ///
+    /// The value is split into chunks written to the subkeys following 'subkey', all of which must be writable,
+    /// and a manifest holding the length and hash of the value is written to 'subkey' itself.
+    /// The writer, if specified, will override the 'default_writer' specified when the record is opened.
+    ///
+    /// Returns the range of subkeys used, including the manifest subkey.
+    /// Returns a TryAgain error if newer values were found on the network for any of those subkeys.
+    #[instrument(target = "veilid_api", level = "debug", skip(data), fields(data.len = data.len()), ret, err)]
+    pub async fn set_dht_large_value(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        data: Vec<u8>,
+        writer: Option<KeyPair>,
+    ) -> VeilidAPIResult<ValueSubkeyRangeSet> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::set_dht_large_value(self: {:?}, key: {:?}, subkey: {:?}, data: len={}, writer: {:?})", self, key, subkey, data.len(), writer);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+        let storage_manager = self.api.storage_manager()?;
+        storage_manager
+            .set_large_value(key, subkey, data, writer)
+            .await
+    }
+
+    /// Gets a value written with set_dht_large_value.
+    /// The DHT record must first by opened via open_dht_record or create_dht_record.
+    ///
+    /// The chunks are reassembled and verified against the length and hash in the manifest at 'subkey'.
+    /// May pull the latest values from the network, but by setting 'force_refresh' you can force a network data refresh.
+    ///
+    /// Returns `None` if the manifest subkey has not been set, or `Some(data)` if the value was reassembled.
+    /// Returns a TryAgain error if the chunks do not match the manifest, such as while a write is in progress.
+    #[instrument(target = "veilid_api", level = "debug", ret, err)]
+    pub async fn get_dht_large_value(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        force_refresh: bool,
+    ) -> VeilidAPIResult<Option<Vec<u8>>> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::get_dht_large_value(self: {:?}, key: {:?}, subkey: {:?}, force_refresh: {:?})", self, key, subkey, force_refresh);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+        let storage_manager = self.api.storage_manager()?;
+        storage_manager
+            .get_large_value(key, subkey, force_refresh)
+            .await
+    }
+
+    /// Seal the values of a DHT record with a symmetric key, or stop sealing them.
+    ///
+    /// Sealed values are encrypted before they are signed, so the nodes storing the record only ever see ciphertext.
+    /// Once a key is set, get_dht_value and set_dht_value and their batched and large value forms seal and open values transparently.
+    /// The key is kept locally with the record. Values already written are not resealed.
+    ///
+    /// * `key` is the record key to seal values for. It must first be opened.
+    /// * `encryption_key` is the key to seal values with, or None to stop sealing them.
+    #[instrument(target = "veilid_api", level = "debug", skip(encryption_key), ret, err)]
+    pub async fn set_dht_record_encryption_key(
+        &self,
+        key: TypedKey,
+        encryption_key: Option<SharedSecret>,
+    ) -> VeilidAPIResult<()> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::set_dht_record_encryption_key(self: {:?}, key: {:?})", self, key);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+        let storage_manager = self.api.storage_manager()?;
+        storage_manager.set_record_encryption_key(key, encryption_key).await
+    }
+
+    /// Share the encryption key of a DHT record with members of the record, generating a key if the record does not have one yet.
+    ///
+    /// The key is wrapped for each member with a key exchange between the writer the record was opened with and the member,
+    /// and the wrapped keys are written unsealed to a subkey the writer can write to. Members then use import_dht_record_encryption_key.
+    /// Sharing again with a different set of members replaces the previous share.
+    ///
+    /// * `key` is the record key to share the encryption key of. It must first be opened with a writer.
+    /// * `subkey` is the subkey to write the wrapped keys to.
+    /// * `members` are the public keys of the members to share the encryption key with. The writer itself is always included.
+    ///
+    /// Returns the encryption key.
+    #[instrument(target = "veilid_api", level = "debug", err)]
+    pub async fn share_dht_record_encryption_key(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        members: Vec<PublicKey>,
+    ) -> VeilidAPIResult<SharedSecret> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::share_dht_record_encryption_key(self: {:?}, key: {:?}, subkey: {:?}, members: {:?})", self, key, subkey, members);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+        let storage_manager = self.api.storage_manager()?;
+        storage_manager.share_record_encryption_key(key, subkey, members).await
+    }
+
+    /// Get the encryption key of a DHT record from the wrapped keys shared in a subkey, and seal the record's values with it.
+    ///
+    /// * `key` is the record key to get the encryption key of. It must first be opened with the writer the key was shared with.
+    /// * `subkey` is the subkey the wrapped keys were written to with share_dht_record_encryption_key.
+    ///
+    /// Returns the encryption key.
+    #[instrument(target = "veilid_api", level = "debug", err)]
+    pub async fn import_dht_record_encryption_key(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+    ) -> VeilidAPIResult<SharedSecret> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::import_dht_record_encryption_key(self: {:?}, key: {:?}, subkey: {:?})", self, key, subkey);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+        let storage_manager = self.api.storage_manager()?;
+        storage_manager.import_record_encryption_key(key, subkey).await
+    }
+
+    /// Publish a successor for a DHT record, pointing to the record that replaces it and to the owner of that record.
+    ///
+    /// The successor is signed by the owner of the record being replaced, so ownership of a long-lived record
+    /// can be moved to a new record with a new owner key. It is written unsealed so it can be followed without
+    /// the record's encryption key.
+    ///
+    /// A successor can only be published once through this API; publishing a different successor fails and
+    /// publishing the same one again does nothing. This does not protect against a compromised owner key:
+    /// anyone holding the owner secret of the record can overwrite the successor subkey with a higher sequence
+    /// number and redirect the record to a successor of their choosing.
+    ///
+    /// * `key` is the record key to publish a successor for. It must first be opened with its owner as the writer.
+    /// * `subkey` is the subkey to write the successor to.
+    /// * `successor` is the record key of the successor. It must first be created or opened so its owner is known.
+    #[instrument(target = "veilid_api", level = "debug", ret, err)]
+    pub async fn set_dht_record_successor(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        successor: TypedKey,
+    ) -> VeilidAPIResult<()> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::set_dht_record_successor(self: {:?}, key: {:?}, subkey: {:?}, successor: {:?})", self, key, subkey, successor);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+        Crypto::validate_crypto_kind(successor.kind)?;
+        let storage_manager = self.api.storage_manager()?;
+        storage_manager
+            .set_record_successor(key, subkey, successor)
+            .await
+    }
+
+    /// Get the successor published for a DHT record with set_dht_record_successor, validated against the owner of the record.
+    ///
+    /// * `key` is the record key to get the successor of. It must first be opened.
+    /// * `subkey` is the subkey the successor was written to.
+    /// * `force_refresh` will force the successor to be fetched from the network instead of the local store.
+    ///
+    /// Returns None if no successor has been published, or if the subkey holds something other than a successor.
+    /// Returns an error if the successor was not signed by the owner of the record. A successor signed with a
+    /// compromised owner key validates, so the owner key must be trusted for as long as the successor is followed.
+    #[instrument(target = "veilid_api", level = "debug", ret, err)]
+    pub async fn get_dht_record_successor(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        force_refresh: bool,
+    ) -> VeilidAPIResult<Option<TypedKey>> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::get_dht_record_successor(self: {:?}, key: {:?}, subkey: {:?}, force_refresh: {:?})", self, key, subkey, force_refresh);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+        let storage_manager = self.api.storage_manager()?;
+        Ok(storage_manager
+            .get_record_successor(key, subkey, force_refresh)
+            .await?
+            .map(|successor| *successor.successor()))
+    }
+
+    /// Opens a DHT record at a specific key and follows the chain of successors published to a subkey of each record.
+    ///
+    /// Each successor must be owned by the owner named in the successor that points to it. Records along the chain
+    /// that were not already open are closed once they have been followed, and at most 16 successors are followed.
+    ///
+    /// * `key` is the record key to start from.
+    /// * `subkey` is the subkey each record in the chain publishes its successor to.
+    /// * `default_writer` is the writer to open each record with, as with open_dht_record.
+    ///
+    /// Returns the DHT record descriptor for the last record in the chain, which is left open.
+    #[instrument(target = "veilid_api", level = "debug", ret, err)]
+    pub async fn open_dht_record_following_successors(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        default_writer: Option<KeyPair>,
+    ) -> VeilidAPIResult<DHTRecordDescriptor> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::open_dht_record_following_successors(self: {:?}, key: {:?}, subkey: {:?}, default_writer: {:?})", self, key, subkey, default_writer);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+        let storage_manager = self.api.storage_manager()?;
+        storage_manager
+            .open_record_following_successors(
+                key,
+                subkey,
+                default_writer,
+                self.unlocked_inner.safety_selection,
+            )
+            .await
+    }
+
+    /// Keep prior values of the subkeys of a DHT record in the local table store.
+    ///
+    /// History is kept only locally and only for values that pass through this node after it is enabled,
+    /// whether written here or fetched from the network. The setting persists with the record.
+    ///
+    /// * `key` is the record key to keep history for. It must first be opened.
+    /// * `history_length` is the number of prior values to keep for each subkey. Zero turns history off and drops what was kept.
+    #[instrument(target = "veilid_api", level = "debug", ret, err)]
+    pub async fn set_dht_value_history_length(
+        &self,
+        key: TypedKey,
+        history_length: u32,
+    ) -> VeilidAPIResult<()> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::set_dht_value_history_length(self: {:?}, key: {:?}, history_length: {:?})", self, key, history_length);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+        let storage_manager = self.api.storage_manager()?;
+        storage_manager
+            .set_value_history_length(key, history_length)
+            .await
+    }
+
+    /// Get the values a subkey of a DHT record has held, from the history kept locally.
+    ///
+    /// * `key` is the record key to get the history of. It must first be opened.
+    /// * `subkey` is the subkey to get the history of.
+    /// * `range` is the inclusive range of sequence numbers to return values for.
+    ///
+    /// Returns the current value and the prior values kept in the history that fall within the range, oldest first.
+    #[instrument(target = "veilid_api", level = "debug", ret, err)]
+    pub async fn get_dht_value_history(
+        &self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        range: (ValueSeqNum, ValueSeqNum),
+    ) -> VeilidAPIResult<Vec<ValueData>> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::get_dht_value_history(self: {:?}, key: {:?}, subkey: {:?}, range: {:?})", self, key, subkey, range);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+        let storage_manager = self.api.storage_manager()?;
+        storage_manager.get_value_history(key, subkey, range).await
     }
 
     /// Add or update a watch to a DHT value that informs the user via an VeilidUpdate::ValueChange callback when the record has subkeys change.
@@ -463,6 +870,67 @@ impl RoutingContext {
         storage_manager.inspect_record(key, subkeys, scope).await
     }
 
+    /// Get the subkeys of a DHT record that were set while offline and have not yet been written to the network.
+    ///
+    /// Queued writes are retried in the background, and a VeilidUpdate::OfflineWrite is sent for each one when it is
+    /// finally accepted by the network or superseded by a newer value.
+    ///
+    /// * `key` is the record key to check.
+    ///
+    /// Returns the set of subkeys still waiting to be written.
+    #[instrument(target = "veilid_api", level = "debug", ret, err)]
+    pub async fn get_dht_offline_writes(
+        &self,
+        key: TypedKey,
+    ) -> VeilidAPIResult<ValueSubkeyRangeSet> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::get_dht_offline_writes(self: {:?}, key: {:?})", self, key);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+        let storage_manager = self.api.storage_manager()?;
+        storage_manager.get_offline_subkey_writes(key).await
+    }
+
+    /// Stop writing queued offline subkeys of a DHT record to the network.
+    ///
+    /// The locally stored values are kept. A write that is already in progress may still complete.
+    ///
+    /// * `key` is the record key to cancel queued writes for.
+    /// * `subkeys` is the the range of subkeys to cancel. If no range is specified, all queued subkeys are cancelled.
+    ///
+    /// Returns the set of subkeys still waiting to be written.
+    #[instrument(target = "veilid_api", level = "debug", ret, err)]
+    pub async fn cancel_dht_offline_writes(
+        &self,
+        key: TypedKey,
+        subkeys: ValueSubkeyRangeSet,
+    ) -> VeilidAPIResult<ValueSubkeyRangeSet> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::cancel_dht_offline_writes(self: {:?}, key: {:?}, subkeys: {:?})", self, key, subkeys);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+        let storage_manager = self.api.storage_manager()?;
+        storage_manager.cancel_offline_subkey_writes(key, subkeys).await
+    }
+
+    /// Write the queued offline subkeys of a DHT record to the network now rather than waiting for the next background retry.
+    ///
+    /// * `key` is the record key to flush queued writes for.
+    ///
+    /// Returns the set of subkeys still waiting to be written, or VeilidAPIError::TryAgain if the network is not available.
+    #[instrument(target = "veilid_api", level = "debug", ret, err)]
+    pub async fn flush_dht_offline_writes(
+        &self,
+        key: TypedKey,
+    ) -> VeilidAPIResult<ValueSubkeyRangeSet> {
+        event!(target: "veilid_api", Level::DEBUG, 
+            "RoutingContext::flush_dht_offline_writes(self: {:?}, key: {:?})", self, key);
+
+        Crypto::validate_crypto_kind(key.kind)?;
+        let storage_manager = self.api.storage_manager()?;
+        storage_manager.flush_offline_subkey_writes(key).await
+    }
+
     ///////////////////////////////////
     /// Block Store
 
@@ -36,6 +36,9 @@ pub fn fix_rpcstats() -> RPCStats {
         first_consecutive_seen_ts: Some(Timestamp::from(1685569111851)),
         recent_lost_answers: 5,
         failed_to_send: 3,
+        questions_sent: 900_000,
+        answers_rcvd: 800_000,
+        lost_answers: 17,
     }
 }
 
@@ -45,6 +48,17 @@ pub fn fix_peerstats() -> PeerStats {
         rpc_stats: fix_rpcstats(),
         latency: Some(fix_latencystats()),
         transfer: fix_transferstatsdownup(),
+        rate_limits: vec![fix_ratelimitstats()],
+    }
+}
+
+pub fn fix_ratelimitstats() -> RateLimitStats {
+    RateLimitStats {
+        operation: "SetValueQ".to_string(),
+        capability: FourCC::from_str("DHTV").unwrap(),
+        available: 100,
+        burst: 128,
+        limited: 7,
     }
 }
 
@@ -109,6 +123,8 @@ pub fn fix_veilidconfiginner() -> VeilidConfigInner {
                 node_id: TypedKeyGroup::new(),
                 node_id_secret: TypedSecretGroup::new(),
                 bootstrap: vec!["boots".to_string()],
+                bootstrap_keys: vec![fix_typedkey()],
+                bootstrap_allow_unsigned: false,
                 limit_over_attached: 1,
                 limit_fully_attached: 2,
                 limit_attached_strong: 3,
@@ -118,6 +134,22 @@ pub fn fix_veilidconfiginner() -> VeilidConfigInner {
             rpc: VeilidConfigRPC {
                 concurrency: 5,
                 queue_size: 6,
+                queues: VeilidConfigRPCQueues {
+                    answer_queue_size: 11,
+                    status_queue_size: 12,
+                    dht_queue_size: 13,
+                    routed_queue_size: 14,
+                    max_queued_per_sender: 15,
+                },
+                rate_limits: VeilidConfigRPCRateLimits {
+                    enabled: false,
+                    punish_violation_count: 16,
+                    dht: VeilidConfigRPCRateLimit::new(17, 18),
+                    dht_watch: VeilidConfigRPCRateLimit::new(19, 20),
+                    app_message: VeilidConfigRPCRateLimit::new(21, 22),
+                    route: VeilidConfigRPCRateLimit::new(23, 24),
+                    signal: VeilidConfigRPCRateLimit::new(25, 26),
+                },
                 max_timestamp_behind_ms: Some(1000),
                 max_timestamp_ahead_ms: Some(2000),
                 timeout_ms: 3000,
@@ -151,6 +183,18 @@ pub fn fix_veilidconfiginner() -> VeilidConfigInner {
             upnp: true,
             detect_address_changes: false,
             restricted_nat_retries: 10000,
+            lan_discovery: VeilidConfigLANDiscovery {
+                enabled: true,
+                interval_ms: 10000,
+                port: 5149,
+                multicast_group_v4: "239.255.86.76".to_string(),
+                multicast_group_v6: "ff02::7665:696c".to_string(),
+                max_beacons_per_min: 12,
+            },
+            bootstrap_server: VeilidConfigBootstrapServer {
+                enabled: true,
+                max_peers_per_type: 2,
+            },
             tls: VeilidConfigTLS {
                 certificate_path: "/etc/ssl/certs/cert.pem".to_string(),
                 private_key_path: "/etc/ssl/keys/key.pem".to_string(),
@@ -205,6 +249,17 @@ pub fn fix_veilidconfiginner() -> VeilidConfigInner {
     }
 }
 
+pub fn fix_veilidofflinewrite() -> VeilidOfflineWrite {
+    VeilidOfflineWrite {
+        key: fix_typedkey(),
+        subkey: 3,
+        value: ValueData::new_with_seq(4, b"Queued".to_vec(), fix_cryptokey()).unwrap(),
+        superseded_by: Some(
+            ValueData::new_with_seq(4, b"Newer".to_vec(), fix_cryptokey()).unwrap(),
+        ),
+    }
+}
+
 pub fn fix_veilidvaluechange() -> VeilidValueChange {
     VeilidValueChange {
         key: fix_typedkey(),
@@ -17,6 +17,11 @@ pub async fn test_all() {
     test_transferstatsdownup().await;
     test_rpcstats().await;
     test_peerstats().await;
+    test_routehealth().await;
+    test_routerotationpolicy().await;
+    test_debugrequest().await;
+    test_debugresponse().await;
+    test_routingtableinfo().await;
     #[cfg(feature = "unstable-tunnels")]
     test_tunnelmode().await;
     #[cfg(feature = "unstable-tunnels")]
@@ -36,6 +41,7 @@ pub async fn test_all() {
     test_veilidroutechange().await;
     test_veilidstateconfig().await;
     test_veilidvaluechange().await;
+    test_veilidofflinewrite().await;
     test_veilidupdate().await;
     test_veilidstate().await;
     // test_types_dht
@@ -16,6 +16,7 @@ pub async fn test_veilidappmessage() {
     let orig = VeilidAppMessage::new(
         Some(fix_typedkey()),
         Some(fix_cryptokey()),
+        Some(AppChannel::from_str("CHAT").unwrap()),
         b"Hi there!".to_vec(),
     );
     let copy = deserialize_json(&serialize_json(&orig)).unwrap();
@@ -27,6 +28,7 @@ pub async fn test_veilidappcall() {
     let orig = VeilidAppCall::new(
         Some(fix_typedkey()),
         Some(fix_cryptokey()),
+        None,
         b"Well, hello!".to_vec(),
         OperationId::from(123),
     );
@@ -116,6 +118,164 @@ pub async fn test_peerstats() {
     assert_eq!(orig, copy);
 }
 
+pub async fn test_routehealth() {
+    let orig = RouteHealth {
+        route_id: fix_cryptokey(),
+        remote: false,
+        published: true,
+        age: TimestampDuration::new(1_000_000),
+        latency: fix_latencystats(),
+        transfer: fix_transferstatsdownup(),
+        successes: 10,
+        failures: 2,
+        recent_failures: 1,
+        last_tested_ts: Some(Timestamp::new(1234567890)),
+        hop_count: 2,
+        hop_ipblock_count: Some(2),
+        stability: Some(Stability::Reliable),
+        can_do_sequenced: Some(false),
+    };
+    let copy = deserialize_json(&serialize_json(&orig)).unwrap();
+
+    assert_eq!(orig, copy);
+}
+
+pub async fn test_routerotationpolicy() {
+    let orig = RouteRotationPolicy {
+        max_age_ms: Some(3_600_000),
+        max_recent_failures: Some(2),
+        publish_key: Some(fix_typedkey()),
+        publish_subkey: 3,
+    };
+    let copy = deserialize_json(&serialize_json(&orig)).unwrap();
+
+    assert_eq!(orig, copy);
+}
+
+pub async fn test_debugrequest() {
+    let orig = DebugRequest::Entries {
+        min_state: RoutingTableEntryState::Reliable,
+        capabilities: vec![FourCC::from_str("ROUT").unwrap()],
+    };
+    let copy = deserialize_json(&serialize_json(&orig)).unwrap();
+
+    assert_eq!(orig, copy);
+
+    // Omitted filters fall back to their defaults
+    let copy: DebugRequest = deserialize_json(r#"{"kind":"Buckets"}"#).unwrap();
+    assert_eq!(
+        copy,
+        DebugRequest::Buckets {
+            min_state: RoutingTableEntryState::Unreliable
+        }
+    );
+}
+
+pub async fn test_debugresponse() {
+    let orig = DebugResponse::Entries {
+        entry_count: 1,
+        buckets: vec![DebugBucketEntries {
+            kind: CRYPTO_KIND_VLD0,
+            index: 3,
+            entries: vec![RoutingTableEntry {
+                node_ids: vec![fix_typedkey()],
+                state: RoutingTableEntryState::Reliable,
+                state_reason: "RELIBL".to_string(),
+                is_relay: false,
+                public_internet: Some(RoutingTableNodeInfo {
+                    network_class: NetworkClass::InboundCapable,
+                    capabilities: vec![FourCC::from_str("ROUT").unwrap()],
+                    dial_info: vec![],
+                    relay_ids: vec![],
+                }),
+                local_network: None,
+                peer_stats: fix_peerstats(),
+            }],
+        }],
+    };
+    let copy = deserialize_json(&serialize_json(&orig)).unwrap();
+
+    assert_eq!(orig, copy);
+
+    let orig = DebugResponse::PunishList {
+        punishments: vec![DebugPunishment {
+            target: "1.2.3.4".to_string(),
+            reason: "ShortPacket".to_string(),
+            timestamp: Timestamp::new(1234567890),
+        }],
+    };
+    let copy = deserialize_json(&serialize_json(&orig)).unwrap();
+
+    assert_eq!(orig, copy);
+
+    let orig = DebugResponse::RoutePublish {
+        route_id: fix_cryptokey(),
+        blob: b"Hi there!".to_vec(),
+    };
+    let copy = deserialize_json(&serialize_json(&orig)).unwrap();
+
+    assert_eq!(orig, copy);
+
+    let orig = DebugResponse::CommandSchema {
+        commands: vec![DebugCommand {
+            words: vec!["route".to_string(), "publish".to_string()],
+            args: vec![
+                DebugCommandArg {
+                    name: "route".to_string(),
+                    kind: DebugArgKind::RouteId,
+                    optional: false,
+                },
+                DebugCommandArg {
+                    name: "full".to_string(),
+                    kind: DebugArgKind::Choice {
+                        values: vec!["full".to_string()],
+                    },
+                    optional: true,
+                },
+            ],
+            description: "Publish a route".to_string(),
+        }],
+    };
+    let copy = deserialize_json(&serialize_json(&orig)).unwrap();
+
+    assert_eq!(orig, copy);
+    if let DebugResponse::CommandSchema { commands } = copy {
+        assert_eq!(commands[0].usage(), "route publish <route> [full]");
+    }
+}
+
+pub async fn test_routingtableinfo() {
+    let orig = RoutingTableInfo {
+        node_ids: vec![fix_typedkey()],
+        public_internet_relay: vec![],
+        local_network_relay: vec![fix_typedkey()],
+        buckets: vec![DebugBucketCounts {
+            kind: CRYPTO_KIND_VLD0,
+            counts: vec![0, 1, 2],
+        }],
+        entries: vec![RoutingTableEntry {
+            node_ids: vec![fix_typedkey()],
+            state: RoutingTableEntryState::Unreliable,
+            state_reason: "UNSEEN".to_string(),
+            is_relay: true,
+            public_internet: Some(RoutingTableNodeInfo {
+                network_class: NetworkClass::InboundCapable,
+                capabilities: vec![FourCC::from_str("RLAY").unwrap()],
+                dial_info: vec![RoutingTableDialInfo {
+                    class: DialInfoClass::Direct,
+                    dial_info: "udp|1.2.3.4:5150".to_string(),
+                }],
+                relay_ids: vec![],
+            }),
+            local_network: None,
+            peer_stats: fix_peerstats(),
+        }],
+    };
+    let copy = deserialize_json(&serialize_json(&orig)).unwrap();
+
+    assert_eq!(orig, copy);
+}
+
 //  tunnel
 
 #[cfg(feature = "unstable-tunnels")]
@@ -242,6 +402,11 @@ pub async fn test_veilidroutechange() {
     let orig = VeilidRouteChange {
         dead_routes: vec![fix_cryptokey()],
         dead_remote_routes: vec![fix_cryptokey()],
+        rotated_routes: vec![VeilidRouteRotation {
+            old_route_id: fix_cryptokey(),
+            new_route_id: fix_cryptokey(),
+            blob: b"Hi".to_vec(),
+        }],
     };
     let copy = deserialize_json(&serialize_json(&orig)).unwrap();
 
@@ -264,11 +429,23 @@ pub async fn test_veilidvaluechange() {
     assert_eq!(orig, copy);
 }
 
+pub async fn test_veilidofflinewrite() {
+    let orig = fix_veilidofflinewrite();
+    let copy = deserialize_json(&serialize_json(&orig)).unwrap();
+
+    assert_eq!(orig, copy);
+}
+
 pub async fn test_veilidupdate() {
     let orig = VeilidUpdate::ValueChange(Box::new(fix_veilidvaluechange()));
     let copy = deserialize_json(&serialize_json(&orig)).unwrap();
 
     assert_eq!(orig, copy);
+
+    let orig = VeilidUpdate::OfflineWrite(Box::new(fix_veilidofflinewrite()));
+    let copy = deserialize_json(&serialize_json(&orig)).unwrap();
+
+    assert_eq!(orig, copy);
 }
 
 pub async fn test_veilidstate() {
@@ -0,0 +1,95 @@
+use super::*;
+
+/// Maximum length of an application channel identifier in bytes.
+pub const MAX_APP_CHANNEL_LENGTH: usize = 32;
+
+/// Application channel identifier for app_message and app_call traffic.
+///
+/// A channel is a FourCC or any other short string of 1 to 32 printable ASCII characters.
+/// Messages sent on a channel are delivered with that channel so applications can multiplex
+/// features without inventing their own framing.
+#[derive(Clone, Hash, PartialOrd, Ord, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String")]
+#[serde(into = "String")]
+pub struct AppChannel(Vec<u8>);
+
+impl AppChannel {
+    /// Make a channel from its identifier bytes, validating the length and characters
+    pub fn new(bytes: &[u8]) -> VeilidAPIResult<Self> {
+        if bytes.is_empty() || bytes.len() > MAX_APP_CHANNEL_LENGTH {
+            apibail_invalid_argument!(
+                "app channel must be between 1 and 32 bytes",
+                "bytes",
+                bytes.len()
+            );
+        }
+        if !bytes.iter().all(|b| b.is_ascii_graphic()) {
+            apibail_invalid_argument!(
+                "app channel must be printable ascii",
+                "bytes",
+                String::from_utf8_lossy(bytes)
+            );
+        }
+        Ok(Self(bytes.to_vec()))
+    }
+
+    /// The channel identifier bytes as carried in the RPC operation
+    pub fn bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<FourCC> for AppChannel {
+    fn from(fourcc: FourCC) -> Self {
+        Self(fourcc.0.to_vec())
+    }
+}
+
+impl TryFrom<&[u8]> for AppChannel {
+    type Error = VeilidAPIError;
+    fn try_from(b: &[u8]) -> Result<Self, Self::Error> {
+        Self::new(b)
+    }
+}
+
+impl TryFrom<String> for AppChannel {
+    type Error = VeilidAPIError;
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_str(s.as_str())
+    }
+}
+
+impl From<AppChannel> for String {
+    fn from(c: AppChannel) -> Self {
+        c.to_string()
+    }
+}
+
+impl fmt::Display for AppChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{}", String::from_utf8_lossy(&self.0))
+    }
+}
+
+impl fmt::Debug for AppChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{}", String::from_utf8_lossy(&self.0))
+    }
+}
+
+impl FromStr for AppChannel {
+    type Err = VeilidAPIError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.as_bytes())
+    }
+}
+
+/// Options for an application channel registered with [VeilidAPI::register_app_channel].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct AppChannelOptions {
+    /// Largest app_message or app_call request accepted on this channel, in bytes
+    /// Larger messages are rejected at the RPC layer before reaching the application
+    #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+    pub max_message_size: Option<u32>,
+}
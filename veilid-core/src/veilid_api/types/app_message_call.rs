@@ -14,6 +14,11 @@ pub struct VeilidAppMessage {
     #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "string"))]
     route_id: Option<RouteId>,
 
+    #[serde(default, with = "as_human_opt_string")]
+    #[schemars(with = "Option<String>")]
+    #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "string"))]
+    channel: Option<AppChannel>,
+
     #[cfg_attr(not(target_arch = "wasm32"), serde(with = "as_human_base64"))]
     #[schemars(with = "String")]
     #[cfg_attr(
@@ -25,10 +30,16 @@ pub struct VeilidAppMessage {
 }
 
 impl VeilidAppMessage {
-    pub fn new(sender: Option<TypedKey>, route_id: Option<RouteId>, message: Vec<u8>) -> Self {
+    pub fn new(
+        sender: Option<TypedKey>,
+        route_id: Option<RouteId>,
+        channel: Option<AppChannel>,
+        message: Vec<u8>,
+    ) -> Self {
         Self {
             sender,
             route_id,
+            channel,
             message,
         }
     }
@@ -43,6 +54,11 @@ impl VeilidAppMessage {
         self.route_id.as_ref()
     }
 
+    /// Some(channel) if the message was sent on an application channel, None if it was sent without one.
+    pub fn channel(&self) -> Option<&AppChannel> {
+        self.channel.as_ref()
+    }
+
     /// The content of the message to deliver to the application.
     pub fn message(&self) -> &[u8] {
         &self.message
@@ -63,6 +79,11 @@ pub struct VeilidAppCall {
     #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "string"))]
     route_id: Option<RouteId>,
 
+    #[serde(default, with = "as_human_opt_string")]
+    #[schemars(with = "Option<String>")]
+    #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "string"))]
+    channel: Option<AppChannel>,
+
     #[cfg_attr(not(target_arch = "wasm32"), serde(with = "as_human_base64"))]
     #[schemars(with = "String")]
     #[cfg_attr(
@@ -81,12 +102,14 @@ impl VeilidAppCall {
     pub fn new(
         sender: Option<TypedKey>,
         route_id: Option<RouteId>,
+        channel: Option<AppChannel>,
         message: Vec<u8>,
         call_id: OperationId,
     ) -> Self {
         Self {
             sender,
             route_id,
+            channel,
             message,
             call_id,
         }
@@ -102,6 +125,11 @@ impl VeilidAppCall {
         self.route_id.as_ref()
     }
 
+    /// Some(channel) if the request was sent on an application channel, None if it was sent without one.
+    pub fn channel(&self) -> Option<&AppChannel> {
+        self.channel.as_ref()
+    }
+
     /// The content of the request to deliver to the application.
     pub fn message(&self) -> &[u8] {
         &self.message
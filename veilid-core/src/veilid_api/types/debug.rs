@@ -0,0 +1,330 @@
+use super::*;
+
+/// Which DHT record store to inspect
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    target_arch = "wasm32",
+    derive(Tsify),
+    tsify(from_wasm_abi, into_wasm_abi)
+)]
+pub enum DebugRecordScope {
+    /// Records this node has created or opened
+    Local,
+    /// Records this node stores on behalf of other nodes
+    Remote,
+}
+
+/// A typed debug query, the structured form of the introspection commands of [VeilidAPI::debug]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    target_arch = "wasm32",
+    derive(Tsify),
+    tsify(from_wasm_abi, into_wasm_abi)
+)]
+#[serde(tag = "kind")]
+pub enum DebugRequest {
+    /// Count the routing table entries in each bucket
+    Buckets {
+        /// Only count entries in at least this state
+        #[serde(default)]
+        min_state: RoutingTableEntryState,
+    },
+    /// List the routing table entries in each bucket
+    Entries {
+        /// Only list entries in at least this state
+        #[serde(default)]
+        min_state: RoutingTableEntryState,
+        /// Only list entries that have all of these capabilities
+        #[serde(default)]
+        capabilities: Vec<FourCC>,
+    },
+    /// Get a single routing table entry
+    Entry {
+        /// The id of the node to look up
+        #[schemars(with = "String")]
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "string"))]
+        node_id: TypedKey,
+    },
+    /// List the allocated and imported private routes
+    RouteList,
+    /// Get the details of a private route
+    Route {
+        /// The route id
+        #[schemars(with = "String")]
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "string"))]
+        route_id: RouteId,
+    },
+    /// Send a test question over a private route
+    RouteTest {
+        /// The route id
+        #[schemars(with = "String")]
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "string"))]
+        route_id: RouteId,
+    },
+    /// Mark an allocated private route as published and export it as a blob
+    RoutePublish {
+        /// The route id
+        #[schemars(with = "String")]
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "string"))]
+        route_id: RouteId,
+        /// Include the full route rather than an optimized one
+        #[serde(default)]
+        full: bool,
+    },
+    /// List the records in a DHT record store
+    RecordList {
+        /// The record store to list
+        scope: DebugRecordScope,
+    },
+    /// Get a single record from a DHT record store
+    RecordInfo {
+        /// The record store to look in
+        scope: DebugRecordScope,
+        /// The record key
+        #[schemars(with = "String")]
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "string"))]
+        key: TypedKey,
+    },
+    /// List the tables in the table store
+    TableList,
+    /// Get the details of a table in the table store
+    TableInfo {
+        /// The table name
+        name: String,
+    },
+    /// List the punishments this node has assigned to other nodes and networks
+    PunishList,
+    /// Describe the commands accepted by [VeilidAPI::debug]
+    CommandSchema,
+}
+
+/// The result of a [DebugRequest]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    target_arch = "wasm32",
+    derive(Tsify),
+    tsify(from_wasm_abi, into_wasm_abi)
+)]
+#[serde(tag = "kind")]
+pub enum DebugResponse {
+    Buckets {
+        buckets: Vec<DebugBucketCounts>,
+    },
+    Entries {
+        /// The total number of entries in the routing table, before filtering
+        entry_count: u32,
+        /// The non-empty buckets after filtering
+        buckets: Vec<DebugBucketEntries>,
+    },
+    Entry {
+        #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+        entry: Option<Box<RoutingTableEntry>>,
+    },
+    RouteList {
+        #[schemars(with = "Vec<String>")]
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "string[]"))]
+        allocated: Vec<RouteId>,
+        #[schemars(with = "Vec<String>")]
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "string[]"))]
+        remote: Vec<RouteId>,
+    },
+    Route {
+        #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+        route: Option<RouteHealth>,
+    },
+    RouteTest {
+        /// True if the test passed, false if it failed, unset if the route could not be tested
+        #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+        success: Option<bool>,
+    },
+    RoutePublish {
+        #[schemars(with = "String")]
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "string"))]
+        route_id: RouteId,
+        #[serde(with = "as_human_base64")]
+        #[schemars(with = "String")]
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "string"))]
+        blob: Vec<u8>,
+    },
+    RecordList {
+        store: DebugRecordStore,
+    },
+    RecordInfo {
+        #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+        record: Option<DebugRecord>,
+    },
+    TableList {
+        tables: Vec<DebugTableName>,
+    },
+    TableInfo {
+        #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+        info: Option<TableInfo>,
+    },
+    PunishList {
+        punishments: Vec<DebugPunishment>,
+    },
+    CommandSchema {
+        commands: Vec<DebugCommand>,
+    },
+}
+
+/// The number of routing table entries in each bucket for one crypto kind
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct DebugBucketCounts {
+    /// The crypto kind of the buckets
+    #[schemars(with = "String")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "string"))]
+    pub kind: CryptoKind,
+    /// Entry counts, indexed by bucket number
+    pub counts: Vec<u32>,
+}
+
+/// The routing table entries in one bucket
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct DebugBucketEntries {
+    /// The crypto kind of the bucket
+    #[schemars(with = "String")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "string"))]
+    pub kind: CryptoKind,
+    /// The bucket number
+    pub index: u32,
+    /// The entries in the bucket
+    pub entries: Vec<RoutingTableEntry>,
+}
+
+/// The contents of a DHT record store
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct DebugRecordStore {
+    /// The records in the store, least recently used first
+    pub records: Vec<DebugRecord>,
+    /// The number of subkeys in the subkey cache
+    pub subkey_cache_count: u32,
+    /// The total size of the subkeys in the subkey cache
+    pub subkey_cache_total_size: u64,
+    /// The total storage space used by the store
+    pub total_storage_space: u64,
+    /// Records that are waiting to be deleted
+    #[schemars(with = "Vec<String>")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "string[]"))]
+    pub dead_records: Vec<TypedKey>,
+    /// Records that have changes waiting to be flushed
+    #[schemars(with = "Vec<String>")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "string[]"))]
+    pub changed_records: Vec<TypedKey>,
+}
+
+/// A record in a DHT record store
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct DebugRecord {
+    /// The record key
+    #[schemars(with = "String")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "string"))]
+    pub key: TypedKey,
+    /// The record owner
+    #[schemars(with = "String")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "string"))]
+    pub owner: PublicKey,
+    /// The record schema
+    pub schema: DHTSchema,
+    /// The number of subkeys in the record
+    pub subkey_count: u32,
+    /// The subkeys that have values in the store
+    pub stored_subkeys: ValueSubkeyRangeSet,
+    /// When the record was last accessed
+    pub last_touched: Timestamp,
+    /// The total size of the record's subkey data
+    pub record_data_size: u64,
+    /// The number of historical values kept for the record
+    pub history_length: u32,
+}
+
+/// A table in the table store
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct DebugTableName {
+    /// The table name
+    pub name: String,
+    /// The name the table is stored under
+    pub internal_name: String,
+}
+
+/// A punishment this node has assigned to another node or network
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct DebugPunishment {
+    /// The punished node id or network address block
+    pub target: String,
+    /// Why the punishment was given
+    pub reason: String,
+    /// When the punishment was given
+    pub timestamp: Timestamp,
+}
+
+/// The kind of value a debug command argument takes, so command lines can offer completions
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[serde(tag = "kind")]
+pub enum DebugArgKind {
+    /// One of a fixed set of words
+    Choice {
+        /// The words to choose from
+        values: Vec<String>,
+    },
+    /// The id of a node
+    NodeId,
+    /// A node, relayed node or private route to send to
+    Destination,
+    /// The id of an allocated or imported private route
+    RouteId,
+    /// The key of a DHT record
+    RecordKey,
+    /// The name of a table in the table store
+    TableName,
+    /// A number
+    Number,
+    /// Free-form text
+    Text,
+}
+
+/// An argument of a debug command
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct DebugCommandArg {
+    /// The argument name, as shown in usage text
+    pub name: String,
+    /// The kind of value the argument takes
+    pub kind: DebugArgKind,
+    /// True if the argument may be left out
+    pub optional: bool,
+}
+
+/// A command accepted by [VeilidAPI::debug]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct DebugCommand {
+    /// The words that select the command, such as `["route", "test"]`
+    pub words: Vec<String>,
+    /// The arguments that follow the command words
+    pub args: Vec<DebugCommandArg>,
+    /// A one line description of the command
+    pub description: String,
+}
+
+impl DebugCommand {
+    /// The usage line for the command, such as `route publish <route> [full]`
+    pub fn usage(&self) -> String {
+        let mut out = self.words.join(" ");
+        for arg in &self.args {
+            if arg.optional {
+                out += &format!(" [{}]", arg.name);
+            } else {
+                out += &format!(" <{}>", arg.name);
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,16 @@
+use super::*;
+
+/// Options for writing a subkey with [RoutingContext::set_dht_value].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct SetDHTValueOptions {
+    /// The writer to use, overriding the 'default_writer' specified when the record was opened
+    #[schemars(with = "Option<String>")]
+    #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "string"))]
+    pub writer: Option<KeyPair>,
+    /// Only write the subkey if its current sequence number is this one, making the write a compare-and-set
+    /// Use `ValueSeqNum::MAX` to only write a subkey that has no value yet
+    /// If another value got there first, the write fails with [VeilidAPIError::Conflict] carrying that value
+    #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+    pub expected_seq: Option<ValueSeqNum>,
+}
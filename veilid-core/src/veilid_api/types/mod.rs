@@ -1,8 +1,12 @@
 #[macro_use]
 mod aligned_u64;
+mod app_channel;
 mod app_message_call;
+mod debug;
 mod dht;
 mod fourcc;
+mod route_health;
+mod routing_table_info;
 mod safety;
 mod stats;
 mod timestamp;
@@ -15,9 +19,13 @@ mod veilid_state;
 use super::*;
 
 pub use aligned_u64::*;
+pub use app_channel::*;
 pub use app_message_call::*;
+pub use debug::*;
 pub use dht::*;
 pub use fourcc::*;
+pub use route_health::*;
+pub use routing_table_info::*;
 pub use safety::*;
 pub use stats::*;
 pub use timestamp::*;
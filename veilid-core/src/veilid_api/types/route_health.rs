@@ -0,0 +1,69 @@
+use super::*;
+
+/// Health of a private route that was allocated or imported by this node
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    target_arch = "wasm32",
+    derive(Tsify),
+    tsify(from_wasm_abi, into_wasm_abi)
+)]
+pub struct RouteHealth {
+    /// The route id
+    #[schemars(with = "String")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "string"))]
+    pub route_id: RouteId,
+    /// True if this route was imported from a remote private route blob
+    pub remote: bool,
+    /// True if this route has been published as a private route blob
+    pub published: bool,
+    /// How long ago the route was allocated or imported
+    pub age: TimestampDuration,
+    /// Latencies for questions sent over the route
+    pub latency: LatencyStats,
+    /// Bandwidth used over the route
+    pub transfer: TransferStatsDownUp,
+    /// Number of messages or answers received over the route
+    pub successes: u64,
+    /// Number of messages that failed to send or questions that were lost over the route
+    pub failures: u64,
+    /// Number of failures since the route last tested clean
+    pub recent_failures: u32,
+    /// When the route was last tested or received something
+    #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+    pub last_tested_ts: Option<Timestamp>,
+    /// Number of hops in the route
+    pub hop_count: u32,
+    /// Number of distinct network blocks the hops are on, only known for allocated routes
+    #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+    pub hop_ipblock_count: Option<u32>,
+    /// Whether the route was built from reliable or low latency nodes, only known for allocated routes
+    #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+    pub stability: Option<Stability>,
+    /// True if every hop can use connection oriented protocols, only known for allocated routes
+    #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+    pub can_do_sequenced: Option<bool>,
+}
+
+/// Policy for replacing an allocated private route before it dies
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    target_arch = "wasm32",
+    derive(Tsify),
+    tsify(from_wasm_abi, into_wasm_abi)
+)]
+pub struct RouteRotationPolicy {
+    /// Rotate the route once it has been allocated for this long
+    #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+    pub max_age_ms: Option<u32>,
+    /// Rotate the route once it has this many failures since it last tested clean
+    #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+    pub max_recent_failures: Option<u32>,
+    /// If set, the new route blob is written to this subkey of an opened DHT record
+    #[serde(with = "as_human_opt_string")]
+    #[schemars(with = "Option<String>")]
+    #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "string"))]
+    pub publish_key: Option<TypedKey>,
+    /// The subkey of the DHT record to write the new route blob to
+    #[serde(default)]
+    pub publish_subkey: ValueSubkey,
+}
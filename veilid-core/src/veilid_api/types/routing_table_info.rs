@@ -0,0 +1,124 @@
+use super::*;
+
+/// The structure of this node's routing table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    target_arch = "wasm32",
+    derive(Tsify),
+    tsify(from_wasm_abi, into_wasm_abi)
+)]
+pub struct RoutingTableInfo {
+    /// The node ids of this node
+    #[schemars(with = "Vec<String>")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "string[]"))]
+    pub node_ids: Vec<TypedKey>,
+    /// The node ids of the relay this node uses on the public internet, empty if it has none
+    #[schemars(with = "Vec<String>")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "string[]"))]
+    pub public_internet_relay: Vec<TypedKey>,
+    /// The node ids of the relay this node uses on the local network, empty if it has none
+    #[schemars(with = "Vec<String>")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "string[]"))]
+    pub local_network_relay: Vec<TypedKey>,
+    /// The number of entries in each bucket, for each crypto kind
+    pub buckets: Vec<DebugBucketCounts>,
+    /// The entries in the routing table
+    pub entries: Vec<RoutingTableEntry>,
+}
+
+/// A node in this node's routing table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(
+    target_arch = "wasm32",
+    derive(Tsify),
+    tsify(from_wasm_abi, into_wasm_abi)
+)]
+pub struct RoutingTableEntry {
+    /// The node ids of the node
+    #[schemars(with = "Vec<String>")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "string[]"))]
+    pub node_ids: Vec<TypedKey>,
+    /// The state of the node
+    pub state: RoutingTableEntryState,
+    /// A short code for why the node is in its state, such as 'RELIBL' or 'DNOPNG'
+    pub state_reason: String,
+    /// True if this node uses the node as its relay
+    pub is_relay: bool,
+    /// What the node published about itself on the public internet, if it is known
+    #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+    pub public_internet: Option<RoutingTableNodeInfo>,
+    /// What the node published about itself on the local network, if it is known
+    #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+    pub local_network: Option<RoutingTableNodeInfo>,
+    /// Statistics collected on the node
+    pub peer_stats: PeerStats,
+}
+
+/// The state of a node in the routing table, from worst to best
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(
+    target_arch = "wasm32",
+    derive(Tsify),
+    tsify(from_wasm_abi, into_wasm_abi)
+)]
+pub enum RoutingTableEntryState {
+    /// The node has been punished for bad behavior
+    Punished,
+    /// The node is not responding
+    Dead,
+    /// The node is responding but has not proven itself reliable yet
+    #[default]
+    Unreliable,
+    /// The node is responding reliably
+    Reliable,
+}
+
+impl From<routing_table::BucketEntryState> for RoutingTableEntryState {
+    fn from(value: routing_table::BucketEntryState) -> Self {
+        match value {
+            routing_table::BucketEntryState::Punished => RoutingTableEntryState::Punished,
+            routing_table::BucketEntryState::Dead => RoutingTableEntryState::Dead,
+            routing_table::BucketEntryState::Unreliable => RoutingTableEntryState::Unreliable,
+            routing_table::BucketEntryState::Reliable => RoutingTableEntryState::Reliable,
+        }
+    }
+}
+
+impl From<RoutingTableEntryState> for routing_table::BucketEntryState {
+    fn from(value: RoutingTableEntryState) -> Self {
+        match value {
+            RoutingTableEntryState::Punished => routing_table::BucketEntryState::Punished,
+            RoutingTableEntryState::Dead => routing_table::BucketEntryState::Dead,
+            RoutingTableEntryState::Unreliable => routing_table::BucketEntryState::Unreliable,
+            RoutingTableEntryState::Reliable => routing_table::BucketEntryState::Reliable,
+        }
+    }
+}
+
+/// What a node published about itself in a routing domain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct RoutingTableNodeInfo {
+    /// How the node can be reached
+    pub network_class: NetworkClass,
+    /// The capabilities of the node
+    pub capabilities: Vec<FourCC>,
+    /// The addresses the node can be reached at
+    pub dial_info: Vec<RoutingTableDialInfo>,
+    /// The node ids of the relay the node uses, empty if it is not relayed
+    #[schemars(with = "Vec<String>")]
+    #[cfg_attr(target_arch = "wasm32", tsify(type = "string[]"))]
+    pub relay_ids: Vec<TypedKey>,
+}
+
+/// An address a node can be reached at
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct RoutingTableDialInfo {
+    /// How directly the address can be reached
+    pub class: DialInfoClass,
+    /// The address, such as 'udp|1.2.3.4:5150'
+    pub dial_info: String,
+}
@@ -35,6 +35,23 @@ pub struct RPCStats {
     pub first_consecutive_seen_ts: Option<Timestamp>, // the timestamp of the first consecutive proof-of-life for this node (an answer or received question)
     pub recent_lost_answers: u32, // number of answers that have been lost since we lost reliability
     pub failed_to_send: u32, // number of messages that have failed to send or connections dropped since we last successfully sent one
+    #[serde(default)]
+    pub questions_sent: u32, // number of questions sent that expected an answer
+    #[serde(default)]
+    pub answers_rcvd: u32, // number of answers received to our questions
+    #[serde(default)]
+    pub lost_answers: u32, // number of answers that never arrived, unlike recent_lost_answers this is never reset
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct RateLimitStats {
+    pub operation: String, // the kind of rpc operation being limited
+    #[schemars(with = "String")]
+    pub capability: FourCC, // the capability whose limit applies to the operation
+    pub available: u32, // number of operations the peer could send right now without being limited
+    pub burst: u32,     // maximum number of operations the peer may send at once
+    pub limited: u32,   // number of operations dropped for exceeding the limit
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
@@ -44,4 +61,6 @@ pub struct PeerStats {
     pub rpc_stats: RPCStats,   // information about RPCs
     pub latency: Option<LatencyStats>, // latencies for communications with the peer
     pub transfer: TransferStatsDownUp, // Stats for communications with the peer
+    #[serde(default)]
+    pub rate_limits: Vec<RateLimitStats>, // inbound rate limit usage per operation kind
 }
@@ -119,6 +119,30 @@ pub struct VeilidRouteChange {
     /// If a private route that was imported has died, it is listed here.
     #[schemars(with = "Vec<String>")]
     pub dead_remote_routes: Vec<RouteId>,
+    /// If a private route that was allocated was replaced by its rotation policy, it is listed here.
+    #[serde(default)]
+    pub rotated_routes: Vec<VeilidRouteRotation>,
+}
+
+/// Describe a private route that was replaced with a new route before it died
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct VeilidRouteRotation {
+    /// The route that was replaced and released
+    #[schemars(with = "String")]
+    pub old_route_id: RouteId,
+    /// The newly allocated route
+    #[schemars(with = "String")]
+    pub new_route_id: RouteId,
+    /// The private route blob for the new route
+    #[cfg_attr(not(target_arch = "wasm32"), serde(with = "as_human_base64"))]
+    #[schemars(with = "String")]
+    #[cfg_attr(
+        target_arch = "wasm32",
+        serde(with = "serde_bytes"),
+        tsify(type = "Uint8Array")
+    )]
+    pub blob: Vec<u8>,
 }
 
 /// Describe changes to the Veilid node configuration
@@ -152,6 +176,22 @@ pub struct VeilidValueChange {
     pub value: Option<ValueData>,
 }
 
+/// Describe the outcome of a DHT subkey write that was queued while offline
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct VeilidOfflineWrite {
+    /// The DHT Record key that was written
+    #[schemars(with = "String")]
+    pub key: TypedKey,
+    /// The subkey that was written
+    pub subkey: ValueSubkey,
+    /// The value that was queued to be written
+    pub value: ValueData,
+    /// The newer value found on the network that replaced the queued value
+    /// If this is None, the queued value was accepted by the network
+    pub superseded_by: Option<ValueData>,
+}
+
 /// An update from the veilid-core to the host application describing a change
 /// to the internal state of the Veilid node.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
@@ -166,6 +206,7 @@ pub enum VeilidUpdate {
     Config(Box<VeilidStateConfig>),
     RouteChange(Box<VeilidRouteChange>),
     ValueChange(Box<VeilidValueChange>),
+    OfflineWrite(Box<VeilidOfflineWrite>),
     Shutdown,
 }
 from_impl_to_jsvalue!(VeilidUpdate);
@@ -412,6 +412,133 @@ impl Default for VeilidConfigDHT {
     }
 }
 
+/// Configure the RPC work queues.
+///
+/// Incoming RPC operations are queued by priority class: answers and receipts first,
+/// then status and find_node, then DHT and application operations, then relayed and
+/// routed traffic. Within a class, senders take turns so one peer can not starve the others.
+/// The total number of queued operations is still limited by `rpc.queue_size`.
+///
+/// ```yaml
+/// queues:
+///     answer_queue_size: 256
+///     status_queue_size: 256
+///     dht_queue_size: 512
+///     routed_queue_size: 512
+///     max_queued_per_sender: 64
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct VeilidConfigRPCQueues {
+    pub answer_queue_size: u32,
+    pub status_queue_size: u32,
+    pub dht_queue_size: u32,
+    pub routed_queue_size: u32,
+    pub max_queued_per_sender: u32,
+}
+
+impl Default for VeilidConfigRPCQueues {
+    fn default() -> Self {
+        Self {
+            answer_queue_size: 256,
+            status_queue_size: 256,
+            dht_queue_size: 512,
+            routed_queue_size: 512,
+            max_queued_per_sender: 64,
+        }
+    }
+}
+
+/// Token bucket limit for one class of inbound RPC operations.
+///
+/// Each peer may send `burst` operations at once, refilled at `rate_per_min`.
+/// A `rate_per_min` of zero disables the limit.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct VeilidConfigRPCRateLimit {
+    pub rate_per_min: u32,
+    pub burst: u32,
+}
+
+impl VeilidConfigRPCRateLimit {
+    pub fn new(rate_per_min: u32, burst: u32) -> Self {
+        Self {
+            rate_per_min,
+            burst,
+        }
+    }
+}
+
+/// Configure per-peer inbound RPC rate limits.
+///
+/// Limits are kept per node id and per operation kind, using the limit of the
+/// capability that provides the operation. Operations over the limit are dropped,
+/// and a peer that exceeds its limits `punish_violation_count` times within a
+/// minute is punished. A `punish_violation_count` of zero never punishes.
+/// Our current relay, and value changes sent by the node holding one of our
+/// watches, are never limited.
+///
+/// ```yaml
+/// rate_limits:
+///     enabled: true
+///     punish_violation_count: 64
+///     dht:
+///         rate_per_min: 1200
+///         burst: 128
+///     dht_watch:
+///         rate_per_min: 120
+///         burst: 16
+///     app_message:
+///         rate_per_min: 1200
+///         burst: 128
+///     route:
+///         rate_per_min: 6000
+///         burst: 512
+///     signal:
+///         rate_per_min: 120
+///         burst: 16
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct VeilidConfigRPCRateLimits {
+    pub enabled: bool,
+    pub punish_violation_count: u32,
+    /// Limit for get, set, and inspect value operations (DHTV)
+    pub dht: VeilidConfigRPCRateLimit,
+    /// Limit for watch value and value changed operations (DHTW)
+    pub dht_watch: VeilidConfigRPCRateLimit,
+    /// Limit for app call and app message operations (APPM)
+    pub app_message: VeilidConfigRPCRateLimit,
+    /// Limit for relayed route operations (ROUT)
+    pub route: VeilidConfigRPCRateLimit,
+    /// Limit for signal operations (SGNL)
+    pub signal: VeilidConfigRPCRateLimit,
+}
+
+impl VeilidConfigRPCRateLimits {
+    /// Check if a peer with this many recent violations should be punished
+    pub fn should_punish(&self, violations: u32) -> bool {
+        self.punish_violation_count != 0 && violations >= self.punish_violation_count
+    }
+}
+
+impl Default for VeilidConfigRPCRateLimits {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            punish_violation_count: 64,
+            dht: VeilidConfigRPCRateLimit::new(1200, 128),
+            dht_watch: VeilidConfigRPCRateLimit::new(120, 16),
+            app_message: VeilidConfigRPCRateLimit::new(1200, 128),
+            route: VeilidConfigRPCRateLimit::new(6000, 512),
+            signal: VeilidConfigRPCRateLimit::new(120, 16),
+        }
+    }
+}
+
 /// Configure RPC.
 ///
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
@@ -419,6 +546,10 @@ impl Default for VeilidConfigDHT {
 pub struct VeilidConfigRPC {
     pub concurrency: u32,
     pub queue_size: u32,
+    #[serde(default)]
+    pub queues: VeilidConfigRPCQueues,
+    #[serde(default)]
+    pub rate_limits: VeilidConfigRPCRateLimits,
     #[cfg_attr(target_arch = "wasm32", tsify(optional))]
     pub max_timestamp_behind_ms: Option<u32>,
     #[cfg_attr(target_arch = "wasm32", tsify(optional))]
@@ -433,6 +564,8 @@ impl Default for VeilidConfigRPC {
         Self {
             concurrency: 0,
             queue_size: 1024,
+            queues: VeilidConfigRPCQueues::default(),
+            rate_limits: VeilidConfigRPCRateLimits::default(),
             max_timestamp_behind_ms: Some(10000),
             max_timestamp_ahead_ms: Some(10000),
             timeout_ms: 5000,
@@ -444,6 +577,11 @@ impl Default for VeilidConfigRPC {
 
 /// Configure the network routing table.
 ///
+/// `bootstrap_keys` lists the keys trusted to sign version 1 bootstrap TXT records.
+/// Unsigned version 0 records are only used when no signed records can be verified
+/// and `bootstrap_allow_unsigned` is set. Configuring any bootstrap key requires
+/// `bootstrap_allow_unsigned` to be unset, so unsigned records are always refused.
+///
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 pub struct VeilidConfigRoutingTable {
@@ -452,6 +590,11 @@ pub struct VeilidConfigRoutingTable {
     #[schemars(with = "Vec<String>")]
     pub node_id_secret: TypedSecretGroup,
     pub bootstrap: Vec<String>,
+    #[serde(default)]
+    #[schemars(with = "Vec<String>")]
+    pub bootstrap_keys: Vec<TypedKey>,
+    #[serde(default = "default_bootstrap_allow_unsigned")]
+    pub bootstrap_allow_unsigned: bool,
     pub limit_over_attached: u32,
     pub limit_fully_attached: u32,
     pub limit_attached_strong: u32,
@@ -461,6 +604,10 @@ pub struct VeilidConfigRoutingTable {
     // xxx pub enable_local_network: bool,
 }
 
+fn default_bootstrap_allow_unsigned() -> bool {
+    true
+}
+
 impl Default for VeilidConfigRoutingTable {
     fn default() -> Self {
         cfg_if::cfg_if! {
@@ -475,6 +622,8 @@ impl Default for VeilidConfigRoutingTable {
             node_id: TypedKeyGroup::default(),
             node_id_secret: TypedSecretGroup::default(),
             bootstrap,
+            bootstrap_keys: Vec::new(),
+            bootstrap_allow_unsigned: true,
             limit_over_attached: 64,
             limit_fully_attached: 32,
             limit_attached_strong: 16,
@@ -484,6 +633,70 @@ impl Default for VeilidConfigRoutingTable {
     }
 }
 
+/// Configure discovery of other nodes on the local network via multicast beacons.
+///
+/// ```yaml
+/// lan_discovery:
+///     enabled: false
+///     interval_ms: 10000
+///     port: 5149
+///     multicast_group_v4: '239.255.86.76'
+///     multicast_group_v6: 'ff02::7665:696c'
+///     max_beacons_per_min: 12
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct VeilidConfigLANDiscovery {
+    pub enabled: bool,
+    pub interval_ms: u32,
+    pub port: u16,
+    pub multicast_group_v4: String,
+    pub multicast_group_v6: String,
+    pub max_beacons_per_min: u32,
+}
+
+impl Default for VeilidConfigLANDiscovery {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_ms: 10000,
+            port: 5149,
+            multicast_group_v4: String::from("239.255.86.76"),
+            multicast_group_v6: String::from("ff02::7665:696c"),
+            max_beacons_per_min: 12,
+        }
+    }
+}
+
+/// Configure serving a signed list of reliable peers to bootstrapping nodes.
+///
+/// Nodes use this server by adding a bootstrap entry of the form
+/// `<node_id>@<url>`, such as `VLD0:xxxx@udp://bootstrap.example.com:5150`.
+/// The peer list is only accepted if it is signed by the pinned node id.
+///
+/// ```yaml
+/// bootstrap_server:
+///     enabled: false
+///     max_peers_per_type: 2
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+pub struct VeilidConfigBootstrapServer {
+    pub enabled: bool,
+    pub max_peers_per_type: u32,
+}
+
+impl Default for VeilidConfigBootstrapServer {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_peers_per_type: 2,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 pub struct VeilidConfigNetwork {
@@ -504,6 +717,10 @@ pub struct VeilidConfigNetwork {
     pub upnp: bool,
     pub detect_address_changes: bool,
     pub restricted_nat_retries: u32,
+    #[serde(default)]
+    pub lan_discovery: VeilidConfigLANDiscovery,
+    #[serde(default)]
+    pub bootstrap_server: VeilidConfigBootstrapServer,
     pub tls: VeilidConfigTLS,
     pub application: VeilidConfigApplication,
     pub protocol: VeilidConfigProtocol,
@@ -528,6 +745,8 @@ impl Default for VeilidConfigNetwork {
             upnp: true,
             detect_address_changes: true,
             restricted_nat_retries: 0,
+            lan_discovery: VeilidConfigLANDiscovery::default(),
+            bootstrap_server: VeilidConfigBootstrapServer::default(),
             tls: VeilidConfigTLS::default(),
             application: VeilidConfigApplication::default(),
             protocol: VeilidConfigProtocol::default(),
@@ -841,6 +1060,8 @@ impl VeilidConfig {
             get_config!(inner.network.routing_table.node_id);
             get_config!(inner.network.routing_table.node_id_secret);
             get_config!(inner.network.routing_table.bootstrap);
+            get_config!(inner.network.routing_table.bootstrap_keys);
+            get_config!(inner.network.routing_table.bootstrap_allow_unsigned);
             get_config!(inner.network.routing_table.limit_over_attached);
             get_config!(inner.network.routing_table.limit_fully_attached);
             get_config!(inner.network.routing_table.limit_attached_strong);
@@ -870,6 +1091,23 @@ impl VeilidConfig {
             get_config!(inner.network.dht.max_watch_expiration_ms);
             get_config!(inner.network.rpc.concurrency);
             get_config!(inner.network.rpc.queue_size);
+            get_config!(inner.network.rpc.queues.answer_queue_size);
+            get_config!(inner.network.rpc.queues.status_queue_size);
+            get_config!(inner.network.rpc.queues.dht_queue_size);
+            get_config!(inner.network.rpc.queues.routed_queue_size);
+            get_config!(inner.network.rpc.queues.max_queued_per_sender);
+            get_config!(inner.network.rpc.rate_limits.enabled);
+            get_config!(inner.network.rpc.rate_limits.punish_violation_count);
+            get_config!(inner.network.rpc.rate_limits.dht.rate_per_min);
+            get_config!(inner.network.rpc.rate_limits.dht.burst);
+            get_config!(inner.network.rpc.rate_limits.dht_watch.rate_per_min);
+            get_config!(inner.network.rpc.rate_limits.dht_watch.burst);
+            get_config!(inner.network.rpc.rate_limits.app_message.rate_per_min);
+            get_config!(inner.network.rpc.rate_limits.app_message.burst);
+            get_config!(inner.network.rpc.rate_limits.route.rate_per_min);
+            get_config!(inner.network.rpc.rate_limits.route.burst);
+            get_config!(inner.network.rpc.rate_limits.signal.rate_per_min);
+            get_config!(inner.network.rpc.rate_limits.signal.burst);
             get_config!(inner.network.rpc.max_timestamp_behind_ms);
             get_config!(inner.network.rpc.max_timestamp_ahead_ms);
             get_config!(inner.network.rpc.timeout_ms);
@@ -878,6 +1116,14 @@ impl VeilidConfig {
             get_config!(inner.network.upnp);
             get_config!(inner.network.detect_address_changes);
             get_config!(inner.network.restricted_nat_retries);
+            get_config!(inner.network.lan_discovery.enabled);
+            get_config!(inner.network.lan_discovery.interval_ms);
+            get_config!(inner.network.lan_discovery.port);
+            get_config!(inner.network.lan_discovery.multicast_group_v4);
+            get_config!(inner.network.lan_discovery.multicast_group_v6);
+            get_config!(inner.network.lan_discovery.max_beacons_per_min);
+            get_config!(inner.network.bootstrap_server.enabled);
+            get_config!(inner.network.bootstrap_server.max_peers_per_type);
             get_config!(inner.network.tls.certificate_path);
             get_config!(inner.network.tls.private_key_path);
             get_config!(inner.network.tls.connection_initial_timeout_ms);
@@ -1158,6 +1404,13 @@ impl VeilidConfig {
                 );
             }
         }
+        if !inner.network.routing_table.bootstrap_keys.is_empty()
+            && inner.network.routing_table.bootstrap_allow_unsigned
+        {
+            apibail_generic!(
+                "unsigned bootstrap records can not be allowed when bootstrap keys are configured in 'network.routing_table.bootstrap_allow_unsigned'"
+            );
+        }
         if inner.network.rpc.max_route_hop_count == 0 {
             apibail_generic!(
                 "max route hop count must be >= 1 in 'network.rpc.max_route_hop_count'"
@@ -1184,6 +1437,67 @@ impl VeilidConfig {
         if inner.network.rpc.timeout_ms < 1000 {
             apibail_generic!("rpc timeout must be >= 1000 in 'network.rpc.timeout_ms'");
         }
+        if inner.network.lan_discovery.enabled {
+            // Validate LAN discovery settings
+            if inner.network.lan_discovery.interval_ms < 1000 {
+                apibail_generic!(
+                    "LAN discovery interval must be >= 1000 in 'network.lan_discovery.interval_ms'"
+                );
+            }
+            if inner.network.lan_discovery.port == 0 {
+                apibail_generic!("LAN discovery port must be > 0 in 'network.lan_discovery.port'");
+            }
+            if !inner
+                .network
+                .lan_discovery
+                .multicast_group_v4
+                .parse::<Ipv4Addr>()
+                .map(|a| a.is_multicast())
+                .unwrap_or_default()
+            {
+                apibail_generic!("LAN discovery IPv4 group must be a multicast address in 'network.lan_discovery.multicast_group_v4'");
+            }
+            if !inner
+                .network
+                .lan_discovery
+                .multicast_group_v6
+                .parse::<Ipv6Addr>()
+                .map(|a| a.is_multicast())
+                .unwrap_or_default()
+            {
+                apibail_generic!("LAN discovery IPv6 group must be a multicast address in 'network.lan_discovery.multicast_group_v6'");
+            }
+        }
+        if inner.network.bootstrap_server.enabled
+            && (inner.network.bootstrap_server.max_peers_per_type == 0
+                || inner.network.bootstrap_server.max_peers_per_type > 8)
+        {
+            apibail_generic!("bootstrap server peers per type must be between 1 and 8 in 'network.bootstrap_server.max_peers_per_type'");
+        }
+        if inner.network.rpc.queues.answer_queue_size == 0
+            || inner.network.rpc.queues.status_queue_size == 0
+            || inner.network.rpc.queues.dht_queue_size == 0
+            || inner.network.rpc.queues.routed_queue_size == 0
+        {
+            apibail_generic!("RPC queue sizes must be > 0 in 'network.rpc.queues'");
+        }
+        if inner.network.rpc.queues.max_queued_per_sender == 0 {
+            apibail_generic!("RPC queued operations per sender must be > 0 in 'network.rpc.queues.max_queued_per_sender'");
+        }
+        for (name, limit) in [
+            ("dht", &inner.network.rpc.rate_limits.dht),
+            ("dht_watch", &inner.network.rpc.rate_limits.dht_watch),
+            ("app_message", &inner.network.rpc.rate_limits.app_message),
+            ("route", &inner.network.rpc.rate_limits.route),
+            ("signal", &inner.network.rpc.rate_limits.signal),
+        ] {
+            if limit.rate_per_min != 0 && limit.burst == 0 {
+                apibail_generic!(format!(
+                    "RPC rate limit burst must be > 0 when rate is limited in 'network.rpc.rate_limits.{}.burst'",
+                    name
+                ));
+            }
+        }
 
         Ok(())
     }
@@ -1283,10 +1597,31 @@ impl VeilidConfig {
                 .get(ck)
                 .expect("Valid crypto kind is not actually valid.");
 
+            // Tests use a configured node id if there is one, but never touch storage
             #[cfg(test)]
             let (node_id, node_id_secret) = {
-                let kp = vcrypto.generate_keypair();
-                (TypedKey::new(ck, kp.key), TypedSecret::new(ck, kp.secret))
+                let configured = {
+                    let inner = self.inner.read();
+                    (
+                        inner.network.routing_table.node_id.get(ck),
+                        inner.network.routing_table.node_id_secret.get(ck),
+                    )
+                };
+                match configured {
+                    (Some(node_id), Some(node_id_secret)) => {
+                        if !vcrypto.validate_keypair(&node_id.value, &node_id_secret.value) {
+                            apibail_generic!(format!(
+                                "node_id_secret_{} and node_id_key_{} don't match",
+                                ck, ck
+                            ));
+                        }
+                        (node_id, node_id_secret)
+                    }
+                    _ => {
+                        let kp = vcrypto.generate_keypair();
+                        (TypedKey::new(ck, kp.key), TypedSecret::new(ck, kp.secret))
+                    }
+                }
             };
             #[cfg(not(test))]
             let (node_id, node_id_secret) = self.init_node_id(vcrypto, table_store.clone()).await?;
@@ -103,11 +103,18 @@ async fn wasm_test_serialize_routing_table() {
     setup();
     test_serialize_routing_table::test_all().await;
 }
-/* Should be an integration test not a unit test
+
+#[wasm_bindgen_test]
+#[serial]
+async fn wasm_test_bootstrap() {
+    setup();
+    test_bootstrap::test_all().await;
+}
+// The virtual network is native only, so in the browser this runs against the public network
 #[wasm_bindgen_test]
+#[ignore = "needs the public network, run with --include-ignored"]
 #[serial]
 async fn wasm_test_dht() {
     setup();
     test_dht::test_all().await;
 }
-*/
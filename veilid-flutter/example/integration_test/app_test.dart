@@ -72,6 +72,7 @@ void main() {
             testCreateDeleteDHTRecordNoClose);
         test('get dht value nonexistent', testGetDHTValueNonexistent);
         test('set get dht value', testSetGetDHTValue);
+        test('compare and set dht value', testCompareAndSetDHTValue);
         test('open writer dht value', testOpenWriterDHTValue);
         // xxx: needs to be a multi-server integration test
         // test('watch dht values',
@@ -102,6 +102,34 @@ Future<void> testSetGetDHTValue() async {
   }
 }
 
+Future<void> testCompareAndSetDHTValue() async {
+  final rc = await Veilid.instance.routingContext();
+  try {
+    final rec = await rc.createDHTRecord(const DHTSchema.dflt(oCnt: 1));
+    expect(await rc.setDHTValue(rec.key, 0, utf8.encode('FIRST')), isNull);
+
+    // Succeeds while the subkey is still at the expected sequence number
+    expect(
+        await rc.setDHTValue(rec.key, 0, utf8.encode('SECOND'),
+            expectedSeq: 0),
+        isNull);
+    final vd = await rc.getDHTValue(rec.key, 0);
+    expect(vd!.seq, equals(1));
+
+    // Fails with the winning value once it has moved on
+    await expectLater(
+        () async => rc.setDHTValue(rec.key, 0, utf8.encode('THIRD'),
+            expectedSeq: 0),
+        throwsA(isA<VeilidAPIExceptionConflict>()
+            .having((e) => e.value.data, 'value', utf8.encode('SECOND'))));
+
+    await rc.closeDHTRecord(rec.key);
+    await rc.deleteDHTRecord(rec.key);
+  } finally {
+    rc.close();
+  }
+}
+
 Future<void> testOpenWriterDHTValue() async {
   final rc = await Veilid.instance.routingContext();
   try {
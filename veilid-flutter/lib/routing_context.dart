@@ -288,8 +288,9 @@ abstract class VeilidRoutingContext {
   Future<SafetySelection> safety();
 
   // App call/message
-  Future<Uint8List> appCall(String target, Uint8List request);
-  Future<void> appMessage(String target, Uint8List message);
+  Future<Uint8List> appCall(String target, Uint8List request,
+      {String? channel});
+  Future<void> appMessage(String target, Uint8List message, {String? channel});
 
   // DHT Operations
   Future<DHTRecordDescriptor> createDHTRecord(DHTSchema schema,
@@ -300,7 +301,12 @@ abstract class VeilidRoutingContext {
   Future<ValueData?> getDHTValue(TypedKey key, int subkey,
       {bool forceRefresh = false});
   Future<ValueData?> setDHTValue(TypedKey key, int subkey, Uint8List data,
+      {KeyPair? writer, int? expectedSeq});
+  Future<List<ValueSubkeyRange>> setDHTLargeValue(
+      TypedKey key, int subkey, Uint8List data,
       {KeyPair? writer});
+  Future<Uint8List?> getDHTLargeValue(TypedKey key, int subkey,
+      {bool forceRefresh = false});
   Future<Timestamp> watchDHTValues(TypedKey key,
       {List<ValueSubkeyRange>? subkeys, Timestamp? expiration, int? count});
   Future<bool> cancelDHTWatch(TypedKey key, {List<ValueSubkeyRange>? subkeys});
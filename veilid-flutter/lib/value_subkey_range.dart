@@ -74,6 +74,8 @@ extension ValueSubkeyRangeExt on ValueSubkeyRange {
 extension ListValueSubkeyRangeExt on List<ValueSubkeyRange> {
   static List<ValueSubkeyRange> fromIntPairs(List<(int, int)> x) =>
       x.map(ValueSubkeyRange.fromIntPair).toList();
+  static List<ValueSubkeyRange> fromJson(dynamic json) =>
+      (json as List<dynamic>).map(ValueSubkeyRange.fromJson).toList();
 
   void validate() {
     int? lastHigh;
@@ -1,5 +1,7 @@
 import 'package:freezed_annotation/freezed_annotation.dart';
 
+import 'veilid.dart';
+
 //////////////////////////////////////
 /// VeilidAPIException
 
@@ -68,6 +70,10 @@ abstract class VeilidAPIException implements Exception {
         {
           return VeilidAPIExceptionGeneric(json['message']! as String);
         }
+      case 'Conflict':
+        {
+          return VeilidAPIExceptionConflict(ValueData.fromJson(json['value']));
+        }
       default:
         {
           throw VeilidAPIExceptionInternal(
@@ -249,3 +255,16 @@ class VeilidAPIExceptionGeneric implements VeilidAPIException {
   @override
   String toDisplayError() => message;
 }
+
+@immutable
+class VeilidAPIExceptionConflict implements VeilidAPIException {
+  //
+  const VeilidAPIExceptionConflict(this.value);
+  final ValueData value;
+
+  @override
+  String toString() => 'VeilidAPIException: Conflict (seq: ${value.seq})';
+
+  @override
+  String toDisplayError() => 'Conflict with value at seq ${value.seq}';
+}
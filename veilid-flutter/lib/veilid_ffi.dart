@@ -57,13 +57,13 @@ typedef _RoutingContextWithSequencingDart = int Function(int, Pointer<Utf8>);
 //    id: u32)
 typedef _RoutingContextSafetyDart = void Function(int, int);
 // fn routing_context_app_call(port: i64,
-//    id: u32, target: FfiStr, request: FfiStr)
+//    id: u32, target: FfiStr, request: FfiStr, channel: FfiStr)
 typedef _RoutingContextAppCallDart = void Function(
-    int, int, Pointer<Utf8>, Pointer<Utf8>);
+    int, int, Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>);
 // fn routing_context_app_message(port: i64,
-//    id: u32, target: FfiStr, request: FfiStr)
+//    id: u32, target: FfiStr, request: FfiStr, channel: FfiStr)
 typedef _RoutingContextAppMessageDart = void Function(
-    int, int, Pointer<Utf8>, Pointer<Utf8>);
+    int, int, Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>);
 // fn routing_context_create_dht_record(port: i64,
 //    id: u32, kind: u32, schema: FfiStr)
 typedef _RoutingContextCreateDHTRecordDart = void Function(
@@ -83,9 +83,18 @@ typedef _RoutingContextDeleteDHTRecordDart = void Function(
 typedef _RoutingContextGetDHTValueDart = void Function(
     int, int, Pointer<Utf8>, int, bool);
 // fn routing_context_set_dht_value(port: i64,
+//    id: u32, key: FfiStr, subkey: u32, data: FfiStr, writer: FfiStr,
+//    expected_seq: FfiStr)
+typedef _RoutingContextSetDHTValueDart = void Function(int, int, Pointer<Utf8>,
+    int, Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>);
+// fn routing_context_set_dht_large_value(port: i64,
 //    id: u32, key: FfiStr, subkey: u32, data: FfiStr, writer: FfiStr)
-typedef _RoutingContextSetDHTValueDart = void Function(
+typedef _RoutingContextSetDHTLargeValueDart = void Function(
     int, int, Pointer<Utf8>, int, Pointer<Utf8>, Pointer<Utf8>);
+// fn routing_context_get_dht_large_value(port: i64,
+//    id: u32, key: FfiStr, subkey: u32, force_refresh: bool)
+typedef _RoutingContextGetDHTLargeValueDart = void Function(
+    int, int, Pointer<Utf8>, int, bool);
 // fn routing_context_watch_dht_values(port: i64,
 //     id: u32, key: FfiStr, subkeys: FfiStr, expiration: FfiStr, count: u32)
 typedef _RoutingContextWatchDHTValuesDart = void Function(
@@ -590,29 +599,33 @@ class VeilidRoutingContextFFI extends VeilidRoutingContext {
   }
 
   @override
-  Future<Uint8List> appCall(String target, Uint8List request) async {
+  Future<Uint8List> appCall(String target, Uint8List request,
+      {String? channel}) async {
     _ctx.ensureValid();
     final nativeEncodedTarget = target.toNativeUtf8();
     final nativeEncodedRequest = base64UrlNoPadEncode(request).toNativeUtf8();
+    final nativeChannel = channel != null ? channel.toNativeUtf8() : nullptr;
 
     final recvPort = ReceivePort('routing_context_app_call');
     final sendPort = recvPort.sendPort;
     _ctx.ffi._routingContextAppCall(sendPort.nativePort, _ctx.id!,
-        nativeEncodedTarget, nativeEncodedRequest);
+        nativeEncodedTarget, nativeEncodedRequest, nativeChannel);
     final out = await processFuturePlain<String>(recvPort.first);
     return base64UrlNoPadDecode(out);
   }
 
   @override
-  Future<void> appMessage(String target, Uint8List message) async {
+  Future<void> appMessage(String target, Uint8List message,
+      {String? channel}) async {
     _ctx.ensureValid();
     final nativeEncodedTarget = target.toNativeUtf8();
     final nativeEncodedMessage = base64UrlNoPadEncode(message).toNativeUtf8();
+    final nativeChannel = channel != null ? channel.toNativeUtf8() : nullptr;
 
     final recvPort = ReceivePort('routing_context_app_message');
     final sendPort = recvPort.sendPort;
     _ctx.ffi._routingContextAppMessage(sendPort.nativePort, _ctx.id!,
-        nativeEncodedTarget, nativeEncodedMessage);
+        nativeEncodedTarget, nativeEncodedMessage, nativeChannel);
     return processFutureVoid(recvPort.first);
   }
 
@@ -684,22 +697,57 @@ class VeilidRoutingContextFFI extends VeilidRoutingContext {
 
   @override
   Future<ValueData?> setDHTValue(TypedKey key, int subkey, Uint8List data,
-      {KeyPair? writer}) async {
+      {KeyPair? writer, int? expectedSeq}) async {
     _ctx.ensureValid();
     final nativeKey = jsonEncode(key).toNativeUtf8();
     final nativeData = base64UrlNoPadEncode(data).toNativeUtf8();
     final nativeWriter =
         writer != null ? jsonEncode(writer).toNativeUtf8() : nullptr;
+    final nativeExpectedSeq =
+        expectedSeq != null ? jsonEncode(expectedSeq).toNativeUtf8() : nullptr;
 
     final recvPort = ReceivePort('routing_context_set_dht_value');
     final sendPort = recvPort.sendPort;
     _ctx.ffi._routingContextSetDHTValue(sendPort.nativePort, _ctx.id!,
-        nativeKey, subkey, nativeData, nativeWriter);
+        nativeKey, subkey, nativeData, nativeWriter, nativeExpectedSeq);
     final valueData =
         await processFutureOptJson(ValueData.fromJson, recvPort.first);
     return valueData;
   }
 
+  @override
+  Future<List<ValueSubkeyRange>> setDHTLargeValue(
+      TypedKey key, int subkey, Uint8List data,
+      {KeyPair? writer}) async {
+    _ctx.ensureValid();
+    final nativeKey = jsonEncode(key).toNativeUtf8();
+    final nativeData = base64UrlNoPadEncode(data).toNativeUtf8();
+    final nativeWriter =
+        writer != null ? jsonEncode(writer).toNativeUtf8() : nullptr;
+
+    final recvPort = ReceivePort('routing_context_set_dht_large_value');
+    final sendPort = recvPort.sendPort;
+    _ctx.ffi._routingContextSetDHTLargeValue(sendPort.nativePort, _ctx.id!,
+        nativeKey, subkey, nativeData, nativeWriter);
+    final subkeys = await processFutureJson(
+        ListValueSubkeyRangeExt.fromJson, recvPort.first);
+    return subkeys;
+  }
+
+  @override
+  Future<Uint8List?> getDHTLargeValue(TypedKey key, int subkey,
+      {bool forceRefresh = false}) async {
+    _ctx.ensureValid();
+    final nativeKey = jsonEncode(key).toNativeUtf8();
+    final recvPort = ReceivePort('routing_context_get_dht_large_value');
+    final sendPort = recvPort.sendPort;
+    _ctx.ffi._routingContextGetDHTLargeValue(
+        sendPort.nativePort, _ctx.id!, nativeKey, subkey, forceRefresh);
+    final data =
+        await processFutureOptJson(convertUint8ListFromJson, recvPort.first);
+    return data;
+  }
+
   @override
   Future<Timestamp> watchDHTValues(TypedKey key,
       {List<ValueSubkeyRange>? subkeys,
@@ -1278,10 +1326,12 @@ class VeilidFFI extends Veilid {
             Void Function(Int64, Uint32),
             _RoutingContextSafetyDart>('routing_context_safety'),
         _routingContextAppCall = dylib.lookupFunction<
-            Void Function(Int64, Uint32, Pointer<Utf8>, Pointer<Utf8>),
+            Void Function(
+                Int64, Uint32, Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>),
             _RoutingContextAppCallDart>('routing_context_app_call'),
         _routingContextAppMessage = dylib.lookupFunction<
-            Void Function(Int64, Uint32, Pointer<Utf8>, Pointer<Utf8>),
+            Void Function(
+                Int64, Uint32, Pointer<Utf8>, Pointer<Utf8>, Pointer<Utf8>),
             _RoutingContextAppMessageDart>('routing_context_app_message'),
         _routingContextCreateDHTRecord = dylib.lookupFunction<
                 Void Function(Int64, Uint32, Pointer<Utf8>, Uint32),
@@ -1304,8 +1354,17 @@ class VeilidFFI extends Veilid {
             _RoutingContextGetDHTValueDart>('routing_context_get_dht_value'),
         _routingContextSetDHTValue = dylib.lookupFunction<
             Void Function(Int64, Uint32, Pointer<Utf8>, Uint32, Pointer<Utf8>,
-                Pointer<Utf8>),
+                Pointer<Utf8>, Pointer<Utf8>),
             _RoutingContextSetDHTValueDart>('routing_context_set_dht_value'),
+        _routingContextSetDHTLargeValue = dylib.lookupFunction<
+                Void Function(Int64, Uint32, Pointer<Utf8>, Uint32,
+                    Pointer<Utf8>, Pointer<Utf8>),
+                _RoutingContextSetDHTLargeValueDart>(
+            'routing_context_set_dht_large_value'),
+        _routingContextGetDHTLargeValue = dylib.lookupFunction<
+                Void Function(Int64, Uint32, Pointer<Utf8>, Uint32, Bool),
+                _RoutingContextGetDHTLargeValueDart>(
+            'routing_context_get_dht_large_value'),
         _routingContextWatchDHTValues = dylib.lookupFunction<
                 Void Function(Int64, Uint32, Pointer<Utf8>, Pointer<Utf8>,
                     Uint64, Uint32),
@@ -1504,6 +1563,8 @@ class VeilidFFI extends Veilid {
   final _RoutingContextDeleteDHTRecordDart _routingContextDeleteDHTRecord;
   final _RoutingContextGetDHTValueDart _routingContextGetDHTValue;
   final _RoutingContextSetDHTValueDart _routingContextSetDHTValue;
+  final _RoutingContextSetDHTLargeValueDart _routingContextSetDHTLargeValue;
+  final _RoutingContextGetDHTLargeValueDart _routingContextGetDHTLargeValue;
   final _RoutingContextWatchDHTValuesDart _routingContextWatchDHTValues;
   final _RoutingContextCancelDHTWatchDart _routingContextCancelDHTWatch;
   final _RoutingContextInspectDHTRecordDart _routingContextInspectDHTRecord;
@@ -115,21 +115,23 @@ class VeilidRoutingContextJS extends VeilidRoutingContext {
   }
 
   @override
-  Future<Uint8List> appCall(String target, Uint8List request) async {
+  Future<Uint8List> appCall(String target, Uint8List request,
+      {String? channel}) async {
     final id = _ctx.requireId();
     final encodedRequest = base64UrlNoPadEncode(request);
 
-    return base64UrlNoPadDecode(await _wrapApiPromise(js_util.callMethod(
-        wasm, 'routing_context_app_call', [id, target, encodedRequest])));
+    return base64UrlNoPadDecode(await _wrapApiPromise(js_util.callMethod(wasm,
+        'routing_context_app_call', [id, target, encodedRequest, channel])));
   }
 
   @override
-  Future<void> appMessage(String target, Uint8List message) {
+  Future<void> appMessage(String target, Uint8List message,
+      {String? channel}) {
     final id = _ctx.requireId();
     final encodedMessage = base64UrlNoPadEncode(message);
 
-    return _wrapApiPromise(js_util.callMethod(
-        wasm, 'routing_context_app_message', [id, target, encodedMessage]));
+    return _wrapApiPromise(js_util.callMethod(wasm,
+        'routing_context_app_message', [id, target, encodedMessage, channel]));
   }
 
   @override
@@ -184,7 +186,7 @@ class VeilidRoutingContextJS extends VeilidRoutingContext {
 
   @override
   Future<ValueData?> setDHTValue(TypedKey key, int subkey, Uint8List data,
-      {KeyPair? writer}) async {
+      {KeyPair? writer, int? expectedSeq}) async {
     final id = _ctx.requireId();
     final opt = await _wrapApiPromise<String?>(
         js_util.callMethod(wasm, 'routing_context_set_dht_value', [
@@ -192,7 +194,8 @@ class VeilidRoutingContextJS extends VeilidRoutingContext {
       jsonEncode(key),
       subkey,
       base64UrlNoPadEncode(data),
-      if (writer != null) jsonEncode(writer) else null
+      if (writer != null) jsonEncode(writer) else null,
+      expectedSeq
     ]));
     if (opt == null) {
       return null;
@@ -201,6 +204,36 @@ class VeilidRoutingContextJS extends VeilidRoutingContext {
     return jsonOpt == null ? null : ValueData.fromJson(jsonOpt);
   }
 
+  @override
+  Future<List<ValueSubkeyRange>> setDHTLargeValue(
+      TypedKey key, int subkey, Uint8List data,
+      {KeyPair? writer}) async {
+    final id = _ctx.requireId();
+    return ListValueSubkeyRangeExt.fromJson(jsonDecode(await _wrapApiPromise(
+        js_util.callMethod(wasm, 'routing_context_set_dht_large_value', [
+      id,
+      jsonEncode(key),
+      subkey,
+      base64UrlNoPadEncode(data),
+      if (writer != null) jsonEncode(writer) else null
+    ]))));
+  }
+
+  @override
+  Future<Uint8List?> getDHTLargeValue(TypedKey key, int subkey,
+      {bool forceRefresh = false}) async {
+    final id = _ctx.requireId();
+    final opt = await _wrapApiPromise<String?>(js_util.callMethod(
+        wasm,
+        'routing_context_get_dht_large_value',
+        [id, jsonEncode(key), subkey, forceRefresh]));
+    if (opt == null) {
+      return null;
+    }
+    final jsonOpt = jsonDecode(opt);
+    return jsonOpt == null ? null : base64UrlNoPadDecode(jsonOpt as String);
+  }
+
   @override
   Future<Timestamp> watchDHTValues(TypedKey key,
       {List<ValueSubkeyRange>? subkeys,
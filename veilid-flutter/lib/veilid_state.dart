@@ -176,6 +176,12 @@ sealed class VeilidUpdate with _$VeilidUpdate {
     required int count,
     required ValueData? value,
   }) = VeilidUpdateValueChange;
+  const factory VeilidUpdate.offlineWrite({
+    required TypedKey key,
+    required int subkey,
+    required ValueData value,
+    ValueData? supersededBy,
+  }) = VeilidUpdateOfflineWrite;
 
   factory VeilidUpdate.fromJson(dynamic json) =>
       _$VeilidUpdateFromJson(json as Map<String, dynamic>);
@@ -1324,6 +1324,8 @@ VeilidUpdate _$VeilidUpdateFromJson(Map<String, dynamic> json) {
       return VeilidUpdateRouteChange.fromJson(json);
     case 'ValueChange':
       return VeilidUpdateValueChange.fromJson(json);
+    case 'OfflineWrite':
+      return VeilidUpdateOfflineWrite.fromJson(json);
 
     default:
       throw CheckedFromJsonException(json, 'kind', 'VeilidUpdate',
@@ -1362,6 +1364,9 @@ mixin _$VeilidUpdate {
     required TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)
         valueChange,
+    required TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)
+        offlineWrite,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -1390,6 +1395,9 @@ mixin _$VeilidUpdate {
     TResult? Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult? Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -1418,6 +1426,9 @@ mixin _$VeilidUpdate {
     TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
@@ -1431,6 +1442,7 @@ mixin _$VeilidUpdate {
     required TResult Function(VeilidUpdateConfig value) config,
     required TResult Function(VeilidUpdateRouteChange value) routeChange,
     required TResult Function(VeilidUpdateValueChange value) valueChange,
+    required TResult Function(VeilidUpdateOfflineWrite value) offlineWrite,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -1443,6 +1455,7 @@ mixin _$VeilidUpdate {
     TResult? Function(VeilidUpdateConfig value)? config,
     TResult? Function(VeilidUpdateRouteChange value)? routeChange,
     TResult? Function(VeilidUpdateValueChange value)? valueChange,
+    TResult? Function(VeilidUpdateOfflineWrite value)? offlineWrite,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -1455,6 +1468,7 @@ mixin _$VeilidUpdate {
     TResult Function(VeilidUpdateConfig value)? config,
     TResult Function(VeilidUpdateRouteChange value)? routeChange,
     TResult Function(VeilidUpdateValueChange value)? valueChange,
+    TResult Function(VeilidUpdateOfflineWrite value)? offlineWrite,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
@@ -1600,6 +1614,9 @@ class _$VeilidLogImpl implements VeilidLog {
     required TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)
         valueChange,
+    required TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)
+        offlineWrite,
   }) {
     return log(logLevel, message, backtrace);
   }
@@ -1631,6 +1648,9 @@ class _$VeilidLogImpl implements VeilidLog {
     TResult? Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult? Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
   }) {
     return log?.call(logLevel, message, backtrace);
   }
@@ -1662,6 +1682,9 @@ class _$VeilidLogImpl implements VeilidLog {
     TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
     required TResult orElse(),
   }) {
     if (log != null) {
@@ -1681,6 +1704,7 @@ class _$VeilidLogImpl implements VeilidLog {
     required TResult Function(VeilidUpdateConfig value) config,
     required TResult Function(VeilidUpdateRouteChange value) routeChange,
     required TResult Function(VeilidUpdateValueChange value) valueChange,
+    required TResult Function(VeilidUpdateOfflineWrite value) offlineWrite,
   }) {
     return log(this);
   }
@@ -1696,6 +1720,7 @@ class _$VeilidLogImpl implements VeilidLog {
     TResult? Function(VeilidUpdateConfig value)? config,
     TResult? Function(VeilidUpdateRouteChange value)? routeChange,
     TResult? Function(VeilidUpdateValueChange value)? valueChange,
+    TResult? Function(VeilidUpdateOfflineWrite value)? offlineWrite,
   }) {
     return log?.call(this);
   }
@@ -1711,6 +1736,7 @@ class _$VeilidLogImpl implements VeilidLog {
     TResult Function(VeilidUpdateConfig value)? config,
     TResult Function(VeilidUpdateRouteChange value)? routeChange,
     TResult Function(VeilidUpdateValueChange value)? valueChange,
+    TResult Function(VeilidUpdateOfflineWrite value)? offlineWrite,
     required TResult orElse(),
   }) {
     if (log != null) {
@@ -1869,6 +1895,9 @@ class _$VeilidAppMessageImpl implements VeilidAppMessage {
     required TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)
         valueChange,
+    required TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)
+        offlineWrite,
   }) {
     return appMessage(message, sender, routeId);
   }
@@ -1900,6 +1929,9 @@ class _$VeilidAppMessageImpl implements VeilidAppMessage {
     TResult? Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult? Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
   }) {
     return appMessage?.call(message, sender, routeId);
   }
@@ -1931,6 +1963,9 @@ class _$VeilidAppMessageImpl implements VeilidAppMessage {
     TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
     required TResult orElse(),
   }) {
     if (appMessage != null) {
@@ -1950,6 +1985,7 @@ class _$VeilidAppMessageImpl implements VeilidAppMessage {
     required TResult Function(VeilidUpdateConfig value) config,
     required TResult Function(VeilidUpdateRouteChange value) routeChange,
     required TResult Function(VeilidUpdateValueChange value) valueChange,
+    required TResult Function(VeilidUpdateOfflineWrite value) offlineWrite,
   }) {
     return appMessage(this);
   }
@@ -1965,6 +2001,7 @@ class _$VeilidAppMessageImpl implements VeilidAppMessage {
     TResult? Function(VeilidUpdateConfig value)? config,
     TResult? Function(VeilidUpdateRouteChange value)? routeChange,
     TResult? Function(VeilidUpdateValueChange value)? valueChange,
+    TResult? Function(VeilidUpdateOfflineWrite value)? offlineWrite,
   }) {
     return appMessage?.call(this);
   }
@@ -1980,6 +2017,7 @@ class _$VeilidAppMessageImpl implements VeilidAppMessage {
     TResult Function(VeilidUpdateConfig value)? config,
     TResult Function(VeilidUpdateRouteChange value)? routeChange,
     TResult Function(VeilidUpdateValueChange value)? valueChange,
+    TResult Function(VeilidUpdateOfflineWrite value)? offlineWrite,
     required TResult orElse(),
   }) {
     if (appMessage != null) {
@@ -2148,6 +2186,9 @@ class _$VeilidAppCallImpl implements VeilidAppCall {
     required TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)
         valueChange,
+    required TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)
+        offlineWrite,
   }) {
     return appCall(message, callId, sender, routeId);
   }
@@ -2179,6 +2220,9 @@ class _$VeilidAppCallImpl implements VeilidAppCall {
     TResult? Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult? Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
   }) {
     return appCall?.call(message, callId, sender, routeId);
   }
@@ -2210,6 +2254,9 @@ class _$VeilidAppCallImpl implements VeilidAppCall {
     TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
     required TResult orElse(),
   }) {
     if (appCall != null) {
@@ -2229,6 +2276,7 @@ class _$VeilidAppCallImpl implements VeilidAppCall {
     required TResult Function(VeilidUpdateConfig value) config,
     required TResult Function(VeilidUpdateRouteChange value) routeChange,
     required TResult Function(VeilidUpdateValueChange value) valueChange,
+    required TResult Function(VeilidUpdateOfflineWrite value) offlineWrite,
   }) {
     return appCall(this);
   }
@@ -2244,6 +2292,7 @@ class _$VeilidAppCallImpl implements VeilidAppCall {
     TResult? Function(VeilidUpdateConfig value)? config,
     TResult? Function(VeilidUpdateRouteChange value)? routeChange,
     TResult? Function(VeilidUpdateValueChange value)? valueChange,
+    TResult? Function(VeilidUpdateOfflineWrite value)? offlineWrite,
   }) {
     return appCall?.call(this);
   }
@@ -2259,6 +2308,7 @@ class _$VeilidAppCallImpl implements VeilidAppCall {
     TResult Function(VeilidUpdateConfig value)? config,
     TResult Function(VeilidUpdateRouteChange value)? routeChange,
     TResult Function(VeilidUpdateValueChange value)? valueChange,
+    TResult Function(VeilidUpdateOfflineWrite value)? offlineWrite,
     required TResult orElse(),
   }) {
     if (appCall != null) {
@@ -2423,6 +2473,9 @@ class _$VeilidUpdateAttachmentImpl implements VeilidUpdateAttachment {
     required TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)
         valueChange,
+    required TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)
+        offlineWrite,
   }) {
     return attachment(state, publicInternetReady, localNetworkReady);
   }
@@ -2454,6 +2507,9 @@ class _$VeilidUpdateAttachmentImpl implements VeilidUpdateAttachment {
     TResult? Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult? Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
   }) {
     return attachment?.call(state, publicInternetReady, localNetworkReady);
   }
@@ -2485,6 +2541,9 @@ class _$VeilidUpdateAttachmentImpl implements VeilidUpdateAttachment {
     TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
     required TResult orElse(),
   }) {
     if (attachment != null) {
@@ -2504,6 +2563,7 @@ class _$VeilidUpdateAttachmentImpl implements VeilidUpdateAttachment {
     required TResult Function(VeilidUpdateConfig value) config,
     required TResult Function(VeilidUpdateRouteChange value) routeChange,
     required TResult Function(VeilidUpdateValueChange value) valueChange,
+    required TResult Function(VeilidUpdateOfflineWrite value) offlineWrite,
   }) {
     return attachment(this);
   }
@@ -2519,6 +2579,7 @@ class _$VeilidUpdateAttachmentImpl implements VeilidUpdateAttachment {
     TResult? Function(VeilidUpdateConfig value)? config,
     TResult? Function(VeilidUpdateRouteChange value)? routeChange,
     TResult? Function(VeilidUpdateValueChange value)? valueChange,
+    TResult? Function(VeilidUpdateOfflineWrite value)? offlineWrite,
   }) {
     return attachment?.call(this);
   }
@@ -2534,6 +2595,7 @@ class _$VeilidUpdateAttachmentImpl implements VeilidUpdateAttachment {
     TResult Function(VeilidUpdateConfig value)? config,
     TResult Function(VeilidUpdateRouteChange value)? routeChange,
     TResult Function(VeilidUpdateValueChange value)? valueChange,
+    TResult Function(VeilidUpdateOfflineWrite value)? offlineWrite,
     required TResult orElse(),
   }) {
     if (attachment != null) {
@@ -2704,6 +2766,9 @@ class _$VeilidUpdateNetworkImpl implements VeilidUpdateNetwork {
     required TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)
         valueChange,
+    required TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)
+        offlineWrite,
   }) {
     return network(started, bpsDown, bpsUp, peers);
   }
@@ -2735,6 +2800,9 @@ class _$VeilidUpdateNetworkImpl implements VeilidUpdateNetwork {
     TResult? Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult? Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
   }) {
     return network?.call(started, bpsDown, bpsUp, peers);
   }
@@ -2766,6 +2834,9 @@ class _$VeilidUpdateNetworkImpl implements VeilidUpdateNetwork {
     TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
     required TResult orElse(),
   }) {
     if (network != null) {
@@ -2785,6 +2856,7 @@ class _$VeilidUpdateNetworkImpl implements VeilidUpdateNetwork {
     required TResult Function(VeilidUpdateConfig value) config,
     required TResult Function(VeilidUpdateRouteChange value) routeChange,
     required TResult Function(VeilidUpdateValueChange value) valueChange,
+    required TResult Function(VeilidUpdateOfflineWrite value) offlineWrite,
   }) {
     return network(this);
   }
@@ -2800,6 +2872,7 @@ class _$VeilidUpdateNetworkImpl implements VeilidUpdateNetwork {
     TResult? Function(VeilidUpdateConfig value)? config,
     TResult? Function(VeilidUpdateRouteChange value)? routeChange,
     TResult? Function(VeilidUpdateValueChange value)? valueChange,
+    TResult? Function(VeilidUpdateOfflineWrite value)? offlineWrite,
   }) {
     return network?.call(this);
   }
@@ -2815,6 +2888,7 @@ class _$VeilidUpdateNetworkImpl implements VeilidUpdateNetwork {
     TResult Function(VeilidUpdateConfig value)? config,
     TResult Function(VeilidUpdateRouteChange value)? routeChange,
     TResult Function(VeilidUpdateValueChange value)? valueChange,
+    TResult Function(VeilidUpdateOfflineWrite value)? offlineWrite,
     required TResult orElse(),
   }) {
     if (network != null) {
@@ -2960,6 +3034,9 @@ class _$VeilidUpdateConfigImpl implements VeilidUpdateConfig {
     required TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)
         valueChange,
+    required TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)
+        offlineWrite,
   }) {
     return config(this.config);
   }
@@ -2991,6 +3068,9 @@ class _$VeilidUpdateConfigImpl implements VeilidUpdateConfig {
     TResult? Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult? Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
   }) {
     return config?.call(this.config);
   }
@@ -3022,6 +3102,9 @@ class _$VeilidUpdateConfigImpl implements VeilidUpdateConfig {
     TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
     required TResult orElse(),
   }) {
     if (config != null) {
@@ -3041,6 +3124,7 @@ class _$VeilidUpdateConfigImpl implements VeilidUpdateConfig {
     required TResult Function(VeilidUpdateConfig value) config,
     required TResult Function(VeilidUpdateRouteChange value) routeChange,
     required TResult Function(VeilidUpdateValueChange value) valueChange,
+    required TResult Function(VeilidUpdateOfflineWrite value) offlineWrite,
   }) {
     return config(this);
   }
@@ -3056,6 +3140,7 @@ class _$VeilidUpdateConfigImpl implements VeilidUpdateConfig {
     TResult? Function(VeilidUpdateConfig value)? config,
     TResult? Function(VeilidUpdateRouteChange value)? routeChange,
     TResult? Function(VeilidUpdateValueChange value)? valueChange,
+    TResult? Function(VeilidUpdateOfflineWrite value)? offlineWrite,
   }) {
     return config?.call(this);
   }
@@ -3071,6 +3156,7 @@ class _$VeilidUpdateConfigImpl implements VeilidUpdateConfig {
     TResult Function(VeilidUpdateConfig value)? config,
     TResult Function(VeilidUpdateRouteChange value)? routeChange,
     TResult Function(VeilidUpdateValueChange value)? valueChange,
+    TResult Function(VeilidUpdateOfflineWrite value)? offlineWrite,
     required TResult orElse(),
   }) {
     if (config != null) {
@@ -3232,6 +3318,9 @@ class _$VeilidUpdateRouteChangeImpl implements VeilidUpdateRouteChange {
     required TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)
         valueChange,
+    required TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)
+        offlineWrite,
   }) {
     return routeChange(deadRoutes, deadRemoteRoutes);
   }
@@ -3263,6 +3352,9 @@ class _$VeilidUpdateRouteChangeImpl implements VeilidUpdateRouteChange {
     TResult? Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult? Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
   }) {
     return routeChange?.call(deadRoutes, deadRemoteRoutes);
   }
@@ -3294,6 +3386,9 @@ class _$VeilidUpdateRouteChangeImpl implements VeilidUpdateRouteChange {
     TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
     required TResult orElse(),
   }) {
     if (routeChange != null) {
@@ -3313,6 +3408,7 @@ class _$VeilidUpdateRouteChangeImpl implements VeilidUpdateRouteChange {
     required TResult Function(VeilidUpdateConfig value) config,
     required TResult Function(VeilidUpdateRouteChange value) routeChange,
     required TResult Function(VeilidUpdateValueChange value) valueChange,
+    required TResult Function(VeilidUpdateOfflineWrite value) offlineWrite,
   }) {
     return routeChange(this);
   }
@@ -3328,6 +3424,7 @@ class _$VeilidUpdateRouteChangeImpl implements VeilidUpdateRouteChange {
     TResult? Function(VeilidUpdateConfig value)? config,
     TResult? Function(VeilidUpdateRouteChange value)? routeChange,
     TResult? Function(VeilidUpdateValueChange value)? valueChange,
+    TResult? Function(VeilidUpdateOfflineWrite value)? offlineWrite,
   }) {
     return routeChange?.call(this);
   }
@@ -3343,6 +3440,7 @@ class _$VeilidUpdateRouteChangeImpl implements VeilidUpdateRouteChange {
     TResult Function(VeilidUpdateConfig value)? config,
     TResult Function(VeilidUpdateRouteChange value)? routeChange,
     TResult Function(VeilidUpdateValueChange value)? valueChange,
+    TResult Function(VeilidUpdateOfflineWrite value)? offlineWrite,
     required TResult orElse(),
   }) {
     if (routeChange != null) {
@@ -3532,6 +3630,9 @@ class _$VeilidUpdateValueChangeImpl implements VeilidUpdateValueChange {
     required TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)
         valueChange,
+    required TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)
+        offlineWrite,
   }) {
     return valueChange(key, subkeys, count, value);
   }
@@ -3563,6 +3664,9 @@ class _$VeilidUpdateValueChangeImpl implements VeilidUpdateValueChange {
     TResult? Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult? Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
   }) {
     return valueChange?.call(key, subkeys, count, value);
   }
@@ -3594,6 +3698,9 @@ class _$VeilidUpdateValueChangeImpl implements VeilidUpdateValueChange {
     TResult Function(Typed<FixedEncodedString43> key,
             List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
         valueChange,
+    TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
     required TResult orElse(),
   }) {
     if (valueChange != null) {
@@ -3613,6 +3720,7 @@ class _$VeilidUpdateValueChangeImpl implements VeilidUpdateValueChange {
     required TResult Function(VeilidUpdateConfig value) config,
     required TResult Function(VeilidUpdateRouteChange value) routeChange,
     required TResult Function(VeilidUpdateValueChange value) valueChange,
+    required TResult Function(VeilidUpdateOfflineWrite value) offlineWrite,
   }) {
     return valueChange(this);
   }
@@ -3628,6 +3736,7 @@ class _$VeilidUpdateValueChangeImpl implements VeilidUpdateValueChange {
     TResult? Function(VeilidUpdateConfig value)? config,
     TResult? Function(VeilidUpdateRouteChange value)? routeChange,
     TResult? Function(VeilidUpdateValueChange value)? valueChange,
+    TResult? Function(VeilidUpdateOfflineWrite value)? offlineWrite,
   }) {
     return valueChange?.call(this);
   }
@@ -3643,6 +3752,7 @@ class _$VeilidUpdateValueChangeImpl implements VeilidUpdateValueChange {
     TResult Function(VeilidUpdateConfig value)? config,
     TResult Function(VeilidUpdateRouteChange value)? routeChange,
     TResult Function(VeilidUpdateValueChange value)? valueChange,
+    TResult Function(VeilidUpdateOfflineWrite value)? offlineWrite,
     required TResult orElse(),
   }) {
     if (valueChange != null) {
@@ -3678,6 +3788,324 @@ abstract class VeilidUpdateValueChange implements VeilidUpdate {
       get copyWith => throw _privateConstructorUsedError;
 }
 
+/// @nodoc
+abstract class _$$VeilidUpdateOfflineWriteImplCopyWith<$Res> {
+  factory _$$VeilidUpdateOfflineWriteImplCopyWith(
+          _$VeilidUpdateOfflineWriteImpl value,
+          $Res Function(_$VeilidUpdateOfflineWriteImpl) then) =
+      __$$VeilidUpdateOfflineWriteImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call(
+      {Typed<FixedEncodedString43> key,
+      int subkey,
+      ValueData value,
+      ValueData? supersededBy});
+
+  $ValueDataCopyWith<$Res> get value;
+  $ValueDataCopyWith<$Res>? get supersededBy;
+}
+
+/// @nodoc
+class __$$VeilidUpdateOfflineWriteImplCopyWithImpl<$Res>
+    extends _$VeilidUpdateCopyWithImpl<$Res, _$VeilidUpdateOfflineWriteImpl>
+    implements _$$VeilidUpdateOfflineWriteImplCopyWith<$Res> {
+  __$$VeilidUpdateOfflineWriteImplCopyWithImpl(
+      _$VeilidUpdateOfflineWriteImpl _value,
+      $Res Function(_$VeilidUpdateOfflineWriteImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? key = null,
+    Object? subkey = null,
+    Object? value = null,
+    Object? supersededBy = freezed,
+  }) {
+    return _then(_$VeilidUpdateOfflineWriteImpl(
+      key: null == key
+          ? _value.key
+          : key // ignore: cast_nullable_to_non_nullable
+              as Typed<FixedEncodedString43>,
+      subkey: null == subkey
+          ? _value.subkey
+          : subkey // ignore: cast_nullable_to_non_nullable
+              as int,
+      value: null == value
+          ? _value.value
+          : value // ignore: cast_nullable_to_non_nullable
+              as ValueData,
+      supersededBy: freezed == supersededBy
+          ? _value.supersededBy
+          : supersededBy // ignore: cast_nullable_to_non_nullable
+              as ValueData?,
+    ));
+  }
+
+  @override
+  @pragma('vm:prefer-inline')
+  $ValueDataCopyWith<$Res> get value {
+    return $ValueDataCopyWith<$Res>(_value.value, (value) {
+      return _then(_value.copyWith(value: value));
+    });
+  }
+
+  @override
+  @pragma('vm:prefer-inline')
+  $ValueDataCopyWith<$Res>? get supersededBy {
+    if (_value.supersededBy == null) {
+      return null;
+    }
+
+    return $ValueDataCopyWith<$Res>(_value.supersededBy!, (value) {
+      return _then(_value.copyWith(supersededBy: value));
+    });
+  }
+}
+
+/// @nodoc
+@JsonSerializable()
+class _$VeilidUpdateOfflineWriteImpl implements VeilidUpdateOfflineWrite {
+  const _$VeilidUpdateOfflineWriteImpl(
+      {required this.key,
+      required this.subkey,
+      required this.value,
+      this.supersededBy,
+      final String? $type})
+      : $type = $type ?? 'OfflineWrite';
+
+  factory _$VeilidUpdateOfflineWriteImpl.fromJson(Map<String, dynamic> json) =>
+      _$$VeilidUpdateOfflineWriteImplFromJson(json);
+
+  @override
+  final Typed<FixedEncodedString43> key;
+  @override
+  final int subkey;
+  @override
+  final ValueData value;
+  @override
+  final ValueData? supersededBy;
+
+  @JsonKey(name: 'kind')
+  final String $type;
+
+  @override
+  String toString() {
+    return 'VeilidUpdate.offlineWrite(key: $key, subkey: $subkey, value: $value, supersededBy: $supersededBy)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VeilidUpdateOfflineWriteImpl &&
+            (identical(other.key, key) || other.key == key) &&
+            (identical(other.subkey, subkey) || other.subkey == subkey) &&
+            (identical(other.value, value) || other.value == value) &&
+            (identical(other.supersededBy, supersededBy) ||
+                other.supersededBy == supersededBy));
+  }
+
+  @JsonKey(ignore: true)
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, key, subkey, value, supersededBy);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$VeilidUpdateOfflineWriteImplCopyWith<_$VeilidUpdateOfflineWriteImpl>
+      get copyWith => __$$VeilidUpdateOfflineWriteImplCopyWithImpl<
+          _$VeilidUpdateOfflineWriteImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(
+            VeilidLogLevel logLevel, String message, String? backtrace)
+        log,
+    required TResult Function(
+            @Uint8ListJsonConverter.jsIsArray() Uint8List message,
+            Typed<FixedEncodedString43>? sender,
+            String? routeId)
+        appMessage,
+    required TResult Function(
+            @Uint8ListJsonConverter.jsIsArray() Uint8List message,
+            String callId,
+            Typed<FixedEncodedString43>? sender,
+            String? routeId)
+        appCall,
+    required TResult Function(AttachmentState state, bool publicInternetReady,
+            bool localNetworkReady)
+        attachment,
+    required TResult Function(bool started, BigInt bpsDown, BigInt bpsUp,
+            List<PeerTableData> peers)
+        network,
+    required TResult Function(VeilidConfig config) config,
+    required TResult Function(
+            List<String> deadRoutes, List<String> deadRemoteRoutes)
+        routeChange,
+    required TResult Function(Typed<FixedEncodedString43> key,
+            List<ValueSubkeyRange> subkeys, int count, ValueData? value)
+        valueChange,
+    required TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)
+        offlineWrite,
+  }) {
+    return offlineWrite(key, subkey, value, supersededBy);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(
+            VeilidLogLevel logLevel, String message, String? backtrace)?
+        log,
+    TResult? Function(@Uint8ListJsonConverter.jsIsArray() Uint8List message,
+            Typed<FixedEncodedString43>? sender, String? routeId)?
+        appMessage,
+    TResult? Function(
+            @Uint8ListJsonConverter.jsIsArray() Uint8List message,
+            String callId,
+            Typed<FixedEncodedString43>? sender,
+            String? routeId)?
+        appCall,
+    TResult? Function(AttachmentState state, bool publicInternetReady,
+            bool localNetworkReady)?
+        attachment,
+    TResult? Function(bool started, BigInt bpsDown, BigInt bpsUp,
+            List<PeerTableData> peers)?
+        network,
+    TResult? Function(VeilidConfig config)? config,
+    TResult? Function(List<String> deadRoutes, List<String> deadRemoteRoutes)?
+        routeChange,
+    TResult? Function(Typed<FixedEncodedString43> key,
+            List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
+        valueChange,
+    TResult? Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
+  }) {
+    return offlineWrite?.call(key, subkey, value, supersededBy);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(
+            VeilidLogLevel logLevel, String message, String? backtrace)?
+        log,
+    TResult Function(@Uint8ListJsonConverter.jsIsArray() Uint8List message,
+            Typed<FixedEncodedString43>? sender, String? routeId)?
+        appMessage,
+    TResult Function(
+            @Uint8ListJsonConverter.jsIsArray() Uint8List message,
+            String callId,
+            Typed<FixedEncodedString43>? sender,
+            String? routeId)?
+        appCall,
+    TResult Function(AttachmentState state, bool publicInternetReady,
+            bool localNetworkReady)?
+        attachment,
+    TResult Function(bool started, BigInt bpsDown, BigInt bpsUp,
+            List<PeerTableData> peers)?
+        network,
+    TResult Function(VeilidConfig config)? config,
+    TResult Function(List<String> deadRoutes, List<String> deadRemoteRoutes)?
+        routeChange,
+    TResult Function(Typed<FixedEncodedString43> key,
+            List<ValueSubkeyRange> subkeys, int count, ValueData? value)?
+        valueChange,
+    TResult Function(Typed<FixedEncodedString43> key, int subkey,
+            ValueData value, ValueData? supersededBy)?
+        offlineWrite,
+    required TResult orElse(),
+  }) {
+    if (valueChange != null) {
+      return offlineWrite(key, subkey, value, supersededBy);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VeilidLog value) log,
+    required TResult Function(VeilidAppMessage value) appMessage,
+    required TResult Function(VeilidAppCall value) appCall,
+    required TResult Function(VeilidUpdateAttachment value) attachment,
+    required TResult Function(VeilidUpdateNetwork value) network,
+    required TResult Function(VeilidUpdateConfig value) config,
+    required TResult Function(VeilidUpdateRouteChange value) routeChange,
+    required TResult Function(VeilidUpdateValueChange value) valueChange,
+    required TResult Function(VeilidUpdateOfflineWrite value) offlineWrite,
+  }) {
+    return offlineWrite(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VeilidLog value)? log,
+    TResult? Function(VeilidAppMessage value)? appMessage,
+    TResult? Function(VeilidAppCall value)? appCall,
+    TResult? Function(VeilidUpdateAttachment value)? attachment,
+    TResult? Function(VeilidUpdateNetwork value)? network,
+    TResult? Function(VeilidUpdateConfig value)? config,
+    TResult? Function(VeilidUpdateRouteChange value)? routeChange,
+    TResult? Function(VeilidUpdateValueChange value)? valueChange,
+    TResult? Function(VeilidUpdateOfflineWrite value)? offlineWrite,
+  }) {
+    return offlineWrite?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VeilidLog value)? log,
+    TResult Function(VeilidAppMessage value)? appMessage,
+    TResult Function(VeilidAppCall value)? appCall,
+    TResult Function(VeilidUpdateAttachment value)? attachment,
+    TResult Function(VeilidUpdateNetwork value)? network,
+    TResult Function(VeilidUpdateConfig value)? config,
+    TResult Function(VeilidUpdateRouteChange value)? routeChange,
+    TResult Function(VeilidUpdateValueChange value)? valueChange,
+    TResult Function(VeilidUpdateOfflineWrite value)? offlineWrite,
+    required TResult orElse(),
+  }) {
+    if (valueChange != null) {
+      return offlineWrite(this);
+    }
+    return orElse();
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return _$$VeilidUpdateOfflineWriteImplToJson(
+      this,
+    );
+  }
+}
+
+abstract class VeilidUpdateOfflineWrite implements VeilidUpdate {
+  const factory VeilidUpdateOfflineWrite(
+      {required final Typed<FixedEncodedString43> key,
+      required final int subkey,
+      required final ValueData value,
+      final ValueData? supersededBy}) = _$VeilidUpdateOfflineWriteImpl;
+
+  factory VeilidUpdateOfflineWrite.fromJson(Map<String, dynamic> json) =
+      _$VeilidUpdateOfflineWriteImpl.fromJson;
+
+  Typed<FixedEncodedString43> get key;
+  int get subkey;
+  ValueData get value;
+  ValueData? get supersededBy;
+  @JsonKey(ignore: true)
+  _$$VeilidUpdateOfflineWriteImplCopyWith<_$VeilidUpdateOfflineWriteImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
 VeilidStateAttachment _$VeilidStateAttachmentFromJson(
     Map<String, dynamic> json) {
   return _VeilidStateAttachment.fromJson(json);
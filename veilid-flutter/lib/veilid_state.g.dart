@@ -269,6 +269,28 @@ Map<String, dynamic> _$$VeilidUpdateValueChangeImplToJson(
       'kind': instance.$type,
     };
 
+_$VeilidUpdateOfflineWriteImpl _$$VeilidUpdateOfflineWriteImplFromJson(
+        Map<String, dynamic> json) =>
+    _$VeilidUpdateOfflineWriteImpl(
+      key: Typed<FixedEncodedString43>.fromJson(json['key']),
+      subkey: (json['subkey'] as num).toInt(),
+      value: ValueData.fromJson(json['value']),
+      supersededBy: json['superseded_by'] == null
+          ? null
+          : ValueData.fromJson(json['superseded_by']),
+      $type: json['kind'] as String?,
+    );
+
+Map<String, dynamic> _$$VeilidUpdateOfflineWriteImplToJson(
+        _$VeilidUpdateOfflineWriteImpl instance) =>
+    <String, dynamic>{
+      'key': instance.key.toJson(),
+      'subkey': instance.subkey,
+      'value': instance.value.toJson(),
+      'superseded_by': instance.supersededBy?.toJson(),
+      'kind': instance.$type,
+    };
+
 _$VeilidStateAttachmentImpl _$$VeilidStateAttachmentImplFromJson(
         Map<String, dynamic> json) =>
     _$VeilidStateAttachmentImpl(
@@ -117,6 +117,7 @@ class DefaultVeilidFixture implements VeilidFixture {
       } else if (update is VeilidAppCall) {
       } else if (update is VeilidUpdateValueChange) {
       } else if (update is VeilidUpdateRouteChange) {
+      } else if (update is VeilidUpdateOfflineWrite) {
       } else {
         throw Exception('unexpected update: $update');
       }
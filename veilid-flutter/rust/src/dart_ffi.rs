@@ -613,18 +613,33 @@ pub extern "C" fn routing_context_safety(port: i64, id: u32) {
 
 #[no_mangle]
 #[instrument(level = "trace", target = "ffi", skip_all)]
-pub extern "C" fn routing_context_app_call(port: i64, id: u32, target: FfiStr, request: FfiStr) {
+pub extern "C" fn routing_context_app_call(
+    port: i64,
+    id: u32,
+    target: FfiStr,
+    request: FfiStr,
+    channel: FfiStr,
+) {
     let target_string: String = target.into_opt_string().unwrap();
     let request: Vec<u8> = data_encoding::BASE64URL_NOPAD
         .decode(request.into_opt_string().unwrap().as_bytes())
         .unwrap();
+    let channel: Option<String> = channel.into_opt_string();
     DartIsolateWrapper::new(port).spawn_result(
         async move {
             let routing_context = get_routing_context(id, "routing_context_app_call")?;
 
+            let channel = channel.map(veilid_core::AppChannel::try_from).transpose()?;
             let veilid_api = get_veilid_api().await?;
             let target = veilid_api.parse_as_target(target_string)?;
-            let answer = routing_context.app_call(target, request).await?;
+            let answer = match channel {
+                Some(channel) => {
+                    routing_context
+                        .app_call_on_channel(target, channel, request)
+                        .await?
+                }
+                None => routing_context.app_call(target, request).await?,
+            };
             let answer = data_encoding::BASE64URL_NOPAD.encode(&answer);
             APIResult::Ok(answer)
         }
@@ -634,18 +649,33 @@ pub extern "C" fn routing_context_app_call(port: i64, id: u32, target: FfiStr, r
 
 #[no_mangle]
 #[instrument(level = "trace", target = "ffi", skip_all)]
-pub extern "C" fn routing_context_app_message(port: i64, id: u32, target: FfiStr, message: FfiStr) {
+pub extern "C" fn routing_context_app_message(
+    port: i64,
+    id: u32,
+    target: FfiStr,
+    message: FfiStr,
+    channel: FfiStr,
+) {
     let target_string: String = target.into_opt_string().unwrap();
     let message: Vec<u8> = data_encoding::BASE64URL_NOPAD
         .decode(message.into_opt_string().unwrap().as_bytes())
         .unwrap();
+    let channel: Option<String> = channel.into_opt_string();
     DartIsolateWrapper::new(port).spawn_result(
         async move {
             let routing_context = get_routing_context(id, "routing_context_app_message")?;
 
+            let channel = channel.map(veilid_core::AppChannel::try_from).transpose()?;
             let veilid_api = get_veilid_api().await?;
             let target = veilid_api.parse_as_target(target_string)?;
-            routing_context.app_message(target, message).await?;
+            match channel {
+                Some(channel) => {
+                    routing_context
+                        .app_message_on_channel(target, channel, message)
+                        .await?
+                }
+                None => routing_context.app_message(target, message).await?,
+            }
             APIRESULT_VOID
         }
         .in_current_span(),
@@ -760,6 +790,7 @@ pub extern "C" fn routing_context_set_dht_value(
     subkey: u32,
     data: FfiStr,
     writer: FfiStr,
+    expected_seq: FfiStr,
 ) {
     let key: veilid_core::TypedKey =
         veilid_core::deserialize_opt_json(key.into_opt_string()).unwrap();
@@ -769,14 +800,82 @@ pub extern "C" fn routing_context_set_dht_value(
     let writer: Option<veilid_core::KeyPair> = writer
         .into_opt_string()
         .map(|s| veilid_core::deserialize_json(&s).unwrap());
+    let expected_seq: Option<veilid_core::ValueSeqNum> = expected_seq
+        .into_opt_string()
+        .map(|s| veilid_core::deserialize_json(&s).unwrap());
 
     DartIsolateWrapper::new(port).spawn_result_json(
         async move {
             let routing_context = get_routing_context(id, "routing_context_set_dht_value")?;
 
             let res = routing_context
-                .set_dht_value(key, subkey, data, writer)
+                .set_dht_value(
+                    key,
+                    subkey,
+                    data,
+                    Some(veilid_core::SetDHTValueOptions {
+                        writer,
+                        expected_seq,
+                    }),
+                )
+                .await?;
+            APIResult::Ok(res)
+        }
+        .in_current_span(),
+    );
+}
+
+#[no_mangle]
+#[instrument(level = "trace", target = "ffi", skip_all)]
+pub extern "C" fn routing_context_set_dht_large_value(
+    port: i64,
+    id: u32,
+    key: FfiStr,
+    subkey: u32,
+    data: FfiStr,
+    writer: FfiStr,
+) {
+    let key: veilid_core::TypedKey =
+        veilid_core::deserialize_opt_json(key.into_opt_string()).unwrap();
+    let data: Vec<u8> = data_encoding::BASE64URL_NOPAD
+        .decode(data.into_opt_string().unwrap().as_bytes())
+        .unwrap();
+    let writer: Option<veilid_core::KeyPair> = writer
+        .into_opt_string()
+        .map(|s| veilid_core::deserialize_json(&s).unwrap());
+
+    DartIsolateWrapper::new(port).spawn_result_json(
+        async move {
+            let routing_context = get_routing_context(id, "routing_context_set_dht_large_value")?;
+
+            let res = routing_context
+                .set_dht_large_value(key, subkey, data, writer)
+                .await?;
+            APIResult::Ok(res)
+        }
+        .in_current_span(),
+    );
+}
+
+#[no_mangle]
+#[instrument(level = "trace", target = "ffi", skip_all)]
+pub extern "C" fn routing_context_get_dht_large_value(
+    port: i64,
+    id: u32,
+    key: FfiStr,
+    subkey: u32,
+    force_refresh: bool,
+) {
+    let key: veilid_core::TypedKey =
+        veilid_core::deserialize_opt_json(key.into_opt_string()).unwrap();
+    DartIsolateWrapper::new(port).spawn_result_json(
+        async move {
+            let routing_context = get_routing_context(id, "routing_context_get_dht_large_value")?;
+
+            let res = routing_context
+                .get_dht_large_value(key, subkey, force_refresh)
                 .await?;
+            let res = res.map(|data| data_encoding::BASE64URL_NOPAD.encode(&data));
             APIResult::Ok(res)
         }
         .in_current_span(),
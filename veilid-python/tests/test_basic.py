@@ -58,3 +58,29 @@ async def test_config(api_connection: veilid.VeilidAPI):
     
     assert veilidConfigInstance == veilidConfigInstance2
 
+
+
+@pytest.mark.asyncio
+async def test_debug_request(api_connection: veilid.VeilidAPI):
+    buckets = await api_connection.debug_request({"kind": "Buckets"})
+    assert buckets["kind"] == "Buckets"
+    assert len(buckets["buckets"]) >= 1
+
+    routes = await api_connection.debug_request({"kind": "RouteList"})
+    assert routes["kind"] == "RouteList"
+    assert isinstance(routes["allocated"], list)
+
+    tables = await api_connection.debug_request({"kind": "TableList"})
+    assert tables["kind"] == "TableList"
+
+
+@pytest.mark.asyncio
+async def test_get_routing_table_info(api_connection: veilid.VeilidAPI):
+    rti = await api_connection.get_routing_table_info(veilid.RoutingTableEntryState.DEAD)
+    assert len(rti.node_ids) >= 1
+    assert len(rti.buckets) >= 1
+
+    peers = await api_connection.get_closest_peers(rti.node_ids[0], 4)
+    assert len(peers) <= 4
+    for peer in peers:
+        assert rti.node_ids[0] not in peer.node_ids
@@ -94,6 +94,193 @@ async def test_set_get_dht_value(api_connection: veilid.VeilidAPI):
         await rc.delete_dht_record(rec.key)
 
 
+@pytest.mark.asyncio
+async def test_set_get_dht_values(api_connection: veilid.VeilidAPI):
+    rc = await api_connection.new_routing_context()
+    async with rc:
+        rec = await rc.create_dht_record(veilid.DHTSchema.dflt(4))
+
+        vds = await rc.set_dht_values(
+            rec.key, [(ValueSubkey(2), b"BLAH"), (ValueSubkey(0), b"BLAH BLAH")]
+        )
+        assert vds == [(ValueSubkey(0), None), (ValueSubkey(2), None)]
+
+        vds2 = await rc.get_dht_values(rec.key, [], False)
+        assert [subkey for subkey, _ in vds2] == [0, 1, 2, 3]
+        assert vds2[0][1] is not None and vds2[0][1].data == b"BLAH BLAH"
+        assert vds2[1][1] is None
+        assert vds2[2][1] is not None and vds2[2][1].data == b"BLAH"
+        assert vds2[3][1] is None
+
+        vds3 = await rc.get_dht_values(rec.key, [(ValueSubkey(0), ValueSubkey(2))], True)
+        assert vds3 == vds2[0:3]
+
+        with pytest.raises(veilid.VeilidAPIError):
+            await rc.set_dht_values(
+                rec.key, [(ValueSubkey(1), b"ONE"), (ValueSubkey(1), b"TWO")]
+            )
+
+        await rc.close_dht_record(rec.key)
+        await rc.delete_dht_record(rec.key)
+
+
+@pytest.mark.asyncio
+async def test_set_get_dht_large_value(api_connection: veilid.VeilidAPI):
+    rc = await api_connection.new_routing_context()
+    async with rc:
+        rec = await rc.create_dht_record(veilid.DHTSchema.dflt(9))
+
+        assert await rc.get_dht_large_value(rec.key, ValueSubkey(1)) is None
+
+        data = bytes(n % 251 for n in range(200 * 1024))
+        span = await rc.set_dht_large_value(rec.key, ValueSubkey(1), data)
+        assert span == [(1, 8)]
+
+        assert await rc.get_dht_large_value(rec.key, ValueSubkey(1)) == data
+        assert await rc.get_dht_large_value(rec.key, ValueSubkey(1), True) == data
+
+        await rc.close_dht_record(rec.key)
+        await rc.delete_dht_record(rec.key)
+
+
+@pytest.mark.asyncio
+async def test_compare_and_set_dht_value(api_connection: veilid.VeilidAPI):
+    rc = await api_connection.new_routing_context()
+    async with rc:
+        rec = await rc.create_dht_record(veilid.DHTSchema.dflt(1))
+
+        vd = await rc.set_dht_value(
+            rec.key,
+            ValueSubkey(0),
+            b"FIRST",
+            veilid.SetDHTValueOptions(expected_seq=veilid.ValueSeqNum(2**32 - 1)),
+        )
+        assert vd is None
+
+        vd = await rc.set_dht_value(
+            rec.key, ValueSubkey(0), b"SECOND", veilid.SetDHTValueOptions(expected_seq=veilid.ValueSeqNum(0))
+        )
+        assert vd is None
+
+        with pytest.raises(veilid.VeilidAPIErrorConflict) as exc:
+            await rc.set_dht_value(
+                rec.key, ValueSubkey(0), b"THIRD", veilid.SetDHTValueOptions(expected_seq=veilid.ValueSeqNum(0))
+            )
+        assert exc.value.value.seq == 1
+        assert exc.value.value.data == b"SECOND"
+
+        vd2 = await rc.get_dht_value(rec.key, ValueSubkey(0), False)
+        assert vd2 is not None and vd2.data == b"SECOND"
+
+        await rc.close_dht_record(rec.key)
+        await rc.delete_dht_record(rec.key)
+
+
+@pytest.mark.asyncio
+async def test_dht_record_encryption(api_connection: veilid.VeilidAPI):
+    rc = await api_connection.new_routing_context()
+    async with rc:
+        rec = await rc.create_dht_record(veilid.DHTSchema.dflt(2))
+
+        # Share a new key in subkey 0, which stays readable
+        secret = await rc.share_dht_record_encryption_key(rec.key, ValueSubkey(0))
+        share = await rc.get_dht_value(rec.key, ValueSubkey(0), False)
+        assert share is not None and share.data.startswith(b"RKS0")
+
+        vd = await rc.set_dht_value(rec.key, ValueSubkey(1), b"SECRET")
+        assert vd is None
+        vd2 = await rc.get_dht_value(rec.key, ValueSubkey(1), True)
+        assert vd2 is not None and vd2.data == b"SECRET"
+
+        # Without the key, only the sealed value is visible
+        await rc.set_dht_record_encryption_key(rec.key, None)
+        vd3 = await rc.get_dht_value(rec.key, ValueSubkey(1), False)
+        assert vd3 is not None and vd3.data != b"SECRET"
+
+        # The owner can get the key back from the share
+        assert await rc.import_dht_record_encryption_key(rec.key, ValueSubkey(0)) == secret
+        vd4 = await rc.get_dht_value(rec.key, ValueSubkey(1), False)
+        assert vd4 is not None and vd4.data == b"SECRET"
+
+        await rc.close_dht_record(rec.key)
+        await rc.delete_dht_record(rec.key)
+
+
+@pytest.mark.asyncio
+async def test_dht_record_successor(api_connection: veilid.VeilidAPI):
+    rc = await api_connection.new_routing_context()
+    async with rc:
+        old_rec = await rc.create_dht_record(veilid.DHTSchema.dflt(1))
+        new_rec = await rc.create_dht_record(veilid.DHTSchema.dflt(1))
+
+        # No successor until one is published
+        assert await rc.get_dht_record_successor(old_rec.key, ValueSubkey(0)) is None
+
+        await rc.set_dht_record_successor(old_rec.key, ValueSubkey(0), new_rec.key)
+        assert (
+            await rc.get_dht_record_successor(old_rec.key, ValueSubkey(0), True) == new_rec.key
+        )
+
+        # Following the chain ends at the new record with its owner
+        rec = await rc.open_dht_record_following_successors(old_rec.key, ValueSubkey(0))
+        assert rec.key == new_rec.key
+        assert rec.owner == new_rec.owner
+
+        await rc.close_dht_record(old_rec.key)
+        await rc.delete_dht_record(old_rec.key)
+        await rc.close_dht_record(new_rec.key)
+        await rc.delete_dht_record(new_rec.key)
+
+
+@pytest.mark.asyncio
+async def test_dht_value_history(api_connection: veilid.VeilidAPI):
+    rc = await api_connection.new_routing_context()
+    async with rc:
+        rec = await rc.create_dht_record(veilid.DHTSchema.dflt(1))
+
+        # Nothing is kept until history is turned on
+        await rc.set_dht_value(rec.key, ValueSubkey(0), b"ZERO")
+        await rc.set_dht_value_history_length(rec.key, 2)
+
+        for data in [b"ONE", b"TWO", b"THREE"]:
+            await rc.set_dht_value(rec.key, ValueSubkey(0), data)
+
+        history = await rc.get_dht_value_history(rec.key, ValueSubkey(0))
+        assert [vd.data for vd in history] == [b"ONE", b"TWO", b"THREE"]
+        assert [vd.seq for vd in history] == [1, 2, 3]
+
+        history = await rc.get_dht_value_history(
+            rec.key, ValueSubkey(0), (veilid.ValueSeqNum(2), veilid.ValueSeqNum(2))
+        )
+        assert [vd.data for vd in history] == [b"TWO"]
+
+        # Turning history off drops what was kept
+        await rc.set_dht_value_history_length(rec.key, 0)
+        history = await rc.get_dht_value_history(rec.key, ValueSubkey(0))
+        assert [vd.data for vd in history] == [b"THREE"]
+
+        await rc.close_dht_record(rec.key)
+        await rc.delete_dht_record(rec.key)
+
+
+@pytest.mark.asyncio
+async def test_dht_offline_writes(api_connection: veilid.VeilidAPI):
+    rc = await api_connection.new_routing_context()
+    async with rc:
+        rec = await rc.create_dht_record(veilid.DHTSchema.dflt(2))
+
+        vd = await rc.set_dht_value(rec.key, ValueSubkey(0), b"BLAH")
+        assert vd is None
+
+        # Writes that reached the network are not queued, so there is nothing to cancel
+        assert await rc.get_dht_offline_writes(rec.key) == []
+        assert await rc.cancel_dht_offline_writes(rec.key) == []
+        assert await rc.cancel_dht_offline_writes(rec.key, [(1, 1)]) == []
+
+        await rc.close_dht_record(rec.key)
+        await rc.delete_dht_record(rec.key)
+
+
 @pytest.mark.asyncio
 async def test_open_writer_dht_value(api_connection: veilid.VeilidAPI):
     rc = await api_connection.new_routing_context()
@@ -199,7 +386,12 @@ async def test_open_writer_dht_value(api_connection: veilid.VeilidAPI):
             await rc.set_dht_value(key, ValueSubkey(0), va)
 
         # Verify subkey 0 can be set because override with the right writer
-        vdtemp = await rc.set_dht_value(key, ValueSubkey(0), va, veilid.KeyPair.from_parts(owner, secret))
+        vdtemp = await rc.set_dht_value(
+            key,
+            ValueSubkey(0),
+            va,
+            veilid.SetDHTValueOptions(writer=veilid.KeyPair.from_parts(owner, secret)),
+        )
         assert vdtemp is None
 
         # Clean up
@@ -2,7 +2,13 @@ from abc import ABC, abstractmethod
 from typing import Optional, Self
 
 from . import types
-from .state import VeilidState
+from .state import (
+    RoutingTableEntryState,
+    RouteHealth,
+    RoutingTableEntry,
+    RoutingTableInfo,
+    VeilidState,
+)
 
 
 class RoutingContext(ABC):
@@ -40,11 +46,21 @@ class RoutingContext(ABC):
         pass
 
     @abstractmethod
-    async def app_call(self, target: types.TypedKey | types.RouteId, request: bytes) -> bytes:
+    async def app_call(
+        self,
+        target: types.TypedKey | types.RouteId,
+        request: bytes,
+        channel: Optional[str] = None,
+    ) -> bytes:
         pass
 
     @abstractmethod
-    async def app_message(self, target: types.TypedKey | types.RouteId, message: bytes):
+    async def app_message(
+        self,
+        target: types.TypedKey | types.RouteId,
+        message: bytes,
+        channel: Optional[str] = None,
+    ):
         pass
 
     @abstractmethod
@@ -75,10 +91,106 @@ class RoutingContext(ABC):
 
     @abstractmethod
     async def set_dht_value(
-        self, key: types.TypedKey, subkey: types.ValueSubkey, data: bytes, writer: Optional[types.KeyPair] = None
+        self,
+        key: types.TypedKey,
+        subkey: types.ValueSubkey,
+        data: bytes,
+        options: Optional[types.SetDHTValueOptions] = None,
     ) -> Optional[types.ValueData]:
         pass
 
+    @abstractmethod
+    async def get_dht_values(
+        self,
+        key: types.TypedKey,
+        subkeys: list[tuple[types.ValueSubkey, types.ValueSubkey]],
+        force_refresh: bool = False,
+    ) -> list[tuple[types.ValueSubkey, Optional[types.ValueData]]]:
+        pass
+
+    @abstractmethod
+    async def set_dht_values(
+        self,
+        key: types.TypedKey,
+        values: list[tuple[types.ValueSubkey, bytes]],
+        writer: Optional[types.KeyPair] = None,
+    ) -> list[tuple[types.ValueSubkey, Optional[types.ValueData]]]:
+        pass
+
+    @abstractmethod
+    async def set_dht_large_value(
+        self,
+        key: types.TypedKey,
+        subkey: types.ValueSubkey,
+        data: bytes,
+        writer: Optional[types.KeyPair] = None,
+    ) -> list[tuple[types.ValueSubkey, types.ValueSubkey]]:
+        pass
+
+    @abstractmethod
+    async def get_dht_large_value(
+        self, key: types.TypedKey, subkey: types.ValueSubkey, force_refresh: bool = False
+    ) -> Optional[bytes]:
+        pass
+
+    @abstractmethod
+    async def set_dht_record_encryption_key(
+        self, key: types.TypedKey, encryption_key: Optional[types.SharedSecret]
+    ):
+        pass
+
+    @abstractmethod
+    async def share_dht_record_encryption_key(
+        self,
+        key: types.TypedKey,
+        subkey: types.ValueSubkey,
+        members: list[types.PublicKey] = [],
+    ) -> types.SharedSecret:
+        pass
+
+    @abstractmethod
+    async def import_dht_record_encryption_key(
+        self, key: types.TypedKey, subkey: types.ValueSubkey
+    ) -> types.SharedSecret:
+        pass
+
+    @abstractmethod
+    async def set_dht_record_successor(
+        self, key: types.TypedKey, subkey: types.ValueSubkey, successor: types.TypedKey
+    ):
+        pass
+
+    @abstractmethod
+    async def get_dht_record_successor(
+        self, key: types.TypedKey, subkey: types.ValueSubkey, force_refresh: bool = False
+    ) -> Optional[types.TypedKey]:
+        pass
+
+    @abstractmethod
+    async def open_dht_record_following_successors(
+        self,
+        key: types.TypedKey,
+        subkey: types.ValueSubkey,
+        writer: Optional[types.KeyPair] = None,
+    ) -> types.DHTRecordDescriptor:
+        pass
+
+    @abstractmethod
+    async def set_dht_value_history_length(self, key: types.TypedKey, history_length: int):
+        pass
+
+    @abstractmethod
+    async def get_dht_value_history(
+        self,
+        key: types.TypedKey,
+        subkey: types.ValueSubkey,
+        range: tuple[types.ValueSeqNum, types.ValueSeqNum] = (
+            types.ValueSeqNum(0),
+            types.ValueSeqNum(2**32 - 1),
+        ),
+    ) -> list[types.ValueData]:
+        pass
+
     @abstractmethod
     async def watch_dht_values(
         self,
@@ -106,6 +218,32 @@ class RoutingContext(ABC):
     ) -> types.DHTRecordReport:
         pass
 
+    @abstractmethod
+    async def resolve_mailbox(
+        self, key: types.TypedKey, subkey: types.ValueSubkey, force_refresh: bool = False
+    ) -> types.RouteId:
+        pass
+
+    @abstractmethod
+    async def get_dht_offline_writes(
+        self, key: types.TypedKey
+    ) -> list[tuple[types.ValueSubkey, types.ValueSubkey]]:
+        pass
+
+    @abstractmethod
+    async def cancel_dht_offline_writes(
+        self,
+        key: types.TypedKey,
+        subkeys: list[tuple[types.ValueSubkey, types.ValueSubkey]] = [],
+    ) -> list[tuple[types.ValueSubkey, types.ValueSubkey]]:
+        pass
+
+    @abstractmethod
+    async def flush_dht_offline_writes(
+        self, key: types.TypedKey
+    ) -> list[tuple[types.ValueSubkey, types.ValueSubkey]]:
+        pass
+
 
 
 class TableDbTransaction(ABC):
@@ -358,6 +496,59 @@ class VeilidAPI(ABC):
     async def release_private_route(self, route_id: types.RouteId):
         pass
 
+    @abstractmethod
+    async def publish_mailbox(
+        self,
+        key: types.TypedKey,
+        subkey: types.ValueSubkey,
+        stability: types.Stability = types.Stability.RELIABLE,
+        sequencing: types.Sequencing = types.Sequencing.PREFER_ORDERED,
+        rotation_policy: Optional[types.RouteRotationPolicy] = None,
+    ) -> types.RouteId:
+        pass
+
+    @abstractmethod
+    async def unpublish_mailbox(self, key: types.TypedKey, subkey: types.ValueSubkey):
+        pass
+
+    @abstractmethod
+    async def get_route_health(self, route_id: types.RouteId) -> Optional[RouteHealth]:
+        pass
+
+    @abstractmethod
+    async def list_route_health(self) -> list[RouteHealth]:
+        pass
+
+    @abstractmethod
+    async def get_routing_table_info(
+        self, min_state: RoutingTableEntryState = RoutingTableEntryState.UNRELIABLE
+    ) -> RoutingTableInfo:
+        pass
+
+    @abstractmethod
+    async def get_closest_peers(self, key: types.TypedKey, count: int) -> list[RoutingTableEntry]:
+        pass
+
+    @abstractmethod
+    async def set_route_rotation_policy(
+        self, route_id: types.RouteId, policy: Optional[types.RouteRotationPolicy]
+    ):
+        pass
+
+    @abstractmethod
+    async def register_app_channel(
+        self, channel: str, options: Optional[types.AppChannelOptions] = None
+    ):
+        pass
+
+    @abstractmethod
+    async def unregister_app_channel(self, channel: str) -> bool:
+        pass
+
+    @abstractmethod
+    async def set_reject_unknown_app_channels(self, reject_unknown: bool):
+        pass
+
     @abstractmethod
     async def app_call_reply(self, call_id: types.OperationId, message: bytes):
         pass
@@ -409,6 +600,10 @@ class VeilidAPI(ABC):
     async def debug(self, command: str) -> str:
         pass
 
+    @abstractmethod
+    async def debug_request(self, request: dict) -> dict:
+        pass
+
     @abstractmethod
     async def veilid_version_string(self) -> str:
         pass
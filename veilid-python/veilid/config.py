@@ -71,6 +71,8 @@ class VeilidConfigRoutingTable(ConfigBase):
     node_id: list[TypedKey]
     node_id_secret: list[TypedSecret]
     bootstrap: list[str]
+    bootstrap_keys: list[TypedKey]
+    bootstrap_allow_unsigned: bool
     limit_over_attached: int
     limit_fully_attached: int
     limit_attached_strong: int
@@ -78,10 +80,38 @@ class VeilidConfigRoutingTable(ConfigBase):
     limit_attached_weak: int
 
 
+@dataclass
+class VeilidConfigRPCQueues(ConfigBase):
+    answer_queue_size: int
+    status_queue_size: int
+    dht_queue_size: int
+    routed_queue_size: int
+    max_queued_per_sender: int
+
+
+@dataclass
+class VeilidConfigRPCRateLimit(ConfigBase):
+    rate_per_min: int
+    burst: int
+
+
+@dataclass
+class VeilidConfigRPCRateLimits(ConfigBase):
+    enabled: bool
+    punish_violation_count: int
+    dht: VeilidConfigRPCRateLimit
+    dht_watch: VeilidConfigRPCRateLimit
+    app_message: VeilidConfigRPCRateLimit
+    route: VeilidConfigRPCRateLimit
+    signal: VeilidConfigRPCRateLimit
+
+
 @dataclass
 class VeilidConfigRPC(ConfigBase):
     concurrency: int
     queue_size: int
+    queues: VeilidConfigRPCQueues
+    rate_limits: VeilidConfigRPCRateLimits
     max_timestamp_behind_ms: Optional[int]
     max_timestamp_ahead_ms: Optional[int]
     timeout_ms: int
@@ -188,6 +218,22 @@ class VeilidConfigProtocol(ConfigBase):
     wss: VeilidConfigWSS
 
 
+@dataclass
+class VeilidConfigLANDiscovery(ConfigBase):
+    enabled: bool
+    interval_ms: int
+    port: int
+    multicast_group_v4: str
+    multicast_group_v6: str
+    max_beacons_per_min: int
+
+
+@dataclass
+class VeilidConfigBootstrapServer(ConfigBase):
+    enabled: bool
+    max_peers_per_type: int
+
+
 @dataclass
 class VeilidConfigNetwork(ConfigBase):
     connection_initial_timeout_ms: int
@@ -206,6 +252,8 @@ class VeilidConfigNetwork(ConfigBase):
     upnp: bool
     detect_address_changes: bool
     restricted_nat_retries: int
+    lan_discovery: VeilidConfigLANDiscovery
+    bootstrap_server: VeilidConfigBootstrapServer
     tls: VeilidConfigTLS
     application: VeilidConfigApplication
     protocol: VeilidConfigProtocol
@@ -2,6 +2,8 @@ import inspect
 from dataclasses import dataclass
 from typing import Any, Self
 
+from .types import ValueData
+
 _ERROR_REGISTRY: dict[str, type] = {}
 
 
@@ -141,6 +143,18 @@ class VeilidAPIErrorGeneric(VeilidAPIError):
     message: str
 
 
+@dataclass
+class VeilidAPIErrorConflict(VeilidAPIError):
+    """A compare-and-set lost to another value, which is carried here"""
+
+    label = "Conflict"
+    value: ValueData
+
+    def __post_init__(self):
+        if isinstance(self.value, dict):
+            self.value = ValueData.from_json(self.value)
+
+
 # Build a mapping of canonicalized labels to their exception classes. Do this in-place to update
 # the object inside the closure so VeilidAPIError.from_json can access the values.
 _ERROR_REGISTRY.clear()
@@ -16,8 +16,16 @@ from .operations import (
     TableDbOperation,
     TableDbTransactionOperation,
 )
-from .state import VeilidState, VeilidUpdate
+from .state import (
+    RoutingTableEntryState,
+    RouteHealth,
+    RoutingTableEntry,
+    RoutingTableInfo,
+    VeilidState,
+    VeilidUpdate,
+)
 from .types import (
+    AppChannelOptions,
     CryptoKey,
     CryptoKeyDistance,
     CryptoKind,
@@ -32,9 +40,11 @@ from .types import (
     OperationId,
     PublicKey,
     RouteId,
+    RouteRotationPolicy,
     SafetySelection,
     SecretKey,
     Sequencing,
+    SetDHTValueOptions,
     SharedSecret,
     Signature,
     Stability,
@@ -43,6 +53,7 @@ from .types import (
     TypedKeyPair,
     TypedSignature,
     ValueData,
+    ValueSeqNum,
     ValueSubkey,
     VeilidJSONEncoder,
     VeilidVersion,
@@ -349,6 +360,97 @@ class _JsonVeilidAPI(VeilidAPI):
             await self.send_ndjson_request(Operation.RELEASE_PRIVATE_ROUTE, route_id=route_id)
         )
 
+    async def publish_mailbox(
+        self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        stability: Stability = Stability.RELIABLE,
+        sequencing: Sequencing = Sequencing.PREFER_ORDERED,
+        rotation_policy: Optional[RouteRotationPolicy] = None,
+    ) -> RouteId:
+        return RouteId(
+            raise_api_result(
+                await self.send_ndjson_request(
+                    Operation.PUBLISH_MAILBOX,
+                    key=key,
+                    subkey=subkey,
+                    stability=stability,
+                    sequencing=sequencing,
+                    rotation_policy=rotation_policy,
+                )
+            )
+        )
+
+    async def unpublish_mailbox(self, key: TypedKey, subkey: ValueSubkey):
+        raise_api_result(
+            await self.send_ndjson_request(
+                Operation.UNPUBLISH_MAILBOX, key=key, subkey=subkey
+            )
+        )
+
+    async def get_route_health(self, route_id: RouteId) -> Optional[RouteHealth]:
+        rh = raise_api_result(
+            await self.send_ndjson_request(Operation.GET_ROUTE_HEALTH, route_id=route_id)
+        )
+        return None if rh is None else RouteHealth.from_json(rh)
+
+    async def list_route_health(self) -> list[RouteHealth]:
+        return [
+            RouteHealth.from_json(rh)
+            for rh in raise_api_result(
+                await self.send_ndjson_request(Operation.LIST_ROUTE_HEALTH)
+            )
+        ]
+
+    async def get_routing_table_info(
+        self, min_state: RoutingTableEntryState = RoutingTableEntryState.UNRELIABLE
+    ) -> RoutingTableInfo:
+        return RoutingTableInfo.from_json(
+            raise_api_result(
+                await self.send_ndjson_request(
+                    Operation.GET_ROUTING_TABLE_INFO, min_state=min_state
+                )
+            )
+        )
+
+    async def get_closest_peers(self, key: TypedKey, count: int) -> list[RoutingTableEntry]:
+        return [
+            RoutingTableEntry.from_json(e)
+            for e in raise_api_result(
+                await self.send_ndjson_request(Operation.GET_CLOSEST_PEERS, key=key, count=count)
+            )
+        ]
+
+    async def set_route_rotation_policy(
+        self, route_id: RouteId, policy: Optional[RouteRotationPolicy]
+    ):
+        raise_api_result(
+            await self.send_ndjson_request(
+                Operation.SET_ROUTE_ROTATION_POLICY, route_id=route_id, policy=policy
+            )
+        )
+
+    async def register_app_channel(
+        self, channel: str, options: Optional[AppChannelOptions] = None
+    ):
+        raise_api_result(
+            await self.send_ndjson_request(
+                Operation.REGISTER_APP_CHANNEL, channel=channel, options=options
+            )
+        )
+
+    async def unregister_app_channel(self, channel: str) -> bool:
+        return raise_api_result(
+            await self.send_ndjson_request(Operation.UNREGISTER_APP_CHANNEL, channel=channel)
+        )
+
+    async def set_reject_unknown_app_channels(self, reject_unknown: bool):
+        raise_api_result(
+            await self.send_ndjson_request(
+                Operation.SET_REJECT_UNKNOWN_APP_CHANNELS, reject_unknown=reject_unknown
+            )
+        )
+
     async def app_call_reply(self, call_id: OperationId, message: bytes):
         raise_api_result(
             await self.send_ndjson_request(
@@ -433,6 +535,11 @@ class _JsonVeilidAPI(VeilidAPI):
     async def debug(self, command: str) -> str:
         return raise_api_result(await self.send_ndjson_request(Operation.DEBUG, command=command))
 
+    async def debug_request(self, request: dict) -> dict:
+        return raise_api_result(
+            await self.send_ndjson_request(Operation.DEBUG_REQUEST, request=request)
+        )
+
     async def veilid_version_string(self) -> str:
         return raise_api_result(await self.send_ndjson_request(Operation.VEILID_VERSION_STRING))
 
@@ -540,7 +647,9 @@ class _JsonRoutingContext(RoutingContext):
                 )
             )
         )
-    async def app_call(self, target: TypedKey | RouteId, message: bytes) -> bytes:
+    async def app_call(
+        self, target: TypedKey | RouteId, message: bytes, channel: Optional[str] = None
+    ) -> bytes:
         return urlsafe_b64decode_no_pad(
             raise_api_result(
                 await self.api.send_ndjson_request(
@@ -549,12 +658,15 @@ class _JsonRoutingContext(RoutingContext):
                     rc_id=self.rc_id,
                     rc_op=RoutingContextOperation.APP_CALL,
                     target=target,
+                    channel=channel,
                     message=message,
                 )
             )
         )
 
-    async def app_message(self, target: TypedKey | RouteId, message: bytes):
+    async def app_message(
+        self, target: TypedKey | RouteId, message: bytes, channel: Optional[str] = None
+    ):
         raise_api_result(
             await self.api.send_ndjson_request(
                 Operation.ROUTING_CONTEXT,
@@ -562,6 +674,7 @@ class _JsonRoutingContext(RoutingContext):
                 rc_id=self.rc_id,
                 rc_op=RoutingContextOperation.APP_MESSAGE,
                 target=target,
+                channel=channel,
                 message=message,
             )
         )
@@ -637,7 +750,11 @@ class _JsonRoutingContext(RoutingContext):
         return None if ret is None else ValueData.from_json(ret)
 
     async def set_dht_value(
-        self, key: TypedKey, subkey: ValueSubkey, data: bytes, writer: Optional[KeyPair] = None
+        self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        data: bytes,
+        options: Optional[SetDHTValueOptions] = None,
     ) -> Optional[ValueData]:
         ret = raise_api_result(
             await self.api.send_ndjson_request(
@@ -648,11 +765,218 @@ class _JsonRoutingContext(RoutingContext):
                 key=key,
                 subkey=subkey,
                 data=data,
-                writer=writer,
+                options=options,
             )
         )
         return None if ret is None else ValueData.from_json(ret)
 
+    async def get_dht_values(
+        self,
+        key: TypedKey,
+        subkeys: list[tuple[ValueSubkey, ValueSubkey]],
+        force_refresh: bool = False,
+    ) -> list[tuple[ValueSubkey, Optional[ValueData]]]:
+        ret = raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.GET_DHT_VALUES,
+                key=key,
+                subkeys=subkeys,
+                force_refresh=force_refresh,
+            )
+        )
+        return [
+            (ValueSubkey(subkey), None if value is None else ValueData.from_json(value))
+            for subkey, value in ret
+        ]
+
+    async def set_dht_values(
+        self,
+        key: TypedKey,
+        values: list[tuple[ValueSubkey, bytes]],
+        writer: Optional[KeyPair] = None,
+    ) -> list[tuple[ValueSubkey, Optional[ValueData]]]:
+        ret = raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.SET_DHT_VALUES,
+                key=key,
+                values=values,
+                writer=writer,
+            )
+        )
+        return [
+            (ValueSubkey(subkey), None if value is None else ValueData.from_json(value))
+            for subkey, value in ret
+        ]
+
+    async def set_dht_large_value(
+        self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        data: bytes,
+        writer: Optional[KeyPair] = None,
+    ) -> list[tuple[ValueSubkey, ValueSubkey]]:
+        ret = raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.SET_DHT_LARGE_VALUE,
+                key=key,
+                subkey=subkey,
+                data=data,
+                writer=writer,
+            )
+        )
+        return [(ValueSubkey(low), ValueSubkey(high)) for low, high in ret]
+
+    async def get_dht_large_value(
+        self, key: TypedKey, subkey: ValueSubkey, force_refresh: bool = False
+    ) -> Optional[bytes]:
+        ret = raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.GET_DHT_LARGE_VALUE,
+                key=key,
+                subkey=subkey,
+                force_refresh=force_refresh,
+            )
+        )
+        return None if ret is None else urlsafe_b64decode_no_pad(ret)
+
+    async def set_dht_record_encryption_key(
+        self, key: TypedKey, encryption_key: Optional[SharedSecret]
+    ):
+        raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.SET_DHT_RECORD_ENCRYPTION_KEY,
+                key=key,
+                encryption_key=encryption_key,
+            )
+        )
+
+    async def share_dht_record_encryption_key(
+        self, key: TypedKey, subkey: ValueSubkey, members: list[PublicKey] = []
+    ) -> SharedSecret:
+        return SharedSecret(
+            raise_api_result(
+                await self.api.send_ndjson_request(
+                    Operation.ROUTING_CONTEXT,
+                    validate=validate_rc_op,
+                    rc_id=self.rc_id,
+                    rc_op=RoutingContextOperation.SHARE_DHT_RECORD_ENCRYPTION_KEY,
+                    key=key,
+                    subkey=subkey,
+                    members=members,
+                )
+            )
+        )
+
+    async def import_dht_record_encryption_key(
+        self, key: TypedKey, subkey: ValueSubkey
+    ) -> SharedSecret:
+        return SharedSecret(
+            raise_api_result(
+                await self.api.send_ndjson_request(
+                    Operation.ROUTING_CONTEXT,
+                    validate=validate_rc_op,
+                    rc_id=self.rc_id,
+                    rc_op=RoutingContextOperation.IMPORT_DHT_RECORD_ENCRYPTION_KEY,
+                    key=key,
+                    subkey=subkey,
+                )
+            )
+        )
+
+    async def set_dht_record_successor(
+        self, key: TypedKey, subkey: ValueSubkey, successor: TypedKey
+    ):
+        raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.SET_DHT_RECORD_SUCCESSOR,
+                key=key,
+                subkey=subkey,
+                successor=successor,
+            )
+        )
+
+    async def get_dht_record_successor(
+        self, key: TypedKey, subkey: ValueSubkey, force_refresh: bool = False
+    ) -> Optional[TypedKey]:
+        ret = raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.GET_DHT_RECORD_SUCCESSOR,
+                key=key,
+                subkey=subkey,
+                force_refresh=force_refresh,
+            )
+        )
+        return None if ret is None else TypedKey(ret)
+
+    async def open_dht_record_following_successors(
+        self, key: TypedKey, subkey: ValueSubkey, writer: Optional[KeyPair] = None
+    ) -> DHTRecordDescriptor:
+        return DHTRecordDescriptor.from_json(
+            raise_api_result(
+                await self.api.send_ndjson_request(
+                    Operation.ROUTING_CONTEXT,
+                    validate=validate_rc_op,
+                    rc_id=self.rc_id,
+                    rc_op=RoutingContextOperation.OPEN_DHT_RECORD_FOLLOWING_SUCCESSORS,
+                    key=key,
+                    subkey=subkey,
+                    writer=writer,
+                )
+            )
+        )
+
+    async def set_dht_value_history_length(self, key: TypedKey, history_length: int):
+        raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.SET_DHT_VALUE_HISTORY_LENGTH,
+                key=key,
+                history_length=history_length,
+            )
+        )
+
+    async def get_dht_value_history(
+        self,
+        key: TypedKey,
+        subkey: ValueSubkey,
+        range: tuple[ValueSeqNum, ValueSeqNum] = (ValueSeqNum(0), ValueSeqNum(2**32 - 1)),
+    ) -> list[ValueData]:
+        ret = raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.GET_DHT_VALUE_HISTORY,
+                key=key,
+                subkey=subkey,
+                range=range,
+            )
+        )
+        return [ValueData.from_json(value) for value in ret]
+
     async def watch_dht_values(
         self,
         key: TypedKey,
@@ -708,6 +1032,66 @@ class _JsonRoutingContext(RoutingContext):
                 )
             )
         )
+
+    async def resolve_mailbox(
+        self, key: TypedKey, subkey: ValueSubkey, force_refresh: bool = False
+    ) -> RouteId:
+        return RouteId(
+            raise_api_result(
+                await self.api.send_ndjson_request(
+                    Operation.ROUTING_CONTEXT,
+                    validate=validate_rc_op,
+                    rc_id=self.rc_id,
+                    rc_op=RoutingContextOperation.RESOLVE_MAILBOX,
+                    key=key,
+                    subkey=subkey,
+                    force_refresh=force_refresh,
+                )
+            )
+        )
+
+    async def get_dht_offline_writes(
+        self, key: TypedKey
+    ) -> list[tuple[ValueSubkey, ValueSubkey]]:
+        ret = raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.GET_DHT_OFFLINE_WRITES,
+                key=key,
+            )
+        )
+        return [(ValueSubkey(low), ValueSubkey(high)) for low, high in ret]
+
+    async def cancel_dht_offline_writes(
+        self, key: TypedKey, subkeys: list[tuple[ValueSubkey, ValueSubkey]] = []
+    ) -> list[tuple[ValueSubkey, ValueSubkey]]:
+        ret = raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.CANCEL_DHT_OFFLINE_WRITES,
+                key=key,
+                subkeys=subkeys,
+            )
+        )
+        return [(ValueSubkey(low), ValueSubkey(high)) for low, high in ret]
+
+    async def flush_dht_offline_writes(
+        self, key: TypedKey
+    ) -> list[tuple[ValueSubkey, ValueSubkey]]:
+        ret = raise_api_result(
+            await self.api.send_ndjson_request(
+                Operation.ROUTING_CONTEXT,
+                validate=validate_rc_op,
+                rc_id=self.rc_id,
+                rc_op=RoutingContextOperation.FLUSH_DHT_OFFLINE_WRITES,
+                key=key,
+            )
+        )
+        return [(ValueSubkey(low), ValueSubkey(high)) for low, high in ret]
         
 
 
@@ -11,6 +11,16 @@ class Operation(StrEnum):
     NEW_CUSTOM_PRIVATE_ROUTE = "NewCustomPrivateRoute"
     IMPORT_REMOTE_PRIVATE_ROUTE = "ImportRemotePrivateRoute"
     RELEASE_PRIVATE_ROUTE = "ReleasePrivateRoute"
+    PUBLISH_MAILBOX = "PublishMailbox"
+    UNPUBLISH_MAILBOX = "UnpublishMailbox"
+    GET_ROUTE_HEALTH = "GetRouteHealth"
+    LIST_ROUTE_HEALTH = "ListRouteHealth"
+    GET_ROUTING_TABLE_INFO = "GetRoutingTableInfo"
+    GET_CLOSEST_PEERS = "GetClosestPeers"
+    SET_ROUTE_ROTATION_POLICY = "SetRouteRotationPolicy"
+    REGISTER_APP_CHANNEL = "RegisterAppChannel"
+    UNREGISTER_APP_CHANNEL = "UnregisterAppChannel"
+    SET_REJECT_UNKNOWN_APP_CHANNELS = "SetRejectUnknownAppChannels"
     APP_CALL_REPLY = "AppCallReply"
     NEW_ROUTING_CONTEXT = "NewRoutingContext"
     ROUTING_CONTEXT = "RoutingContext"
@@ -26,6 +36,7 @@ class Operation(StrEnum):
     GENERATE_KEY_PAIR = "GenerateKeyPair"
     NOW = "Now"
     DEBUG = "Debug"
+    DEBUG_REQUEST = "DebugRequest"
     VEILID_VERSION_STRING = "VeilidVersionString"
     VEILID_VERSION = "VeilidVersion"
     DEFAULT_VEILID_CONFIG = "DefaultVeilidConfig"
@@ -46,9 +57,25 @@ class RoutingContextOperation(StrEnum):
     DELETE_DHT_RECORD = "DeleteDhtRecord"
     GET_DHT_VALUE = "GetDhtValue"
     SET_DHT_VALUE = "SetDhtValue"
+    GET_DHT_VALUES = "GetDhtValues"
+    SET_DHT_VALUES = "SetDhtValues"
+    SET_DHT_LARGE_VALUE = "SetDhtLargeValue"
+    GET_DHT_LARGE_VALUE = "GetDhtLargeValue"
+    SET_DHT_RECORD_ENCRYPTION_KEY = "SetDhtRecordEncryptionKey"
+    SHARE_DHT_RECORD_ENCRYPTION_KEY = "ShareDhtRecordEncryptionKey"
+    IMPORT_DHT_RECORD_ENCRYPTION_KEY = "ImportDhtRecordEncryptionKey"
+    SET_DHT_RECORD_SUCCESSOR = "SetDhtRecordSuccessor"
+    GET_DHT_RECORD_SUCCESSOR = "GetDhtRecordSuccessor"
+    OPEN_DHT_RECORD_FOLLOWING_SUCCESSORS = "OpenDhtRecordFollowingSuccessors"
+    SET_DHT_VALUE_HISTORY_LENGTH = "SetDhtValueHistoryLength"
+    GET_DHT_VALUE_HISTORY = "GetDhtValueHistory"
     WATCH_DHT_VALUES = "WatchDhtValues"
     CANCEL_DHT_WATCH = "CancelDhtWatch"
     INSPECT_DHT_RECORD = "InspectDhtRecord"
+    RESOLVE_MAILBOX = "ResolveMailbox"
+    GET_DHT_OFFLINE_WRITES = "GetDhtOfflineWrites"
+    CANCEL_DHT_OFFLINE_WRITES = "CancelDhtOfflineWrites"
+    FLUSH_DHT_OFFLINE_WRITES = "FlushDhtOfflineWrites"
 
 
 class TableDbOperation(StrEnum):
@@ -5,6 +5,7 @@ from .config import VeilidConfig
 from .types import (
     ByteCount,
     RouteId,
+    Stability,
     Timestamp,
     TimestampDuration,
     TypedKey,
@@ -61,6 +62,9 @@ class RPCStats:
     first_consecutive_seen_ts: Optional[Timestamp]
     recent_lost_answers: int
     failed_to_send: int
+    questions_sent: int
+    answers_rcvd: int
+    lost_answers: int
 
     def __init__(
         self,
@@ -72,6 +76,9 @@ class RPCStats:
         first_consecutive_seen_ts: Optional[Timestamp],
         recent_lost_answers: int,
         failed_to_send: int,
+        questions_sent: int,
+        answers_rcvd: int,
+        lost_answers: int,
     ):
         self.messages_sent = messages_sent
         self.messages_rcvd = messages_rcvd
@@ -81,6 +88,9 @@ class RPCStats:
         self.first_consecutive_seen_ts = first_consecutive_seen_ts
         self.recent_lost_answers = recent_lost_answers
         self.failed_to_send = failed_to_send
+        self.questions_sent = questions_sent
+        self.answers_rcvd = answers_rcvd
+        self.lost_answers = lost_answers
 
     @classmethod
     def from_json(cls, j: dict) -> Self:
@@ -96,6 +106,9 @@ class RPCStats:
             else Timestamp(j["first_consecutive_seen_ts"]),
             j["recent_lost_answers"],
             j["failed_to_send"],
+            j.get("questions_sent", 0),
+            j.get("answers_rcvd", 0),
+            j.get("lost_answers", 0),
         )
 
 
@@ -167,11 +180,45 @@ class TransferStatsDownUp:
         return cls(TransferStats.from_json(j["down"]), TransferStats.from_json(j["up"]))
 
 
+class RateLimitStats:
+    operation: str
+    capability: str
+    available: int
+    burst: int
+    limited: int
+
+    def __init__(
+        self,
+        operation: str,
+        capability: str,
+        available: int,
+        burst: int,
+        limited: int,
+    ):
+        self.operation = operation
+        self.capability = capability
+        self.available = available
+        self.burst = burst
+        self.limited = limited
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        """JSON object hook"""
+        return cls(
+            j["operation"],
+            j["capability"],
+            j["available"],
+            j["burst"],
+            j["limited"],
+        )
+
+
 class PeerStats:
     time_added: Timestamp
     rpc_stats: RPCStats
     latency: Optional[LatencyStats]
     transfer: TransferStatsDownUp
+    rate_limits: list[RateLimitStats]
 
     def __init__(
         self,
@@ -179,11 +226,13 @@ class PeerStats:
         rpc_stats: RPCStats,
         latency: Optional[LatencyStats],
         transfer: TransferStatsDownUp,
+        rate_limits: list[RateLimitStats],
     ):
         self.time_added = time_added
         self.rpc_stats = rpc_stats
         self.latency = latency
         self.transfer = transfer
+        self.rate_limits = rate_limits
 
     @classmethod
     def from_json(cls, j: dict) -> Self:
@@ -193,6 +242,7 @@ class PeerStats:
             RPCStats.from_json(j["rpc_stats"]),
             None if j["latency"] is None else LatencyStats.from_json(j["latency"]),
             TransferStatsDownUp.from_json(j["transfer"]),
+            [RateLimitStats.from_json(x) for x in j.get("rate_limits", [])],
         )
 
 
@@ -212,6 +262,233 @@ class PeerTableData:
         return cls(j["node_ids"], j["peer_address"], PeerStats.from_json(j["peer_stats"]))
 
 
+class RouteHealth:
+    route_id: RouteId
+    remote: bool
+    published: bool
+    age: TimestampDuration
+    latency: LatencyStats
+    transfer: TransferStatsDownUp
+    successes: int
+    failures: int
+    recent_failures: int
+    last_tested_ts: Optional[Timestamp]
+    hop_count: int
+    hop_ipblock_count: Optional[int]
+    stability: Optional[Stability]
+    can_do_sequenced: Optional[bool]
+
+    def __init__(
+        self,
+        route_id: RouteId,
+        remote: bool,
+        published: bool,
+        age: TimestampDuration,
+        latency: LatencyStats,
+        transfer: TransferStatsDownUp,
+        successes: int,
+        failures: int,
+        recent_failures: int,
+        last_tested_ts: Optional[Timestamp],
+        hop_count: int,
+        hop_ipblock_count: Optional[int],
+        stability: Optional[Stability],
+        can_do_sequenced: Optional[bool],
+    ):
+        self.route_id = route_id
+        self.remote = remote
+        self.published = published
+        self.age = age
+        self.latency = latency
+        self.transfer = transfer
+        self.successes = successes
+        self.failures = failures
+        self.recent_failures = recent_failures
+        self.last_tested_ts = last_tested_ts
+        self.hop_count = hop_count
+        self.hop_ipblock_count = hop_ipblock_count
+        self.stability = stability
+        self.can_do_sequenced = can_do_sequenced
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        """JSON object hook"""
+        return cls(
+            RouteId(j["route_id"]),
+            j["remote"],
+            j["published"],
+            TimestampDuration(j["age"]),
+            LatencyStats.from_json(j["latency"]),
+            TransferStatsDownUp.from_json(j["transfer"]),
+            j["successes"],
+            j["failures"],
+            j["recent_failures"],
+            None if j["last_tested_ts"] is None else Timestamp(j["last_tested_ts"]),
+            j["hop_count"],
+            j["hop_ipblock_count"],
+            None if j["stability"] is None else Stability(j["stability"]),
+            j["can_do_sequenced"],
+        )
+
+
+class RoutingTableEntryState(StrEnum):
+    PUNISHED = "Punished"
+    DEAD = "Dead"
+    UNRELIABLE = "Unreliable"
+    RELIABLE = "Reliable"
+
+
+class NetworkClass(StrEnum):
+    INBOUND_CAPABLE = "InboundCapable"
+    OUTBOUND_ONLY = "OutboundOnly"
+    WEB_APP = "WebApp"
+    INVALID = "Invalid"
+
+
+class DialInfoClass(StrEnum):
+    DIRECT = "Direct"
+    MAPPED = "Mapped"
+    FULL_CONE_NAT = "FullConeNAT"
+    BLOCKED = "Blocked"
+    ADDRESS_RESTRICTED_NAT = "AddressRestrictedNAT"
+    PORT_RESTRICTED_NAT = "PortRestrictedNAT"
+
+
+class DebugBucketCounts:
+    kind: str
+    counts: list[int]
+
+    def __init__(self, kind: str, counts: list[int]):
+        self.kind = kind
+        self.counts = counts
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        """JSON object hook"""
+        return cls(j["kind"], j["counts"])
+
+
+class RoutingTableDialInfo:
+    class_: DialInfoClass
+    dial_info: str
+
+    def __init__(self, class_: DialInfoClass, dial_info: str):
+        self.class_ = class_
+        self.dial_info = dial_info
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        """JSON object hook"""
+        return cls(DialInfoClass(j["class"]), j["dial_info"])
+
+
+class RoutingTableNodeInfo:
+    network_class: NetworkClass
+    capabilities: list[str]
+    dial_info: list[RoutingTableDialInfo]
+    relay_ids: list[TypedKey]
+
+    def __init__(
+        self,
+        network_class: NetworkClass,
+        capabilities: list[str],
+        dial_info: list[RoutingTableDialInfo],
+        relay_ids: list[TypedKey],
+    ):
+        self.network_class = network_class
+        self.capabilities = capabilities
+        self.dial_info = dial_info
+        self.relay_ids = relay_ids
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        """JSON object hook"""
+        return cls(
+            NetworkClass(j["network_class"]),
+            j["capabilities"],
+            [RoutingTableDialInfo.from_json(di) for di in j["dial_info"]],
+            [TypedKey(k) for k in j["relay_ids"]],
+        )
+
+
+class RoutingTableEntry:
+    node_ids: list[TypedKey]
+    state: RoutingTableEntryState
+    state_reason: str
+    is_relay: bool
+    public_internet: Optional[RoutingTableNodeInfo]
+    local_network: Optional[RoutingTableNodeInfo]
+    peer_stats: PeerStats
+
+    def __init__(
+        self,
+        node_ids: list[TypedKey],
+        state: RoutingTableEntryState,
+        state_reason: str,
+        is_relay: bool,
+        public_internet: Optional[RoutingTableNodeInfo],
+        local_network: Optional[RoutingTableNodeInfo],
+        peer_stats: PeerStats,
+    ):
+        self.node_ids = node_ids
+        self.state = state
+        self.state_reason = state_reason
+        self.is_relay = is_relay
+        self.public_internet = public_internet
+        self.local_network = local_network
+        self.peer_stats = peer_stats
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        """JSON object hook"""
+        return cls(
+            [TypedKey(k) for k in j["node_ids"]],
+            RoutingTableEntryState(j["state"]),
+            j["state_reason"],
+            j["is_relay"],
+            None
+            if j.get("public_internet") is None
+            else RoutingTableNodeInfo.from_json(j["public_internet"]),
+            None
+            if j.get("local_network") is None
+            else RoutingTableNodeInfo.from_json(j["local_network"]),
+            PeerStats.from_json(j["peer_stats"]),
+        )
+
+
+class RoutingTableInfo:
+    node_ids: list[TypedKey]
+    public_internet_relay: list[TypedKey]
+    local_network_relay: list[TypedKey]
+    buckets: list[DebugBucketCounts]
+    entries: list[RoutingTableEntry]
+
+    def __init__(
+        self,
+        node_ids: list[TypedKey],
+        public_internet_relay: list[TypedKey],
+        local_network_relay: list[TypedKey],
+        buckets: list[DebugBucketCounts],
+        entries: list[RoutingTableEntry],
+    ):
+        self.node_ids = node_ids
+        self.public_internet_relay = public_internet_relay
+        self.local_network_relay = local_network_relay
+        self.buckets = buckets
+        self.entries = entries
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        """JSON object hook"""
+        return cls(
+            [TypedKey(k) for k in j["node_ids"]],
+            [TypedKey(k) for k in j["public_internet_relay"]],
+            [TypedKey(k) for k in j["local_network_relay"]],
+            [DebugBucketCounts.from_json(b) for b in j["buckets"]],
+            [RoutingTableEntry.from_json(e) for e in j["entries"]],
+        )
+
+
 class VeilidStateNetwork:
     started: bool
     bps_down: ByteCount
@@ -298,11 +575,19 @@ class VeilidAppMessage:
     sender: Optional[TypedKey]
     route_id: Optional[RouteId]
     message: bytes
+    channel: Optional[str]
 
-    def __init__(self, sender: Optional[TypedKey], route_id: Optional[RouteId], message: bytes):
+    def __init__(
+        self,
+        sender: Optional[TypedKey],
+        route_id: Optional[RouteId],
+        message: bytes,
+        channel: Optional[str] = None,
+    ):
         self.sender = sender
         self.route_id = route_id
         self.message = message
+        self.channel = channel
 
     @classmethod
     def from_json(cls, j: dict) -> Self:
@@ -311,6 +596,7 @@ class VeilidAppMessage:
             None if j["sender"] is None else TypedKey(j["sender"]),
             None if j["route_id"] is None else RouteId(j["route_id"]),
             urlsafe_b64decode_no_pad(j["message"]),
+            j.get("channel"),
         )
 
 
@@ -319,12 +605,21 @@ class VeilidAppCall:
     route_id: Optional[RouteId]
     message: bytes
     call_id: OperationId
+    channel: Optional[str]
 
-    def __init__(self, sender: Optional[TypedKey], route_id: Optional[TypedKey], message: bytes, call_id: OperationId):
+    def __init__(
+        self,
+        sender: Optional[TypedKey],
+        route_id: Optional[TypedKey],
+        message: bytes,
+        call_id: OperationId,
+        channel: Optional[str] = None,
+    ):
         self.sender = sender
         self.route_id = route_id
         self.message = message
         self.call_id = call_id
+        self.channel = channel
 
     @classmethod
     def from_json(cls, j: dict) -> Self:
@@ -334,16 +629,44 @@ class VeilidAppCall:
             None if j["route_id"] is None else RouteId(j["route_id"]),
             urlsafe_b64decode_no_pad(j["message"]),
             OperationId(j["call_id"]),
+            j.get("channel"),
+        )
+
+
+class VeilidRouteRotation:
+    old_route_id: RouteId
+    new_route_id: RouteId
+    blob: bytes
+
+    def __init__(self, old_route_id: RouteId, new_route_id: RouteId, blob: bytes):
+        self.old_route_id = old_route_id
+        self.new_route_id = new_route_id
+        self.blob = blob
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        """JSON object hook"""
+        return cls(
+            RouteId(j["old_route_id"]),
+            RouteId(j["new_route_id"]),
+            urlsafe_b64decode_no_pad(j["blob"]),
         )
 
 
 class VeilidRouteChange:
     dead_routes: list[RouteId]
     dead_remote_routes: list[RouteId]
+    rotated_routes: list[VeilidRouteRotation]
 
-    def __init__(self, dead_routes: list[RouteId], dead_remote_routes: list[RouteId]):
+    def __init__(
+        self,
+        dead_routes: list[RouteId],
+        dead_remote_routes: list[RouteId],
+        rotated_routes: list[VeilidRouteRotation],
+    ):
         self.dead_routes = dead_routes
         self.dead_remote_routes = dead_remote_routes
+        self.rotated_routes = rotated_routes
 
     @classmethod
     def from_json(cls, j: dict) -> Self:
@@ -351,6 +674,7 @@ class VeilidRouteChange:
         return cls(
             [RouteId(route) for route in j["dead_routes"]],
             [RouteId(route) for route in j["dead_remote_routes"]],
+            [VeilidRouteRotation.from_json(r) for r in j.get("rotated_routes", [])],
         )
 
 
@@ -377,6 +701,29 @@ class VeilidValueChange:
         )
 
 
+class VeilidOfflineWrite:
+    key: TypedKey
+    subkey: ValueSubkey
+    value: ValueData
+    superseded_by: Optional[ValueData]
+
+    def __init__(self, key: TypedKey, subkey: ValueSubkey, value: ValueData, superseded_by: Optional[ValueData]):
+        self.key = key
+        self.subkey = subkey
+        self.value = value
+        self.superseded_by = superseded_by
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        """JSON object hook"""
+        return cls(
+            TypedKey(j["key"]),
+            ValueSubkey(j["subkey"]),
+            ValueData.from_json(j["value"]),
+            None if j["superseded_by"] is None else ValueData.from_json(j["superseded_by"]),
+        )
+
+
 class VeilidUpdateKind(StrEnum):
     LOG = "Log"
     APP_MESSAGE = "AppMessage"
@@ -386,6 +733,7 @@ class VeilidUpdateKind(StrEnum):
     CONFIG = "Config"
     ROUTE_CHANGE = "RouteChange"
     VALUE_CHANGE = "ValueChange"
+    OFFLINE_WRITE = "OfflineWrite"
     SHUTDOWN = "Shutdown"
 
 
@@ -398,6 +746,7 @@ VeilidUpdateDetailType = Optional[
     | VeilidStateConfig
     | VeilidRouteChange
     | VeilidValueChange
+    | VeilidOfflineWrite
 ]
 
 
@@ -435,6 +784,8 @@ class VeilidUpdate:
                 detail = VeilidRouteChange.from_json(j)
             case VeilidUpdateKind.VALUE_CHANGE:
                 detail = VeilidValueChange.from_json(j)
+            case VeilidUpdateKind.OFFLINE_WRITE:
+                detail = VeilidOfflineWrite.from_json(j)
             case VeilidUpdateKind.SHUTDOWN:
                 detail = None
             case _:
@@ -60,6 +60,7 @@ class Capability(StrEnum):
     CAP_VALIDATE_DIAL_INFO = "DIAL"
     CAP_DHT = "DHTV"
     CAP_DHT_WATCH = "DHTW"
+    CAP_DHT_BATCH = "DHTB"
     CAP_APPMESSAGE = "APPM"
     CAP_BLOCKSTORE = "BLOC"
 
@@ -295,6 +296,72 @@ class NewPrivateRouteResult:
         return cls(RouteId(j["route_id"]), urlsafe_b64decode_no_pad(j["blob"]))
 
 
+class AppChannelOptions:
+    max_message_size: Optional[int]
+
+    def __init__(self, max_message_size: Optional[int] = None):
+        self.max_message_size = max_message_size
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        return cls(j.get("max_message_size"))
+
+    def to_json(self) -> dict:
+        return self.__dict__
+
+
+class SetDHTValueOptions:
+    writer: Optional[KeyPair]
+    expected_seq: Optional[ValueSeqNum]
+
+    def __init__(
+        self, writer: Optional[KeyPair] = None, expected_seq: Optional[ValueSeqNum] = None
+    ):
+        self.writer = writer
+        self.expected_seq = expected_seq
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        return cls(
+            None if j.get("writer") is None else KeyPair(j["writer"]),
+            None if j.get("expected_seq") is None else ValueSeqNum(j["expected_seq"]),
+        )
+
+    def to_json(self) -> dict:
+        return self.__dict__
+
+
+class RouteRotationPolicy:
+    max_age_ms: Optional[int]
+    max_recent_failures: Optional[int]
+    publish_key: Optional[TypedKey]
+    publish_subkey: ValueSubkey
+
+    def __init__(
+        self,
+        max_age_ms: Optional[int] = None,
+        max_recent_failures: Optional[int] = None,
+        publish_key: Optional[TypedKey] = None,
+        publish_subkey: ValueSubkey = ValueSubkey(0),
+    ):
+        self.max_age_ms = max_age_ms
+        self.max_recent_failures = max_recent_failures
+        self.publish_key = publish_key
+        self.publish_subkey = publish_subkey
+
+    @classmethod
+    def from_json(cls, j: dict) -> Self:
+        return cls(
+            j["max_age_ms"],
+            j["max_recent_failures"],
+            None if j["publish_key"] is None else TypedKey(j["publish_key"]),
+            ValueSubkey(j.get("publish_subkey", 0)),
+        )
+
+    def to_json(self) -> dict:
+        return self.__dict__
+
+
 class DHTSchemaSMPLMember:
     m_key: PublicKey
     m_cnt: int
@@ -27,7 +27,7 @@ use std::ffi::OsString;
 use std::path::Path;
 use std::str::FromStr;
 use tools::*;
-use veilid_core::{TypedKeyGroup, TypedSecretGroup};
+use veilid_core::{TypedKeyGroup, TypedKeyPair, TypedSecretGroup};
 use veilid_logs::*;
 
 #[derive(Args, Debug, Clone)]
@@ -129,10 +129,18 @@ pub struct CmdlineArgs {
     #[arg(long)]
     dump_txt_record: bool,
 
+    /// Prompts for a bootstrap signing keypair, prints the signed (v1) bootstrap TXT record for this node and then quits
+    #[arg(long)]
+    sign_txt_record: bool,
+
     /// Emits a JSON-Schema for a named type
     #[arg(long, value_name = "schema_name")]
     emit_schema: Option<String>,
 
+    /// Run as a bootstrap server, serving a signed list of reliable peers to bootstrapping nodes
+    #[arg(long)]
+    bootstrap_server: bool,
+
     /// Specify a list of bootstrap hostnames to use
     #[arg(long, value_name = "BOOTSTRAP_LIST")]
     bootstrap: Option<String>,
@@ -277,10 +285,20 @@ fn main() -> EyreResult<()> {
     if let Some(network_key) = args.network_key {
         settingsrw.core.network.network_key_password = Some(network_key);
     }
-    if args.dump_txt_record {
+    if args.dump_txt_record || args.sign_txt_record {
         // Turn off terminal logging so we can be interactive
         settingsrw.logging.terminal.enabled = false;
     }
+    let mut opt_txt_record_signing_key_pair = None;
+    if args.sign_txt_record {
+        let buffer =
+            rpassword::prompt_password("Enter bootstrap signing keypair (will not echo): ")
+                .wrap_err("invalid bootstrap signing keypair")?;
+        let buffer = buffer.trim().to_string();
+        let kp = TypedKeyPair::from_str(&buffer)
+            .wrap_err("failed to decode bootstrap signing keypair")?;
+        opt_txt_record_signing_key_pair = Some(kp);
+    }
     let mut node_id_set = false;
     if let Some(key_set) = args.set_node_id {
         node_id_set = true;
@@ -313,6 +331,10 @@ fn main() -> EyreResult<()> {
         settingsrw.core.network.routing_table.bootstrap = bootstrap_list;
     };
 
+    if args.bootstrap_server {
+        settingsrw.core.network.bootstrap_server.enabled = true;
+    }
+
     #[cfg(feature = "rt-tokio")]
     if args.console {
         settingsrw.logging.console.enabled = true;
@@ -405,8 +427,12 @@ fn main() -> EyreResult<()> {
             "Node Id and Secret set successfully",
             "Failed to set Node Id and Secret",
         )
-    } else if args.dump_txt_record {
-        (ServerMode::DumpTXTRecord, "", "Failed to dump txt record")
+    } else if args.dump_txt_record || args.sign_txt_record {
+        (
+            ServerMode::DumpTXTRecord(opt_txt_record_signing_key_pair),
+            "",
+            "Failed to dump txt record",
+        )
     } else {
         (ServerMode::Normal, "", "")
     };
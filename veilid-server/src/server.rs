@@ -11,12 +11,13 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tracing::*;
 use veilid_core::tools::*;
+use veilid_core::TypedKeyPair;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ServerMode {
     Normal,
     ShutdownImmediate,
-    DumpTXTRecord,
+    DumpTXTRecord(Option<TypedKeyPair>),
 }
 
 lazy_static! {
@@ -146,7 +147,7 @@ pub async fn run_veilid_server(
     }
 
     // Process dump-txt-record
-    if matches!(server_mode, ServerMode::DumpTXTRecord) {
+    if let ServerMode::DumpTXTRecord(opt_signing_key_pair) = server_mode {
         let start_time = Instant::now();
         while Instant::now().duration_since(start_time) < Duration::from_secs(10) {
             match veilid_api.get_state().await {
@@ -162,7 +163,11 @@ pub async fn run_veilid_server(
             }
             sleep(100).await;
         }
-        match veilid_api.debug("txtrecord".to_string()).await {
+        let command = match opt_signing_key_pair {
+            Some(signing_key_pair) => format!("txtrecord {}", signing_key_pair),
+            None => "txtrecord".to_string(),
+        };
+        match veilid_api.debug(command).await {
             Ok(v) => {
                 print!("{}", v);
             }
@@ -108,6 +108,8 @@ core:
             node_id: null
             node_id_secret: null
             bootstrap: ['bootstrap.veilid.net']
+            bootstrap_keys: []
+            bootstrap_allow_unsigned: true
             limit_over_attached: 64
             limit_fully_attached: 32
             limit_attached_strong: 16
@@ -116,6 +118,30 @@ core:
         rpc: 
             concurrency: 0
             queue_size: 1024
+            queues:
+                answer_queue_size: 256
+                status_queue_size: 256
+                dht_queue_size: 512
+                routed_queue_size: 512
+                max_queued_per_sender: 64
+            rate_limits:
+                enabled: true
+                punish_violation_count: 64
+                dht:
+                    rate_per_min: 1200
+                    burst: 128
+                dht_watch:
+                    rate_per_min: 120
+                    burst: 16
+                app_message:
+                    rate_per_min: 1200
+                    burst: 128
+                route:
+                    rate_per_min: 6000
+                    burst: 512
+                signal:
+                    rate_per_min: 120
+                    burst: 16
             max_timestamp_behind_ms: 10000
             max_timestamp_ahead_ms: 10000
             timeout_ms: 5000
@@ -147,6 +173,16 @@ core:
         upnp: true
         detect_address_changes: true
         restricted_nat_retries: 0
+        lan_discovery:
+            enabled: false
+            interval_ms: 10000
+            port: 5149
+            multicast_group_v4: '239.255.86.76'
+            multicast_group_v6: 'ff02::7665:696c'
+            max_beacons_per_min: 12
+        bootstrap_server:
+            enabled: false
+            max_peers_per_type: 2
         tls:
             certificate_path: '%CERTIFICATE_PATH%'
             private_key_path: '%PRIVATE_KEY_PATH%'
@@ -590,10 +626,54 @@ pub struct Tls {
     pub connection_initial_timeout_ms: u32,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LanDiscovery {
+    pub enabled: bool,
+    pub interval_ms: u32,
+    pub port: u16,
+    pub multicast_group_v4: String,
+    pub multicast_group_v6: String,
+    pub max_beacons_per_min: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BootstrapServer {
+    pub enabled: bool,
+    pub max_peers_per_type: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RpcQueues {
+    pub answer_queue_size: u32,
+    pub status_queue_size: u32,
+    pub dht_queue_size: u32,
+    pub routed_queue_size: u32,
+    pub max_queued_per_sender: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RpcRateLimit {
+    pub rate_per_min: u32,
+    pub burst: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RpcRateLimits {
+    pub enabled: bool,
+    pub punish_violation_count: u32,
+    pub dht: RpcRateLimit,
+    pub dht_watch: RpcRateLimit,
+    pub app_message: RpcRateLimit,
+    pub route: RpcRateLimit,
+    pub signal: RpcRateLimit,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Rpc {
     pub concurrency: u32,
     pub queue_size: u32,
+    pub queues: RpcQueues,
+    pub rate_limits: RpcRateLimits,
     pub max_timestamp_behind_ms: Option<u32>,
     pub max_timestamp_ahead_ms: Option<u32>,
     pub timeout_ms: u32,
@@ -632,6 +712,8 @@ pub struct RoutingTable {
     pub node_id: Option<veilid_core::TypedKeyGroup>,
     pub node_id_secret: Option<veilid_core::TypedSecretGroup>,
     pub bootstrap: Vec<String>,
+    pub bootstrap_keys: Vec<veilid_core::TypedKey>,
+    pub bootstrap_allow_unsigned: bool,
     pub limit_over_attached: u32,
     pub limit_fully_attached: u32,
     pub limit_attached_strong: u32,
@@ -657,6 +739,8 @@ pub struct Network {
     pub upnp: bool,
     pub detect_address_changes: bool,
     pub restricted_nat_retries: u32,
+    pub lan_discovery: LanDiscovery,
+    pub bootstrap_server: BootstrapServer,
     pub tls: Tls,
     pub application: Application,
     pub protocol: Protocol,
@@ -1059,6 +1143,11 @@ impl Settings {
         set_config_value!(inner.core.network.routing_table.node_id, value);
         set_config_value!(inner.core.network.routing_table.node_id_secret, value);
         set_config_value!(inner.core.network.routing_table.bootstrap, value);
+        set_config_value!(inner.core.network.routing_table.bootstrap_keys, value);
+        set_config_value!(
+            inner.core.network.routing_table.bootstrap_allow_unsigned,
+            value
+        );
         set_config_value!(inner.core.network.routing_table.limit_over_attached, value);
         set_config_value!(inner.core.network.routing_table.limit_fully_attached, value);
         set_config_value!(
@@ -1069,6 +1158,35 @@ impl Settings {
         set_config_value!(inner.core.network.routing_table.limit_attached_weak, value);
         set_config_value!(inner.core.network.rpc.concurrency, value);
         set_config_value!(inner.core.network.rpc.queue_size, value);
+        set_config_value!(inner.core.network.rpc.queues.answer_queue_size, value);
+        set_config_value!(inner.core.network.rpc.queues.status_queue_size, value);
+        set_config_value!(inner.core.network.rpc.queues.dht_queue_size, value);
+        set_config_value!(inner.core.network.rpc.queues.routed_queue_size, value);
+        set_config_value!(inner.core.network.rpc.queues.max_queued_per_sender, value);
+        set_config_value!(inner.core.network.rpc.rate_limits.enabled, value);
+        set_config_value!(
+            inner.core.network.rpc.rate_limits.punish_violation_count,
+            value
+        );
+        set_config_value!(inner.core.network.rpc.rate_limits.dht.rate_per_min, value);
+        set_config_value!(inner.core.network.rpc.rate_limits.dht.burst, value);
+        set_config_value!(
+            inner.core.network.rpc.rate_limits.dht_watch.rate_per_min,
+            value
+        );
+        set_config_value!(inner.core.network.rpc.rate_limits.dht_watch.burst, value);
+        set_config_value!(
+            inner.core.network.rpc.rate_limits.app_message.rate_per_min,
+            value
+        );
+        set_config_value!(inner.core.network.rpc.rate_limits.app_message.burst, value);
+        set_config_value!(inner.core.network.rpc.rate_limits.route.rate_per_min, value);
+        set_config_value!(inner.core.network.rpc.rate_limits.route.burst, value);
+        set_config_value!(
+            inner.core.network.rpc.rate_limits.signal.rate_per_min,
+            value
+        );
+        set_config_value!(inner.core.network.rpc.rate_limits.signal.burst, value);
         set_config_value!(inner.core.network.rpc.max_timestamp_behind_ms, value);
         set_config_value!(inner.core.network.rpc.max_timestamp_ahead_ms, value);
         set_config_value!(inner.core.network.rpc.timeout_ms, value);
@@ -1108,6 +1226,17 @@ impl Settings {
         set_config_value!(inner.core.network.upnp, value);
         set_config_value!(inner.core.network.detect_address_changes, value);
         set_config_value!(inner.core.network.restricted_nat_retries, value);
+        set_config_value!(inner.core.network.lan_discovery.enabled, value);
+        set_config_value!(inner.core.network.lan_discovery.interval_ms, value);
+        set_config_value!(inner.core.network.lan_discovery.port, value);
+        set_config_value!(inner.core.network.lan_discovery.multicast_group_v4, value);
+        set_config_value!(inner.core.network.lan_discovery.multicast_group_v6, value);
+        set_config_value!(inner.core.network.lan_discovery.max_beacons_per_min, value);
+        set_config_value!(inner.core.network.bootstrap_server.enabled, value);
+        set_config_value!(
+            inner.core.network.bootstrap_server.max_peers_per_type,
+            value
+        );
         set_config_value!(inner.core.network.tls.certificate_path, value);
         set_config_value!(inner.core.network.tls.private_key_path, value);
         set_config_value!(inner.core.network.tls.connection_initial_timeout_ms, value);
@@ -1245,6 +1374,12 @@ impl Settings {
                 "network.routing_table.bootstrap" => {
                     Ok(Box::new(inner.core.network.routing_table.bootstrap.clone()))
                 }
+                "network.routing_table.bootstrap_keys" => Ok(Box::new(
+                    inner.core.network.routing_table.bootstrap_keys.clone(),
+                )),
+                "network.routing_table.bootstrap_allow_unsigned" => Ok(Box::new(
+                    inner.core.network.routing_table.bootstrap_allow_unsigned,
+                )),
                 "network.routing_table.limit_over_attached" => Ok(Box::new(
                     inner.core.network.routing_table.limit_over_attached,
                 )),
@@ -1262,6 +1397,57 @@ impl Settings {
                 )),
                 "network.rpc.concurrency" => Ok(Box::new(inner.core.network.rpc.concurrency)),
                 "network.rpc.queue_size" => Ok(Box::new(inner.core.network.rpc.queue_size)),
+                "network.rpc.queues.answer_queue_size" => {
+                    Ok(Box::new(inner.core.network.rpc.queues.answer_queue_size))
+                }
+                "network.rpc.queues.status_queue_size" => {
+                    Ok(Box::new(inner.core.network.rpc.queues.status_queue_size))
+                }
+                "network.rpc.queues.dht_queue_size" => {
+                    Ok(Box::new(inner.core.network.rpc.queues.dht_queue_size))
+                }
+                "network.rpc.queues.routed_queue_size" => {
+                    Ok(Box::new(inner.core.network.rpc.queues.routed_queue_size))
+                }
+                "network.rpc.queues.max_queued_per_sender" => Ok(Box::new(
+                    inner.core.network.rpc.queues.max_queued_per_sender,
+                )),
+                "network.rpc.rate_limits.enabled" => {
+                    Ok(Box::new(inner.core.network.rpc.rate_limits.enabled))
+                }
+                "network.rpc.rate_limits.punish_violation_count" => Ok(Box::new(
+                    inner.core.network.rpc.rate_limits.punish_violation_count,
+                )),
+                "network.rpc.rate_limits.dht.rate_per_min" => Ok(Box::new(
+                    inner.core.network.rpc.rate_limits.dht.rate_per_min,
+                )),
+                "network.rpc.rate_limits.dht.burst" => {
+                    Ok(Box::new(inner.core.network.rpc.rate_limits.dht.burst))
+                }
+                "network.rpc.rate_limits.dht_watch.rate_per_min" => Ok(Box::new(
+                    inner.core.network.rpc.rate_limits.dht_watch.rate_per_min,
+                )),
+                "network.rpc.rate_limits.dht_watch.burst" => {
+                    Ok(Box::new(inner.core.network.rpc.rate_limits.dht_watch.burst))
+                }
+                "network.rpc.rate_limits.app_message.rate_per_min" => Ok(Box::new(
+                    inner.core.network.rpc.rate_limits.app_message.rate_per_min,
+                )),
+                "network.rpc.rate_limits.app_message.burst" => Ok(Box::new(
+                    inner.core.network.rpc.rate_limits.app_message.burst,
+                )),
+                "network.rpc.rate_limits.route.rate_per_min" => Ok(Box::new(
+                    inner.core.network.rpc.rate_limits.route.rate_per_min,
+                )),
+                "network.rpc.rate_limits.route.burst" => {
+                    Ok(Box::new(inner.core.network.rpc.rate_limits.route.burst))
+                }
+                "network.rpc.rate_limits.signal.rate_per_min" => Ok(Box::new(
+                    inner.core.network.rpc.rate_limits.signal.rate_per_min,
+                )),
+                "network.rpc.rate_limits.signal.burst" => {
+                    Ok(Box::new(inner.core.network.rpc.rate_limits.signal.burst))
+                }
                 "network.rpc.max_timestamp_behind_ms" => {
                     Ok(Box::new(inner.core.network.rpc.max_timestamp_behind_ms))
                 }
@@ -1346,6 +1532,28 @@ impl Settings {
                 "network.restricted_nat_retries" => {
                     Ok(Box::new(inner.core.network.restricted_nat_retries))
                 }
+                "network.lan_discovery.enabled" => {
+                    Ok(Box::new(inner.core.network.lan_discovery.enabled))
+                }
+                "network.lan_discovery.interval_ms" => {
+                    Ok(Box::new(inner.core.network.lan_discovery.interval_ms))
+                }
+                "network.lan_discovery.port" => Ok(Box::new(inner.core.network.lan_discovery.port)),
+                "network.lan_discovery.multicast_group_v4" => Ok(Box::new(
+                    inner.core.network.lan_discovery.multicast_group_v4.clone(),
+                )),
+                "network.lan_discovery.multicast_group_v6" => Ok(Box::new(
+                    inner.core.network.lan_discovery.multicast_group_v6.clone(),
+                )),
+                "network.lan_discovery.max_beacons_per_min" => Ok(Box::new(
+                    inner.core.network.lan_discovery.max_beacons_per_min,
+                )),
+                "network.bootstrap_server.enabled" => {
+                    Ok(Box::new(inner.core.network.bootstrap_server.enabled))
+                }
+                "network.bootstrap_server.max_peers_per_type" => Ok(Box::new(
+                    inner.core.network.bootstrap_server.max_peers_per_type,
+                )),
                 "network.tls.certificate_path" => {
                     Ok(Box::new(inner.core.network.tls.certificate_path.clone()))
                 }
@@ -1634,9 +1842,31 @@ mod tests {
             s.core.network.routing_table.bootstrap,
             vec!["bootstrap.veilid.net".to_owned()]
         );
+        assert!(s.core.network.routing_table.bootstrap_keys.is_empty());
+        assert!(s.core.network.routing_table.bootstrap_allow_unsigned);
         //
         assert_eq!(s.core.network.rpc.concurrency, 0);
         assert_eq!(s.core.network.rpc.queue_size, 1024);
+        assert_eq!(s.core.network.rpc.queues.answer_queue_size, 256);
+        assert_eq!(s.core.network.rpc.queues.status_queue_size, 256);
+        assert_eq!(s.core.network.rpc.queues.dht_queue_size, 512);
+        assert_eq!(s.core.network.rpc.queues.routed_queue_size, 512);
+        assert_eq!(s.core.network.rpc.queues.max_queued_per_sender, 64);
+        assert!(s.core.network.rpc.rate_limits.enabled);
+        assert_eq!(s.core.network.rpc.rate_limits.punish_violation_count, 64);
+        assert_eq!(s.core.network.rpc.rate_limits.dht.rate_per_min, 1200);
+        assert_eq!(s.core.network.rpc.rate_limits.dht.burst, 128);
+        assert_eq!(s.core.network.rpc.rate_limits.dht_watch.rate_per_min, 120);
+        assert_eq!(s.core.network.rpc.rate_limits.dht_watch.burst, 16);
+        assert_eq!(
+            s.core.network.rpc.rate_limits.app_message.rate_per_min,
+            1200
+        );
+        assert_eq!(s.core.network.rpc.rate_limits.app_message.burst, 128);
+        assert_eq!(s.core.network.rpc.rate_limits.route.rate_per_min, 6000);
+        assert_eq!(s.core.network.rpc.rate_limits.route.burst, 512);
+        assert_eq!(s.core.network.rpc.rate_limits.signal.rate_per_min, 120);
+        assert_eq!(s.core.network.rpc.rate_limits.signal.burst, 16);
         assert_eq!(s.core.network.rpc.max_timestamp_behind_ms, Some(10_000u32));
         assert_eq!(s.core.network.rpc.max_timestamp_ahead_ms, Some(10_000u32));
         assert_eq!(s.core.network.rpc.timeout_ms, 5_000u32);
@@ -1666,6 +1896,20 @@ mod tests {
         assert!(s.core.network.upnp);
         assert!(s.core.network.detect_address_changes);
         assert_eq!(s.core.network.restricted_nat_retries, 0u32);
+        assert!(!s.core.network.lan_discovery.enabled);
+        assert_eq!(s.core.network.lan_discovery.interval_ms, 10_000u32);
+        assert_eq!(s.core.network.lan_discovery.port, 5149u16);
+        assert_eq!(
+            s.core.network.lan_discovery.multicast_group_v4,
+            "239.255.86.76".to_owned()
+        );
+        assert_eq!(
+            s.core.network.lan_discovery.multicast_group_v6,
+            "ff02::7665:696c".to_owned()
+        );
+        assert_eq!(s.core.network.lan_discovery.max_beacons_per_min, 12u32);
+        assert!(!s.core.network.bootstrap_server.enabled);
+        assert_eq!(s.core.network.bootstrap_server.max_peers_per_type, 2u32);
         //
         assert_eq!(
             s.core.network.tls.certificate_path,
@@ -20,6 +20,9 @@
 //! * `rt-async-std` - Uses `async-std` as the async runtime
 //! * `rt-wasm-bindgen` - When building for the `wasm32` architecture, use this to enable `wasm-bindgen-futures` as the async runtime
 //!
+//! For testing, the `simulation` feature allows a virtual clock and a random number seed to be installed,
+//! see the `simulation` module.
+//!
 #![deny(clippy::all)]
 #![allow(clippy::comparison_chain, clippy::upper_case_acronyms)]
 #![deny(unused_must_use)]
@@ -45,6 +48,8 @@ pub mod mutable_future;
 pub mod network_interfaces;
 pub mod network_result;
 pub mod random;
+#[cfg(feature = "simulation")]
+pub mod simulation;
 pub mod single_shot_eventual;
 pub mod sleep;
 pub mod spawn;
@@ -222,6 +227,9 @@ pub use network_result::*;
 #[doc(inline)]
 pub use random::*;
 #[doc(inline)]
+#[cfg(feature = "simulation")]
+pub use simulation::*;
+#[doc(inline)]
 pub use single_shot_eventual::*;
 #[doc(inline)]
 pub use sleep::*;
@@ -1,3 +1,5 @@
+#[cfg(feature = "simulation")]
+use crate::simulation::with_simulation_rng;
 use rand::prelude::*;
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -25,16 +27,28 @@ impl RngCore for VeilidRng {
 }
 
 pub fn random_bytes(dest: &mut [u8]) {
+    #[cfg(feature = "simulation")]
+    if with_simulation_rng(|rng| rng.fill_bytes(dest)).is_some() {
+        return;
+    }
     let mut rng = rand::thread_rng();
     rng.fill_bytes(dest);
 }
 
 pub fn get_random_u32() -> u32 {
+    #[cfg(feature = "simulation")]
+    if let Some(v) = with_simulation_rng(|rng| rng.next_u32()) {
+        return v;
+    }
     let mut rng = rand::thread_rng();
     rng.next_u32()
 }
 
 pub fn get_random_u64() -> u64 {
+    #[cfg(feature = "simulation")]
+    if let Some(v) = with_simulation_rng(|rng| rng.next_u64()) {
+        return v;
+    }
     let mut rng = rand::thread_rng();
     rng.next_u64()
 }
@@ -0,0 +1,206 @@
+//! Deterministic simulation support
+//!
+//! A [SimulationClock] installed with [set_simulation_clock] replaces wall-clock time for
+//! [get_timestamp], [sleep] and [timeout], and so for everything built on them such as [TickTask]
+//! and [interval]. Time only moves when the clock is advanced, so long periods can be skipped
+//! instantly. A seed set with [set_simulation_seed] makes [VeilidRng] and the random functions
+//! reproducible, so a failing run can be repeated from its seed.
+//!
+//! The clock and seed are process-wide. Runs are only fully reproducible on a single-threaded
+//! executor, where woken tasks run in a fixed order.
+use super::*;
+
+use core::task::{Context, Poll, Waker};
+use futures_util::future::{select, Either};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+static SIMULATION_CLOCK: RwLock<Option<SimulationClock>> = const_rwlock(None);
+static SIMULATION_RNG: Mutex<Option<StdRng>> = const_mutex(None);
+
+/// How many times the executor is yielded to after timers fire, so woken tasks can run
+/// and set new timers before the clock moves again
+const SIMULATION_SETTLE_YIELDS: usize = 16;
+
+/// Install a clock to use in place of wall-clock time, or None to go back to wall-clock time
+pub fn set_simulation_clock(clock: Option<SimulationClock>) {
+    *SIMULATION_CLOCK.write() = clock;
+}
+
+/// The installed simulation clock, if there is one
+pub fn simulation_clock() -> Option<SimulationClock> {
+    SIMULATION_CLOCK.read().clone()
+}
+
+/// Seed the random number generator used by [VeilidRng] and the random functions,
+/// or None to go back to the system random number generator
+pub fn set_simulation_seed(seed: Option<u64>) {
+    *SIMULATION_RNG.lock() = seed.map(StdRng::seed_from_u64);
+}
+
+pub(crate) fn with_simulation_rng<R, F: FnOnce(&mut StdRng) -> R>(f: F) -> Option<R> {
+    SIMULATION_RNG.lock().as_mut().map(f)
+}
+
+#[derive(Debug)]
+struct SimulationClockInner {
+    now_us: u64,
+    next_timer_id: u64,
+    /// Pending timers by deadline and the order they were made in
+    timers: BTreeMap<(u64, u64), Option<Waker>>,
+}
+
+/// A clock that only moves when it is advanced, with timers that fire in deadline order
+#[derive(Debug, Clone)]
+pub struct SimulationClock {
+    inner: Arc<Mutex<SimulationClockInner>>,
+}
+
+impl SimulationClock {
+    /// Make a clock that starts at 'start_us' microseconds since the epoch
+    pub fn new(start_us: u64) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(SimulationClockInner {
+                now_us: start_us,
+                next_timer_id: 0,
+                timers: BTreeMap::new(),
+            })),
+        }
+    }
+
+    /// The current simulated time in microseconds since the epoch
+    pub fn now_us(&self) -> u64 {
+        self.inner.lock().now_us
+    }
+
+    /// The deadline of the earliest pending timer
+    pub fn next_deadline_us(&self) -> Option<u64> {
+        self.inner
+            .lock()
+            .timers
+            .keys()
+            .next()
+            .map(|(deadline_us, _)| *deadline_us)
+    }
+
+    /// The number of timers waiting for the clock to advance
+    pub fn pending_timers(&self) -> usize {
+        self.inner.lock().timers.len()
+    }
+
+    /// Move the clock forward by 'dur_us', firing each timer that comes due in deadline order
+    pub async fn advance_us(&self, dur_us: u64) {
+        let target_us = self.now_us().saturating_add(dur_us);
+        while self.fire_next_timers(target_us) {
+            Self::settle().await;
+        }
+        let mut inner = self.inner.lock();
+        inner.now_us = inner.now_us.max(target_us);
+    }
+
+    /// Move the clock forward to the earliest pending timer and fire it
+    /// Returns false if there were no timers to fire
+    pub async fn advance_to_next(&self) -> bool {
+        let Some(deadline_us) = self.next_deadline_us() else {
+            return false;
+        };
+        if self.fire_next_timers(deadline_us) {
+            Self::settle().await;
+        }
+        true
+    }
+
+    /// A future that completes once the clock has advanced by 'dur_us'
+    pub fn sleep_us(&self, dur_us: u64) -> SimulationSleep {
+        let mut inner = self.inner.lock();
+        let id = inner.next_timer_id;
+        inner.next_timer_id += 1;
+        let deadline_us = inner.now_us.saturating_add(dur_us);
+        inner.timers.insert((deadline_us, id), None);
+        SimulationSleep {
+            clock: self.clone(),
+            deadline_us,
+            id,
+        }
+    }
+
+    /// Run a future until it completes or the clock advances by 'dur_ms'
+    pub async fn timeout<F, T>(&self, dur_ms: u32, f: F) -> Result<T, TimeoutError>
+    where
+        F: Future<Output = T>,
+    {
+        let tout = select(
+            Box::pin(self.sleep_us(u64::from(dur_ms) * 1_000u64)),
+            Box::pin(f),
+        );
+
+        match tout.await {
+            Either::Left((_x, _b)) => Err(TimeoutError()),
+            Either::Right((y, _a)) => Ok(y),
+        }
+    }
+
+    // Move to the earliest deadline at or before 'target_us' and wake its timers
+    fn fire_next_timers(&self, target_us: u64) -> bool {
+        let wakers = {
+            let mut inner = self.inner.lock();
+            let Some(deadline_us) = inner
+                .timers
+                .keys()
+                .next()
+                .map(|(deadline_us, _)| *deadline_us)
+                .filter(|deadline_us| *deadline_us <= target_us)
+            else {
+                return false;
+            };
+            inner.now_us = inner.now_us.max(deadline_us);
+            let later = inner.timers.split_off(&(deadline_us + 1, 0));
+            let due = mem::replace(&mut inner.timers, later);
+            due.into_values().flatten().collect::<Vec<_>>()
+        };
+        for waker in wakers {
+            waker.wake();
+        }
+        true
+    }
+
+    async fn settle() {
+        for _ in 0..SIMULATION_SETTLE_YIELDS {
+            sleep(0).await;
+        }
+    }
+}
+
+/// A timer on a [SimulationClock]
+#[derive(Debug)]
+pub struct SimulationSleep {
+    clock: SimulationClock,
+    deadline_us: u64,
+    id: u64,
+}
+
+impl Future for SimulationSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut inner = self.clock.inner.lock();
+        if inner.now_us >= self.deadline_us {
+            inner.timers.remove(&(self.deadline_us, self.id));
+            return Poll::Ready(());
+        }
+        inner
+            .timers
+            .insert((self.deadline_us, self.id), Some(cx.waker().clone()));
+        Poll::Pending
+    }
+}
+
+impl Drop for SimulationSleep {
+    fn drop(&mut self) {
+        self.clock
+            .inner
+            .lock()
+            .timers
+            .remove(&(self.deadline_us, self.id));
+    }
+}
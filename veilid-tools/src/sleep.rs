@@ -1,17 +1,31 @@
 use super::*;
 use std::time::Duration;
 
+/// Sleep for 'millis' milliseconds, or yield to other tasks if 'millis' is zero
+///
+/// Under a simulation clock, nonzero sleeps wait for the clock instead of real time,
+/// while zero sleeps still yield on every platform so the clock can settle
+pub async fn sleep(millis: u32) {
+    #[cfg(feature = "simulation")]
+    if millis != 0 {
+        if let Some(clock) = simulation_clock() {
+            return clock.sleep_us(u64::from(millis) * 1_000u64).await;
+        }
+    }
+    platform_sleep(millis).await
+}
+
 cfg_if! {
     if #[cfg(target_arch = "wasm32")] {
         use async_executors::{Bindgen, Timer};
 
-        pub async fn sleep(millis: u32) {
+        async fn platform_sleep(millis: u32) {
             Bindgen.sleep(Duration::from_millis(millis.into())).await
         }
 
     } else {
 
-        pub async fn sleep(millis: u32) {
+        async fn platform_sleep(millis: u32) {
             if millis == 0 {
                 cfg_if! {
                     if #[cfg(feature="rt-async-std")] {
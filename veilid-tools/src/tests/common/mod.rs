@@ -1,5 +1,7 @@
 pub mod test_async_tag_lock;
 pub mod test_host_interface;
+#[cfg(feature = "simulation")]
+pub mod test_simulation;
 pub mod test_startup_lock;
 
 #[allow(dead_code)]
@@ -0,0 +1,104 @@
+use crate::*;
+
+use futures_util::FutureExt;
+
+const START_US: u64 = 1_000_000_000_000u64;
+
+pub async fn test_simulation_clock() {
+    info!("test_simulation_clock");
+
+    let clock = SimulationClock::new(START_US);
+    set_simulation_clock(Some(clock.clone()));
+
+    assert_eq!(get_timestamp(), START_US);
+
+    // Sleeps only finish when the clock gets to them
+    let mut short = Box::pin(sleep(500));
+    let mut long = Box::pin(sleep(1_000));
+    assert!((&mut short).now_or_never().is_none());
+    assert!((&mut long).now_or_never().is_none());
+    assert_eq!(clock.pending_timers(), 2);
+    assert_eq!(clock.next_deadline_us(), Some(START_US + 500_000));
+
+    clock.advance_us(600_000).await;
+    assert_eq!(get_timestamp(), START_US + 600_000);
+    assert!((&mut short).now_or_never().is_some());
+    assert!((&mut long).now_or_never().is_none());
+
+    assert!(clock.advance_to_next().await);
+    assert_eq!(get_timestamp(), START_US + 1_000_000);
+    assert!((&mut long).now_or_never().is_some());
+    assert!(!clock.advance_to_next().await);
+
+    // Dropped sleeps do not hold timers
+    drop(Box::pin(sleep(1_000)));
+    assert_eq!(clock.pending_timers(), 0);
+
+    // Timeouts fire on simulated time
+    let mut tout = Box::pin(timeout(100, futures_util::future::pending::<()>()));
+    assert!((&mut tout).now_or_never().is_none());
+    clock.advance_us(100_000).await;
+    assert_eq!((&mut tout).now_or_never(), Some(Err(TimeoutError())));
+
+    set_simulation_clock(None);
+}
+
+pub async fn test_simulation_tick_task() {
+    info!("test_simulation_tick_task");
+
+    let clock = SimulationClock::new(START_US);
+    set_simulation_clock(Some(clock.clone()));
+
+    let task = TickTask::<()>::new("simulation tick task", 1);
+    task.set_routine(|_stop_token, _last_ts, _cur_ts| Box::pin(async { Ok(()) }));
+
+    task.tick().await.unwrap();
+    task.stop().await.unwrap();
+    assert_eq!(task.last_timestamp_us(), Some(START_US));
+
+    // Not time yet
+    clock.advance_us(500_000).await;
+    task.tick().await.unwrap();
+    task.stop().await.unwrap();
+    assert_eq!(task.last_timestamp_us(), Some(START_US));
+
+    clock.advance_us(500_000).await;
+    task.tick().await.unwrap();
+    task.stop().await.unwrap();
+    assert_eq!(task.last_timestamp_us(), Some(START_US + 1_000_000));
+
+    set_simulation_clock(None);
+}
+
+pub async fn test_simulation_seed() {
+    info!("test_simulation_seed");
+
+    let draw = || {
+        let mut bytes = [0u8; 16];
+        random_bytes(&mut bytes);
+        let mut rng = VeilidRng;
+        (
+            get_random_u32(),
+            get_random_u64(),
+            bytes,
+            rand::RngCore::next_u64(&mut rng),
+        )
+    };
+
+    set_simulation_seed(Some(42));
+    let first = draw();
+    set_simulation_seed(Some(42));
+    let second = draw();
+    set_simulation_seed(Some(43));
+    let third = draw();
+    set_simulation_seed(None);
+
+    assert_eq!(first, second);
+    assert_ne!(first, third);
+}
+
+pub async fn test_all() {
+    test_simulation_clock().await;
+    test_simulation_tick_task().await;
+    test_simulation_seed().await;
+}
@@ -24,6 +24,11 @@ pub async fn run_all_tests() {
     test_async_tag_lock::test_all().await;
     info!("TEST: exec_test_assembly_buffer");
     test_assembly_buffer::test_all().await;
+    #[cfg(feature = "simulation")]
+    {
+        info!("TEST: exec_test_simulation");
+        test_simulation::test_all().await;
+    }
 
     info!("Finished unit tests");
 }
@@ -133,5 +138,15 @@ cfg_if! {
                 test_assembly_buffer::test_all().await;
             });
         }
+
+        #[cfg(feature = "simulation")]
+        #[test]
+        #[serial]
+        fn run_test_simulation() {
+            setup();
+            block_on(async {
+                test_simulation::test_all().await;
+            });
+        }
     }
 }
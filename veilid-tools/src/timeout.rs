@@ -8,6 +8,10 @@ cfg_if! {
         where
             F: Future<Output = T>,
         {
+            #[cfg(feature = "simulation")]
+            if let Some(clock) = simulation_clock() {
+                return clock.timeout(dur_ms, f).await;
+            }
             let tout = select(Box::pin(sleep(dur_ms)), Box::pin(f));
 
             match tout.await {
@@ -22,6 +26,10 @@ cfg_if! {
         where
             F: Future<Output = T>,
         {
+            #[cfg(feature = "simulation")]
+            if let Some(clock) = simulation_clock() {
+                return clock.timeout(dur_ms, f).await;
+            }
             cfg_if! {
                 if #[cfg(feature="rt-async-std")] {
                     let tout = async_std::future::timeout(Duration::from_millis(dur_ms as u64), f);
@@ -5,6 +5,10 @@ cfg_if! {
         use js_sys::Date;
 
         pub fn get_timestamp() -> u64 {
+            #[cfg(feature = "simulation")]
+            if let Some(clock) = simulation_clock() {
+                return clock.now_us();
+            }
             if is_browser() {
                 (Date::now() * 1000.0f64) as u64
             } else {
@@ -60,6 +64,10 @@ cfg_if! {
         use chrono::{Datelike, Timelike};
 
         pub fn get_timestamp() -> u64 {
+            #[cfg(feature = "simulation")]
+            if let Some(clock) = simulation_clock() {
+                return clock.now_us();
+            }
             match SystemTime::now().duration_since(UNIX_EPOCH) {
                 Ok(n) => n.as_micros() as u64,
                 Err(_) => panic!("SystemTime before UNIX_EPOCH!"),
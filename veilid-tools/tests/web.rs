@@ -51,3 +51,11 @@ async fn run_test_startup_lock() {
 
     test_startup_lock::test_all().await;
 }
+
+#[cfg(feature = "simulation")]
+#[wasm_bindgen_test]
+async fn run_test_simulation() {
+    setup();
+
+    test_simulation::test_all().await;
+}
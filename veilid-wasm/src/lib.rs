@@ -445,32 +445,58 @@ pub fn routing_context_safety(id: u32) -> Promise {
 }
 
 #[wasm_bindgen()]
-pub fn routing_context_app_call(id: u32, target_string: String, request: String) -> Promise {
+pub fn routing_context_app_call(
+    id: u32,
+    target_string: String,
+    request: String,
+    channel: Option<String>,
+) -> Promise {
     let request: Vec<u8> = data_encoding::BASE64URL_NOPAD
         .decode(request.as_bytes())
         .unwrap();
     wrap_api_future_plain(async move {
         let routing_context = get_routing_context(id, "routing_context_app_call")?;
 
+        let channel = channel.map(veilid_core::AppChannel::try_from).transpose()?;
         let veilid_api = get_veilid_api()?;
         let target = veilid_api.parse_as_target(target_string)?;
-        let answer = routing_context.app_call(target, request).await?;
+        let answer = match channel {
+            Some(channel) => {
+                routing_context
+                    .app_call_on_channel(target, channel, request)
+                    .await?
+            }
+            None => routing_context.app_call(target, request).await?,
+        };
         let answer = data_encoding::BASE64URL_NOPAD.encode(&answer);
         APIResult::Ok(answer)
     })
 }
 
 #[wasm_bindgen()]
-pub fn routing_context_app_message(id: u32, target_string: String, message: String) -> Promise {
+pub fn routing_context_app_message(
+    id: u32,
+    target_string: String,
+    message: String,
+    channel: Option<String>,
+) -> Promise {
     let message: Vec<u8> = data_encoding::BASE64URL_NOPAD
         .decode(message.as_bytes())
         .unwrap();
     wrap_api_future_void(async move {
         let routing_context = get_routing_context(id, "routing_context_app_message")?;
 
+        let channel = channel.map(veilid_core::AppChannel::try_from).transpose()?;
         let veilid_api = get_veilid_api()?;
         let target = veilid_api.parse_as_target(target_string)?;
-        routing_context.app_message(target, message).await?;
+        match channel {
+            Some(channel) => {
+                routing_context
+                    .app_message_on_channel(target, channel, message)
+                    .await?
+            }
+            None => routing_context.app_message(target, message).await?,
+        }
         APIRESULT_UNDEFINED
     })
 }
@@ -554,6 +580,7 @@ pub fn routing_context_set_dht_value(
     subkey: u32,
     data: String,
     writer: Option<String>,
+    expected_seq: Option<u32>,
 ) -> Promise {
     let key: veilid_core::TypedKey = veilid_core::deserialize_json(&key).unwrap();
     let data: Vec<u8> = data_encoding::BASE64URL_NOPAD
@@ -566,8 +593,60 @@ pub fn routing_context_set_dht_value(
         let routing_context = get_routing_context(id, "routing_context_set_dht_value")?;
 
         let res = routing_context
-            .set_dht_value(key, subkey, data, writer)
+            .set_dht_value(
+                key,
+                subkey,
+                data,
+                Some(veilid_core::SetDHTValueOptions {
+                    writer,
+                    expected_seq,
+                }),
+            )
+            .await?;
+        APIResult::Ok(res)
+    })
+}
+
+#[wasm_bindgen()]
+pub fn routing_context_set_dht_large_value(
+    id: u32,
+    key: String,
+    subkey: u32,
+    data: String,
+    writer: Option<String>,
+) -> Promise {
+    let key: veilid_core::TypedKey = veilid_core::deserialize_json(&key).unwrap();
+    let data: Vec<u8> = data_encoding::BASE64URL_NOPAD
+        .decode(data.as_bytes())
+        .unwrap();
+    let writer: Option<veilid_core::KeyPair> =
+        writer.map(|s| veilid_core::deserialize_json(&s).unwrap());
+
+    wrap_api_future_json(async move {
+        let routing_context = get_routing_context(id, "routing_context_set_dht_large_value")?;
+
+        let res = routing_context
+            .set_dht_large_value(key, subkey, data, writer)
+            .await?;
+        APIResult::Ok(res)
+    })
+}
+
+#[wasm_bindgen()]
+pub fn routing_context_get_dht_large_value(
+    id: u32,
+    key: String,
+    subkey: u32,
+    force_refresh: bool,
+) -> Promise {
+    let key: veilid_core::TypedKey = veilid_core::deserialize_json(&key).unwrap();
+    wrap_api_future_json(async move {
+        let routing_context = get_routing_context(id, "routing_context_get_dht_large_value")?;
+
+        let res = routing_context
+            .get_dht_large_value(key, subkey, force_refresh)
             .await?;
+        let res = res.map(|data| data_encoding::BASE64URL_NOPAD.encode(&data));
         APIResult::Ok(res)
     })
 }
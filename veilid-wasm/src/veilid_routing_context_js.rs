@@ -156,13 +156,27 @@ impl VeilidRoutingContext {
     ///
     /// @param {string} target - can be either a direct node id or a private route.
     /// @param {string} message - an arbitrary message blob of up to `32768` bytes.
+    /// @param {string} [channel] - an optional application channel registered by the receiver.
     #[wasm_bindgen(skip_jsdoc)]
-    pub async fn appMessage(&self, target_string: String, message: Box<[u8]>) -> APIResult<()> {
+    pub async fn appMessage(
+        &self,
+        target_string: String,
+        message: Box<[u8]>,
+        channel: Option<String>,
+    ) -> APIResult<()> {
         let routing_context = self.getRoutingContext()?;
         let message = message.into_vec();
+        let channel = channel.map(AppChannel::try_from).transpose()?;
         let veilid_api = get_veilid_api()?;
         let target = veilid_api.parse_as_target(target_string)?;
-        routing_context.app_message(target, message).await?;
+        match channel {
+            Some(channel) => {
+                routing_context
+                    .app_message_on_channel(target, channel, message)
+                    .await?
+            }
+            None => routing_context.app_message(target, message).await?,
+        }
         APIRESULT_UNDEFINED
     }
 
@@ -172,19 +186,29 @@ impl VeilidRoutingContext {
     ///
     /// @param {string} target_string - can be either a direct node id or a private route.
     /// @param {Uint8Array} message - an arbitrary message blob of up to `32768` bytes.
+    /// @param {string} [channel] - an optional application channel registered by the receiver.
     /// @returns {Uint8Array} an answer blob of up to `32768` bytes.
     #[wasm_bindgen(skip_jsdoc)]
     pub async fn appCall(
         &self,
         target_string: String,
         request: Box<[u8]>,
+        channel: Option<String>,
     ) -> APIResult<Uint8Array> {
         let request: Vec<u8> = request.into_vec();
+        let channel = channel.map(AppChannel::try_from).transpose()?;
         let routing_context = self.getRoutingContext()?;
 
         let veilid_api = get_veilid_api()?;
         let target = veilid_api.parse_as_target(target_string)?;
-        let answer = routing_context.app_call(target, request).await?;
+        let answer = match channel {
+            Some(channel) => {
+                routing_context
+                    .app_call_on_channel(target, channel, request)
+                    .await?
+            }
+            None => routing_context.app_call(target, request).await?,
+        };
         let answer = Uint8Array::from(answer.as_slice());
         APIResult::Ok(answer)
     }
@@ -281,12 +305,16 @@ impl VeilidRoutingContext {
     ///
     /// Returns `undefined` if the value was successfully put.
     /// Returns a Uint8Array of `data` if the value put was older than the one available on the network.
+    ///
+    /// With `expected_seq`, the write is a compare-and-set that only succeeds if the subkey is still at that
+    /// sequence number, and otherwise fails with a `Conflict` error carrying the value that got there first.
     pub async fn setDhtValue(
         &self,
         key: String,
         subkey: u32,
         data: Box<[u8]>,
         writer: Option<String>,
+        expected_seq: Option<u32>,
     ) -> APIResult<Option<ValueData>> {
         let key = TypedKey::from_str(&key)?;
         let data = data.into_vec();
@@ -296,8 +324,63 @@ impl VeilidRoutingContext {
 
         let routing_context = self.getRoutingContext()?;
         let res = routing_context
-            .set_dht_value(key, subkey, data, writer)
+            .set_dht_value(
+                key,
+                subkey,
+                data,
+                Some(SetDHTValueOptions {
+                    writer,
+                    expected_seq,
+                }),
+            )
+            .await?;
+        APIResult::Ok(res)
+    }
+
+    /// Pushes a value that may be larger than a single subkey to the network
+    ///
+    /// The value is split into chunks written to the subkeys following `subkey`, and a manifest
+    /// holding the length and hash of the value is written to `subkey` itself.
+    ///
+    /// Returns the range of subkeys used, including the manifest subkey.
+    pub async fn setDhtLargeValue(
+        &self,
+        key: String,
+        subkey: u32,
+        data: Box<[u8]>,
+        writer: Option<String>,
+    ) -> APIResult<ValueSubkeyRangeSet> {
+        let key = TypedKey::from_str(&key)?;
+        let data = data.into_vec();
+        let writer = writer
+            .map(|writer| KeyPair::from_str(&writer))
+            .map_or(APIResult::Ok(None), |r| r.map(Some))?;
+
+        let routing_context = self.getRoutingContext()?;
+        let res = routing_context
+            .set_dht_large_value(key, subkey, data, writer)
+            .await?;
+        APIResult::Ok(res)
+    }
+
+    /// Gets a value written with setDhtLargeValue
+    ///
+    /// May pull the latest values from the network, but by settings 'force_refresh' you can force a network data refresh.
+    ///
+    /// Returns `undefined` if the manifest subkey has not yet been set.
+    /// Returns a Uint8Array of the reassembled value otherwise.
+    pub async fn getDhtLargeValue(
+        &self,
+        key: String,
+        subkey: u32,
+        forceRefresh: bool,
+    ) -> APIResult<Option<Uint8Array>> {
+        let key = TypedKey::from_str(&key)?;
+        let routing_context = self.getRoutingContext()?;
+        let res = routing_context
+            .get_dht_large_value(key, subkey, forceRefresh)
             .await?;
+        let res = res.map(|data| Uint8Array::from(data.as_slice()));
         APIResult::Ok(res)
     }
 